@@ -0,0 +1,238 @@
+//! User-defined external extraction adapters, config-driven the way
+//! ripgrep-all declares `poppler`/`pandoc` as adapters instead of hardcoding
+//! them: a TOML file lists named commands, each matched to input files by a
+//! glob, whose stdout is fed into the rest of the CLI pipeline.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Deserialize;
+
+/// Adapter config file, a list of `[[adapter]]` tables.
+#[derive(Debug, Deserialize, Default)]
+pub struct AdapterConfig {
+    #[serde(default)]
+    pub adapter: Vec<Adapter>,
+}
+
+/// A single external adapter declaration.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Adapter {
+    /// Name used to select this adapter with `--adapter <name>`.
+    pub name: String,
+    /// Glob matched against the input file's name (e.g. `"*.pdf"`).
+    /// An adapter with no `glob` and no `mime` matches every input.
+    #[serde(default)]
+    pub glob: Option<String>,
+    /// MIME type matched against the input (currently only
+    /// `"application/pdf"` is recognized, inferred from the extension).
+    #[serde(default)]
+    pub mime: Option<String>,
+    /// Command template, split into argv. `{input}`, `{output}`, and
+    /// `{pages}` placeholders are substituted per invocation.
+    pub command: Vec<String>,
+}
+
+/// Default config path checked when `--adapters` is not passed.
+pub fn default_config_path() -> PathBuf {
+    PathBuf::from(".pdfplumber.toml")
+}
+
+/// Loads an adapter config from `path`.
+///
+/// Returns an empty config (no adapters) if `path` does not exist, so a
+/// missing default `.pdfplumber.toml` is not an error.
+pub fn load_config(path: &Path) -> Result<AdapterConfig, String> {
+    if !path.exists() {
+        return Ok(AdapterConfig::default());
+    }
+
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read adapter config {}: {e}", path.display()))?;
+    toml::from_str(&text)
+        .map_err(|e| format!("failed to parse adapter config {}: {e}", path.display()))
+}
+
+/// Returns `true` if `adapter` matches `input`, by glob, MIME, or (if it
+/// declares neither) unconditionally.
+fn matches(adapter: &Adapter, input: &Path) -> bool {
+    if let Some(glob) = &adapter.glob {
+        let name = input.file_name().and_then(|s| s.to_str()).unwrap_or("");
+        if glob_match(glob, name) {
+            return true;
+        }
+    }
+
+    if let Some(mime) = &adapter.mime {
+        let is_pdf = input.extension().and_then(|e| e.to_str()) == Some("pdf");
+        if mime == "application/pdf" && is_pdf {
+            return true;
+        }
+    }
+
+    adapter.glob.is_none() && adapter.mime.is_none()
+}
+
+/// Matches a single-wildcard glob (`"*.pdf"`, `"report-*"`, or a literal
+/// name with no `*`) against a file name.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => name.starts_with(prefix) && name.ends_with(suffix),
+        None => pattern == name,
+    }
+}
+
+/// Returns the adapters in `config` that match `input`, in declaration order.
+pub fn resolve_adapters<'a>(config: &'a AdapterConfig, input: &Path) -> Vec<&'a Adapter> {
+    config.adapter.iter().filter(|a| matches(a, input)).collect()
+}
+
+/// Finds the adapter named `name` among those matching `input`.
+pub fn find_adapter<'a>(config: &'a AdapterConfig, input: &Path, name: &str) -> Option<&'a Adapter> {
+    resolve_adapters(config, input)
+        .into_iter()
+        .find(|a| a.name == name)
+}
+
+/// Expands `{input}`/`{output}`/`{pages}` placeholders in an adapter's
+/// command template.
+fn build_argv(adapter: &Adapter, input: &Path, output: Option<&Path>, pages: Option<&str>) -> Vec<String> {
+    let input_str = input.display().to_string();
+    let output_str = output.map(|o| o.display().to_string()).unwrap_or_default();
+    let pages_str = pages.unwrap_or_default();
+
+    adapter
+        .command
+        .iter()
+        .map(|part| {
+            part.replace("{input}", &input_str)
+                .replace("{output}", &output_str)
+                .replace("{pages}", pages_str)
+        })
+        .collect()
+}
+
+/// Runs `adapter` against `input`, returning its captured stdout as text.
+pub fn run_adapter(
+    adapter: &Adapter,
+    input: &Path,
+    output: Option<&Path>,
+    pages: Option<&str>,
+) -> Result<String, String> {
+    let argv = build_argv(adapter, input, output, pages);
+    let (program, args) = argv
+        .split_first()
+        .ok_or_else(|| format!("adapter '{}' has an empty command template", adapter.name))?;
+
+    let result = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("failed to run adapter '{}': {e}", adapter.name))?;
+
+    if !result.status.success() {
+        return Err(format!(
+            "adapter '{}' exited with {}: {}",
+            adapter.name,
+            result.status,
+            String::from_utf8_lossy(&result.stderr)
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&result.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_match_wildcard_suffix() {
+        assert!(glob_match("*.pdf", "report.pdf"));
+        assert!(!glob_match("*.pdf", "report.docx"));
+    }
+
+    #[test]
+    fn glob_match_literal() {
+        assert!(glob_match("report.pdf", "report.pdf"));
+        assert!(!glob_match("report.pdf", "other.pdf"));
+    }
+
+    #[test]
+    fn adapter_with_no_match_rule_matches_everything() {
+        let adapter = Adapter {
+            name: "catch-all".to_string(),
+            glob: None,
+            mime: None,
+            command: vec!["cat".to_string(), "{input}".to_string()],
+        };
+        assert!(matches(&adapter, Path::new("anything.xyz")));
+    }
+
+    #[test]
+    fn resolve_adapters_filters_by_glob() {
+        let config = AdapterConfig {
+            adapter: vec![
+                Adapter {
+                    name: "pdf-only".to_string(),
+                    glob: Some("*.pdf".to_string()),
+                    mime: None,
+                    command: vec!["true".to_string()],
+                },
+                Adapter {
+                    name: "docx-only".to_string(),
+                    glob: Some("*.docx".to_string()),
+                    mime: None,
+                    command: vec!["true".to_string()],
+                },
+            ],
+        };
+        let matched = resolve_adapters(&config, Path::new("report.pdf"));
+        assert_eq!(matched.len(), 1);
+        assert_eq!(matched[0].name, "pdf-only");
+    }
+
+    #[test]
+    fn build_argv_substitutes_placeholders() {
+        let adapter = Adapter {
+            name: "echo".to_string(),
+            glob: None,
+            mime: None,
+            command: vec![
+                "mytool".to_string(),
+                "{input}".to_string(),
+                "--pages".to_string(),
+                "{pages}".to_string(),
+            ],
+        };
+        let argv = build_argv(&adapter, Path::new("in.pdf"), None, Some("1-3"));
+        assert_eq!(argv, vec!["mytool", "in.pdf", "--pages", "1-3"]);
+    }
+
+    #[test]
+    fn load_config_missing_file_returns_empty() {
+        let config = load_config(Path::new("/nonexistent/.pdfplumber.toml")).unwrap();
+        assert!(config.adapter.is_empty());
+    }
+
+    #[test]
+    fn load_config_parses_toml() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("pdfplumber-test-adapters-{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"
+            [[adapter]]
+            name = "ocr-figures"
+            glob = "*.pdf"
+            command = ["figuretool", "{input}"]
+            "#,
+        )
+        .unwrap();
+
+        let config = load_config(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(config.adapter.len(), 1);
+        assert_eq!(config.adapter[0].name, "ocr-figures");
+    }
+}
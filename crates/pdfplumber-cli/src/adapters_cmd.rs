@@ -0,0 +1,27 @@
+use std::path::{Path, PathBuf};
+
+use crate::adapter::{default_config_path, load_config, resolve_adapters};
+
+/// Enumerates adapters from the resolved config that match `file`.
+pub fn list(file: &Path, adapters: Option<&Path>) -> Result<(), i32> {
+    let config_path: PathBuf = adapters
+        .map(Path::to_path_buf)
+        .unwrap_or_else(default_config_path);
+
+    let config = load_config(&config_path).map_err(|e| {
+        eprintln!("Error: {e}");
+        1
+    })?;
+
+    let matched = resolve_adapters(&config, file);
+    if matched.is_empty() {
+        println!("No adapters match {}", file.display());
+        return Ok(());
+    }
+
+    for adapter in matched {
+        println!("{}\t{}", adapter.name, adapter.command.join(" "));
+    }
+
+    Ok(())
+}
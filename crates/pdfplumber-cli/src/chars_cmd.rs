@@ -1,78 +1,109 @@
 use std::path::Path;
 
-use pdfplumber::{Char, Pdf, TextDirection};
+use pdfplumber::{BBox, Char, Pdf, TextDirection, UnicodeNorm, normalize_chars};
 
 use crate::cli::OutputFormat;
-use crate::page_range::parse_page_range;
-
-pub fn run(file: &Path, pages: Option<&str>, format: &OutputFormat) -> Result<(), i32> {
-    let pdf = open_pdf(file)?;
+use crate::ocr::{OcrOptions, ocr_page_if_needed};
+use crate::shared::{direction_str, open_pdf_maybe_repair, resolve_pages};
+
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    file: &Path,
+    pages: Option<&str>,
+    format: &OutputFormat,
+    unicode_norm: Option<UnicodeNorm>,
+    password: Option<&str>,
+    repair: bool,
+    ocr: bool,
+    ocr_threshold: usize,
+    ocr_dpi: f64,
+) -> Result<(), i32> {
+    let pdf: Pdf = open_pdf_maybe_repair(file, None, password, repair)?;
     let page_indices = resolve_pages(pages, pdf.page_count())?;
+    let ocr_options = OcrOptions {
+        threshold: ocr_threshold,
+        dpi: ocr_dpi,
+    };
 
-    match format {
-        OutputFormat::Text => write_text(&pdf, &page_indices),
-        OutputFormat::Json => write_json(&pdf, &page_indices),
-        OutputFormat::Csv => write_csv(&pdf, &page_indices),
-    }
-}
+    let mut all_chars: Vec<(usize, Char)> = Vec::new();
+    for &idx in &page_indices {
+        let page = pdf.page(idx).map_err(|e| {
+            eprintln!("Error reading page {}: {e}", idx + 1);
+            1
+        })?;
 
-fn open_pdf(file: &Path) -> Result<Pdf, i32> {
-    if !file.exists() {
-        eprintln!("Error: file not found: {}", file.display());
-        return Err(1);
-    }
+        let mut chars = if ocr {
+            match ocr_page_if_needed(&page, &ocr_options).map_err(|e| {
+                eprintln!("Error: {e}");
+                1
+            })? {
+                Some(words) => words.iter().map(ocr_word_to_char).collect(),
+                None => page.chars().to_vec(),
+            }
+        } else {
+            page.chars().to_vec()
+        };
+
+        if let Some(norm) = unicode_norm {
+            chars = normalize_chars(&chars, &norm);
+        }
 
-    Pdf::open_file(file, None).map_err(|e| {
-        eprintln!("Error: failed to open PDF: {e}");
-        1
-    })
-}
+        all_chars.extend(chars.into_iter().map(|ch| (idx + 1, ch)));
+    }
 
-fn resolve_pages(pages: Option<&str>, page_count: usize) -> Result<Vec<usize>, i32> {
-    match pages {
-        Some(range) => parse_page_range(range, page_count).map_err(|e| {
-            eprintln!("Error: {e}");
-            1
-        }),
-        None => Ok((0..page_count).collect()),
+    match format {
+        OutputFormat::Text => write_text(&all_chars),
+        OutputFormat::Json => write_json(&all_chars),
+        OutputFormat::Csv => write_csv(&all_chars),
     }
 }
 
-fn direction_str(dir: &TextDirection) -> &'static str {
-    match dir {
-        TextDirection::Ltr => "ltr",
-        TextDirection::Rtl => "rtl",
-        TextDirection::Ttb => "ttb",
-        TextDirection::Btt => "btt",
+/// Synthesizes a pseudo-[`Char`] spanning an OCR'd word's bounding box.
+///
+/// Tesseract only reports word-level boxes, so there is no true per-character
+/// geometry to report; downstream consumers of `chars --ocr` get one record
+/// per word rather than per glyph.
+fn ocr_word_to_char(word: &crate::ocr::OcrWord) -> Char {
+    Char {
+        text: word.text.clone(),
+        bbox: BBox {
+            x0: word.x0,
+            top: word.top,
+            x1: word.x1,
+            bottom: word.bottom,
+        },
+        fontname: "OCR".to_string(),
+        size: word.bottom - word.top,
+        doctop: word.top,
+        upright: true,
+        direction: TextDirection::Ltr,
+        stroking_color: None,
+        non_stroking_color: None,
+        ctm: [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+        char_code: 0,
+        mcid: None,
+        tag: None,
     }
 }
 
-fn write_text(pdf: &Pdf, page_indices: &[usize]) -> Result<(), i32> {
+fn write_text(chars: &[(usize, Char)]) -> Result<(), i32> {
     println!("page\ttext\tx0\ttop\tx1\tbottom\tfontname\tsize\tdoctop\tupright\tdirection");
 
-    for &idx in page_indices {
-        let page = pdf.page(idx).map_err(|e| {
-            eprintln!("Error reading page {}: {e}", idx + 1);
-            1
-        })?;
-
-        let chars = page.chars();
-        for ch in chars {
-            println!(
-                "{}\t{}\t{:.2}\t{:.2}\t{:.2}\t{:.2}\t{}\t{:.2}\t{:.2}\t{}\t{}",
-                idx + 1,
-                ch.text,
-                ch.bbox.x0,
-                ch.bbox.top,
-                ch.bbox.x1,
-                ch.bbox.bottom,
-                ch.fontname,
-                ch.size,
-                ch.doctop,
-                ch.upright,
-                direction_str(&ch.direction),
-            );
-        }
+    for (page_num, ch) in chars {
+        println!(
+            "{}\t{}\t{:.2}\t{:.2}\t{:.2}\t{:.2}\t{}\t{:.2}\t{:.2}\t{}\t{}",
+            page_num,
+            ch.text,
+            ch.bbox.x0,
+            ch.bbox.top,
+            ch.bbox.x1,
+            ch.bbox.bottom,
+            ch.fontname,
+            ch.size,
+            ch.doctop,
+            ch.upright,
+            direction_str(&ch.direction),
+        );
     }
 
     Ok(())
@@ -94,50 +125,33 @@ fn char_to_json(ch: &Char, page_num: usize) -> serde_json::Value {
     })
 }
 
-fn write_json(pdf: &Pdf, page_indices: &[usize]) -> Result<(), i32> {
-    let mut all_chars = Vec::new();
+fn write_json(chars: &[(usize, Char)]) -> Result<(), i32> {
+    let all: Vec<_> = chars
+        .iter()
+        .map(|(page_num, ch)| char_to_json(ch, *page_num))
+        .collect();
 
-    for &idx in page_indices {
-        let page = pdf.page(idx).map_err(|e| {
-            eprintln!("Error reading page {}: {e}", idx + 1);
-            1
-        })?;
-
-        let chars = page.chars();
-        for ch in chars {
-            all_chars.push(char_to_json(ch, idx + 1));
-        }
-    }
-
-    let json_str = serde_json::to_string(&all_chars).unwrap();
+    let json_str = serde_json::to_string(&all).unwrap();
     println!("{json_str}");
 
     Ok(())
 }
 
-fn write_csv(pdf: &Pdf, page_indices: &[usize]) -> Result<(), i32> {
+fn write_csv(chars: &[(usize, Char)]) -> Result<(), i32> {
     println!("page,text,x0,top,x1,bottom,fontname,size");
 
-    for &idx in page_indices {
-        let page = pdf.page(idx).map_err(|e| {
-            eprintln!("Error reading page {}: {e}", idx + 1);
-            1
-        })?;
-
-        let chars = page.chars();
-        for ch in chars {
-            println!(
-                "{},{},{:.2},{:.2},{:.2},{:.2},{},{:.2}",
-                idx + 1,
-                ch.text,
-                ch.bbox.x0,
-                ch.bbox.top,
-                ch.bbox.x1,
-                ch.bbox.bottom,
-                ch.fontname,
-                ch.size,
-            );
-        }
+    for (page_num, ch) in chars {
+        println!(
+            "{},{},{:.2},{:.2},{:.2},{:.2},{},{:.2}",
+            page_num,
+            ch.text,
+            ch.bbox.x0,
+            ch.bbox.top,
+            ch.bbox.x1,
+            ch.bbox.bottom,
+            ch.fontname,
+            ch.size,
+        );
     }
 
     Ok(())
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use clap::{Parser, Subcommand, ValueEnum};
 
@@ -42,6 +42,27 @@ pub enum Commands {
         /// Attempt best-effort repair before extraction
         #[arg(long)]
         repair: bool,
+
+        /// OCR pages with no extractable text via `tesseract`
+        #[arg(long)]
+        ocr: bool,
+
+        /// OCR pages with this many characters or fewer (default: 0)
+        #[arg(long, default_value_t = 0)]
+        ocr_threshold: usize,
+
+        /// Rasterization DPI used for the `--ocr` fallback (default: 300)
+        #[arg(long, default_value_t = 300.0)]
+        ocr_dpi: f64,
+
+        /// Run a named external adapter (see `pdfplumber adapters list`)
+        /// instead of the native extractor, feeding its stdout as text
+        #[arg(long)]
+        adapter: Option<String>,
+
+        /// Adapter config file (default: `.pdfplumber.toml`)
+        #[arg(long)]
+        adapters: Option<PathBuf>,
     },
 
     /// Extract individual characters with coordinates
@@ -69,6 +90,18 @@ pub enum Commands {
         /// Attempt best-effort repair before extraction
         #[arg(long)]
         repair: bool,
+
+        /// OCR pages with no extractable text via `tesseract`
+        #[arg(long)]
+        ocr: bool,
+
+        /// OCR pages with this many characters or fewer (default: 0)
+        #[arg(long, default_value_t = 0)]
+        ocr_threshold: usize,
+
+        /// Rasterization DPI used for the `--ocr` fallback (default: 300)
+        #[arg(long, default_value_t = 300.0)]
+        ocr_dpi: f64,
     },
 
     /// Extract words with bounding box coordinates
@@ -117,8 +150,8 @@ pub enum Commands {
         pages: Option<String>,
 
         /// Output format
-        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
-        format: OutputFormat,
+        #[arg(long, value_enum, default_value_t = TableFormat::Text)]
+        format: TableFormat,
 
         /// Table detection strategy
         #[arg(long, value_enum, default_value_t = TableStrategy::Lattice)]
@@ -136,6 +169,11 @@ pub enum Commands {
         #[arg(long, default_value_t = 3.0)]
         text_tolerance: f64,
 
+        /// Write one file per table here instead of concatenating to stdout
+        /// (`csv`/`md` formats only), named `table_{page}_{index}.{ext}`
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+
         /// Password for encrypted PDFs
         #[arg(long)]
         password: Option<String>,
@@ -274,6 +312,39 @@ pub enum Commands {
         #[arg(long)]
         tables: bool,
 
+        /// Detect repeating headers/footers across the document and render
+        /// only the body region, excluding the detected boilerplate (has no
+        /// effect together with `--tables`)
+        #[arg(long)]
+        strip_boilerplate: bool,
+
+        /// Password for encrypted PDFs
+        #[arg(long)]
+        password: Option<String>,
+
+        /// Attempt best-effort repair before extraction
+        #[arg(long)]
+        repair: bool,
+    },
+
+    /// Render a page to a PNG raster image for visual debugging
+    Render {
+        /// Path to the PDF file
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// Page range (e.g. '1,3-5'). Default: all pages
+        #[arg(long)]
+        pages: Option<String>,
+
+        /// Output PNG file path
+        #[arg(long, value_name = "FILE")]
+        output: PathBuf,
+
+        /// Scale factor mapping page points to pixels (default: 2.0)
+        #[arg(long, default_value_t = 2.0)]
+        scale: f64,
+
         /// Password for encrypted PDFs
         #[arg(long)]
         password: Option<String>,
@@ -309,6 +380,12 @@ pub enum Commands {
         #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
         format: OutputFormat,
 
+        /// Number of surrounding words to include as context on each side of
+        /// a match (text/csv formats ignore this; json reports it as
+        /// context_before/context_after)
+        #[arg(short = 'C', long, default_value_t = 0)]
+        context: usize,
+
         /// Password for encrypted PDFs
         #[arg(long)]
         password: Option<String>,
@@ -363,6 +440,45 @@ pub enum Commands {
         #[arg(long)]
         password: Option<String>,
     },
+
+    /// Manage user-defined external extraction adapters
+    Adapters {
+        #[command(subcommand)]
+        action: AdaptersCommand,
+    },
+
+    /// Print the JSON Schema for a `--format json` output shape
+    Schema {
+        /// Which output shape to describe
+        #[arg(value_enum)]
+        kind: SchemaKind,
+    },
+}
+
+/// Output record kinds with a published JSON Schema.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum SchemaKind {
+    /// Shape of `chars --format json` records
+    Chars,
+    /// Shape of `words --format json` records
+    Words,
+    /// Shape of `tables --format json` records
+    Tables,
+}
+
+/// Subcommands of `pdfplumber adapters`.
+#[derive(Debug, Subcommand)]
+pub enum AdaptersCommand {
+    /// List adapters that match a given input file
+    List {
+        /// Path to the input file to match adapters against
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// Adapter config file (default: `.pdfplumber.toml`)
+        #[arg(long)]
+        adapters: Option<PathBuf>,
+    },
 }
 
 /// Table detection strategy.
@@ -374,6 +490,19 @@ pub enum TableStrategy {
     Stream,
 }
 
+/// Output format for the `tables` subcommand.
+#[derive(Debug, Clone, ValueEnum)]
+pub enum TableFormat {
+    /// Aligned text grid, one block per table
+    Text,
+    /// JSON array of table records
+    Json,
+    /// RFC-4180 CSV, one table per blank-line-separated block (or file, with `--out-dir`)
+    Csv,
+    /// GitHub-flavored Markdown pipe tables
+    Md,
+}
+
 /// Output format for text subcommand.
 #[derive(Debug, Clone, ValueEnum)]
 pub enum TextFormat {
@@ -601,7 +730,7 @@ mod tests {
             } => {
                 assert_eq!(file, &PathBuf::from("doc.pdf"));
                 assert_eq!(pages.as_deref(), Some("2-4"));
-                assert!(matches!(format, OutputFormat::Json));
+                assert!(matches!(format, TableFormat::Json));
                 assert!(matches!(strategy, TableStrategy::Stream));
                 assert!((snap_tolerance - 5.0).abs() < f64::EPSILON);
                 assert!((join_tolerance - 4.0).abs() < f64::EPSILON);
@@ -860,6 +989,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn search_default_context_is_zero() {
+        let cli = Cli::parse_from(["pdfplumber", "search", "test.pdf", "query"]);
+        match cli.command {
+            Commands::Search { context, .. } => {
+                assert_eq!(context, 0);
+            }
+            _ => panic!("expected Search subcommand"),
+        }
+    }
+
+    #[test]
+    fn parse_search_with_context_flag() {
+        let cli = Cli::parse_from(["pdfplumber", "search", "test.pdf", "query", "-C", "3"]);
+        match cli.command {
+            Commands::Search { context, .. } => {
+                assert_eq!(context, 3);
+            }
+            _ => panic!("expected Search subcommand"),
+        }
+    }
+
     #[test]
     fn parse_text_with_unicode_norm_nfc() {
         let cli = Cli::parse_from(["pdfplumber", "text", "test.pdf", "--unicode-norm", "nfc"]);
@@ -899,6 +1050,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_text_with_ocr_flag_and_threshold() {
+        let cli = Cli::parse_from([
+            "pdfplumber",
+            "text",
+            "test.pdf",
+            "--ocr",
+            "--ocr-threshold",
+            "5",
+            "--ocr-dpi",
+            "150",
+        ]);
+        match cli.command {
+            Commands::Text {
+                ocr,
+                ocr_threshold,
+                ocr_dpi,
+                ..
+            } => {
+                assert!(ocr);
+                assert_eq!(ocr_threshold, 5);
+                assert_eq!(ocr_dpi, 150.0);
+            }
+            _ => panic!("expected Text subcommand"),
+        }
+    }
+
+    #[test]
+    fn parse_chars_without_ocr_defaults() {
+        let cli = Cli::parse_from(["pdfplumber", "chars", "test.pdf"]);
+        match cli.command {
+            Commands::Chars {
+                ocr,
+                ocr_threshold,
+                ocr_dpi,
+                ..
+            } => {
+                assert!(!ocr);
+                assert_eq!(ocr_threshold, 0);
+                assert_eq!(ocr_dpi, 300.0);
+            }
+            _ => panic!("expected Chars subcommand"),
+        }
+    }
+
     #[test]
     fn parse_words_with_unicode_norm_nfkd() {
         let cli = Cli::parse_from(["pdfplumber", "words", "test.pdf", "--unicode-norm", "nfkd"]);
@@ -932,6 +1128,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_render_subcommand() {
+        let cli = Cli::parse_from(["pdfplumber", "render", "test.pdf", "--output", "out.png"]);
+        match cli.command {
+            Commands::Render {
+                ref file,
+                ref pages,
+                ref output,
+                scale,
+                ..
+            } => {
+                assert_eq!(file, &PathBuf::from("test.pdf"));
+                assert!(pages.is_none());
+                assert_eq!(output, &PathBuf::from("out.png"));
+                assert_eq!(scale, 2.0);
+            }
+            _ => panic!("expected Render subcommand"),
+        }
+    }
+
+    #[test]
+    fn parse_render_with_scale() {
+        let cli = Cli::parse_from([
+            "pdfplumber",
+            "render",
+            "test.pdf",
+            "--output",
+            "out.png",
+            "--scale",
+            "3.5",
+        ]);
+        match cli.command {
+            Commands::Render { scale, .. } => {
+                assert_eq!(scale, 3.5);
+            }
+            _ => panic!("expected Render subcommand"),
+        }
+    }
+
     #[test]
     fn parse_debug_with_tables_flag() {
         let cli = Cli::parse_from([
@@ -1283,4 +1518,103 @@ mod tests {
             _ => panic!("expected Tables subcommand"),
         }
     }
+
+    #[test]
+    fn parse_tables_with_md_format() {
+        let cli = Cli::parse_from(["pdfplumber", "tables", "test.pdf", "--format", "md"]);
+        match cli.command {
+            Commands::Tables { ref format, .. } => {
+                assert!(matches!(format, TableFormat::Md));
+            }
+            _ => panic!("expected Tables subcommand"),
+        }
+    }
+
+    #[test]
+    fn parse_tables_with_out_dir() {
+        let cli = Cli::parse_from([
+            "pdfplumber",
+            "tables",
+            "test.pdf",
+            "--format",
+            "csv",
+            "--out-dir",
+            "out",
+        ]);
+        match cli.command {
+            Commands::Tables { ref out_dir, .. } => {
+                assert_eq!(out_dir.as_deref(), Some(Path::new("out")));
+            }
+            _ => panic!("expected Tables subcommand"),
+        }
+    }
+
+    #[test]
+    fn parse_adapters_list_subcommand() {
+        let cli = Cli::parse_from(["pdfplumber", "adapters", "list", "test.pdf"]);
+        match cli.command {
+            Commands::Adapters {
+                action: AdaptersCommand::List { ref file, ref adapters },
+            } => {
+                assert_eq!(file, &PathBuf::from("test.pdf"));
+                assert!(adapters.is_none());
+            }
+            _ => panic!("expected Adapters List subcommand"),
+        }
+    }
+
+    #[test]
+    fn parse_adapters_list_with_config_file() {
+        let cli = Cli::parse_from([
+            "pdfplumber",
+            "adapters",
+            "list",
+            "test.pdf",
+            "--adapters",
+            "custom.toml",
+        ]);
+        match cli.command {
+            Commands::Adapters {
+                action: AdaptersCommand::List { ref adapters, .. },
+            } => {
+                assert_eq!(adapters.as_deref(), Some(Path::new("custom.toml")));
+            }
+            _ => panic!("expected Adapters List subcommand"),
+        }
+    }
+
+    #[test]
+    fn parse_schema_subcommand() {
+        let cli = Cli::parse_from(["pdfplumber", "schema", "chars"]);
+        match cli.command {
+            Commands::Schema { kind: SchemaKind::Chars } => {}
+            _ => panic!("expected Schema Chars subcommand"),
+        }
+    }
+
+    #[test]
+    fn parse_schema_tables_kind() {
+        let cli = Cli::parse_from(["pdfplumber", "schema", "tables"]);
+        match cli.command {
+            Commands::Schema { kind: SchemaKind::Tables } => {}
+            _ => panic!("expected Schema Tables subcommand"),
+        }
+    }
+
+    #[test]
+    fn parse_text_with_adapter_flag() {
+        let cli = Cli::parse_from([
+            "pdfplumber",
+            "text",
+            "test.pdf",
+            "--adapter",
+            "ocr-figures",
+        ]);
+        match cli.command {
+            Commands::Text { ref adapter, .. } => {
+                assert_eq!(adapter.as_deref(), Some("ocr-figures"));
+            }
+            _ => panic!("expected Text subcommand"),
+        }
+    }
 }
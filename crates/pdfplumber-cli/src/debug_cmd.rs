@@ -1,7 +1,10 @@
 use std::fs;
 use std::path::Path;
 
-use pdfplumber::{DrawStyle, SvgDebugOptions, SvgOptions, SvgRenderer, TableSettings};
+use pdfplumber::{
+    DrawStyle, PageRegionOptions, PageRegions, SvgDebugOptions, SvgOptions, SvgRenderer,
+    StripRegionOptions, TableSettings, strip_chars, strip_edges, strip_lines, strip_rects,
+};
 
 use crate::shared::{open_pdf_maybe_repair, resolve_pages};
 
@@ -10,12 +13,25 @@ pub fn run(
     pages: Option<&str>,
     output: &Path,
     tables: bool,
+    strip_boilerplate: bool,
     password: Option<&str>,
     repair: bool,
 ) -> Result<(), i32> {
     let pdf = open_pdf_maybe_repair(file, None, password, repair)?;
     let page_indices = resolve_pages(pages, pdf.page_count())?;
 
+    let page_regions: Option<Vec<PageRegions>> = if strip_boilerplate {
+        Some(
+            pdf.detect_page_regions_with_bounds(&PageRegionOptions::default())
+                .map_err(|e| {
+                    eprintln!("Error detecting page regions: {e}");
+                    1
+                })?,
+        )
+    } else {
+        None
+    };
+
     // Generate SVG for each page; if multiple pages, append page number to filename
     let multi_page = page_indices.len() > 1;
     let stem = output
@@ -31,21 +47,43 @@ pub fn run(
             1
         })?;
 
+        let regions = page_regions.as_ref().map(|r| &r[idx]);
+
         let svg = if tables {
             // Table detection debug mode: show pipeline stages
             page.debug_tablefinder_svg(&TableSettings::default(), &SvgDebugOptions::default())
         } else {
             // Standard debug mode: show all extracted objects
             let mut renderer = SvgRenderer::new(page.width(), page.height());
+            let edges = page.edges();
 
-            renderer.draw_chars(page.chars(), &DrawStyle::chars_default());
-            renderer.draw_lines(page.lines(), &DrawStyle::lines_default());
-            renderer.draw_rects(page.rects(), &DrawStyle::rects_default());
+            let (chars, lines, rects, edges, found_tables) = match regions {
+                Some(regions) => {
+                    let strip_options = StripRegionOptions::default();
+                    let chars = strip_chars(page.chars(), regions, &strip_options).kept;
+                    let lines = strip_lines(page.lines(), regions, &strip_options).kept;
+                    let rects = strip_rects(page.rects(), regions, &strip_options).kept;
+                    let edges = strip_edges(&edges, regions, &strip_options).kept;
+                    let found_tables = page.find_tables_excluding_regions(
+                        &TableSettings::default(),
+                        regions,
+                        &strip_options,
+                    );
+                    (chars, lines, rects, edges, found_tables)
+                }
+                None => (
+                    page.chars().to_vec(),
+                    page.lines().to_vec(),
+                    page.rects().to_vec(),
+                    edges,
+                    page.find_tables(&TableSettings::default()),
+                ),
+            };
 
-            let edges = page.edges();
+            renderer.draw_chars(&chars, &DrawStyle::chars_default());
+            renderer.draw_lines(&lines, &DrawStyle::lines_default());
+            renderer.draw_rects(&rects, &DrawStyle::rects_default());
             renderer.draw_edges(&edges, &DrawStyle::edges_default());
-
-            let found_tables = page.find_tables(&TableSettings::default());
             renderer.draw_tables(&found_tables, &DrawStyle::tables_default());
 
             renderer.to_svg(&SvgOptions::default())
@@ -134,6 +134,9 @@ pub fn run(
                 if let Some(ref v) = metadata.mod_date {
                     println!("  ModDate: {v}");
                 }
+                if let Some(ref v) = metadata.trapped {
+                    println!("  Trapped: {v}");
+                }
             }
             println!();
             println!("Pages: {page_count}");
@@ -168,6 +171,9 @@ pub fn run(
             if let Some(ref v) = metadata.mod_date {
                 metadata_json.insert("mod_date".to_string(), serde_json::json!(v));
             }
+            if let Some(ref v) = metadata.trapped {
+                metadata_json.insert("trapped".to_string(), serde_json::json!(v));
+            }
 
             let output = serde_json::json!({
                 "metadata": metadata_json,
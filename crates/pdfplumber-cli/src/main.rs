@@ -1,3 +1,5 @@
+mod adapter;
+mod adapters_cmd;
 mod annots_cmd;
 mod bookmarks_cmd;
 mod chars_cmd;
@@ -7,7 +9,10 @@ mod forms_cmd;
 mod images_cmd;
 mod info_cmd;
 mod links_cmd;
+mod ocr;
 mod page_range;
+mod render_cmd;
+mod schema;
 mod search_cmd;
 mod shared;
 mod tables_cmd;
@@ -30,6 +35,11 @@ fn main() {
             ref unicode_norm,
             ref password,
             repair,
+            ocr,
+            ocr_threshold,
+            ocr_dpi,
+            ref adapter,
+            ref adapters,
         } => text_cmd::run(
             file,
             pages.as_deref(),
@@ -38,6 +48,11 @@ fn main() {
             unicode_norm.as_ref().map(|n| n.to_unicode_norm()),
             password.as_deref(),
             repair,
+            ocr,
+            ocr_threshold,
+            ocr_dpi,
+            adapter.as_deref(),
+            adapters.as_deref(),
         ),
         cli::Commands::Chars {
             ref file,
@@ -46,6 +61,9 @@ fn main() {
             ref unicode_norm,
             ref password,
             repair,
+            ocr,
+            ocr_threshold,
+            ocr_dpi,
         } => chars_cmd::run(
             file,
             pages.as_deref(),
@@ -53,6 +71,9 @@ fn main() {
             unicode_norm.as_ref().map(|n| n.to_unicode_norm()),
             password.as_deref(),
             repair,
+            ocr,
+            ocr_threshold,
+            ocr_dpi,
         ),
         cli::Commands::Words {
             ref file,
@@ -81,6 +102,7 @@ fn main() {
             snap_tolerance,
             join_tolerance,
             text_tolerance,
+            ref out_dir,
             ref password,
             repair,
         } => tables_cmd::run(
@@ -91,6 +113,7 @@ fn main() {
             snap_tolerance,
             join_tolerance,
             text_tolerance,
+            out_dir.as_deref(),
             password.as_deref(),
             repair,
         ),
@@ -133,6 +156,7 @@ fn main() {
             ref pages,
             ref output,
             tables,
+            strip_boilerplate,
             ref password,
             repair,
         } => debug_cmd::run(
@@ -140,6 +164,22 @@ fn main() {
             pages.as_deref(),
             output,
             tables,
+            strip_boilerplate,
+            password.as_deref(),
+            repair,
+        ),
+        cli::Commands::Render {
+            ref file,
+            ref pages,
+            ref output,
+            scale,
+            ref password,
+            repair,
+        } => render_cmd::run(
+            file,
+            pages.as_deref(),
+            output,
+            scale,
             password.as_deref(),
             repair,
         ),
@@ -150,6 +190,7 @@ fn main() {
             case_insensitive,
             no_regex,
             ref format,
+            context,
             ref password,
             repair,
         } => search_cmd::run(
@@ -159,6 +200,7 @@ fn main() {
             case_insensitive,
             no_regex,
             format,
+            context,
             password.as_deref(),
             repair,
         ),
@@ -184,6 +226,15 @@ fn main() {
             ref format,
             ref password,
         } => validate_cmd::run(file, format, password.as_deref()),
+        cli::Commands::Adapters { ref action } => match action {
+            cli::AdaptersCommand::List { ref file, ref adapters } => {
+                adapters_cmd::list(file, adapters.as_deref())
+            }
+        },
+        cli::Commands::Schema { ref kind } => {
+            schema::print_schema(kind);
+            Ok(())
+        }
     };
 
     if let Err(code) = result {
@@ -0,0 +1,167 @@
+//! Optional OCR fallback for pages with no extractable text.
+//!
+//! Scanned pages carry no character objects, so the native extractor
+//! returns nothing for them. When `--ocr` is passed to the `text` or
+//! `chars` subcommands, pages at or below `--ocr-threshold` characters are
+//! rasterized via [`pdfplumber::Page::render`] and piped through `tesseract`
+//! (mirroring how tools like ripgrep-all shell out to `tesseract` as an
+//! extraction adapter), producing word boxes in image-pixel space that are
+//! mapped back into PDF points. Pages that already have real text are left
+//! untouched and never re-OCR'd.
+
+use std::path::Path;
+use std::process::Command;
+
+use pdfplumber::Page;
+
+/// Controls when and how OCR fallback kicks in.
+#[derive(Debug, Clone, Copy)]
+pub struct OcrOptions {
+    /// Pages with this many characters or fewer are OCR'd.
+    pub threshold: usize,
+    /// Rasterization DPI; image-pixel coordinates are divided by `dpi / 72.0`
+    /// to recover PDF points.
+    pub dpi: f64,
+}
+
+impl Default for OcrOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 0,
+            dpi: 300.0,
+        }
+    }
+}
+
+/// A single OCR'd word, in the same `x0,x1,top,bottom,text` shape as the
+/// native extractor's word records.
+#[derive(Debug, Clone)]
+pub struct OcrWord {
+    pub text: String,
+    pub x0: f64,
+    pub top: f64,
+    pub x1: f64,
+    pub bottom: f64,
+}
+
+/// Runs OCR over `page` if its character count is at or below
+/// `opts.threshold`, returning `None` for pages with real text so callers
+/// can fall back to the native extractor untouched.
+pub fn ocr_page_if_needed(page: &Page, opts: &OcrOptions) -> Result<Option<Vec<OcrWord>>, String> {
+    if page.chars().len() > opts.threshold {
+        return Ok(None);
+    }
+
+    let scale = opts.dpi / 72.0;
+    let bitmap = page.render(scale).map_err(|e| format!("failed to rasterize page for OCR: {e}"))?;
+
+    let image_file = tempfile::Builder::new()
+        .suffix(".png")
+        .tempfile()
+        .map_err(|e| format!("failed to create temp file for OCR: {e}"))?;
+    bitmap
+        .save_png(image_file.path())
+        .map_err(|e| format!("failed to rasterize page for OCR: {e}"))?;
+
+    let words = run_tesseract(image_file.path(), opts.dpi)?;
+    Ok(Some(words))
+}
+
+/// Shells out to `tesseract <image> stdout --psm 6 tsv` and parses its
+/// word-level TSV output into PDF-space [`OcrWord`] records.
+fn run_tesseract(image_path: &Path, dpi: f64) -> Result<Vec<OcrWord>, String> {
+    let output = Command::new("tesseract")
+        .arg(image_path)
+        .arg("stdout")
+        .arg("--psm")
+        .arg("6")
+        .arg("tsv")
+        .output()
+        .map_err(|e| format!("failed to run `tesseract` (is it installed?): {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "tesseract exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let tsv = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_tesseract_tsv(&tsv, dpi))
+}
+
+/// Parses `tesseract ... tsv` output into PDF-space word boxes.
+///
+/// Tesseract's TSV columns are `level, page_num, block_num, par_num,
+/// line_num, word_num, left, top, width, height, conf, text`, in image
+/// pixels measured from the top-left corner, the same origin this crate's
+/// bboxes use — only the scale (`72/dpi`) needs correcting, no y-flip.
+fn parse_tesseract_tsv(tsv: &str, dpi: f64) -> Vec<OcrWord> {
+    let px_to_pt = 72.0 / dpi;
+    let mut words = Vec::new();
+
+    for line in tsv.lines().skip(1) {
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() < 12 {
+            continue;
+        }
+        let text = fields[11].trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        let (Ok(left), Ok(top), Ok(width), Ok(height)) = (
+            fields[6].parse::<f64>(),
+            fields[7].parse::<f64>(),
+            fields[8].parse::<f64>(),
+            fields[9].parse::<f64>(),
+        ) else {
+            continue;
+        };
+
+        words.push(OcrWord {
+            text: text.to_string(),
+            x0: left * px_to_pt,
+            top: top * px_to_pt,
+            x1: (left + width) * px_to_pt,
+            bottom: (top + height) * px_to_pt,
+        });
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tesseract_tsv_skips_header_and_empty_text() {
+        let tsv = "level\tpage_num\tblock_num\tpar_num\tline_num\tword_num\tleft\ttop\twidth\theight\tconf\ttext\n\
+                    5\t1\t1\t1\t1\t1\t100\t200\t50\t20\t95.5\tHello\n\
+                    5\t1\t1\t1\t1\t2\t160\t200\t40\t20\t-1\t\n";
+        let words = parse_tesseract_tsv(tsv, 300.0);
+        assert_eq!(words.len(), 1);
+        assert_eq!(words[0].text, "Hello");
+    }
+
+    #[test]
+    fn parse_tesseract_tsv_maps_pixel_coords_to_points() {
+        let tsv = "level\tpage_num\tblock_num\tpar_num\tline_num\tword_num\tleft\ttop\twidth\theight\tconf\ttext\n\
+                    5\t1\t1\t1\t1\t1\t300\t600\t150\t60\t90.0\tWord\n";
+        let words = parse_tesseract_tsv(tsv, 300.0);
+        assert_eq!(words.len(), 1);
+        let w = &words[0];
+        assert!((w.x0 - 72.0).abs() < 1e-9);
+        assert!((w.top - 144.0).abs() < 1e-9);
+        assert!((w.x1 - 108.0).abs() < 1e-9);
+        assert!((w.bottom - 158.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ocr_options_default_threshold_is_zero() {
+        let opts = OcrOptions::default();
+        assert_eq!(opts.threshold, 0);
+    }
+}
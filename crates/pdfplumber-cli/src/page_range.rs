@@ -1,8 +1,368 @@
+/// An error parsing a page range string.
+///
+/// `Display` output matches the messages this module has always produced,
+/// so existing human-facing output (e.g. `eprintln!("Error: {e}")` at call
+/// sites) is unchanged; callers that want to react programmatically (e.g.
+/// re-prompt only on `OutOfBounds`) can match on the variant instead of
+/// substring-matching the message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageRangeError {
+    /// A page number token could not be parsed as an integer.
+    InvalidNumber {
+        /// The offending token, as written in the input.
+        text: String,
+    },
+    /// Page 0 was specified; pages are 1-indexed.
+    PageZero,
+    /// A page number is outside `1..=page_count`.
+    OutOfBounds {
+        /// The out-of-range page number.
+        page: usize,
+        /// The document's page count.
+        page_count: usize,
+    },
+    /// The selection contains no pages.
+    EmptySelection,
+    /// A `start:end:step` term's step was 0, which would loop forever.
+    ZeroStep,
+    /// A `start:end:step` term had `end < start`.
+    InvertedRange {
+        /// The term's (1-indexed) start.
+        start: usize,
+        /// The term's (1-indexed) end.
+        end: usize,
+    },
+}
+
+impl std::fmt::Display for PageRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PageRangeError::InvalidNumber { text } => write!(f, "invalid page number: '{text}'"),
+            PageRangeError::PageZero => write!(f, "page 0 is invalid (pages start at 1)"),
+            PageRangeError::OutOfBounds { page, page_count } => write!(
+                f,
+                "page {page} exceeds document page count ({page_count})"
+            ),
+            PageRangeError::EmptySelection => write!(f, "page selection is empty"),
+            PageRangeError::ZeroStep => write!(f, "step must not be 0"),
+            PageRangeError::InvertedRange { start, end } => {
+                write!(f, "range end {end} is before start {start}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PageRangeError {}
+
+/// A range's start/end separator, in the order they're tested for in a part.
+enum RangeSeparator {
+    /// `a..b`: exclusive of `b`.
+    DotDot,
+    /// `a..=b` or `a:b` or `a-b`: inclusive of `b`.
+    Inclusive,
+}
+
+/// Split a single comma-separated part into its range separator, if any,
+/// testing `..=` before `..` (so `..` doesn't swallow half of `..=`), then
+/// `:`, then `-`.
+fn split_range_part(part: &str) -> Option<(&str, &str, RangeSeparator)> {
+    if let Some((s, e)) = part.split_once("..=") {
+        Some((s, e, RangeSeparator::Inclusive))
+    } else if let Some((s, e)) = part.split_once("..") {
+        Some((s, e, RangeSeparator::DotDot))
+    } else if let Some((s, e)) = part.split_once(':') {
+        Some((s, e, RangeSeparator::Inclusive))
+    } else {
+        part.split_once('-').map(|(s, e)| (s, e, RangeSeparator::Inclusive))
+    }
+}
+
+/// Split a `start:end:step` term into its three colon-delimited fields, or
+/// `None` if `part` has fewer than two colons (i.e. isn't using the
+/// stepped-range grammar, so the plain `-`/`..`/`..=`/`:` handling in
+/// [`part_to_interval`] applies instead). Any colons past the second are
+/// left in the step field.
+fn split_stepped_range(part: &str) -> Option<(&str, &str, &str)> {
+    let mut fields = part.splitn(3, ':');
+    let start = fields.next()?;
+    let end = fields.next()?;
+    let step = fields.next()?;
+    Some((start, end, step))
+}
+
+/// Parse and validate a single 1-indexed page number bound, defaulting to
+/// `default` when `text` is empty (an open-ended bound).
+fn parse_bound(text: &str, default: usize, page_count: usize) -> Result<usize, PageRangeError> {
+    let n: usize = if text.is_empty() {
+        default
+    } else {
+        text.parse().map_err(|_| PageRangeError::InvalidNumber { text: text.to_string() })?
+    };
+
+    if n == 0 {
+        return Err(PageRangeError::PageZero);
+    }
+    if n > page_count {
+        return Err(PageRangeError::OutOfBounds { page: n, page_count });
+    }
+    Ok(n)
+}
+
+/// Parse a single `start:end:step` term into the individual half-open,
+/// 0-indexed `[page, page + 1)` intervals it selects (one per page on the
+/// stride), or an empty `Vec` if it selects no pages. Errors on a zero step
+/// or an inverted range (`end < start`) rather than silently selecting
+/// nothing, unlike the other range forms in [`part_to_interval`].
+fn stepped_term_to_intervals(
+    start_str: &str,
+    end_str: &str,
+    step_str: &str,
+    page_count: usize,
+) -> Result<Vec<(usize, usize)>, PageRangeError> {
+    let start = parse_bound(start_str.trim(), 1, page_count)?;
+    let end = parse_bound(end_str.trim(), page_count, page_count)?;
+    let step: usize = if step_str.trim().is_empty() {
+        1
+    } else {
+        step_str
+            .trim()
+            .parse()
+            .map_err(|_| PageRangeError::InvalidNumber { text: step_str.to_string() })?
+    };
+
+    if step == 0 {
+        return Err(PageRangeError::ZeroStep);
+    }
+    if end < start {
+        return Err(PageRangeError::InvertedRange { start, end });
+    }
+
+    Ok((start..=end).step_by(step).map(|p| (p - 1, p)).collect())
+}
+
+/// Parse a single comma-separated part into a half-open, 0-indexed `[start,
+/// end)` interval, or `None` if the part selects no pages (e.g. a reversed
+/// or empty `..` range). Shared by [`parse_page_range_intervals`].
+fn part_to_interval(
+    part: &str,
+    page_count: usize,
+) -> Result<Option<(usize, usize)>, PageRangeError> {
+    if let Some((start_str, end_str, separator)) = split_range_part(part) {
+        let start_str = start_str.trim();
+        let end_str = end_str.trim();
+
+        let start: usize = if start_str.is_empty() {
+            1
+        } else {
+            start_str.parse().map_err(|_| PageRangeError::InvalidNumber {
+                text: start_str.to_string(),
+            })?
+        };
+        let end: usize = if end_str.is_empty() {
+            page_count
+        } else {
+            end_str.parse().map_err(|_| PageRangeError::InvalidNumber {
+                text: end_str.to_string(),
+            })?
+        };
+
+        if start == 0 || end == 0 {
+            return Err(PageRangeError::PageZero);
+        }
+        if start > page_count {
+            return Err(PageRangeError::OutOfBounds { page: start, page_count });
+        }
+        if end > page_count {
+            return Err(PageRangeError::OutOfBounds { page: end, page_count });
+        }
+
+        let last = match separator {
+            RangeSeparator::Inclusive => end,
+            RangeSeparator::DotDot => end.saturating_sub(1),
+        };
+
+        if last < start {
+            return Ok(None);
+        }
+        Ok(Some((start - 1, last)))
+    } else {
+        let page: usize = part.parse().map_err(|_| PageRangeError::InvalidNumber {
+            text: part.to_string(),
+        })?;
+
+        if page == 0 {
+            return Err(PageRangeError::PageZero);
+        }
+        if page > page_count {
+            return Err(PageRangeError::OutOfBounds { page, page_count });
+        }
+
+        Ok(Some((page - 1, page)))
+    }
+}
+
+/// A normalized set of page selections: sorted, non-overlapping, half-open
+/// `[start, end)` intervals of 0-indexed page numbers, with adjacent and
+/// overlapping parts merged. Built by [`parse_page_range_intervals`].
+///
+/// Prefer this over [`parse_page_range`] when the selection might span a
+/// huge range (e.g. "1-1000000"): it costs O(number of parts) memory rather
+/// than O(number of pages), and membership testing doesn't require
+/// materializing the full page list. A `start:end:step` term is the
+/// exception: it contributes one interval per page it selects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRangeSet {
+    intervals: Vec<(usize, usize)>,
+}
+
+impl PageRangeSet {
+    /// Returns whether `page` (0-indexed) is part of the selection.
+    pub fn contains(&self, page: usize) -> bool {
+        self.intervals
+            .binary_search_by(|&(start, end)| {
+                if page < start {
+                    std::cmp::Ordering::Greater
+                } else if page >= end {
+                    std::cmp::Ordering::Less
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// The total number of pages selected.
+    pub fn len(&self) -> usize {
+        self.intervals.iter().map(|(start, end)| end - start).sum()
+    }
+
+    /// Returns whether the selection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Lazily expands the selection into individual 0-indexed page numbers,
+    /// in ascending order.
+    pub fn iter_pages(&self) -> impl Iterator<Item = usize> + '_ {
+        self.intervals.iter().flat_map(|&(start, end)| start..end)
+    }
+}
+
+/// Parse a page range string like "1,3-5" into a normalized [`PageRangeSet`]
+/// of half-open, 0-indexed intervals, merging adjacent and overlapping
+/// parts.
+///
+/// Input is 1-indexed (user-facing). A range may use `-` (inclusive), `..`
+/// (exclusive of the end), `..=` (inclusive), or `:` (inclusive) as its
+/// separator, e.g. "2..5" selects pages 2-4 while "2..=5" and "2:5" select
+/// pages 2-5. A `-` range may also be open-ended: "3-" means page 3 through
+/// the last page, "-5" means page 1 through 5, and "-" alone means the
+/// whole document; `:` supports the same open-ended start/end (e.g. "3:",
+/// ":5"). A `start:end:step` term (e.g. "1:20:2") additionally strides by
+/// `step`, selecting every `step`th page from `start` through `end`.
+/// Returns an error for invalid input (page 0, malformed ranges, a step of
+/// 0, or a `start:end:step` term with `end` before `start`, etc.).
+pub fn parse_page_range_intervals(
+    input: &str,
+    page_count: usize,
+) -> Result<PageRangeSet, PageRangeError> {
+    let mut intervals = Vec::new();
+
+    for part in input.split(',') {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+
+        if let Some((start_str, end_str, step_str)) = split_stepped_range(part) {
+            intervals.extend(stepped_term_to_intervals(start_str, end_str, step_str, page_count)?);
+        } else if let Some(interval) = part_to_interval(part, page_count)? {
+            intervals.push(interval);
+        }
+    }
+
+    intervals.sort_by_key(|&(start, _)| start);
+
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    Ok(PageRangeSet { intervals: merged })
+}
+
 /// Parse a page range string like "1,3-5" into a sorted list of 0-indexed page numbers.
 ///
-/// Input is 1-indexed (user-facing). Output is 0-indexed (internal).
-/// Returns an error for invalid input (page 0, malformed ranges, etc.).
-pub fn parse_page_range(input: &str, page_count: usize) -> Result<Vec<usize>, String> {
+/// This is an eager convenience wrapper around [`parse_page_range_intervals`]
+/// — see it for the accepted syntax. Prefer `parse_page_range_intervals`
+/// directly for huge ranges where materializing every page is wasteful.
+pub fn parse_page_range(input: &str, page_count: usize) -> Result<Vec<usize>, PageRangeError> {
+    Ok(parse_page_range_intervals(input, page_count)?
+        .iter_pages()
+        .collect())
+}
+
+/// Split a single comma-separated part into its range separator for
+/// [`parse_page_range_ordered`], the same as [`split_range_part`] except the
+/// `-` case is sign-aware: a leading `-` (and any digits right after it) is
+/// treated as part of a negative number rather than the separator itself, so
+/// `"-3--1"` splits into `"-3"` and `"-1"` instead of `""` and `"3--1"`.
+fn split_range_part_signed(part: &str) -> Option<(&str, &str, RangeSeparator)> {
+    if let Some((s, e)) = part.split_once("..=") {
+        return Some((s, e, RangeSeparator::Inclusive));
+    }
+    if let Some((s, e)) = part.split_once("..") {
+        return Some((s, e, RangeSeparator::DotDot));
+    }
+    if let Some((s, e)) = part.split_once(':') {
+        return Some((s, e, RangeSeparator::Inclusive));
+    }
+
+    let bytes = part.as_bytes();
+    let mut i = if bytes.first() == Some(&b'-') { 1 } else { 0 };
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+    }
+    if i < bytes.len() && bytes[i] == b'-' {
+        Some((&part[..i], &part[i + 1..], RangeSeparator::Inclusive))
+    } else {
+        None
+    }
+}
+
+/// Resolve a possibly-negative, 1-indexed page number to a validated
+/// 1-indexed page number. A negative `n` counts from the end of the
+/// document: `-1` is the last page, `-2` the second-to-last, resolved as
+/// `page_count as isize + n + 1`.
+fn resolve_signed_page(n: isize, page_count: usize) -> Result<usize, PageRangeError> {
+    let resolved = if n < 0 { page_count as isize + n + 1 } else { n };
+
+    if resolved <= 0 {
+        return Err(PageRangeError::PageZero);
+    }
+    if resolved as usize > page_count {
+        return Err(PageRangeError::OutOfBounds { page: resolved as usize, page_count });
+    }
+    Ok(resolved as usize)
+}
+
+/// Parse a page range string like "3,1,2" or "-3--1" into a list of 0-indexed
+/// page numbers, preserving the input order and duplicates instead of
+/// sorting and deduping them — so "3,1,2" yields `[2, 0, 1]`, letting callers
+/// drive page reordering or repeated extraction.
+///
+/// Accepts the same separators as [`parse_page_range`] (`-`, `..`, `..=`,
+/// `:`), plus negative page numbers that count from the end of the document
+/// (`"-1"` is the last page), which a bare leading `-` is treated as unless
+/// it's immediately followed by another `-` acting as a range separator
+/// (e.g. `"-3--1"` is the range from the third-to-last page to the last).
+pub fn parse_page_range_ordered(
+    input: &str,
+    page_count: usize,
+) -> Result<Vec<usize>, PageRangeError> {
     let mut pages = Vec::new();
 
     for part in input.split(',') {
@@ -11,53 +371,49 @@ pub fn parse_page_range(input: &str, page_count: usize) -> Result<Vec<usize>, St
             continue;
         }
 
-        if let Some((start_str, end_str)) = part.split_once('-') {
-            let start: usize = start_str
-                .trim()
-                .parse()
-                .map_err(|_| format!("invalid page number: '{start_str}'"))?;
-            let end: usize = end_str
-                .trim()
-                .parse()
-                .map_err(|_| format!("invalid page number: '{end_str}'"))?;
-
-            if start == 0 || end == 0 {
-                return Err("page 0 is invalid (pages start at 1)".to_string());
-            }
-            if start > page_count {
-                return Err(format!(
-                    "page {start} exceeds document page count ({page_count})"
-                ));
-            }
-            if end > page_count {
-                return Err(format!(
-                    "page {end} exceeds document page count ({page_count})"
-                ));
-            }
+        if let Some((start_str, end_str, separator)) = split_range_part_signed(part) {
+            let start_str = start_str.trim();
+            let end_str = end_str.trim();
+
+            let start: isize = if start_str.is_empty() {
+                1
+            } else {
+                start_str.parse().map_err(|_| PageRangeError::InvalidNumber {
+                    text: start_str.to_string(),
+                })?
+            };
+            let end: isize = if end_str.is_empty() {
+                page_count as isize
+            } else {
+                end_str.parse().map_err(|_| PageRangeError::InvalidNumber {
+                    text: end_str.to_string(),
+                })?
+            };
+
+            let start = resolve_signed_page(start, page_count)?;
+            let end = resolve_signed_page(end, page_count)?;
+
+            let last = match separator {
+                RangeSeparator::Inclusive => end,
+                RangeSeparator::DotDot => end.saturating_sub(1),
+            };
 
-            for p in start..=end {
+            for p in start..=last {
+                if p == 0 {
+                    break; // "a..b" with end <= start leaves nothing to select
+                }
                 pages.push(p - 1); // convert to 0-indexed
             }
         } else {
-            let page: usize = part
-                .parse()
-                .map_err(|_| format!("invalid page number: '{part}'"))?;
-
-            if page == 0 {
-                return Err("page 0 is invalid (pages start at 1)".to_string());
-            }
-            if page > page_count {
-                return Err(format!(
-                    "page {page} exceeds document page count ({page_count})"
-                ));
-            }
+            let page: isize = part.parse().map_err(|_| PageRangeError::InvalidNumber {
+                text: part.to_string(),
+            })?;
 
+            let page = resolve_signed_page(page, page_count)?;
             pages.push(page - 1);
         }
     }
 
-    pages.sort();
-    pages.dedup();
     Ok(pages)
 }
 
@@ -92,13 +448,16 @@ mod tests {
     #[test]
     fn page_zero_invalid() {
         let err = parse_page_range("0", 5).unwrap_err();
-        assert!(err.contains("invalid"));
+        assert_eq!(err, PageRangeError::PageZero);
     }
 
     #[test]
     fn page_exceeds_count() {
         let err = parse_page_range("6", 5).unwrap_err();
-        assert!(err.contains("exceeds"));
+        assert_eq!(
+            err,
+            PageRangeError::OutOfBounds { page: 6, page_count: 5 }
+        );
     }
 
     #[test]
@@ -139,43 +498,52 @@ mod tests {
     #[test]
     fn non_numeric_input() {
         let err = parse_page_range("abc", 5).unwrap_err();
-        assert!(err.contains("invalid page number"));
+        assert_eq!(
+            err,
+            PageRangeError::InvalidNumber { text: "abc".to_string() }
+        );
     }
 
     #[test]
     fn range_with_non_numeric() {
         let err = parse_page_range("1-abc", 5).unwrap_err();
-        assert!(err.contains("invalid page number"));
+        assert_eq!(
+            err,
+            PageRangeError::InvalidNumber { text: "abc".to_string() }
+        );
     }
 
     #[test]
     fn page_zero_in_range_start() {
         let err = parse_page_range("0-3", 5).unwrap_err();
-        assert!(err.contains("page 0 is invalid"));
+        assert_eq!(err, PageRangeError::PageZero);
     }
 
     #[test]
     fn page_zero_in_range_end() {
         let err = parse_page_range("1-0", 5).unwrap_err();
-        assert!(err.contains("page 0 is invalid"));
+        assert_eq!(err, PageRangeError::PageZero);
     }
 
     #[test]
     fn range_end_exceeds_page_count() {
         let err = parse_page_range("1-10", 5).unwrap_err();
-        assert!(err.contains("exceeds document page count (5)"));
+        assert_eq!(
+            err,
+            PageRangeError::OutOfBounds { page: 10, page_count: 5 }
+        );
     }
 
     #[test]
     fn exact_error_message_for_page_zero() {
         let err = parse_page_range("0", 5).unwrap_err();
-        assert_eq!(err, "page 0 is invalid (pages start at 1)");
+        assert_eq!(err.to_string(), "page 0 is invalid (pages start at 1)");
     }
 
     #[test]
     fn exact_error_message_for_exceeds() {
         let err = parse_page_range("99", 5).unwrap_err();
-        assert_eq!(err, "page 99 exceeds document page count (5)");
+        assert_eq!(err.to_string(), "page 99 exceeds document page count (5)");
     }
 
     #[test]
@@ -187,4 +555,206 @@ mod tests {
     fn overlapping_ranges_deduped() {
         assert_eq!(parse_page_range("1-3,2-4", 5).unwrap(), vec![0, 1, 2, 3]);
     }
+
+    #[test]
+    fn open_ended_start() {
+        // "3-" means page 3 through the last page
+        assert_eq!(parse_page_range("3-", 5).unwrap(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn open_ended_end() {
+        // "-5" means page 1 through 5
+        assert_eq!(parse_page_range("-5", 5).unwrap(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn fully_open_range_selects_whole_document() {
+        assert_eq!(parse_page_range("-", 5).unwrap(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn colon_open_ended_start() {
+        // "3:" means page 3 through the last page
+        assert_eq!(parse_page_range("3:", 5).unwrap(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn colon_open_ended_end() {
+        // ":5" means page 1 through 5
+        assert_eq!(parse_page_range(":5", 10).unwrap(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn stepped_range_selects_every_nth_page() {
+        // "1:20:2" selects odd pages 1-19
+        assert_eq!(
+            parse_page_range("1:20:2", 20).unwrap(),
+            vec![0, 2, 4, 6, 8, 10, 12, 14, 16, 18]
+        );
+    }
+
+    #[test]
+    fn stepped_range_open_ended_bounds() {
+        // "::3" strides the whole document by 3
+        assert_eq!(parse_page_range("::3", 10).unwrap(), vec![0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn stepped_range_default_step_omitted_is_one() {
+        // "2:5:" behaves like "2:5" (step 1)
+        assert_eq!(parse_page_range("2:5:", 5).unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn stepped_range_zero_step_errors() {
+        let err = parse_page_range("1:10:0", 10).unwrap_err();
+        assert_eq!(err, PageRangeError::ZeroStep);
+    }
+
+    #[test]
+    fn stepped_range_inverted_errors() {
+        let err = parse_page_range("10:1:2", 10).unwrap_err();
+        assert_eq!(err, PageRangeError::InvertedRange { start: 10, end: 1 });
+    }
+
+    #[test]
+    fn stepped_range_out_of_bounds_errors() {
+        let err = parse_page_range("1:100:2", 10).unwrap_err();
+        assert_eq!(err, PageRangeError::OutOfBounds { page: 100, page_count: 10 });
+    }
+
+    #[test]
+    fn stepped_range_merges_with_plain_terms() {
+        assert_eq!(
+            parse_page_range("1:6:2,2", 6).unwrap(),
+            vec![0, 1, 2, 4]
+        );
+    }
+
+    #[test]
+    fn dot_dot_range_is_exclusive_of_end() {
+        assert_eq!(parse_page_range("2..5", 5).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn dot_dot_equals_range_is_inclusive_of_end() {
+        assert_eq!(parse_page_range("2..=5", 5).unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn colon_range_is_inclusive_of_end() {
+        assert_eq!(parse_page_range("2:5", 5).unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn dot_dot_range_with_end_equal_to_start_is_empty() {
+        assert_eq!(parse_page_range("3..3", 5).unwrap(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn mixed_separators() {
+        assert_eq!(
+            parse_page_range("1-3,5..8,10..=12", 12).unwrap(),
+            vec![0, 1, 2, 4, 5, 6, 9, 10, 11]
+        );
+    }
+
+    #[test]
+    fn ordered_preserves_input_order_and_duplicates() {
+        assert_eq!(parse_page_range_ordered("3,1,2", 5).unwrap(), vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn ordered_does_not_dedup() {
+        assert_eq!(
+            parse_page_range_ordered("1,1,2", 5).unwrap(),
+            vec![0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn ordered_negative_index_is_last_page() {
+        assert_eq!(parse_page_range_ordered("-1", 5).unwrap(), vec![4]);
+    }
+
+    #[test]
+    fn ordered_negative_index_second_to_last() {
+        assert_eq!(parse_page_range_ordered("-2", 5).unwrap(), vec![3]);
+    }
+
+    #[test]
+    fn ordered_negative_range_selects_final_pages() {
+        assert_eq!(parse_page_range_ordered("-3--1", 5).unwrap(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn ordered_negative_out_of_bounds_errors() {
+        let err = parse_page_range_ordered("-6", 5).unwrap_err();
+        assert_eq!(err, PageRangeError::PageZero);
+    }
+
+    #[test]
+    fn ordered_mixed_positive_and_negative() {
+        assert_eq!(
+            parse_page_range_ordered("1,-1,2", 5).unwrap(),
+            vec![0, 4, 1]
+        );
+    }
+
+    #[test]
+    fn ordered_still_supports_positional_separators() {
+        assert_eq!(
+            parse_page_range_ordered("2..4,1", 5).unwrap(),
+            vec![1, 2, 0]
+        );
+    }
+
+    #[test]
+    fn intervals_merges_overlapping_parts() {
+        let set = parse_page_range_intervals("1-3,2-4", 5).unwrap();
+        assert_eq!(set.len(), 4);
+        assert!(set.contains(0));
+        assert!(set.contains(3));
+        assert!(!set.contains(4));
+    }
+
+    #[test]
+    fn intervals_merges_adjacent_parts() {
+        let set = parse_page_range_intervals("1-3,4-5", 5).unwrap();
+        assert_eq!(set.len(), 5);
+        assert!(set.contains(3));
+    }
+
+    #[test]
+    fn intervals_keeps_disjoint_parts_separate() {
+        let set = parse_page_range_intervals("1,3,5", 5).unwrap();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(0));
+        assert!(!set.contains(1));
+        assert!(set.contains(2));
+    }
+
+    #[test]
+    fn intervals_empty_selection() {
+        let set = parse_page_range_intervals("", 5).unwrap();
+        assert!(set.is_empty());
+        assert_eq!(set.len(), 0);
+    }
+
+    #[test]
+    fn intervals_huge_range_stays_cheap() {
+        let set = parse_page_range_intervals("1-1000000", 1_000_000).unwrap();
+        assert_eq!(set.len(), 1_000_000);
+        assert!(set.contains(0));
+        assert!(set.contains(999_999));
+    }
+
+    #[test]
+    fn parse_page_range_matches_expanded_intervals() {
+        assert_eq!(
+            parse_page_range("1-3,7,10-12", 12).unwrap(),
+            vec![0, 1, 2, 6, 9, 10, 11]
+        );
+    }
 }
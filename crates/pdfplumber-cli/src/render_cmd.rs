@@ -0,0 +1,51 @@
+use std::path::Path;
+
+use crate::shared::{open_pdf_maybe_repair, resolve_pages};
+
+pub fn run(
+    file: &Path,
+    pages: Option<&str>,
+    output: &Path,
+    scale: f64,
+    password: Option<&str>,
+    repair: bool,
+) -> Result<(), i32> {
+    let pdf = open_pdf_maybe_repair(file, None, password, repair)?;
+    let page_indices = resolve_pages(pages, pdf.page_count())?;
+
+    // Render each page; if multiple pages, append page number to filename
+    let multi_page = page_indices.len() > 1;
+    let stem = output
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("page");
+    let ext = output.extension().and_then(|s| s.to_str()).unwrap_or("png");
+    let parent = output.parent().unwrap_or(Path::new("."));
+
+    for &idx in &page_indices {
+        let page = pdf.page(idx).map_err(|e| {
+            eprintln!("Error reading page {}: {e}", idx + 1);
+            1
+        })?;
+
+        let bitmap = page.render(scale).map_err(|e| {
+            eprintln!("Error rendering page {}: {e}", idx + 1);
+            1
+        })?;
+
+        let out_path = if multi_page {
+            parent.join(format!("{stem}_page{}.{ext}", idx + 1))
+        } else {
+            output.to_path_buf()
+        };
+
+        bitmap.save_png(&out_path).map_err(|e| {
+            eprintln!("Error writing {}: {e}", out_path.display());
+            1
+        })?;
+
+        eprintln!("Wrote {}", out_path.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+//! Published JSON Schema documents for the `chars`, `words`, and `tables`
+//! `--format json` output shapes, so downstream tooling has a stable,
+//! self-describing contract to validate against instead of an implicit one.
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::cli::SchemaKind;
+
+/// A bounding box as emitted in `chars`/`words`/`tables` JSON records.
+#[derive(Serialize, JsonSchema)]
+struct BboxRecord {
+    x0: f64,
+    top: f64,
+    x1: f64,
+    bottom: f64,
+}
+
+/// Shape of one record in `pdfplumber chars --format json`.
+#[derive(Serialize, JsonSchema)]
+struct CharRecord {
+    page: usize,
+    text: String,
+    fontname: String,
+    size: f64,
+    x0: f64,
+    top: f64,
+    x1: f64,
+    bottom: f64,
+    doctop: f64,
+    upright: bool,
+    direction: String,
+}
+
+/// Shape of one record in `pdfplumber words --format json`.
+#[derive(Serialize, JsonSchema)]
+struct WordRecord {
+    page: usize,
+    text: String,
+    x0: f64,
+    top: f64,
+    x1: f64,
+    bottom: f64,
+    doctop: f64,
+    direction: String,
+}
+
+/// Shape of one record in `pdfplumber tables --format json`.
+#[derive(Serialize, JsonSchema)]
+struct TableRecord {
+    page: usize,
+    bbox: BboxRecord,
+    rows: Vec<Vec<Option<String>>>,
+}
+
+/// Prints the JSON Schema for `kind`'s output shape, with a versioned `$id`
+/// so consumers can pin and validate against a specific revision.
+pub fn print_schema(kind: &SchemaKind) {
+    let (id, json) = match kind {
+        SchemaKind::Chars => (
+            "https://github.com/developer0hye/pdfplumber-rs/schemas/chars-v1.json",
+            schema_json::<CharRecord>(),
+        ),
+        SchemaKind::Words => (
+            "https://github.com/developer0hye/pdfplumber-rs/schemas/words-v1.json",
+            schema_json::<WordRecord>(),
+        ),
+        SchemaKind::Tables => (
+            "https://github.com/developer0hye/pdfplumber-rs/schemas/tables-v1.json",
+            schema_json::<TableRecord>(),
+        ),
+    };
+
+    let mut value = json;
+    value["$id"] = serde_json::Value::String(id.to_string());
+    println!("{}", serde_json::to_string_pretty(&value).unwrap());
+}
+
+fn schema_json<T: JsonSchema>() -> serde_json::Value {
+    let schema = schemars::schema_for!(T);
+    serde_json::to_value(schema).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn char_record_schema_has_expected_properties() {
+        let schema = schema_json::<CharRecord>();
+        let props = &schema["properties"];
+        assert!(props.get("text").is_some());
+        assert!(props.get("fontname").is_some());
+        assert!(props.get("direction").is_some());
+    }
+
+    #[test]
+    fn word_record_schema_has_expected_properties() {
+        let schema = schema_json::<WordRecord>();
+        let props = &schema["properties"];
+        assert!(props.get("text").is_some());
+        assert!(props.get("doctop").is_some());
+    }
+
+    #[test]
+    fn table_record_schema_has_expected_properties() {
+        let schema = schema_json::<TableRecord>();
+        let props = &schema["properties"];
+        assert!(props.get("rows").is_some());
+        assert!(props.get("bbox").is_some());
+    }
+}
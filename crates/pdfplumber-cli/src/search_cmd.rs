@@ -3,8 +3,9 @@ use std::path::Path;
 use pdfplumber::SearchOptions;
 
 use crate::cli::OutputFormat;
-use crate::shared::{ProgressReporter, csv_escape, open_pdf_full, resolve_pages};
+use crate::shared::{ProgressReporter, csv_escape, open_pdf_maybe_repair, resolve_pages};
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     file: &Path,
     pattern: &str,
@@ -12,9 +13,11 @@ pub fn run(
     case_insensitive: bool,
     no_regex: bool,
     format: &OutputFormat,
+    context: usize,
     password: Option<&str>,
+    repair: bool,
 ) -> Result<(), i32> {
-    let pdf = open_pdf_full(file, None, password)?;
+    let pdf = open_pdf_maybe_repair(file, None, password, repair)?;
     let page_indices = resolve_pages(pages, pdf.page_count())?;
     let progress = ProgressReporter::new(page_indices.len());
 
@@ -25,7 +28,7 @@ pub fn run(
 
     match format {
         OutputFormat::Text => write_text(&pdf, &page_indices, pattern, &opts, &progress),
-        OutputFormat::Json => write_json(&pdf, &page_indices, pattern, &opts, &progress),
+        OutputFormat::Json => write_json(&pdf, &page_indices, pattern, &opts, context, &progress),
         OutputFormat::Csv => write_csv(&pdf, &page_indices, pattern, &opts, &progress),
     }
 }
@@ -65,11 +68,15 @@ fn write_text(
     Ok(())
 }
 
+/// Matches with a word-derived bbox and surrounding word context, so
+/// `--format json` gives downstream tooling enough to render a snippet
+/// preview without re-running extraction.
 fn write_json(
     pdf: &pdfplumber::Pdf,
     page_indices: &[usize],
     pattern: &str,
     opts: &SearchOptions,
+    context: usize,
     progress: &ProgressReporter,
 ) -> Result<(), i32> {
     let mut all_matches = Vec::new();
@@ -82,7 +89,7 @@ fn write_json(
             1
         })?;
 
-        let matches = page.search(pattern, opts);
+        let matches = page.search_words(pattern, opts, context);
         for m in &matches {
             all_matches.push(serde_json::json!({
                 "page": idx + 1,
@@ -91,7 +98,8 @@ fn write_json(
                 "top": m.bbox.top,
                 "x1": m.bbox.x1,
                 "bottom": m.bbox.bottom,
-                "char_indices": m.char_indices,
+                "context_before": m.context_before,
+                "context_after": m.context_after,
             }));
         }
     }
@@ -1,7 +1,7 @@
 use std::io::{self, IsTerminal, Write};
 use std::path::Path;
 
-use pdfplumber::{Pdf, TextDirection};
+use pdfplumber::{ExtractOptions, Pdf, TextDirection};
 
 use crate::page_range::parse_page_range;
 
@@ -21,6 +21,74 @@ pub fn open_pdf(file: &Path) -> Result<Pdf, i32> {
     })
 }
 
+/// Open a PDF file with full extraction options, optionally password-protected.
+///
+/// Like [`open_pdf`], but accepts explicit `options` (e.g. enabling image data
+/// extraction) and an optional `password` for encrypted documents.
+pub fn open_pdf_full(
+    file: &Path,
+    options: Option<ExtractOptions>,
+    password: Option<&str>,
+) -> Result<Pdf, i32> {
+    if !file.exists() {
+        eprintln!("Error: file not found: {}", file.display());
+        return Err(1);
+    }
+
+    match password {
+        Some(password) => Pdf::open_file_with_password(file, password.as_bytes(), options),
+        None => Pdf::open_file(file, options),
+    }
+    .map_err(|e| {
+        eprintln!("Error: failed to open PDF: {e}");
+        1
+    })
+}
+
+/// Open a PDF file, falling back to best-effort repair when `repair` is set.
+///
+/// When `repair` is `true` and the document fails to open normally, retries
+/// via [`Pdf::open_with_repair`] and reports what was fixed to stderr.
+pub fn open_pdf_maybe_repair(
+    file: &Path,
+    options: Option<ExtractOptions>,
+    password: Option<&str>,
+    repair: bool,
+) -> Result<Pdf, i32> {
+    if !file.exists() {
+        eprintln!("Error: file not found: {}", file.display());
+        return Err(1);
+    }
+
+    if !repair {
+        return open_pdf_full(file, options, password);
+    }
+
+    let normal = match password {
+        Some(password) => Pdf::open_file_with_password(file, password.as_bytes(), options.clone()),
+        None => Pdf::open_file(file, options.clone()),
+    };
+    if let Ok(pdf) = normal {
+        return Ok(pdf);
+    }
+
+    let bytes = std::fs::read(file).map_err(|e| {
+        eprintln!("Error: failed to read {}: {e}", file.display());
+        1
+    })?;
+
+    let (pdf, result) = Pdf::open_with_repair(&bytes, options, None).map_err(|e| {
+        eprintln!("Error: failed to open PDF even with repair: {e}");
+        1
+    })?;
+
+    for fix in &result.log {
+        eprintln!("Repair: {fix}");
+    }
+
+    Ok(pdf)
+}
+
 /// Resolve an optional page range string into 0-indexed page indices.
 ///
 /// If `pages` is `None`, returns all pages (0..page_count).
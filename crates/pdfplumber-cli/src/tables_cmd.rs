@@ -1,50 +1,33 @@
 use std::path::Path;
 
-use pdfplumber::{Pdf, Strategy, TableSettings};
+use pdfplumber::{Pdf, Strategy, Table, TableSettings};
 
-use crate::cli::{OutputFormat, TableStrategy};
-use crate::page_range::parse_page_range;
+use crate::cli::{TableFormat, TableStrategy};
+use crate::shared::{csv_escape, open_pdf_maybe_repair, resolve_pages};
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     file: &Path,
     pages: Option<&str>,
-    format: &OutputFormat,
+    format: &TableFormat,
     strategy: &TableStrategy,
     snap_tolerance: f64,
     join_tolerance: f64,
     text_tolerance: f64,
+    out_dir: Option<&Path>,
+    password: Option<&str>,
+    repair: bool,
 ) -> Result<(), i32> {
-    let pdf = open_pdf(file)?;
+    let pdf = open_pdf_maybe_repair(file, None, password, repair)?;
     let page_indices = resolve_pages(pages, pdf.page_count())?;
 
     let settings = build_settings(strategy, snap_tolerance, join_tolerance, text_tolerance);
 
     match format {
-        OutputFormat::Text => write_grid(&pdf, &page_indices, &settings),
-        OutputFormat::Json => write_json(&pdf, &page_indices, &settings),
-        OutputFormat::Csv => write_csv(&pdf, &page_indices, &settings),
-    }
-}
-
-fn open_pdf(file: &Path) -> Result<Pdf, i32> {
-    if !file.exists() {
-        eprintln!("Error: file not found: {}", file.display());
-        return Err(1);
-    }
-
-    Pdf::open_file(file, None).map_err(|e| {
-        eprintln!("Error: failed to open PDF: {e}");
-        1
-    })
-}
-
-fn resolve_pages(pages: Option<&str>, page_count: usize) -> Result<Vec<usize>, i32> {
-    match pages {
-        Some(range) => parse_page_range(range, page_count).map_err(|e| {
-            eprintln!("Error: {e}");
-            1
-        }),
-        None => Ok((0..page_count).collect()),
+        TableFormat::Text => write_grid(&pdf, &page_indices, &settings),
+        TableFormat::Json => write_json(&pdf, &page_indices, &settings),
+        TableFormat::Csv => write_tables(&pdf, &page_indices, &settings, out_dir, "csv", render_csv),
+        TableFormat::Md => write_tables(&pdf, &page_indices, &settings, out_dir, "md", render_md),
     }
 }
 
@@ -74,6 +57,25 @@ fn build_settings(
     }
 }
 
+/// Pads every row in `table` to the table's maximum column count, so the
+/// output is always rectangular; empty cells serialize as empty strings.
+fn padded_rows(table: &Table) -> Vec<Vec<String>> {
+    let col_count = table.rows.iter().map(|r| r.len()).max().unwrap_or(0);
+
+    table
+        .rows
+        .iter()
+        .map(|row| {
+            let mut texts: Vec<String> = row
+                .iter()
+                .map(|cell| cell.text.clone().unwrap_or_default())
+                .collect();
+            texts.resize(col_count, String::new());
+            texts
+        })
+        .collect()
+}
+
 fn write_grid(pdf: &Pdf, page_indices: &[usize], settings: &TableSettings) -> Result<(), i32> {
     let mut table_num = 0;
 
@@ -101,38 +103,15 @@ fn write_grid(pdf: &Pdf, page_indices: &[usize], settings: &TableSettings) -> Re
                 continue;
             }
 
-            // Compute column widths for aligned output
-            let col_count = table.rows.iter().map(|r| r.len()).max().unwrap_or(0);
-            let mut col_widths = vec![0usize; col_count];
-
-            let text_rows: Vec<Vec<String>> = table
-                .rows
-                .iter()
-                .map(|row| {
-                    let mut texts = Vec::new();
-                    for (ci, cell) in row.iter().enumerate() {
-                        let text = cell.text.as_deref().unwrap_or("");
-                        if ci < col_widths.len() {
-                            col_widths[ci] = col_widths[ci].max(text.len());
-                        }
-                        texts.push(text.to_string());
-                    }
-                    // Pad if this row has fewer columns
-                    while texts.len() < col_count {
-                        texts.push(String::new());
-                    }
-                    texts
-                })
-                .collect();
-
-            // Ensure minimum width of 1
-            for w in &mut col_widths {
-                if *w == 0 {
-                    *w = 1;
+            let text_rows = padded_rows(table);
+            let col_count = text_rows.first().map(Vec::len).unwrap_or(0);
+            let mut col_widths = vec![1usize; col_count];
+            for row in &text_rows {
+                for (ci, text) in row.iter().enumerate() {
+                    col_widths[ci] = col_widths[ci].max(text.len());
                 }
             }
 
-            // Print rows with | separators
             for row_texts in &text_rows {
                 let cells_formatted: Vec<String> = row_texts
                     .iter()
@@ -193,8 +172,64 @@ fn write_json(pdf: &Pdf, page_indices: &[usize], settings: &TableSettings) -> Re
     Ok(())
 }
 
-fn write_csv(pdf: &Pdf, page_indices: &[usize], settings: &TableSettings) -> Result<(), i32> {
+/// Renders a rectangular table as RFC-4180 CSV.
+fn render_csv(rows: &[Vec<String>]) -> String {
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| csv_escape(cell))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders a rectangular table as a GitHub-flavored Markdown pipe table,
+/// escaping embedded `|` characters and treating the first row as the
+/// header.
+fn render_md(rows: &[Vec<String>]) -> String {
+    let Some((header, body)) = rows.split_first() else {
+        return String::new();
+    };
+
+    let escape = |cell: &str| cell.replace('|', "\\|");
+    let row_line = |row: &[String]| {
+        format!(
+            "| {} |",
+            row.iter().map(|c| escape(c)).collect::<Vec<_>>().join(" | ")
+        )
+    };
+
+    let mut lines = vec![row_line(header)];
+    lines.push(format!(
+        "| {} |",
+        header.iter().map(|_| "---").collect::<Vec<_>>().join(" | ")
+    ));
+    lines.extend(body.iter().map(|row| row_line(row)));
+    lines.join("\n")
+}
+
+/// Shared CSV/Markdown writer: either concatenates one block per table to
+/// stdout (blank-line separated) or, with `out_dir`, writes one
+/// `table_{page}_{index}.{ext}` file per table.
+fn write_tables(
+    pdf: &Pdf,
+    page_indices: &[usize],
+    settings: &TableSettings,
+    out_dir: Option<&Path>,
+    ext: &str,
+    render: fn(&[Vec<String>]) -> String,
+) -> Result<(), i32> {
+    if let Some(dir) = out_dir {
+        std::fs::create_dir_all(dir).map_err(|e| {
+            eprintln!("Error: failed to create {}: {e}", dir.display());
+            1
+        })?;
+    }
+
     let mut first_table = true;
+    let mut table_index = 0usize;
 
     for &idx in page_indices {
         let page = pdf.page(idx).map_err(|e| {
@@ -205,25 +240,25 @@ fn write_csv(pdf: &Pdf, page_indices: &[usize], settings: &TableSettings) -> Res
         let tables = page.find_tables(settings);
 
         for table in &tables {
-            if !first_table {
-                println!();
-            }
-            first_table = false;
-
-            for row in &table.rows {
-                let cells: Vec<String> = row
-                    .iter()
-                    .map(|cell| {
-                        let text = cell.text.as_deref().unwrap_or("");
-                        // Escape CSV: if text contains comma, quote, or newline, wrap in quotes
-                        if text.contains(',') || text.contains('"') || text.contains('\n') {
-                            format!("\"{}\"", text.replace('"', "\"\""))
-                        } else {
-                            text.to_string()
-                        }
-                    })
-                    .collect();
-                println!("{}", cells.join(","));
+            table_index += 1;
+            let rendered = render(&padded_rows(table));
+
+            match out_dir {
+                Some(dir) => {
+                    let path = dir.join(format!("table_{}_{}.{ext}", idx + 1, table_index));
+                    std::fs::write(&path, rendered).map_err(|e| {
+                        eprintln!("Error: failed to write {}: {e}", path.display());
+                        1
+                    })?;
+                    eprintln!("Wrote {}", path.display());
+                }
+                None => {
+                    if !first_table {
+                        println!();
+                    }
+                    first_table = false;
+                    println!("{rendered}");
+                }
             }
         }
     }
@@ -1,21 +1,42 @@
 use std::path::Path;
 
-use pdfplumber::{Pdf, TextOptions};
+use pdfplumber::{Pdf, TextOptions, UnicodeNorm};
 
+use crate::adapter::{default_config_path, find_adapter, load_config, run_adapter};
 use crate::cli::TextFormat;
-use crate::page_range::parse_page_range;
+use crate::ocr::{OcrOptions, ocr_page_if_needed};
+use crate::shared::{open_pdf_maybe_repair, resolve_pages};
 
-pub fn run(file: &Path, pages: Option<&str>, format: &TextFormat, layout: bool) -> Result<(), i32> {
-    // Open PDF with user-friendly error messages
-    let pdf = open_pdf(file)?;
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    file: &Path,
+    pages: Option<&str>,
+    format: &TextFormat,
+    layout: bool,
+    unicode_norm: Option<UnicodeNorm>,
+    password: Option<&str>,
+    repair: bool,
+    ocr: bool,
+    ocr_threshold: usize,
+    ocr_dpi: f64,
+    adapter: Option<&str>,
+    adapters_config: Option<&Path>,
+) -> Result<(), i32> {
+    if let Some(adapter_name) = adapter {
+        return run_via_adapter(file, pages, format, adapter_name, adapters_config);
+    }
 
-    // Resolve page indices
+    let pdf: Pdf = open_pdf_maybe_repair(file, None, password, repair)?;
     let page_indices = resolve_pages(pages, pdf.page_count())?;
 
     let text_options = TextOptions {
         layout,
         ..TextOptions::default()
     };
+    let ocr_options = OcrOptions {
+        threshold: ocr_threshold,
+        dpi: ocr_dpi,
+    };
 
     for &idx in &page_indices {
         let page = pdf.page(idx).map_err(|e| {
@@ -23,7 +44,26 @@ pub fn run(file: &Path, pages: Option<&str>, format: &TextFormat, layout: bool)
             1
         })?;
 
-        let text = page.extract_text(&text_options);
+        let mut text = if ocr {
+            let ocr_words = ocr_page_if_needed(&page, &ocr_options).map_err(|e| {
+                eprintln!("Error: {e}");
+                1
+            })?;
+            match ocr_words {
+                Some(words) => words
+                    .iter()
+                    .map(|w| w.text.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                None => page.extract_text(&text_options),
+            }
+        } else {
+            page.extract_text(&text_options)
+        };
+
+        if let Some(norm) = unicode_norm {
+            text = norm.normalize(&text);
+        }
 
         match format {
             TextFormat::Text => {
@@ -43,24 +83,41 @@ pub fn run(file: &Path, pages: Option<&str>, format: &TextFormat, layout: bool)
     Ok(())
 }
 
-fn open_pdf(file: &Path) -> Result<Pdf, i32> {
-    if !file.exists() {
-        eprintln!("Error: file not found: {}", file.display());
-        return Err(1);
-    }
+/// Runs a named external adapter against the whole file instead of the
+/// native extractor, feeding its stdout into the same `text`/`json` output
+/// the native path produces.
+fn run_via_adapter(
+    file: &Path,
+    pages: Option<&str>,
+    format: &TextFormat,
+    adapter_name: &str,
+    adapters_config: Option<&Path>,
+) -> Result<(), i32> {
+    let config_path = adapters_config
+        .map(Path::to_path_buf)
+        .unwrap_or_else(default_config_path);
+    let config = load_config(&config_path).map_err(|e| {
+        eprintln!("Error: {e}");
+        1
+    })?;
 
-    Pdf::open_file(file, None).map_err(|e| {
-        eprintln!("Error: failed to open PDF: {e}");
+    let adapter = find_adapter(&config, file, adapter_name).ok_or_else(|| {
+        eprintln!("Error: no adapter named '{adapter_name}' matches {}", file.display());
         1
-    })
-}
+    })?;
 
-fn resolve_pages(pages: Option<&str>, page_count: usize) -> Result<Vec<usize>, i32> {
-    match pages {
-        Some(range) => parse_page_range(range, page_count).map_err(|e| {
-            eprintln!("Error: {e}");
-            1
-        }),
-        None => Ok((0..page_count).collect()),
+    let text = run_adapter(adapter, file, None, pages).map_err(|e| {
+        eprintln!("Error: {e}");
+        1
+    })?;
+
+    match format {
+        TextFormat::Text => println!("{text}"),
+        TextFormat::Json => {
+            let obj = serde_json::json!({ "text": text });
+            println!("{}", serde_json::to_string(&obj).unwrap());
+        }
     }
+
+    Ok(())
 }
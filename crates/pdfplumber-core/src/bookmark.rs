@@ -1,7 +1,10 @@
 //! PDF bookmark / outline / table of contents types.
 //!
 //! Provides [`Bookmark`] for representing entries in the PDF document outline
-//! tree (bookmarks / table of contents).
+//! tree (bookmarks / table of contents), and [`OutlineItem`] for the same
+//! data preserved as a hierarchical tree.
+
+use crate::painting::Color;
 
 /// A single entry in the PDF document outline (bookmark / table of contents).
 ///
@@ -21,6 +24,35 @@ pub struct Bookmark {
     pub dest_top: Option<f64>,
 }
 
+/// A single node in the PDF document outline tree.
+///
+/// Unlike [`Bookmark`]'s flattened `level`-indexed list, `OutlineItem`
+/// preserves the outline's nesting via `children`, and carries the
+/// additional display hints (`count`, `color`, `italic`, `bold`) that the
+/// PDF spec attaches to outline entries.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutlineItem {
+    /// The outline entry's title text.
+    pub title: String,
+    /// The 0-indexed destination page number, if resolvable.
+    pub page_number: Option<usize>,
+    /// The y-coordinate on the destination page (top of view), if available.
+    pub dest_top: Option<f64>,
+    /// Signed open/closed descendant count from the `/Count` key: positive
+    /// if the entry is shown expanded by default, negative if collapsed.
+    /// Zero if the entry has no descendants or no `/Count` entry.
+    pub count: i32,
+    /// Entry color from `/C`, if specified.
+    pub color: Option<Color>,
+    /// Whether the title is flagged italic (bit 1 of `/F`).
+    pub italic: bool,
+    /// Whether the title is flagged bold (bit 2 of `/F`).
+    pub bold: bool,
+    /// Child outline entries, from following `/First`/`/Next` links.
+    pub children: Vec<OutlineItem>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +131,51 @@ mod tests {
         assert_eq!(bookmarks[2].level, 2);
         assert_eq!(bookmarks[3].level, 0);
     }
+
+    #[test]
+    fn outline_item_with_children() {
+        let tree = vec![OutlineItem {
+            title: "Chapter 1".to_string(),
+            page_number: Some(0),
+            dest_top: Some(792.0),
+            count: 1,
+            color: Some(Color::new(1.0, 0.0, 0.0)),
+            italic: false,
+            bold: true,
+            children: vec![OutlineItem {
+                title: "Section 1.1".to_string(),
+                page_number: Some(1),
+                dest_top: None,
+                count: 0,
+                color: None,
+                italic: true,
+                bold: false,
+                children: vec![],
+            }],
+        }];
+
+        assert_eq!(tree[0].title, "Chapter 1");
+        assert_eq!(tree[0].count, 1);
+        assert!(tree[0].bold);
+        assert_eq!(tree[0].children.len(), 1);
+        assert_eq!(tree[0].children[0].title, "Section 1.1");
+        assert!(tree[0].children[0].italic);
+        assert!(tree[0].children[0].children.is_empty());
+    }
+
+    #[test]
+    fn outline_item_clone_and_eq() {
+        let item1 = OutlineItem {
+            title: "Appendix".to_string(),
+            page_number: None,
+            dest_top: None,
+            count: -2,
+            color: None,
+            italic: false,
+            bold: false,
+            children: vec![],
+        };
+        let item2 = item1.clone();
+        assert_eq!(item1, item2);
+    }
 }
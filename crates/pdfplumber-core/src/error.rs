@@ -70,6 +70,27 @@ impl From<std::io::Error> for PdfError {
     }
 }
 
+/// Which credential authenticated an encrypted PDF.
+///
+/// The standard security handler supports a user password (required to open
+/// the document) and an optional owner password (used to recover the user
+/// password for "classic" RC4/AES-128 handlers, typically to enforce
+/// permissions without requiring a separate secret). When the password a
+/// caller supplied matches the owner password rather than the user
+/// password, this lets them tell the two apart, e.g. to warn that permission
+/// restrictions were bypassed via the owner credential.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Authentication {
+    /// The document is not encrypted, or no password was needed to open it.
+    #[default]
+    None,
+    /// The supplied password matched the user password directly.
+    User,
+    /// The supplied password matched the owner password; the user password
+    /// was recovered from it to decrypt the document.
+    Owner,
+}
+
 /// Machine-readable warning code for categorizing extraction issues.
 ///
 /// Each variant represents a specific category of non-fatal issue that
@@ -314,6 +335,12 @@ pub struct ExtractOptions {
     pub extract_image_data: bool,
     /// When true, any warning is escalated to an error (default: false).
     pub strict_mode: bool,
+    /// When true, per-page interpretation failures and type-mismatch errors
+    /// on optional structures (annotations, hyperlinks) are caught and
+    /// surfaced as warnings instead of aborting the whole page extraction,
+    /// so callers get back whatever content was extracted before the
+    /// failure (default: false).
+    pub lenient: bool,
     /// Maximum input PDF file size in bytes (default: None = no limit).
     pub max_input_bytes: Option<usize>,
     /// Maximum number of pages to process (default: None = no limit).
@@ -322,6 +349,22 @@ pub struct ExtractOptions {
     pub max_total_image_bytes: Option<usize>,
     /// Maximum total extracted objects across all pages (default: None = no limit).
     pub max_total_objects: Option<usize>,
+    /// Password to try if the document is encrypted (default: None).
+    ///
+    /// The empty user password is always tried first; this password is only
+    /// tried as a fallback if that fails. Ignored for unencrypted documents.
+    ///
+    /// Decryption itself (RC4 and AES-128/256-CBC under the PDF Standard
+    /// security handler, revisions 2-6) is handled by the `lopdf` backend once
+    /// a correct password is found, so content streams, strings, and image
+    /// XObjects all come back already decrypted to the rest of the pipeline.
+    pub password: Option<String>,
+    /// When true, opt into best-effort recovery of PDFs with a misplaced
+    /// `%PDF-` header or an unparseable cross-reference table (default: false).
+    ///
+    /// Strict callers that want a hard failure on structurally broken PDFs
+    /// should leave this off; see `Pdf::open` for what recovery is attempted.
+    pub repair: bool,
 }
 
 impl Default for ExtractOptions {
@@ -334,10 +377,13 @@ impl Default for ExtractOptions {
             unicode_norm: UnicodeNorm::Nfc,
             extract_image_data: false,
             strict_mode: false,
+            lenient: false,
             max_input_bytes: None,
             max_pages: None,
             max_total_image_bytes: None,
             max_total_objects: None,
+            password: None,
+            repair: false,
         }
     }
 }
@@ -611,10 +657,13 @@ mod tests {
         assert!(opts.collect_warnings);
         assert_eq!(opts.unicode_norm, UnicodeNorm::Nfc);
         assert!(!opts.extract_image_data);
+        assert!(!opts.lenient);
         assert!(opts.max_input_bytes.is_none());
         assert!(opts.max_pages.is_none());
         assert!(opts.max_total_image_bytes.is_none());
         assert!(opts.max_total_objects.is_none());
+        assert!(opts.password.is_none());
+        assert!(!opts.repair);
     }
 
     #[test]
@@ -637,10 +686,13 @@ mod tests {
             unicode_norm: UnicodeNorm::None,
             extract_image_data: true,
             strict_mode: true,
+            lenient: true,
             max_input_bytes: Some(1024),
             max_pages: Some(10),
             max_total_image_bytes: Some(5 * 1024 * 1024),
             max_total_objects: Some(100_000),
+            password: Some("secret".to_string()),
+            repair: true,
         };
         assert_eq!(opts.max_recursion_depth, 5);
         assert_eq!(opts.max_objects_per_page, 50_000);
@@ -648,10 +700,13 @@ mod tests {
         assert!(!opts.collect_warnings);
         assert!(opts.extract_image_data);
         assert!(opts.strict_mode);
+        assert!(opts.lenient);
         assert_eq!(opts.max_input_bytes, Some(1024));
         assert_eq!(opts.max_pages, Some(10));
         assert_eq!(opts.max_total_image_bytes, Some(5 * 1024 * 1024));
         assert_eq!(opts.max_total_objects, Some(100_000));
+        assert_eq!(opts.password.as_deref(), Some("secret"));
+        assert!(opts.repair);
     }
 
     #[test]
@@ -782,6 +837,18 @@ mod tests {
         assert!(!opts.strict_mode);
     }
 
+    #[test]
+    fn lenient_default_false() {
+        let opts = ExtractOptions::default();
+        assert!(!opts.lenient);
+    }
+
+    #[test]
+    fn repair_default_false() {
+        let opts = ExtractOptions::default();
+        assert!(!opts.repair);
+    }
+
     #[test]
     fn strict_mode_converts_warning_to_error() {
         let warning = ExtractWarning {
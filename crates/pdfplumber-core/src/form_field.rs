@@ -83,6 +83,37 @@ pub struct FormField {
     pub page_index: Option<usize>,
 }
 
+/// The document's AcroForm: its fields plus form-level behavior flags.
+///
+/// Corresponds to the catalog's `/AcroForm` dictionary (PDF 1.7 §12.7.2).
+/// `Pdf::acro_form()` returns this once per document; `Pdf::form_fields()`/
+/// `Page::form_fields()` expose just the [`Self::fields`] list for callers
+/// that don't need the form-level flags.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcroForm {
+    /// Terminal form fields, resolved from `/Fields` (see [`FormField`]).
+    pub fields: Vec<FormField>,
+    /// Whether viewers must regenerate field appearance streams from current
+    /// values rather than trusting any `/AP` already present (`/NeedAppearances`).
+    pub need_appearances: bool,
+    /// Signature-related flags from `/SigFlags` (PDF 1.7 Table 219).
+    pub sig_flags: u32,
+}
+
+impl AcroForm {
+    /// Bit 1 of `/SigFlags`: the document has at least one signature field.
+    pub fn has_signatures(&self) -> bool {
+        self.sig_flags & 0x1 != 0
+    }
+
+    /// Bit 2 of `/SigFlags`: the document permits no further changes other
+    /// than filling in form fields and adding signatures once signed.
+    pub fn append_only(&self) -> bool {
+        self.sig_flags & 0x2 != 0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,4 +276,34 @@ mod tests {
         let field2 = field1.clone();
         assert_eq!(field1, field2);
     }
+
+    #[test]
+    fn acro_form_default_is_empty() {
+        let form = AcroForm::default();
+        assert!(form.fields.is_empty());
+        assert!(!form.need_appearances);
+        assert_eq!(form.sig_flags, 0);
+        assert!(!form.has_signatures());
+        assert!(!form.append_only());
+    }
+
+    #[test]
+    fn acro_form_sig_flags_has_signatures() {
+        let form = AcroForm {
+            sig_flags: 0x1,
+            ..Default::default()
+        };
+        assert!(form.has_signatures());
+        assert!(!form.append_only());
+    }
+
+    #[test]
+    fn acro_form_sig_flags_append_only() {
+        let form = AcroForm {
+            sig_flags: 0x3,
+            ..Default::default()
+        };
+        assert!(form.has_signatures());
+        assert!(form.append_only());
+    }
 }
@@ -131,6 +131,88 @@ impl BBox {
             bottom: self.bottom.max(other.bottom),
         }
     }
+
+    /// Area of the bounding box (`width() * height()`).
+    pub fn area(&self) -> f64 {
+        self.width() * self.height()
+    }
+
+    /// Center point of the bounding box, as `(x, y)`.
+    pub fn center(&self) -> (f64, f64) {
+        ((self.x0 + self.x1) / 2.0, (self.top + self.bottom) / 2.0)
+    }
+
+    /// Inset the box by `margin` on every side (expand if `margin` is
+    /// negative), clamping so the box never inverts past its own center.
+    pub fn with_margin(&self, margin: f64) -> BBox {
+        let x_inset = margin.min(self.width() / 2.0);
+        let y_inset = margin.min(self.height() / 2.0);
+        BBox {
+            x0: self.x0 + x_inset,
+            top: self.top + y_inset,
+            x1: self.x1 - x_inset,
+            bottom: self.bottom - y_inset,
+        }
+    }
+
+    /// Project `(x, y)` onto the nearest point inside the box.
+    pub fn clamp_point(&self, x: f64, y: f64) -> (f64, f64) {
+        (x.clamp(self.x0, self.x1), y.clamp(self.top, self.bottom))
+    }
+
+    /// Whether `(x, y)` lies within the box, inclusive of all four edges.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        x >= self.x0 && x <= self.x1 && y >= self.top && y <= self.bottom
+    }
+
+    /// Whether `(x, y)` lies within the box, excluding the right and bottom
+    /// edges (matching half-open "contains inside" semantics, so adjacent
+    /// boxes sharing an edge don't both claim a point on it).
+    pub fn contains_strict(&self, x: f64, y: f64) -> bool {
+        x >= self.x0 && x < self.x1 && y >= self.top && y < self.bottom
+    }
+
+    /// Compute the overlap between two bounding boxes, or `None` if they
+    /// don't overlap (touching edges count as no overlap).
+    pub fn intersection(&self, other: &BBox) -> Option<BBox> {
+        let x0 = self.x0.max(other.x0);
+        let top = self.top.max(other.top);
+        let x1 = self.x1.min(other.x1);
+        let bottom = self.bottom.min(other.bottom);
+        if x0 < x1 && top < bottom {
+            Some(BBox { x0, top, x1, bottom })
+        } else {
+            None
+        }
+    }
+
+    /// Whether this box touches or overlaps `other`, inclusive of shared
+    /// edges — unlike [`Self::intersection`], which treats touching edges
+    /// as no overlap.
+    ///
+    /// Because the comparisons are inclusive, a zero-width or zero-height
+    /// box (e.g. the bbox of a perfectly horizontal or vertical line)
+    /// intersects `other` whenever its collapsed coordinate falls inside
+    /// `other`'s range on that axis and the boxes overlap on the other
+    /// axis, rather than always reporting no overlap the way a strict
+    /// positive-area test would.
+    pub fn intersects(&self, other: &BBox) -> bool {
+        self.x0 <= other.x1 && self.x1 >= other.x0 && self.top <= other.bottom && self.bottom >= other.top
+    }
+
+    /// Whether `other` is fully inside this box, inclusive of touching edges.
+    pub fn encloses(&self, other: &BBox) -> bool {
+        other.x0 >= self.x0 && other.x1 <= self.x1 && other.top >= self.top && other.bottom <= self.bottom
+    }
+}
+
+/// Area of `bbox` (`bbox.width() * bbox.height()`).
+///
+/// Equivalent to [`BBox::area`], provided as a free function so candidate
+/// regions can be ranked with e.g. `regions.iter().max_by(|a, b| calculate_area(**a).total_cmp(&calculate_area(**b)))`
+/// without borrowing through the method first.
+pub fn calculate_area(bbox: BBox) -> f64 {
+    bbox.area()
 }
 
 #[cfg(test)]
@@ -283,4 +365,161 @@ mod tests {
         assert_eq!(u.x1, 35.0);
         assert_eq!(u.bottom, 45.0);
     }
+
+    #[test]
+    fn test_bbox_area() {
+        let bbox = BBox::new(10.0, 20.0, 50.0, 60.0);
+        assert_eq!(bbox.area(), 1600.0);
+    }
+
+    #[test]
+    fn test_bbox_area_zero_size() {
+        let bbox = BBox::new(10.0, 20.0, 10.0, 60.0);
+        assert_eq!(bbox.area(), 0.0);
+    }
+
+    #[test]
+    fn test_calculate_area_matches_method() {
+        let bbox = BBox::new(0.0, 0.0, 8.0, 5.0);
+        assert_eq!(calculate_area(bbox), bbox.area());
+        assert_eq!(calculate_area(bbox), 40.0);
+    }
+
+    #[test]
+    fn test_bbox_center() {
+        let bbox = BBox::new(10.0, 20.0, 30.0, 60.0);
+        assert_eq!(bbox.center(), (20.0, 40.0));
+    }
+
+    #[test]
+    fn test_bbox_with_margin_insets() {
+        let bbox = BBox::new(10.0, 10.0, 50.0, 50.0);
+        let inset = bbox.with_margin(5.0);
+        assert_eq!(inset, BBox::new(15.0, 15.0, 45.0, 45.0));
+    }
+
+    #[test]
+    fn test_bbox_with_margin_expands_on_negative() {
+        let bbox = BBox::new(10.0, 10.0, 50.0, 50.0);
+        let expanded = bbox.with_margin(-5.0);
+        assert_eq!(expanded, BBox::new(5.0, 5.0, 55.0, 55.0));
+    }
+
+    #[test]
+    fn test_bbox_with_margin_clamps_instead_of_inverting() {
+        let bbox = BBox::new(0.0, 0.0, 10.0, 10.0);
+        let inset = bbox.with_margin(100.0);
+        assert_eq!(inset.width(), 0.0);
+        assert_eq!(inset.height(), 0.0);
+        assert!(inset.x0 <= inset.x1);
+        assert!(inset.top <= inset.bottom);
+    }
+
+    #[test]
+    fn test_bbox_clamp_point_inside_is_unchanged() {
+        let bbox = BBox::new(0.0, 0.0, 10.0, 10.0);
+        assert_eq!(bbox.clamp_point(5.0, 5.0), (5.0, 5.0));
+    }
+
+    #[test]
+    fn test_bbox_clamp_point_outside_projects_to_edge() {
+        let bbox = BBox::new(0.0, 0.0, 10.0, 10.0);
+        assert_eq!(bbox.clamp_point(-5.0, 20.0), (0.0, 10.0));
+    }
+
+    #[test]
+    fn test_bbox_contains_inclusive_edges() {
+        let bbox = BBox::new(0.0, 0.0, 10.0, 10.0);
+        assert!(bbox.contains(0.0, 0.0));
+        assert!(bbox.contains(10.0, 10.0));
+        assert!(!bbox.contains(10.1, 5.0));
+    }
+
+    #[test]
+    fn test_bbox_contains_strict_excludes_right_and_bottom() {
+        let bbox = BBox::new(0.0, 0.0, 10.0, 10.0);
+        assert!(bbox.contains_strict(0.0, 0.0));
+        assert!(!bbox.contains_strict(10.0, 5.0));
+        assert!(!bbox.contains_strict(5.0, 10.0));
+    }
+
+    #[test]
+    fn test_bbox_intersection_overlapping() {
+        let a = BBox::new(0.0, 0.0, 10.0, 10.0);
+        let b = BBox::new(5.0, 5.0, 15.0, 15.0);
+        assert_eq!(a.intersection(&b), Some(BBox::new(5.0, 5.0, 10.0, 10.0)));
+    }
+
+    #[test]
+    fn test_bbox_intersection_touching_edges_is_none() {
+        let a = BBox::new(0.0, 0.0, 10.0, 10.0);
+        let b = BBox::new(10.0, 0.0, 20.0, 10.0);
+        assert_eq!(a.intersection(&b), None);
+    }
+
+    #[test]
+    fn test_bbox_intersection_disjoint_is_none() {
+        let a = BBox::new(0.0, 0.0, 10.0, 10.0);
+        let b = BBox::new(20.0, 20.0, 30.0, 30.0);
+        assert_eq!(a.intersection(&b), None);
+    }
+
+    #[test]
+    fn test_bbox_intersects_overlapping() {
+        let a = BBox::new(0.0, 0.0, 10.0, 10.0);
+        let b = BBox::new(5.0, 5.0, 15.0, 15.0);
+        assert!(a.intersects(&b));
+    }
+
+    #[test]
+    fn test_bbox_intersects_touching_edges_counts_as_intersecting() {
+        let a = BBox::new(0.0, 0.0, 10.0, 10.0);
+        let b = BBox::new(10.0, 0.0, 20.0, 10.0);
+        assert!(a.intersects(&b));
+    }
+
+    #[test]
+    fn test_bbox_intersects_disjoint_is_false() {
+        let a = BBox::new(0.0, 0.0, 10.0, 10.0);
+        let b = BBox::new(20.0, 20.0, 30.0, 30.0);
+        assert!(!a.intersects(&b));
+    }
+
+    #[test]
+    fn test_bbox_intersects_zero_width_box_inside_range() {
+        // A zero-width box (e.g. a vertical line's bbox) at x=5 intersects
+        // a region spanning x in [0, 10] even though neither box has area
+        // overlap in the strict sense.
+        let vertical_line = BBox::new(5.0, 0.0, 5.0, 10.0);
+        let region = BBox::new(0.0, 0.0, 10.0, 10.0);
+        assert!(vertical_line.intersects(&region));
+    }
+
+    #[test]
+    fn test_bbox_intersects_zero_width_box_outside_range() {
+        let vertical_line = BBox::new(50.0, 0.0, 50.0, 10.0);
+        let region = BBox::new(0.0, 0.0, 10.0, 10.0);
+        assert!(!vertical_line.intersects(&region));
+    }
+
+    #[test]
+    fn test_bbox_encloses_fully_contained() {
+        let outer = BBox::new(0.0, 0.0, 10.0, 10.0);
+        let inner = BBox::new(2.0, 2.0, 8.0, 8.0);
+        assert!(outer.encloses(&inner));
+    }
+
+    #[test]
+    fn test_bbox_encloses_touching_edges_counts_as_enclosed() {
+        let outer = BBox::new(0.0, 0.0, 10.0, 10.0);
+        let inner = BBox::new(0.0, 0.0, 10.0, 10.0);
+        assert!(outer.encloses(&inner));
+    }
+
+    #[test]
+    fn test_bbox_encloses_partial_overlap_is_false() {
+        let outer = BBox::new(0.0, 0.0, 10.0, 10.0);
+        let partial = BBox::new(5.0, 5.0, 15.0, 15.0);
+        assert!(!outer.encloses(&partial));
+    }
 }
@@ -6,8 +6,11 @@
 
 use std::collections::HashMap;
 use std::hash::{DefaultHasher, Hasher};
+use std::path::Path;
 
+use crate::error::PdfError;
 use crate::geometry::{BBox, Ctm, Point};
+use crate::png::{PngColorType, encode_png};
 
 /// Metadata about an image XObject from the PDF resource dictionary.
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -21,6 +24,10 @@ pub struct ImageMetadata {
     pub bits_per_component: Option<u32>,
     /// Color space name (e.g., "DeviceRGB", "DeviceGray").
     pub color_space: Option<String>,
+    /// Whether this image is a stencil mask (`/ImageMask true`).
+    pub is_mask: bool,
+    /// The `/Decode` array, if present (e.g., `[1.0, 0.0]` to invert a mask).
+    pub decode: Option<Vec<f64>>,
 }
 
 /// An image extracted from a PDF page via the Do operator.
@@ -57,6 +64,10 @@ pub struct Image {
     pub filter: Option<ImageFilter>,
     /// MIME type of the image data (e.g., "image/jpeg").
     pub mime_type: Option<String>,
+    /// Whether this image is a stencil mask (`/ImageMask true`).
+    pub is_mask: bool,
+    /// The `/Decode` array, if present (e.g., `[1.0, 0.0]` to invert a mask).
+    pub decode: Option<Vec<f64>>,
 }
 
 /// Extract an Image from the CTM active during a Do operator invocation.
@@ -111,6 +122,8 @@ pub fn image_from_ctm(ctm: &Ctm, name: &str, page_height: f64, metadata: &ImageM
         data: None,
         filter: None,
         mime_type: None,
+        is_mask: metadata.is_mask,
+        decode: metadata.decode.clone(),
     }
 }
 
@@ -119,6 +132,172 @@ impl Image {
     pub fn bbox(&self) -> BBox {
         BBox::new(self.x0, self.top, self.x1, self.bottom)
     }
+
+    /// Write this image's decoded data to `path`, choosing a file format
+    /// from [`Image::filter`](Image::filter).
+    ///
+    /// DCT-encoded (JPEG) and JPX-encoded (JPEG 2000) streams are written
+    /// out unchanged, since `data` already holds a complete JPEG/JP2 file.
+    /// Every other filter is assumed to have been inflated to raw samples
+    /// by the backend and is re-encoded as a PNG, inverting a stencil
+    /// mask's bits when [`Image::decode`] requests it (`[1.0, 0.0]`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PdfError::Other`] if `data` was never populated (see
+    /// `ExtractOptions::extract_image_data`), or [`PdfError::IoError`] if
+    /// the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), PdfError> {
+        let data = self.data.as_ref().ok_or_else(|| {
+            PdfError::Other(
+                "image has no decoded data; extract with extract_image_data enabled".to_string(),
+            )
+        })?;
+
+        let bytes = match self.filter {
+            Some(ImageFilter::DCTDecode) | Some(ImageFilter::JPXDecode) => data.clone(),
+            _ => {
+                let width = self.src_width.unwrap_or(self.width.round() as u32).max(1);
+                let height = self.src_height.unwrap_or(self.height.round() as u32).max(1);
+
+                if self.is_mask {
+                    let invert = matches!(self.decode.as_deref(), Some([d0, d1]) if d0 > d1);
+                    let samples = if invert { invert_bits(data) } else { data.clone() };
+                    encode_png(width, height, PngColorType::Grayscale, 1, &samples)
+                } else {
+                    let color_type = match self.color_space.as_deref() {
+                        Some("DeviceGray") | Some("CalGray") => PngColorType::Grayscale,
+                        _ => PngColorType::Rgb,
+                    };
+                    let bit_depth = match self.bits_per_component.unwrap_or(8) {
+                        16 => 16,
+                        1 | 2 | 4 => self.bits_per_component.unwrap() as u8,
+                        _ => 8,
+                    };
+                    encode_png(width, height, color_type, bit_depth, data)
+                }
+            }
+        };
+
+        std::fs::write(path, bytes).map_err(|e| PdfError::IoError(e.to_string()))
+    }
+}
+
+/// Invert every bit (used to flip a 1-bit stencil mask per its Decode array).
+fn invert_bits(data: &[u8]) -> Vec<u8> {
+    data.iter().map(|b| !b).collect()
+}
+
+/// Reverse a PNG (`/Predictor` 10-15) or TIFF (`/Predictor` 2) predictor
+/// applied to already-inflated `FlateDecode`/`LZWDecode` image samples, per
+/// PDF 32000-1:2008 §7.4.4.4.
+///
+/// `predictor` values of 0 or 1 mean no predictor was applied; `data` is
+/// returned unchanged. `colors`, `bits_per_component`, and `columns` come
+/// from the stream's `/DecodeParms` dictionary (falling back to the image's
+/// own `/BitsPerComponent` and `/Width` where `/DecodeParms` omits them).
+///
+/// For `/Predictor` 10-15, every predictor value reconstructs the same way:
+/// each row carries a leading PNG filter-type byte (0=None, 1=Sub, 2=Up,
+/// 3=Average, 4=Paeth) regardless of which specific value was requested, so
+/// this reconstructs all six uniformly. `/Predictor` 2 (TIFF) is only
+/// reconstructed for 8-bit-per-component samples, the overwhelmingly common
+/// case; other bit depths are returned unchanged.
+pub fn reverse_predictor(
+    data: &[u8],
+    predictor: u32,
+    colors: u32,
+    bits_per_component: u32,
+    columns: u32,
+) -> Vec<u8> {
+    if predictor <= 1 {
+        return data.to_vec();
+    }
+
+    let bpp = (colors * bits_per_component).div_ceil(8).max(1) as usize;
+    let row_bytes = ((columns as u64 * colors as u64 * bits_per_component as u64).div_ceil(8))
+        .max(1) as usize;
+
+    if predictor == 2 {
+        return reverse_tiff_predictor(data, row_bytes, bpp, bits_per_component);
+    }
+
+    reverse_png_row_filters(data, row_bytes, bpp)
+}
+
+/// Reconstruct samples from PNG-style per-row filtering (predictors 10-15).
+fn reverse_png_row_filters(data: &[u8], row_bytes: usize, bpp: usize) -> Vec<u8> {
+    let stride = row_bytes + 1;
+    let num_rows = data.len() / stride;
+    let mut out = Vec::with_capacity(row_bytes * num_rows);
+    let mut prev_row = vec![0u8; row_bytes];
+
+    for row in 0..num_rows {
+        let start = row * stride;
+        let filter_type = data[start];
+        let filtered = &data[start + 1..start + 1 + row_bytes];
+        let mut cur_row = vec![0u8; row_bytes];
+
+        for i in 0..row_bytes {
+            let a = if i >= bpp { cur_row[i - bpp] } else { 0 } as i32;
+            let b = prev_row[i] as i32;
+            let c = if i >= bpp { prev_row[i - bpp] } else { 0 } as i32;
+
+            let recon = match filter_type {
+                1 => filtered[i] as i32 + a,
+                2 => filtered[i] as i32 + b,
+                3 => filtered[i] as i32 + (a + b) / 2,
+                4 => filtered[i] as i32 + paeth_predictor(a, b, c),
+                _ => filtered[i] as i32,
+            };
+            cur_row[i] = recon as u8;
+        }
+
+        out.extend_from_slice(&cur_row);
+        prev_row = cur_row;
+    }
+
+    out
+}
+
+/// PNG Paeth predictor: picks whichever of `a` (left), `b` (up), or `c`
+/// (upper-left) is closest to `a + b - c`.
+fn paeth_predictor(a: i32, b: i32, c: i32) -> i32 {
+    let p = a + b - c;
+    let pa = (p - a).abs();
+    let pb = (p - b).abs();
+    let pc = (p - c).abs();
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+/// Reconstruct samples from TIFF-style horizontal differencing (predictor 2).
+///
+/// Each sample is the difference from the previous sample in the same color
+/// channel, `bpp` bytes earlier in the row; reconstruction sums cumulatively
+/// from the start of each row.
+fn reverse_tiff_predictor(
+    data: &[u8],
+    row_bytes: usize,
+    bpp: usize,
+    bits_per_component: u32,
+) -> Vec<u8> {
+    if bits_per_component != 8 {
+        return data.to_vec();
+    }
+
+    let mut out = data.to_vec();
+    for row in out.chunks_mut(row_bytes) {
+        for i in bpp..row.len() {
+            row[i] = row[i].wrapping_add(row[i - bpp]);
+        }
+    }
+    out
 }
 
 /// PDF stream filter used to encode image data.
@@ -205,6 +384,8 @@ pub enum ImageFormat {
     Jbig2,
     /// CCITT fax compressed image.
     CcittFax,
+    /// JPEG 2000 image (JPXDecode filter).
+    Jp2,
 }
 
 impl ImageFormat {
@@ -216,6 +397,7 @@ impl ImageFormat {
             ImageFormat::Raw => "raw",
             ImageFormat::Jbig2 => "jbig2",
             ImageFormat::CcittFax => "ccitt",
+            ImageFormat::Jp2 => "jp2",
         }
     }
 }
@@ -397,6 +579,8 @@ mod tests {
             data: None,
             filter: None,
             mime_type: None,
+            is_mask: false,
+            decode: None,
         };
         assert_eq!(img.x0, 72.0);
         assert_eq!(img.top, 100.0);
@@ -437,6 +621,8 @@ mod tests {
             data: None,
             filter: None,
             mime_type: None,
+            is_mask: false,
+            decode: None,
         };
         let bbox = img.bbox();
         assert_approx(bbox.x0, 100.0);
@@ -457,6 +643,8 @@ mod tests {
             src_height: Some(480),
             bits_per_component: Some(8),
             color_space: Some("DeviceRGB".to_string()),
+            is_mask: false,
+            decode: None,
         };
 
         let img = image_from_ctm(&ctm, "Im0", PAGE_HEIGHT, &meta);
@@ -737,6 +925,8 @@ mod tests {
             data: Some(jpeg_data.clone()),
             filter: Some(ImageFilter::DCTDecode),
             mime_type: Some("image/jpeg".to_string()),
+            is_mask: false,
+            decode: None,
         };
         assert_eq!(img.data, Some(jpeg_data));
         assert_eq!(img.filter, Some(ImageFilter::DCTDecode));
@@ -884,6 +1074,8 @@ mod tests {
             data: Some(data),
             filter: Some(filter),
             mime_type: Some(filter.mime_type().to_string()),
+            is_mask: false,
+            decode: None,
         }
     }
 
@@ -1045,4 +1237,179 @@ mod tests {
             );
         }
     }
+
+    // --- ImageMetadata with is_mask/decode ---
+
+    #[test]
+    fn test_image_from_ctm_carries_mask_and_decode() {
+        let ctm = Ctm::new(100.0, 0.0, 0.0, 100.0, 0.0, 0.0);
+        let meta = ImageMetadata {
+            is_mask: true,
+            decode: Some(vec![1.0, 0.0]),
+            ..ImageMetadata::default()
+        };
+        let img = image_from_ctm(&ctm, "Im0", PAGE_HEIGHT, &meta);
+        assert!(img.is_mask);
+        assert_eq!(img.decode, Some(vec![1.0, 0.0]));
+    }
+
+    // --- PNG encoding / Image::save ---
+
+    fn image_with_data(
+        data: Vec<u8>,
+        filter: Option<ImageFilter>,
+        src_width: u32,
+        src_height: u32,
+        color_space: Option<&str>,
+        bits_per_component: u32,
+        is_mask: bool,
+        decode: Option<Vec<f64>>,
+    ) -> Image {
+        Image {
+            x0: 0.0,
+            top: 0.0,
+            x1: src_width as f64,
+            bottom: src_height as f64,
+            width: src_width as f64,
+            height: src_height as f64,
+            name: "Im0".to_string(),
+            src_width: Some(src_width),
+            src_height: Some(src_height),
+            bits_per_component: Some(bits_per_component),
+            color_space: color_space.map(|s| s.to_string()),
+            data: Some(data),
+            filter,
+            mime_type: None,
+            is_mask,
+            decode,
+        }
+    }
+
+    #[test]
+    fn test_save_without_data_errors() {
+        let mut img = image_with_data(vec![], None, 1, 1, Some("DeviceGray"), 8, false, None);
+        img.data = None;
+        let err = img.save(std::env::temp_dir().join("pdfplumber_rs_no_data.png"));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_save_dct_writes_raw_jpeg_bytes() {
+        let jpeg_data = vec![0xFF, 0xD8, 0xFF, 0xD9];
+        let img = image_with_data(
+            jpeg_data.clone(),
+            Some(ImageFilter::DCTDecode),
+            10,
+            10,
+            None,
+            8,
+            false,
+            None,
+        );
+        let path = std::env::temp_dir().join("pdfplumber_rs_test_dct.jpg");
+        img.save(&path).expect("save should succeed");
+        assert_eq!(std::fs::read(&path).unwrap(), jpeg_data);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_save_raw_gray_encodes_valid_png() {
+        // 2x2 grayscale image, 1 byte/pixel
+        let img = image_with_data(
+            vec![0, 64, 128, 255],
+            Some(ImageFilter::FlateDecode),
+            2,
+            2,
+            Some("DeviceGray"),
+            8,
+            false,
+            None,
+        );
+        let path = std::env::temp_dir().join("pdfplumber_rs_test_gray.png");
+        img.save(&path).expect("save should succeed");
+        let png = std::fs::read(&path).unwrap();
+        assert_eq!(&png[0..8], &[137, 80, 78, 71, 13, 10, 26, 10]);
+        assert_eq!(&png[12..16], b"IHDR");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_save_mask_inverts_bits_when_decode_is_reversed() {
+        let img = image_with_data(
+            vec![0b1010_1010],
+            Some(ImageFilter::CCITTFaxDecode),
+            8,
+            1,
+            None,
+            1,
+            true,
+            Some(vec![1.0, 0.0]),
+        );
+        let path = std::env::temp_dir().join("pdfplumber_rs_test_mask.png");
+        img.save(&path).expect("save should succeed");
+        // Inverted-mask PNG still decodes to a well-formed file.
+        let png = std::fs::read(&path).unwrap();
+        assert_eq!(&png[0..8], &[137, 80, 78, 71, 13, 10, 26, 10]);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    // --- reverse_predictor ---
+
+    #[test]
+    fn test_reverse_predictor_no_predictor_is_passthrough() {
+        let data = vec![1, 2, 3, 4];
+        assert_eq!(reverse_predictor(&data, 1, 1, 8, 2), data);
+        assert_eq!(reverse_predictor(&data, 0, 1, 8, 2), data);
+    }
+
+    #[test]
+    fn test_reverse_predictor_png_none_filter() {
+        // 2x2 grayscale, 8bpc: each row prefixed with filter type 0 (None).
+        let filtered = vec![0, 10, 20, 0, 30, 40];
+        let samples = reverse_predictor(&filtered, 15, 1, 8, 2);
+        assert_eq!(samples, vec![10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn test_reverse_predictor_png_sub_filter() {
+        // Sub filter: each byte is the difference from the sample bpp bytes
+        // to its left (bpp=1 for 1-color, 8bpc). Row: 10, 10+5=15.
+        let filtered = vec![1, 10, 5];
+        let samples = reverse_predictor(&filtered, 10, 1, 8, 2);
+        assert_eq!(samples, vec![10, 15]);
+    }
+
+    #[test]
+    fn test_reverse_predictor_png_up_filter() {
+        // Up filter: each byte is the difference from the sample directly
+        // above. First row has no "above" (treated as 0).
+        let filtered = vec![0, 10, 20, 2, 5, 5];
+        let samples = reverse_predictor(&filtered, 12, 1, 8, 2);
+        assert_eq!(samples, vec![10, 20, 15, 25]);
+    }
+
+    #[test]
+    fn test_reverse_predictor_png_paeth_filter_matches_up_when_left_absent() {
+        // With no left/upper-left neighbor (first column), Paeth reduces to
+        // the Up filter's behavior.
+        let filtered = vec![0, 100, 4, 10];
+        let samples = reverse_predictor(&filtered, 15, 1, 8, 1);
+        assert_eq!(samples, vec![100, 110]);
+    }
+
+    #[test]
+    fn test_reverse_predictor_tiff_8bpc_rgb() {
+        // TIFF predictor 2, 3 colors, 8bpc: each sample is a delta from the
+        // same channel's previous pixel. Row: (10,20,30), (5,5,5) deltas.
+        let filtered = vec![10, 20, 30, 5, 5, 5];
+        let samples = reverse_predictor(&filtered, 2, 3, 8, 2);
+        assert_eq!(samples, vec![10, 20, 30, 15, 25, 35]);
+    }
+
+    #[test]
+    fn test_reverse_predictor_tiff_non_8bpc_is_passthrough() {
+        // 1-bit-per-component TIFF predictor reconstruction isn't attempted.
+        let data = vec![0b1010_1010];
+        assert_eq!(reverse_predictor(&data, 2, 1, 1, 8), data);
+    }
 }
@@ -15,11 +15,15 @@
 //! - [`edges`] — Edge derivation for table detection: [`Edge`], [`EdgeSource`]
 //! - [`table`] — Table detection: [`Table`], [`TableFinder`], [`TableSettings`]
 //! - [`images`] — Image extraction: [`Image`], [`ImageMetadata`]
+//! - [`raster`] — Page rasterization: debug raster [`Bitmap`], full-page [`RenderedPage`]
 //! - [`painting`] — Graphics state: [`Color`], [`GraphicsState`], [`PaintedPath`]
 //! - [`path`] — Path construction: [`Path`], [`PathBuilder`], [`PathSegment`]
+//! - [`permissions`] — Document permissions and crypt filters: [`Permissions`], [`CryptFilterMethod`]
 //! - [`encoding`] — Font encoding: [`FontEncoding`], [`EncodingResolver`]
 //! - [`error`] — Errors and warnings: [`PdfError`], [`ExtractWarning`], [`ExtractOptions`]
-//! - [`search`] — Text search: [`SearchMatch`], [`SearchOptions`], [`search_chars`]
+//! - [`search`] — Text search: [`SearchMatch`], [`SearchOptions`], [`search_chars`], [`search_words`]
+//! - [`query`] — Spatial queries: [`QueryElement`], [`elements_intersecting`], [`elements_enclosed_by`]
+//! - [`text_render`] — Unicode box-drawing debug output: [`TextRenderer`]
 //! - [`unicode_norm`] — Unicode normalization: [`UnicodeNorm`], [`normalize_chars`]
 
 #![deny(missing_docs)]
@@ -52,12 +56,24 @@ pub mod layout;
 pub mod markdown;
 /// Document-level metadata types.
 pub mod metadata;
+/// Streaming output device for character-extraction events.
+pub mod output;
 /// PageObject enum for custom object filtering.
 pub mod page_object;
+/// Header/footer detection and page region classification.
+pub mod page_regions;
 /// Graphics state, colors, dash patterns, and painted paths.
 pub mod painting;
 /// PDF path construction (MoveTo, LineTo, CurveTo, ClosePath).
 pub mod path;
+/// Document permission flags decoded from the `/P` entry.
+pub mod permissions;
+/// Minimal dependency-free PNG encoding, shared by `images` and `raster`.
+mod png;
+/// Spatial queries (intersects / encloses) over extracted page objects.
+pub mod query;
+/// Rasterization of page content to an RGBA bitmap for visual debugging.
+pub mod raster;
 /// PDF repair types for best-effort fixing of common PDF issues.
 pub mod repair;
 /// Text search with position — find text patterns and return matches with bounding boxes.
@@ -74,6 +90,8 @@ pub mod svg;
 pub mod table;
 /// Character data types and CJK detection.
 pub mod text;
+/// Unicode box-drawing rendering of table structure for terminal/log debugging.
+pub mod text_render;
 /// Unicode normalization for extracted text.
 pub mod unicode_norm;
 /// PDF validation types for detecting specification violations.
@@ -82,27 +100,38 @@ pub mod validation;
 pub mod words;
 
 pub use annotation::{Annotation, AnnotationType};
-pub use bookmark::Bookmark;
+pub use bookmark::{Bookmark, OutlineItem};
 pub use dedupe::{DedupeOptions, dedupe_chars};
 pub use edges::{Edge, EdgeSource, derive_edges, edge_from_curve, edge_from_line, edges_from_rect};
 pub use encoding::{EncodingResolver, FontEncoding, StandardEncoding, glyph_name_to_char};
-pub use error::{ExtractOptions, ExtractResult, ExtractWarning, PdfError};
-pub use form_field::{FieldType, FormField};
-pub use geometry::{BBox, Ctm, Orientation, Point};
+pub use error::{
+    Authentication, ExtractOptions, ExtractResult, ExtractWarning, ExtractWarningCode, PdfError,
+};
+pub use form_field::{AcroForm, FieldType, FormField};
+pub use geometry::{BBox, Ctm, Orientation, Point, calculate_area};
 pub use html::{HtmlOptions, HtmlRenderer};
 pub use hyperlink::Hyperlink;
-pub use images::{Image, ImageContent, ImageFormat, ImageMetadata, image_from_ctm};
+pub use images::{Image, ImageContent, ImageFormat, ImageMetadata, image_from_ctm, reverse_predictor};
 pub use layout::{
     TextBlock, TextLine, TextOptions, blocks_to_text, cluster_lines_into_blocks,
     cluster_words_into_lines, sort_blocks_reading_order, split_lines_at_columns, words_to_text,
 };
 pub use markdown::{MarkdownOptions, MarkdownRenderer};
-pub use metadata::DocumentMetadata;
+pub use metadata::{DocumentMetadata, PdfDate};
+pub use output::{HtmlOutput, HtmlOutputOptions, OutputDevice};
 pub use page_object::PageObject;
+pub use page_regions::{
+    MaskOptions, PageRegionCandidate, PageRegionOptions, PageRegions, Stripped, StripRegionOptions,
+    detect_page_regions, detect_page_regions_with_bounds, mask_variable_elements,
+    mask_variable_elements_with, strip_chars, strip_edges, strip_lines, strip_rects,
+};
 pub use painting::{Color, DashPattern, ExtGState, FillRule, GraphicsState, PaintedPath};
 pub use path::{Path, PathBuilder, PathSegment};
-pub use repair::{RepairOptions, RepairResult};
-pub use search::{SearchMatch, SearchOptions, search_chars};
+pub use permissions::{CryptFilterMethod, Permissions};
+pub use query::{QueryElement, elements_enclosed_by, elements_intersecting};
+pub use raster::{Bitmap, RenderOptions, RenderedPage, check_raster_dimensions, render_page};
+pub use repair::{DanglingRefPolicy, OrphanedObject, RepairOptions, RepairResult};
+pub use search::{SearchMatch, SearchOptions, WordSearchMatch, fuzzy_search_chars, search_chars, search_words};
 pub use shapes::{Curve, Line, LineOrientation, Rect, extract_shapes};
 pub use signature::SignatureInfo;
 pub use struct_tree::StructElement;
@@ -114,6 +143,9 @@ pub use table::{
     words_to_edges_stream,
 };
 pub use text::{Char, TextDirection, is_cjk, is_cjk_text};
+pub use text_render::TextRenderer;
 pub use unicode_norm::{UnicodeNorm, normalize_chars};
-pub use validation::{Severity, ValidationIssue};
+pub use validation::{
+    ColorMode, Severity, SourceSpan, ValidationIssue, ValidationReport, filter_by_severity,
+};
 pub use words::{Word, WordExtractor, WordOptions};
@@ -3,6 +3,8 @@
 //! Provides [`DocumentMetadata`] for PDF document information dictionary fields
 //! such as title, author, creation date, etc.
 
+use std::collections::BTreeMap;
+
 /// Document-level metadata extracted from the PDF /Info dictionary.
 ///
 /// All fields are optional since PDFs may omit the /Info dictionary entirely
@@ -13,6 +15,14 @@
 /// Date fields (`creation_date`, `mod_date`) are stored as raw PDF date
 /// strings in the format `D:YYYYMMDDHHmmSSOHH'mm'`. Use
 /// [`DocumentMetadata::parse_pdf_date`] to extract components.
+///
+/// # XMP Metadata
+///
+/// When the catalog has a `/Metadata` stream, its raw bytes are stored in
+/// [`DocumentMetadata::xmp`]. `title` and `creator` prefer the XMP `dc:title`
+/// / `dc:creator` values over the /Info dictionary when both are present,
+/// since XMP is the more recently-written source in documents that carry
+/// both (see PDF 32000-1:2008 §14.3).
 #[derive(Debug, Clone, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DocumentMetadata {
@@ -32,10 +42,18 @@ pub struct DocumentMetadata {
     pub creation_date: Option<String>,
     /// Date the document was last modified (raw PDF date string).
     pub mod_date: Option<String>,
+    /// Whether the document is fully trapped for print production: `"True"`,
+    /// `"False"`, or `"Unknown"` (the PDF default when `/Trapped` is absent).
+    pub trapped: Option<String>,
+    /// Vendor-specific /Info dictionary keys not covered by the fields above
+    /// (e.g. `PTEX.Fullbanner`), keyed by their PDF name without the slash.
+    pub custom: BTreeMap<String, String>,
+    /// Raw bytes of the catalog's `/Metadata` XMP stream, if present.
+    pub xmp: Option<Vec<u8>>,
 }
 
 impl DocumentMetadata {
-    /// Returns `true` if all metadata fields are `None`.
+    /// Returns `true` if all metadata fields are `None`/empty.
     pub fn is_empty(&self) -> bool {
         self.title.is_none()
             && self.author.is_none()
@@ -45,9 +63,125 @@ impl DocumentMetadata {
             && self.producer.is_none()
             && self.creation_date.is_none()
             && self.mod_date.is_none()
+            && self.trapped.is_none()
+            && self.custom.is_empty()
+            && self.xmp.is_none()
+    }
+
+    /// Parse a PDF date string of the form `D:YYYYMMDDHHmmSSOHH'mm'` (PDF
+    /// 32000-1:2008 §7.9.4) into a [`PdfDate`].
+    ///
+    /// Every component after the 4-digit year is optional: a field that's
+    /// missing (string ends early) defaults to its minimum value (month 1,
+    /// day 1, hour/minute/second 0), and the trailing UTC offset defaults to
+    /// `None` (unspecified relationship to UTC) when absent. The leading
+    /// `D:` prefix is optional on input, since some producers omit it.
+    ///
+    /// Returns `None` if `s` doesn't start with at least a 4-digit year.
+    pub fn parse_pdf_date(s: &str) -> Option<PdfDate> {
+        let s = s.strip_prefix("D:").unwrap_or(s);
+        let bytes = s.as_bytes();
+
+        fn digits(bytes: &[u8], start: usize, n: usize) -> Option<(u32, usize)> {
+            let end = start.checked_add(n)?;
+            let slice = bytes.get(start..end)?;
+            if !slice.iter().all(u8::is_ascii_digit) {
+                return None;
+            }
+            let value = std::str::from_utf8(slice).ok()?.parse::<u32>().ok()?;
+            Some((value, end))
+        }
+
+        let (year, mut pos) = digits(bytes, 0, 4)?;
+
+        let mut month = 1;
+        if let Some((v, next)) = digits(bytes, pos, 2) {
+            month = v;
+            pos = next;
+        }
+        let mut day = 1;
+        if let Some((v, next)) = digits(bytes, pos, 2) {
+            day = v;
+            pos = next;
+        }
+        let mut hour = 0;
+        if let Some((v, next)) = digits(bytes, pos, 2) {
+            hour = v;
+            pos = next;
+        }
+        let mut minute = 0;
+        if let Some((v, next)) = digits(bytes, pos, 2) {
+            minute = v;
+            pos = next;
+        }
+        let mut second = 0;
+        if let Some((v, next)) = digits(bytes, pos, 2) {
+            second = v;
+            pos = next;
+        }
+
+        let mut utc_offset_minutes = None;
+        if let Some(&sign_byte) = bytes.get(pos) {
+            match sign_byte {
+                b'Z' => utc_offset_minutes = Some(0),
+                b'+' | b'-' => {
+                    pos += 1;
+                    let sign = if sign_byte == b'+' { 1 } else { -1 };
+
+                    let mut offset_hours = 0;
+                    if let Some((v, next)) = digits(bytes, pos, 2) {
+                        offset_hours = v;
+                        pos = next;
+                    }
+                    if bytes.get(pos) == Some(&b'\'') {
+                        pos += 1;
+                    }
+                    let mut offset_minutes = 0;
+                    if let Some((v, _)) = digits(bytes, pos, 2) {
+                        offset_minutes = v;
+                    }
+
+                    utc_offset_minutes =
+                        Some(sign * (offset_hours as i32 * 60 + offset_minutes as i32));
+                }
+                _ => {}
+            }
+        }
+
+        Some(PdfDate {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+            utc_offset_minutes,
+        })
     }
 }
 
+/// A PDF date, decomposed from the `D:YYYYMMDDHHmmSSOHH'mm'` string format.
+///
+/// See [`DocumentMetadata::parse_pdf_date`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PdfDate {
+    /// Full 4-digit year.
+    pub year: u16,
+    /// Month, 1-12.
+    pub month: u8,
+    /// Day of month, 1-31.
+    pub day: u8,
+    /// Hour, 0-23.
+    pub hour: u8,
+    /// Minute, 0-59.
+    pub minute: u8,
+    /// Second, 0-59.
+    pub second: u8,
+    /// Offset from UTC in minutes (e.g. `-300` for `-05'00'`), if specified.
+    pub utc_offset_minutes: Option<i32>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,10 +198,14 @@ mod tests {
         assert_eq!(meta.producer, None);
         assert_eq!(meta.creation_date, None);
         assert_eq!(meta.mod_date, None);
+        assert_eq!(meta.trapped, None);
     }
 
     #[test]
     fn metadata_with_all_fields() {
+        let mut custom = BTreeMap::new();
+        custom.insert("PTEX.Fullbanner".to_string(), "This is pdfTeX".to_string());
+
         let meta = DocumentMetadata {
             title: Some("Test Document".to_string()),
             author: Some("John Doe".to_string()),
@@ -77,10 +215,85 @@ mod tests {
             producer: Some("pdfplumber-rs".to_string()),
             creation_date: Some("D:20240101120000+00'00'".to_string()),
             mod_date: Some("D:20240615153000+00'00'".to_string()),
+            trapped: Some("True".to_string()),
+            custom,
+            xmp: Some(b"<x:xmpmeta></x:xmpmeta>".to_vec()),
         };
         assert!(!meta.is_empty());
         assert_eq!(meta.title.as_deref(), Some("Test Document"));
         assert_eq!(meta.author.as_deref(), Some("John Doe"));
+        assert_eq!(meta.trapped.as_deref(), Some("True"));
+        assert_eq!(
+            meta.custom.get("PTEX.Fullbanner").map(String::as_str),
+            Some("This is pdfTeX")
+        );
+    }
+
+    #[test]
+    fn metadata_is_not_empty_with_only_custom_or_xmp() {
+        let mut custom = BTreeMap::new();
+        custom.insert("PTEX.Fullbanner".to_string(), "This is pdfTeX".to_string());
+        let meta = DocumentMetadata {
+            custom,
+            ..Default::default()
+        };
+        assert!(!meta.is_empty());
+
+        let meta = DocumentMetadata {
+            xmp: Some(b"<x:xmpmeta></x:xmpmeta>".to_vec()),
+            ..Default::default()
+        };
+        assert!(!meta.is_empty());
+    }
+
+    #[test]
+    fn parse_pdf_date_full_with_offset() {
+        let date = DocumentMetadata::parse_pdf_date("D:20240615153045+05'30'").unwrap();
+        assert_eq!(date.year, 2024);
+        assert_eq!(date.month, 6);
+        assert_eq!(date.day, 15);
+        assert_eq!(date.hour, 15);
+        assert_eq!(date.minute, 30);
+        assert_eq!(date.second, 45);
+        assert_eq!(date.utc_offset_minutes, Some(5 * 60 + 30));
+    }
+
+    #[test]
+    fn parse_pdf_date_negative_offset() {
+        let date = DocumentMetadata::parse_pdf_date("D:20240101120000-08'00'").unwrap();
+        assert_eq!(date.utc_offset_minutes, Some(-8 * 60));
+    }
+
+    #[test]
+    fn parse_pdf_date_z_offset() {
+        let date = DocumentMetadata::parse_pdf_date("D:20240101120000Z").unwrap();
+        assert_eq!(date.utc_offset_minutes, Some(0));
+    }
+
+    #[test]
+    fn parse_pdf_date_without_d_prefix() {
+        let date = DocumentMetadata::parse_pdf_date("20240101120000").unwrap();
+        assert_eq!(date.year, 2024);
+        assert_eq!(date.utc_offset_minutes, None);
+    }
+
+    #[test]
+    fn parse_pdf_date_year_only_defaults_rest() {
+        let date = DocumentMetadata::parse_pdf_date("D:2024").unwrap();
+        assert_eq!(date.year, 2024);
+        assert_eq!(date.month, 1);
+        assert_eq!(date.day, 1);
+        assert_eq!(date.hour, 0);
+        assert_eq!(date.minute, 0);
+        assert_eq!(date.second, 0);
+        assert_eq!(date.utc_offset_minutes, None);
+    }
+
+    #[test]
+    fn parse_pdf_date_invalid_returns_none() {
+        assert!(DocumentMetadata::parse_pdf_date("not a date").is_none());
+        assert!(DocumentMetadata::parse_pdf_date("D:202").is_none());
+        assert!(DocumentMetadata::parse_pdf_date("").is_none());
     }
 
     #[test]
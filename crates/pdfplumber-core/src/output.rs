@@ -0,0 +1,279 @@
+//! Streaming output device for character-extraction events.
+//!
+//! [`crate::html::HtmlRenderer`] renders an already-extracted `&[Char]`
+//! slice in one pass. [`OutputDevice`] is the push-based counterpart: a
+//! caller drives it with `begin_page`/`output_char`/`end_page` events as
+//! characters are produced, so a sink never has to hold a page's full
+//! `Vec<Char>` itself. This mirrors the `OutputDev` pattern in the
+//! `pdf-extract` crate and gives downstream users a general extension
+//! point for custom consumers (streaming writers, progress bars, etc.).
+
+use crate::geometry::Ctm;
+use crate::text::Char;
+use crate::words::{Word, WordExtractor, WordOptions};
+
+/// A sink for character-extraction events, driven one page at a time.
+///
+/// Implementors receive exactly one [`begin_page`](OutputDevice::begin_page)
+/// call per page, then one [`output_char`](OutputDevice::output_char) call
+/// per character in extraction order, then one
+/// [`end_page`](OutputDevice::end_page) call.
+pub trait OutputDevice {
+    /// Called once at the start of each page, with its dimensions in points.
+    fn begin_page(&mut self, width: f64, height: f64);
+
+    /// Called once per character, in extraction order, with the CTM that was
+    /// in effect when the character was painted.
+    fn output_char(&mut self, ch: &Char, transform: Ctm);
+
+    /// Called once at the end of each page, after its last `output_char`.
+    fn end_page(&mut self);
+}
+
+/// Options for [`HtmlOutput`].
+#[derive(Debug, Clone)]
+pub struct HtmlOutputOptions {
+    /// Emit one `<span>` per word (grouping characters via [`WordExtractor`])
+    /// instead of one per character.
+    pub group_words: bool,
+    /// Vertical tolerance for clustering characters into words (in points).
+    pub y_tolerance: f64,
+}
+
+impl Default for HtmlOutputOptions {
+    fn default() -> Self {
+        Self {
+            group_words: true,
+            y_tolerance: 3.0,
+        }
+    }
+}
+
+/// An [`OutputDevice`] that renders each page as absolutely-positioned
+/// `<span>` elements, reproducing the page's visual layout.
+///
+/// Each span's `left`/`top`/`font-size` are computed in pixels (1 PDF point
+/// = 1px) from the bbox and size of the word (or character, depending on
+/// [`HtmlOutputOptions::group_words`]) it covers.
+pub struct HtmlOutput {
+    options: HtmlOutputOptions,
+    html: String,
+    page_width: f64,
+    page_height: f64,
+    page_chars: Vec<Char>,
+}
+
+impl HtmlOutput {
+    /// Create a new, empty `HtmlOutput` with the given options.
+    pub fn new(options: HtmlOutputOptions) -> Self {
+        Self {
+            options,
+            html: String::new(),
+            page_width: 0.0,
+            page_height: 0.0,
+            page_chars: Vec::new(),
+        }
+    }
+
+    /// The accumulated HTML so far.
+    pub fn html(&self) -> &str {
+        &self.html
+    }
+
+    /// Consume the output device, returning the accumulated HTML.
+    pub fn into_html(self) -> String {
+        self.html
+    }
+
+    fn emit_span(&mut self, text: &str, x0: f64, top: f64, size: f64) {
+        if text.trim().is_empty() {
+            return;
+        }
+        let escaped = escape_html(text);
+        self.html.push_str(&format!(
+            "<span style=\"position:absolute;left:{x0:.2}px;top:{top:.2}px;font-size:{size:.2}px;\">{escaped}</span>\n"
+        ));
+    }
+}
+
+impl OutputDevice for HtmlOutput {
+    fn begin_page(&mut self, width: f64, height: f64) {
+        self.page_width = width;
+        self.page_height = height;
+        self.page_chars.clear();
+        self.html.push_str(&format!(
+            "<div class=\"page\" style=\"position:relative;width:{width:.2}px;height:{height:.2}px;\">\n"
+        ));
+    }
+
+    fn output_char(&mut self, ch: &Char, _transform: Ctm) {
+        self.page_chars.push(ch.clone());
+    }
+
+    fn end_page(&mut self) {
+        if self.options.group_words {
+            let words = WordExtractor::extract(
+                &self.page_chars,
+                &WordOptions {
+                    y_tolerance: self.options.y_tolerance,
+                    ..WordOptions::default()
+                },
+            );
+            for word in &words {
+                let size = word_dominant_size(word);
+                self.emit_span(&word.text, word.bbox.x0, word.bbox.top, size);
+            }
+        } else {
+            for ch in &self.page_chars {
+                self.emit_span(&ch.text, ch.bbox.x0, ch.bbox.top, ch.size);
+            }
+        }
+        self.html.push_str("</div>\n");
+    }
+}
+
+/// The most common character size in a word, falling back to its first
+/// character's size if every character has size `0.0`.
+fn word_dominant_size(word: &Word) -> f64 {
+    let mut best: (f64, usize) = (0.0, 0);
+    for c in word.chars.iter().filter(|c| c.size > 0.0) {
+        let count = word.chars.iter().filter(|o| o.size == c.size).count();
+        if count > best.1 {
+            best = (c.size, count);
+        }
+    }
+    if best.1 == 0 {
+        return word.chars.first().map_or(0.0, |c| c.size);
+    }
+    best.0
+}
+
+/// Escape special HTML characters.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::BBox;
+    use crate::text::TextDirection;
+
+    fn make_char(text: &str, x0: f64, top: f64, x1: f64, bottom: f64, size: f64) -> Char {
+        Char {
+            text: text.to_string(),
+            bbox: BBox::new(x0, top, x1, bottom),
+            fontname: "Helvetica".to_string(),
+            size,
+            doctop: top,
+            upright: true,
+            direction: TextDirection::Ltr,
+            stroking_color: None,
+            non_stroking_color: None,
+            ctm: [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+            char_code: 0,
+            mcid: None,
+            tag: None,
+        }
+    }
+
+    #[test]
+    fn test_html_output_options_default() {
+        let opts = HtmlOutputOptions::default();
+        assert!(opts.group_words);
+        assert_eq!(opts.y_tolerance, 3.0);
+    }
+
+    #[test]
+    fn test_begin_page_opens_positioned_container() {
+        let mut out = HtmlOutput::new(HtmlOutputOptions::default());
+        out.begin_page(200.0, 100.0);
+        assert!(out.html().contains("width:200.00px"));
+        assert!(out.html().contains("height:100.00px"));
+    }
+
+    #[test]
+    fn test_output_char_then_end_page_emits_span_per_word() {
+        let mut out = HtmlOutput::new(HtmlOutputOptions::default());
+        out.begin_page(100.0, 50.0);
+        for ch in [
+            make_char("H", 0.0, 0.0, 8.0, 12.0, 12.0),
+            make_char("i", 8.0, 0.0, 12.0, 12.0, 12.0),
+        ] {
+            out.output_char(&ch, Ctm::identity());
+        }
+        out.end_page();
+        assert!(
+            out.html().contains("<span"),
+            "expected a span, got: {}",
+            out.html()
+        );
+        assert!(out.html().contains(">Hi<"), "got: {}", out.html());
+        assert!(out.html().contains("left:0.00px"));
+        assert!(out.html().contains("font-size:12.00px"));
+    }
+
+    #[test]
+    fn test_per_char_mode_emits_one_span_per_character() {
+        let mut out = HtmlOutput::new(HtmlOutputOptions {
+            group_words: false,
+            y_tolerance: 3.0,
+        });
+        out.begin_page(100.0, 50.0);
+        out.output_char(&make_char("A", 0.0, 0.0, 8.0, 12.0, 12.0), Ctm::identity());
+        out.output_char(&make_char("B", 8.0, 0.0, 16.0, 12.0, 12.0), Ctm::identity());
+        out.end_page();
+        assert_eq!(out.html().matches("<span").count(), 2);
+    }
+
+    #[test]
+    fn test_blank_characters_are_skipped() {
+        let mut out = HtmlOutput::new(HtmlOutputOptions {
+            group_words: false,
+            y_tolerance: 3.0,
+        });
+        out.begin_page(100.0, 50.0);
+        out.output_char(&make_char(" ", 0.0, 0.0, 4.0, 12.0, 12.0), Ctm::identity());
+        out.end_page();
+        assert!(!out.html().contains("<span"));
+    }
+
+    #[test]
+    fn test_multiple_pages_each_get_their_own_container() {
+        let mut out = HtmlOutput::new(HtmlOutputOptions::default());
+        out.begin_page(100.0, 50.0);
+        out.output_char(&make_char("A", 0.0, 0.0, 8.0, 12.0, 12.0), Ctm::identity());
+        out.end_page();
+        out.begin_page(100.0, 50.0);
+        out.output_char(&make_char("B", 0.0, 0.0, 8.0, 12.0, 12.0), Ctm::identity());
+        out.end_page();
+        assert_eq!(out.html().matches("class=\"page\"").count(), 2);
+        assert!(out.html().contains(">A<"));
+        assert!(out.html().contains(">B<"));
+    }
+
+    #[test]
+    fn test_escape_html_in_span_text() {
+        let mut out = HtmlOutput::new(HtmlOutputOptions {
+            group_words: false,
+            y_tolerance: 3.0,
+        });
+        out.begin_page(100.0, 50.0);
+        out.output_char(&make_char("<", 0.0, 0.0, 8.0, 12.0, 12.0), Ctm::identity());
+        out.end_page();
+        assert!(out.html().contains("&lt;"));
+    }
+
+    #[test]
+    fn test_into_html_returns_accumulated_content() {
+        let mut out = HtmlOutput::new(HtmlOutputOptions::default());
+        out.begin_page(100.0, 50.0);
+        out.output_char(&make_char("X", 0.0, 0.0, 8.0, 12.0, 12.0), Ctm::identity());
+        out.end_page();
+        let html = out.into_html();
+        assert!(html.contains(">X<"));
+    }
+}
@@ -1,9 +1,14 @@
 //! Header/footer detection and page region classification.
 //!
 //! Provides cross-page analysis to detect repeating headers and footers
-//! by comparing candidate regions across pages with fuzzy matching.
+//! by comparing candidate regions across pages with fuzzy matching, and
+//! [`strip_chars`]/[`strip_lines`]/[`strip_rects`]/[`strip_edges`] to
+//! actually remove the detected boilerplate from a page's objects.
 
+use crate::edges::Edge;
 use crate::geometry::BBox;
+use crate::shapes::{Line, Rect};
+use crate::text::Char;
 
 /// Configuration for page region detection.
 #[derive(Debug, Clone, PartialEq)]
@@ -15,6 +20,14 @@ pub struct PageRegionOptions {
     pub footer_margin: f64,
     /// Minimum number of pages required for detection. Default: 3.
     pub min_pages: usize,
+    /// Maximum normalized Levenshtein distance (`lev(a, b) / max(len(a), len(b))`)
+    /// for two masked candidate texts to be considered the same repeating
+    /// header/footer. `0.0` requires an exact match (the pre-fuzzy behavior).
+    /// Default: 0.15.
+    pub fuzzy_threshold: f64,
+    /// Which token classes are normalized before comparing candidate texts.
+    /// Default: digit collapsing only (see [`MaskOptions::default`]).
+    pub mask_options: MaskOptions,
 }
 
 impl Default for PageRegionOptions {
@@ -23,6 +36,8 @@ impl Default for PageRegionOptions {
             header_margin: 0.1,
             footer_margin: 0.1,
             min_pages: 3,
+            fuzzy_threshold: 0.15,
+            mask_options: MaskOptions::default(),
         }
     }
 }
@@ -39,12 +54,160 @@ pub struct PageRegions {
     pub body: BBox,
 }
 
-/// Mask variable elements in text for fuzzy comparison.
+/// Which token classes [`mask_variable_elements_with`] normalizes.
 ///
-/// Replaces sequences of digits with `#` and normalizes whitespace.
-/// This allows detecting repeating text even when page numbers, dates,
-/// or other variable elements change across pages.
-pub fn mask_variable_elements(text: &str) -> String {
+/// Each flag is independent, so callers can opt into the richer masking
+/// that fits their documents without affecting the others.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaskOptions {
+    /// Collapse runs of ASCII digits to a single `#`, and fold the `# of #`
+    /// / `#/#` page-count templates so they mask identically. Default: `true`.
+    pub digits: bool,
+    /// Mask whitespace-delimited tokens that are well-formed roman numerals
+    /// (case-insensitive, validated by grammar rather than just the letters
+    /// used) to `#`. Default: `false`.
+    pub romans: bool,
+    /// Mask month names (full, e.g. "January", or 3-letter abbreviation,
+    /// e.g. "Jan") to a fixed `@MONTH` token. Default: `false`.
+    pub months: bool,
+    /// Mask ordinal numbers ("1st", "2nd", "3rd", "4th", ...) to `#`.
+    /// Default: `false`.
+    pub ordinals: bool,
+}
+
+impl Default for MaskOptions {
+    fn default() -> Self {
+        Self {
+            digits: true,
+            romans: false,
+            months: false,
+            ordinals: false,
+        }
+    }
+}
+
+const MONTH_NAMES: [(&str, &str); 12] = [
+    ("january", "jan"),
+    ("february", "feb"),
+    ("march", "mar"),
+    ("april", "apr"),
+    ("may", "may"),
+    ("june", "jun"),
+    ("july", "jul"),
+    ("august", "aug"),
+    ("september", "sep"),
+    ("october", "oct"),
+    ("november", "nov"),
+    ("december", "dec"),
+];
+
+/// Split `token` into its alphanumeric core and any trailing punctuation
+/// (e.g. `"January,"` → `("January", ",")`), so the core can be matched
+/// against a fixed vocabulary while the punctuation is preserved in the
+/// output.
+fn split_trailing_punctuation(token: &str) -> (&str, &str) {
+    let core_end = token
+        .rfind(|c: char| c.is_ascii_alphanumeric())
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    token.split_at(core_end)
+}
+
+/// Consume a leading digit group (thousands/hundreds/tens/ones) from `s` per
+/// standard roman numeral grammar: either of the two subtractive pairs
+/// (`nine`, `four`), or an optional `five` followed by up to three `one`s.
+/// Returns whatever of `s` is left after the group, unchanged if none of the
+/// group's forms match at the start of `s`.
+fn consume_roman_digit_group<'a>(s: &'a str, nine: &str, four: &str, five: char, one: char) -> &'a str {
+    if let Some(rest) = s.strip_prefix(nine) {
+        return rest;
+    }
+    if let Some(rest) = s.strip_prefix(four) {
+        return rest;
+    }
+    let mut rest = s.strip_prefix(five).unwrap_or(s);
+    for _ in 0..3 {
+        match rest.strip_prefix(one) {
+            Some(r) => rest = r,
+            None => break,
+        }
+    }
+    rest
+}
+
+/// Whether `token` is a well-formed roman numeral (1-3999), per the
+/// standard grammar `M{0,3}(CM|CD|D?C{0,3})(XC|XL|L?X{0,3})(IX|IV|V?I{0,3})`.
+///
+/// A bare character-class check (every char is one of I/V/X/L/C/D/M) also
+/// matches common English words built from those letters — "dim", "did",
+/// "mild", "civic", "mill" — that aren't roman numerals at all.
+/// Validating the subtractive/additive grouping rejects all of those (and
+/// malformed numerals like "IIII" or "VX") while still accepting every
+/// canonical numeral. A word that happens to spell a canonical numeral,
+/// such as "mix" (MIX = 1009), is still accepted.
+fn is_roman_numeral_token(token: &str) -> bool {
+    if token.is_empty() {
+        return false;
+    }
+    let upper = token.to_ascii_uppercase();
+    let mut s = upper.as_str();
+    for _ in 0..3 {
+        match s.strip_prefix('M') {
+            Some(rest) => s = rest,
+            None => break,
+        }
+    }
+    s = consume_roman_digit_group(s, "CM", "CD", 'D', 'C');
+    s = consume_roman_digit_group(s, "XC", "XL", 'L', 'X');
+    s = consume_roman_digit_group(s, "IX", "IV", 'V', 'I');
+    s.is_empty()
+}
+
+/// If `token` is an ordinal number ("1st", "22nd", ...), return it masked
+/// to `#` (with any trailing punctuation preserved).
+fn mask_ordinal_token(token: &str) -> Option<String> {
+    let (core, trailing) = split_trailing_punctuation(token);
+    let core_lower = core.to_ascii_lowercase();
+    ["st", "nd", "rd", "th"].iter().find_map(|suffix| {
+        let digits = core_lower.strip_suffix(suffix)?;
+        (!digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())).then(|| format!("#{trailing}"))
+    })
+}
+
+/// If `token` is a month name or 3-letter abbreviation, return it masked to
+/// `@MONTH` (with any trailing punctuation preserved).
+fn mask_month_token(token: &str) -> Option<String> {
+    let (core, trailing) = split_trailing_punctuation(token);
+    let core_lower = core.to_ascii_lowercase();
+    MONTH_NAMES
+        .iter()
+        .any(|&(full, abbr)| core_lower == full || core_lower == abbr)
+        .then(|| format!("@MONTH{trailing}"))
+}
+
+/// Apply the token-level normalizers (`ordinals`, `romans`, `months`) enabled
+/// in `options` to a single whitespace-delimited token, in that priority
+/// order since an ordinal or roman token can otherwise look month-like.
+fn mask_token(token: &str, options: &MaskOptions) -> String {
+    if options.ordinals {
+        if let Some(masked) = mask_ordinal_token(token) {
+            return masked;
+        }
+    }
+    if options.romans && is_roman_numeral_token(token) {
+        return "#".to_string();
+    }
+    if options.months {
+        if let Some(masked) = mask_month_token(token) {
+            return masked;
+        }
+    }
+    token.to_string()
+}
+
+/// Collapse runs of ASCII digits in `text` to a single `#` each.
+fn collapse_digit_runs(text: &str) -> String {
     let mut result = String::with_capacity(text.len());
     let mut in_digit_run = false;
 
@@ -64,6 +227,46 @@ pub fn mask_variable_elements(text: &str) -> String {
     result
 }
 
+/// Fold the `# of #` page-count template onto the shorter `#/#` template
+/// (e.g. "Page 3 of 40" and "Page 3/40" both mask to "Page #/#"), so
+/// documents that vary this phrasing across pages still cluster together.
+fn fold_number_templates(text: &str) -> String {
+    text.replace("# of #", "#/#")
+}
+
+/// Mask variable elements in text for fuzzy comparison, using [`MaskOptions`]
+/// to choose which token classes are normalized.
+///
+/// Whitespace-delimited tokens are checked against the enabled classes
+/// (ordinals, roman numerals, month names) first; afterward, if `digits` is
+/// enabled, runs of ASCII digits are collapsed to `#` and the `# of #` / `#/#`
+/// templates are folded together. Whitespace runs are always normalized to a
+/// single space.
+pub fn mask_variable_elements_with(text: &str, options: &MaskOptions) -> String {
+    let joined = text
+        .split_whitespace()
+        .map(|tok| mask_token(tok, options))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if options.digits {
+        fold_number_templates(&collapse_digit_runs(&joined))
+    } else {
+        joined
+    }
+}
+
+/// Mask variable elements in text for fuzzy comparison.
+///
+/// Replaces sequences of digits with `#` and normalizes whitespace. This is
+/// [`mask_variable_elements_with`] with the default [`MaskOptions`] (digit
+/// collapsing only), preserving this function's historical behavior. This
+/// allows detecting repeating text even when page numbers, dates, or other
+/// variable elements change across pages.
+pub fn mask_variable_elements(text: &str) -> String {
+    mask_variable_elements_with(text, &MaskOptions::default())
+}
+
 /// Detect repeating headers and footers across multiple pages.
 ///
 /// Takes a list of (header_text, footer_text, page_width, page_height) tuples
@@ -97,18 +300,18 @@ pub fn detect_page_regions(
     // Collect masked header/footer texts
     let masked_headers: Vec<String> = page_data
         .iter()
-        .map(|(h, _, _, _)| mask_variable_elements(h.trim()))
+        .map(|(h, _, _, _)| mask_variable_elements_with(h.trim(), &options.mask_options))
         .collect();
     let masked_footers: Vec<String> = page_data
         .iter()
-        .map(|(_, f, _, _)| mask_variable_elements(f.trim()))
+        .map(|(_, f, _, _)| mask_variable_elements_with(f.trim(), &options.mask_options))
         .collect();
 
     // Detect repeating headers
-    let has_header = detect_repeating_text(&masked_headers, options.min_pages);
+    let (has_header, _) = detect_repeating_text(&masked_headers, options.min_pages, options.fuzzy_threshold);
 
     // Detect repeating footers
-    let has_footer = detect_repeating_text(&masked_footers, options.min_pages);
+    let (has_footer, _) = detect_repeating_text(&masked_footers, options.min_pages, options.fuzzy_threshold);
 
     // Build PageRegions for each page
     page_data
@@ -146,77 +349,402 @@ pub fn detect_page_regions(
         .collect()
 }
 
-/// Detect which pages have repeating text that appears on enough pages.
+/// Per-page candidate input for [`detect_page_regions_with_bounds`].
 ///
-/// Returns a boolean for each page indicating whether it participates in
-/// a repeating pattern. Handles both uniform repetition (same text on all pages)
-/// and odd/even alternation (different text on odd vs even pages).
-fn detect_repeating_text(masked_texts: &[String], min_pages: usize) -> Vec<bool> {
-    let n = masked_texts.len();
-    let mut is_repeating = vec![false; n];
+/// Unlike the plain `(header_text, footer_text, width, height)` tuples taken
+/// by [`detect_page_regions`], this carries the *actual* tight bounding box of
+/// the glyphs that produced each candidate text (within the margin-defined
+/// scan window), so a detected header/footer can be cropped to where the
+/// text really sits instead of the full margin slice.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PageRegionCandidate {
+    /// Text extracted from the header scan window.
+    pub header_text: String,
+    /// Tight bbox of the header text's glyphs, in page coordinates.
+    /// `None` if the header scan window contained no characters.
+    pub header_bbox: Option<BBox>,
+    /// Text extracted from the footer scan window.
+    pub footer_text: String,
+    /// Tight bbox of the footer text's glyphs, in page coordinates.
+    /// `None` if the footer scan window contained no characters.
+    pub footer_bbox: Option<BBox>,
+    /// Page width.
+    pub width: f64,
+    /// Page height.
+    pub height: f64,
+}
+
+/// Union the bboxes of every page that shares a cluster, keyed by the
+/// cluster's root index. Pages with no cluster assignment (non-repeating)
+/// or no bbox (empty scan window) don't contribute.
+fn union_bboxes_by_cluster(
+    cluster_of: &[Option<usize>],
+    bbox_of: impl Fn(usize) -> Option<BBox>,
+) -> std::collections::HashMap<usize, BBox> {
+    let mut unioned: std::collections::HashMap<usize, BBox> = std::collections::HashMap::new();
+    for (i, root) in cluster_of.iter().enumerate() {
+        let (Some(root), Some(bbox)) = (root, bbox_of(i)) else {
+            continue;
+        };
+        unioned.entry(*root).and_modify(|u| *u = u.union(&bbox)).or_insert(bbox);
+    }
+    unioned
+}
+
+/// Detect repeating headers and footers across multiple pages, using the
+/// actual glyph bounds of each candidate rather than a fixed margin fraction.
+///
+/// Behaves like [`detect_page_regions`], but once a header/footer cluster is
+/// confirmed, its `header`/`footer` bbox is the tight union of
+/// [`PageRegionCandidate::header_bbox`]/[`PageRegionCandidate::footer_bbox`]
+/// across the cluster's member pages, and `body` is derived from those tight
+/// bounds. `header_margin`/`footer_margin` are not used for the returned
+/// bbox here — they only describe the scan window candidates were collected
+/// from. A page whose own candidate had no bbox takes its cluster's union;
+/// only when no page in the cluster had a bbox does it fall back to the
+/// margin-fraction bbox, as in [`detect_page_regions`].
+pub fn detect_page_regions_with_bounds(page_data: &[PageRegionCandidate], options: &PageRegionOptions) -> Vec<PageRegions> {
+    let num_pages = page_data.len();
 
-    // Count occurrences of each non-empty masked text
-    let mut counts: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
-    for text in masked_texts {
-        if !text.is_empty() {
-            *counts.entry(text.as_str()).or_insert(0) += 1;
+    if num_pages < options.min_pages {
+        return page_data
+            .iter()
+            .map(|p| PageRegions {
+                header: None,
+                footer: None,
+                body: BBox::new(0.0, 0.0, p.width, p.height),
+            })
+            .collect();
+    }
+
+    let masked_headers: Vec<String> = page_data
+        .iter()
+        .map(|p| mask_variable_elements_with(p.header_text.trim(), &options.mask_options))
+        .collect();
+    let masked_footers: Vec<String> = page_data
+        .iter()
+        .map(|p| mask_variable_elements_with(p.footer_text.trim(), &options.mask_options))
+        .collect();
+
+    let (has_header, header_cluster) = detect_repeating_text(&masked_headers, options.min_pages, options.fuzzy_threshold);
+    let (has_footer, footer_cluster) = detect_repeating_text(&masked_footers, options.min_pages, options.fuzzy_threshold);
+
+    let header_bbox_by_cluster = union_bboxes_by_cluster(&header_cluster, |i| page_data[i].header_bbox);
+    let footer_bbox_by_cluster = union_bboxes_by_cluster(&footer_cluster, |i| page_data[i].footer_bbox);
+
+    page_data
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let header = has_header[i].then(|| {
+                header_cluster[i]
+                    .and_then(|root| header_bbox_by_cluster.get(&root).copied())
+                    .unwrap_or_else(|| BBox::new(0.0, 0.0, p.width, p.height * options.header_margin))
+            });
+
+            let footer = has_footer[i].then(|| {
+                footer_cluster[i]
+                    .and_then(|root| footer_bbox_by_cluster.get(&root).copied())
+                    .unwrap_or_else(|| BBox::new(0.0, p.height - p.height * options.footer_margin, p.width, p.height))
+            });
+
+            let body_top = header.map(|h| h.bottom).unwrap_or(0.0);
+            let body_bottom = footer.map(|f| f.top).unwrap_or(p.height);
+
+            PageRegions {
+                header,
+                footer,
+                body: BBox::new(0.0, body_top, p.width, body_bottom),
+            }
+        })
+        .collect()
+}
+
+/// Minimal union-find (disjoint-set) structure used to cluster candidate
+/// texts whose pairwise normalized edit distance falls within the fuzzy
+/// threshold.
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
         }
     }
 
-    // A text is "repeating" if it appears on >= min_pages pages
-    for (i, text) in masked_texts.iter().enumerate() {
-        if !text.is_empty() {
-            if let Some(&count) = counts.get(text.as_str()) {
-                if count >= min_pages {
-                    is_repeating[i] = true;
-                }
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (ra, rb) = (self.find(a), self.find(b));
+        if ra != rb {
+            self.parent[ra] = rb;
+        }
+    }
+}
+
+/// Levenshtein (edit) distance between two strings, in characters.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (n, m) = (a.len(), b.len());
+
+    let mut prev: Vec<usize> = (0..=m).collect();
+    let mut curr = vec![0usize; m + 1];
+    for i in 1..=n {
+        curr[0] = i;
+        for j in 1..=m {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[m]
+}
+
+/// Edit distance normalized by the longer string's length, so it's
+/// comparable across candidates of different lengths. Two empty strings are
+/// considered identical (distance `0.0`).
+fn normalized_edit_distance(a: &str, b: &str) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 0.0;
+    }
+    levenshtein(a, b) as f64 / max_len as f64
+}
+
+/// Cheap pre-filter for whether `a` and `b` are worth the full Levenshtein
+/// computation: candidates sharing neither their first two nor their last
+/// two masked characters are unlikely to be within a small edit distance of
+/// each other.
+fn unlikely_to_match(a: &[char], b: &[char]) -> bool {
+    let prefix_len = 2.min(a.len()).min(b.len());
+    let prefix_matches = a[..prefix_len] == b[..prefix_len];
+    let suffix_matches = a[a.len() - prefix_len..] == b[b.len() - prefix_len..];
+    !prefix_matches && !suffix_matches
+}
+
+/// Cluster the non-empty masked texts at `indices` into groups of
+/// near-identical text (normalized Levenshtein distance `<= fuzzy_threshold`)
+/// and mark every index whose cluster has at least `threshold_count` members
+/// in `is_repeating`, recording which cluster (identified by an arbitrary but
+/// stable root index) each repeating member landed in via `cluster_of`.
+///
+/// Candidates are sorted by length first, so that comparisons only happen
+/// within a window of plausibly-close lengths: edit distance is always at
+/// least the length difference, so once that alone exceeds the allowed
+/// budget, no longer candidate can match either. A cheap check of the first
+/// two and last two characters further skips the full Levenshtein computation for unlikely pairs.
+/// Together this keeps clustering close to O(n·m) (`m` = typical cluster
+/// size) instead of the O(n²·m) of comparing every pair directly.
+fn cluster_repeating_text(
+    masked_texts: &[String],
+    indices: &[usize],
+    threshold_count: usize,
+    fuzzy_threshold: f64,
+    is_repeating: &mut [bool],
+    cluster_of: &mut [Option<usize>],
+) {
+    let chars: Vec<Vec<char>> = masked_texts.iter().map(|t| t.chars().collect()).collect();
+
+    let mut candidates: Vec<usize> = indices.iter().copied().filter(|&i| !chars[i].is_empty()).collect();
+    if candidates.is_empty() {
+        return;
+    }
+    candidates.sort_by_key(|&i| chars[i].len());
+
+    let mut uf = UnionFind::new(masked_texts.len());
+    for (pos, &i) in candidates.iter().enumerate() {
+        let len_i = chars[i].len();
+        for &j in &candidates[pos + 1..] {
+            let len_j = chars[j].len();
+            let max_len = len_i.max(len_j);
+            // Edit distance is at least the length difference; since
+            // `candidates` is sorted ascending, once this exceeds the
+            // budget every later (longer) candidate does too.
+            if (len_j - len_i) as f64 / max_len as f64 > fuzzy_threshold {
+                break;
+            }
+            if unlikely_to_match(&chars[i], &chars[j]) {
+                continue;
+            }
+            if normalized_edit_distance(&masked_texts[i], &masked_texts[j]) <= fuzzy_threshold {
+                uf.union(i, j);
             }
         }
     }
 
-    // Also check odd/even alternation pattern:
-    // If odd pages share text A and even pages share text B,
-    // and each appears on >= min_pages/2 pages, mark both as repeating
-    if !is_repeating.iter().any(|&r| r) {
-        let odd_texts: Vec<&str> = masked_texts
-            .iter()
-            .enumerate()
-            .filter(|(i, t)| i % 2 == 0 && !t.is_empty()) // 0-indexed, so "page 1" is index 0
-            .map(|(_, t)| t.as_str())
-            .collect();
-        let even_texts: Vec<&str> = masked_texts
-            .iter()
-            .enumerate()
-            .filter(|(i, t)| i % 2 == 1 && !t.is_empty())
-            .map(|(_, t)| t.as_str())
-            .collect();
+    let mut cluster_sizes: std::collections::HashMap<usize, usize> = std::collections::HashMap::new();
+    for &i in &candidates {
+        let root = uf.find(i);
+        *cluster_sizes.entry(root).or_insert(0) += 1;
+    }
 
+    for &i in &candidates {
+        let root = uf.find(i);
+        if cluster_sizes[&root] >= threshold_count {
+            is_repeating[i] = true;
+            cluster_of[i] = Some(root);
+        }
+    }
+}
+
+/// Detect which pages have repeating text that appears on enough pages.
+///
+/// Returns a boolean for each page indicating whether it participates in
+/// a repeating pattern, alongside which cluster (an arbitrary but stable id,
+/// shared by every page whose masked text landed in the same cluster) each
+/// repeating page belongs to — callers that need the tight bounds of a
+/// detected header/footer use this to union bboxes per cluster rather than
+/// per uniform/alternating-pattern-wide group. Handles both uniform
+/// repetition (same text on all pages, allowing for minor OCR-noise-style
+/// differences within `fuzzy_threshold`) and odd/even alternation (different
+/// text on odd vs even pages).
+fn detect_repeating_text(masked_texts: &[String], min_pages: usize, fuzzy_threshold: f64) -> (Vec<bool>, Vec<Option<usize>>) {
+    let n = masked_texts.len();
+    let mut is_repeating = vec![false; n];
+    let mut cluster_of = vec![None; n];
+
+    let all_indices: Vec<usize> = (0..n).collect();
+    cluster_repeating_text(masked_texts, &all_indices, min_pages, fuzzy_threshold, &mut is_repeating, &mut cluster_of);
+
+    // Also check odd/even alternation pattern: if odd pages cluster around
+    // text A and even pages cluster around text B, and each cluster reaches
+    // >= min_pages/2 members, mark both as repeating.
+    if !is_repeating.iter().any(|&r| r) {
+        let odd_indices: Vec<usize> = (0..n).filter(|i| i % 2 == 0).collect(); // 0-indexed, so "page 1" is index 0
+        let even_indices: Vec<usize> = (0..n).filter(|i| i % 2 == 1).collect();
         let min_alt = min_pages.div_ceil(2);
 
-        let odd_repeating = if !odd_texts.is_empty() {
-            let first = odd_texts[0];
-            odd_texts.iter().filter(|&&t| t == first).count() >= min_alt
-        } else {
-            false
-        };
+        let mut odd_repeating = vec![false; n];
+        let mut odd_cluster = vec![None; n];
+        cluster_repeating_text(masked_texts, &odd_indices, min_alt, fuzzy_threshold, &mut odd_repeating, &mut odd_cluster);
+        let mut even_repeating = vec![false; n];
+        let mut even_cluster = vec![None; n];
+        cluster_repeating_text(masked_texts, &even_indices, min_alt, fuzzy_threshold, &mut even_repeating, &mut even_cluster);
 
-        let even_repeating = if !even_texts.is_empty() {
-            let first = even_texts[0];
-            even_texts.iter().filter(|&&t| t == first).count() >= min_alt
-        } else {
-            false
-        };
+        let odd_hit = odd_indices.iter().any(|&i| odd_repeating[i]);
+        let even_hit = even_indices.iter().any(|&i| even_repeating[i]);
 
-        if odd_repeating && even_repeating {
-            for (i, text) in masked_texts.iter().enumerate() {
-                if !text.is_empty() {
-                    is_repeating[i] = true;
-                }
+        if odd_hit && even_hit {
+            for &i in &odd_indices {
+                is_repeating[i] = odd_repeating[i];
+                cluster_of[i] = odd_cluster[i];
+            }
+            for &i in &even_indices {
+                is_repeating[i] = even_repeating[i];
+                cluster_of[i] = even_cluster[i];
             }
         }
     }
 
-    is_repeating
+    (is_repeating, cluster_of)
+}
+
+/// Tolerance controlling how a boundary-straddling object is classified by
+/// [`strip_chars`]/[`strip_lines`]/[`strip_rects`]/[`strip_edges`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StripRegionOptions {
+    /// Fraction of an object's own bbox area that must overlap a detected
+    /// header/footer region for the object to be treated as boilerplate
+    /// (dropped) rather than body content (kept). `0.0` drops anything
+    /// touching a region at all; `1.0` requires the object to be fully
+    /// contained in the region.
+    pub within_region: f64,
+}
+
+impl Default for StripRegionOptions {
+    fn default() -> Self {
+        Self { within_region: 0.5 }
+    }
+}
+
+/// The result of partitioning a page's objects by detected header/footer
+/// regions.
+///
+/// `kept` is the body content; `dropped` is the boilerplate that fell
+/// inside a header/footer region, returned alongside `kept` so callers can
+/// audit what was removed rather than having it silently discarded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stripped<T> {
+    /// Objects outside the detected header/footer regions.
+    pub kept: Vec<T>,
+    /// Objects that fell inside a detected header/footer region.
+    pub dropped: Vec<T>,
+}
+
+/// Fraction of `bbox`'s area that overlaps `region`, in `[0.0, 1.0]`.
+/// A zero-area `bbox` (e.g. a perfectly horizontal or vertical line) counts
+/// as fully overlapping if it intersects `region` at all.
+fn overlap_fraction(bbox: &BBox, region: &BBox) -> f64 {
+    let bbox_area = bbox.area();
+    if bbox_area <= 0.0 {
+        return if bbox.intersects(region) { 1.0 } else { 0.0 };
+    }
+    let Some(overlap) = bbox.intersection(region) else {
+        return 0.0;
+    };
+    (overlap.area() / bbox_area).min(1.0)
+}
+
+fn strip_by_region<T: Clone>(
+    items: &[T],
+    bbox_of: impl Fn(&T) -> BBox,
+    regions: &PageRegions,
+    options: &StripRegionOptions,
+) -> Stripped<T> {
+    let mut kept = Vec::new();
+    let mut dropped = Vec::new();
+
+    for item in items {
+        let bbox = bbox_of(item);
+        let in_header = regions
+            .header
+            .is_some_and(|r| bbox.intersects(&r) && overlap_fraction(&bbox, &r) >= options.within_region);
+        let in_footer = regions
+            .footer
+            .is_some_and(|r| bbox.intersects(&r) && overlap_fraction(&bbox, &r) >= options.within_region);
+
+        if in_header || in_footer {
+            dropped.push(item.clone());
+        } else {
+            kept.push(item.clone());
+        }
+    }
+
+    Stripped { kept, dropped }
+}
+
+/// Partition `chars` into body content and detected header/footer
+/// boilerplate using `regions`.
+pub fn strip_chars(chars: &[Char], regions: &PageRegions, options: &StripRegionOptions) -> Stripped<Char> {
+    strip_by_region(chars, |c| c.bbox, regions, options)
+}
+
+/// Partition `lines` into body content and detected header/footer
+/// boilerplate using `regions`.
+pub fn strip_lines(lines: &[Line], regions: &PageRegions, options: &StripRegionOptions) -> Stripped<Line> {
+    strip_by_region(lines, |l| BBox::new(l.x0, l.top, l.x1, l.bottom), regions, options)
+}
+
+/// Partition `rects` into body content and detected header/footer
+/// boilerplate using `regions`.
+pub fn strip_rects(rects: &[Rect], regions: &PageRegions, options: &StripRegionOptions) -> Stripped<Rect> {
+    strip_by_region(rects, |r| BBox::new(r.x0, r.top, r.x1, r.bottom), regions, options)
+}
+
+/// Partition `edges` into body content and detected header/footer
+/// boilerplate using `regions`.
+pub fn strip_edges(edges: &[Edge], regions: &PageRegions, options: &StripRegionOptions) -> Stripped<Edge> {
+    strip_by_region(edges, |e| BBox::new(e.x0, e.top, e.x1, e.bottom), regions, options)
 }
 
 #[cfg(test)]
@@ -252,6 +780,90 @@ mod tests {
         assert_eq!(mask_variable_elements("12345"), "#");
     }
 
+    // --- MaskOptions tests ---
+
+    #[test]
+    fn mask_romans_normalizes_lowercase_and_uppercase_tokens() {
+        let options = MaskOptions {
+            romans: true,
+            ..MaskOptions::default()
+        };
+        assert_eq!(mask_variable_elements_with("Page iv", &options), "Page #");
+        assert_eq!(mask_variable_elements_with("Page XII", &options), "Page #");
+    }
+
+    #[test]
+    fn mask_romans_disabled_by_default() {
+        assert_eq!(mask_variable_elements("Page iv"), "Page iv");
+    }
+
+    #[test]
+    fn mask_romans_does_not_mask_english_words_that_look_roman() {
+        // All of these are built entirely from I/V/X/L/C/D/M but aren't
+        // roman numerals; a bare character-class check would wrongly mask
+        // them. "mix" is a genuine ambiguity ("MIX" is a valid numeral, 1009)
+        // and is intentionally not covered here.
+        let options = MaskOptions {
+            romans: true,
+            ..MaskOptions::default()
+        };
+        for word in ["dim", "did", "mild", "civic", "mill"] {
+            assert_eq!(mask_variable_elements_with(word, &options), word, "{word} should not be masked");
+        }
+    }
+
+    #[test]
+    fn mask_romans_rejects_malformed_numerals() {
+        let options = MaskOptions {
+            romans: true,
+            ..MaskOptions::default()
+        };
+        // Four-in-a-row and a skipped subtractive step are not canonical
+        // roman numeral forms.
+        assert_eq!(mask_variable_elements_with("IIII", &options), "IIII");
+        assert_eq!(mask_variable_elements_with("VX", &options), "VX");
+    }
+
+    #[test]
+    fn mask_romans_accepts_a_full_canonical_numeral() {
+        let options = MaskOptions {
+            romans: true,
+            ..MaskOptions::default()
+        };
+        assert_eq!(mask_variable_elements_with("MCMXCIX", &options), "#");
+    }
+
+    #[test]
+    fn mask_months_normalizes_full_and_abbreviated_names() {
+        let options = MaskOptions {
+            months: true,
+            ..MaskOptions::default()
+        };
+        assert_eq!(mask_variable_elements_with("January 15, 2024", &options), "@MONTH #, #");
+        assert_eq!(mask_variable_elements_with("Jan. 15", &options), "@MONTH. #");
+    }
+
+    #[test]
+    fn mask_ordinals_normalizes_suffixed_numbers() {
+        let options = MaskOptions {
+            ordinals: true,
+            ..MaskOptions::default()
+        };
+        assert_eq!(mask_variable_elements_with("1st place", &options), "# place");
+        assert_eq!(mask_variable_elements_with("22nd floor", &options), "# floor");
+    }
+
+    #[test]
+    fn mask_number_templates_fold_of_and_slash_forms() {
+        assert_eq!(mask_variable_elements("Page 3 of 40"), mask_variable_elements("Page 3/40"));
+        assert_eq!(mask_variable_elements("Page 3 of 40"), mask_variable_elements("Page 12 of 40"));
+    }
+
+    #[test]
+    fn mask_whitespace_runs_normalized_to_single_space() {
+        assert_eq!(mask_variable_elements("Page   1"), "Page #");
+    }
+
     // --- PageRegionOptions tests ---
 
     #[test]
@@ -268,10 +880,13 @@ mod tests {
             header_margin: 0.15,
             footer_margin: 0.05,
             min_pages: 5,
+            fuzzy_threshold: 0.2,
+            mask_options: MaskOptions::default(),
         };
         assert_eq!(opts.header_margin, 0.15);
         assert_eq!(opts.footer_margin, 0.05);
         assert_eq!(opts.min_pages, 5);
+        assert_eq!(opts.fuzzy_threshold, 0.2);
     }
 
     // --- detect_page_regions tests ---
@@ -470,6 +1085,8 @@ mod tests {
             header_margin: 0.1,
             footer_margin: 0.15,
             min_pages: 3,
+            fuzzy_threshold: 0.15,
+            mask_options: MaskOptions::default(),
         };
         let regions = detect_page_regions(&page_data, &options);
 
@@ -482,6 +1099,47 @@ mod tests {
         }
     }
 
+    // --- fuzzy clustering tests ---
+
+    #[test]
+    fn fuzzy_threshold_groups_near_identical_footers() {
+        // A stray OCR character ("l" vs "i") on one page shouldn't break
+        // detection at the default fuzzy_threshold.
+        let footers = ["Confidentlal", "Confidential", "Confidential", "Confidential", "Confidential"];
+        let page_data: Vec<(String, String, f64, f64)> = (0..5)
+            .map(|i| ("".to_string(), footers[i].to_string(), 612.0, 792.0))
+            .collect();
+
+        let options = PageRegionOptions::default();
+        let regions = detect_page_regions(&page_data, &options);
+
+        for region in &regions {
+            assert!(region.footer.is_some(), "near-identical footers should cluster");
+        }
+    }
+
+    #[test]
+    fn fuzzy_threshold_zero_requires_exact_match() {
+        let footers = ["Confidentlal", "Confidential", "Confidential", "Confidential", "Confidential"];
+        let page_data: Vec<(String, String, f64, f64)> = (0..5)
+            .map(|i| ("".to_string(), footers[i].to_string(), 612.0, 792.0))
+            .collect();
+
+        let options = PageRegionOptions {
+            fuzzy_threshold: 0.0,
+            ..PageRegionOptions::default()
+        };
+        let regions = detect_page_regions(&page_data, &options);
+
+        // Only the 4 exact "Confidential" pages form a cluster; with
+        // min_pages=3 that's still enough to detect, but the odd one out
+        // must not be folded in.
+        assert!(regions[0].footer.is_none(), "the misspelled page should not join the exact-match cluster");
+        for region in &regions[1..] {
+            assert!(region.footer.is_some());
+        }
+    }
+
     #[test]
     fn custom_min_pages_threshold() {
         // 3 pages with same header, but min_pages=4 — should not detect
@@ -499,4 +1157,240 @@ mod tests {
             assert!(region.header.is_none());
         }
     }
+
+    // --- detect_page_regions_with_bounds tests ---
+
+    #[test]
+    fn bounds_aware_header_uses_tight_union_not_margin() {
+        // The header margin window is the full 10%, but the glyphs
+        // themselves only occupy a narrow band near the top.
+        let page_data: Vec<PageRegionCandidate> = (0..4)
+            .map(|_| PageRegionCandidate {
+                header_text: "Company Report".to_string(),
+                header_bbox: Some(BBox::new(50.0, 20.0, 300.0, 35.0)),
+                footer_text: "".to_string(),
+                footer_bbox: None,
+                width: 612.0,
+                height: 792.0,
+            })
+            .collect();
+
+        let options = PageRegionOptions::default();
+        let regions = detect_page_regions_with_bounds(&page_data, &options);
+
+        for region in &regions {
+            let header = region.header.expect("header should be detected");
+            assert_eq!(header, BBox::new(50.0, 20.0, 300.0, 35.0));
+            assert_eq!(region.body.top, 35.0);
+        }
+    }
+
+    #[test]
+    fn bounds_aware_header_unions_across_cluster_members() {
+        // Glyph bounds vary slightly page to page; the detected header
+        // should be the union covering every member, not just one page's.
+        let bboxes = [
+            BBox::new(50.0, 20.0, 300.0, 35.0),
+            BBox::new(48.0, 18.0, 305.0, 36.0),
+            BBox::new(52.0, 22.0, 298.0, 34.0),
+        ];
+        let page_data: Vec<PageRegionCandidate> = bboxes
+            .iter()
+            .map(|&bbox| PageRegionCandidate {
+                header_text: "Company Report".to_string(),
+                header_bbox: Some(bbox),
+                footer_text: "".to_string(),
+                footer_bbox: None,
+                width: 612.0,
+                height: 792.0,
+            })
+            .collect();
+
+        let options = PageRegionOptions::default();
+        let regions = detect_page_regions_with_bounds(&page_data, &options);
+
+        for region in &regions {
+            let header = region.header.expect("header should be detected");
+            assert_eq!(header, BBox::new(48.0, 18.0, 305.0, 36.0));
+        }
+    }
+
+    #[test]
+    fn bounds_aware_falls_back_to_margin_when_no_bbox() {
+        let page_data: Vec<PageRegionCandidate> = (0..3)
+            .map(|_| PageRegionCandidate {
+                header_text: "Company Report".to_string(),
+                header_bbox: None,
+                footer_text: "".to_string(),
+                footer_bbox: None,
+                width: 612.0,
+                height: 792.0,
+            })
+            .collect();
+
+        let options = PageRegionOptions::default();
+        let regions = detect_page_regions_with_bounds(&page_data, &options);
+
+        for region in &regions {
+            let header = region.header.expect("header should be detected");
+            assert_eq!(header, BBox::new(0.0, 0.0, 612.0, 79.2));
+        }
+    }
+
+    #[test]
+    fn bounds_aware_no_detection_below_min_pages() {
+        let page_data = vec![
+            PageRegionCandidate {
+                header_text: "Header".to_string(),
+                header_bbox: Some(BBox::new(0.0, 0.0, 100.0, 20.0)),
+                footer_text: "".to_string(),
+                footer_bbox: None,
+                width: 612.0,
+                height: 792.0,
+            },
+            PageRegionCandidate {
+                header_text: "Header".to_string(),
+                header_bbox: Some(BBox::new(0.0, 0.0, 100.0, 20.0)),
+                footer_text: "".to_string(),
+                footer_bbox: None,
+                width: 612.0,
+                height: 792.0,
+            },
+        ];
+
+        let options = PageRegionOptions::default();
+        let regions = detect_page_regions_with_bounds(&page_data, &options);
+
+        assert_eq!(regions.len(), 2);
+        assert!(regions[0].header.is_none());
+        assert_eq!(regions[0].body, BBox::new(0.0, 0.0, 612.0, 792.0));
+    }
+
+    fn make_char(x0: f64, top: f64, x1: f64, bottom: f64) -> Char {
+        Char {
+            text: "a".to_string(),
+            bbox: BBox::new(x0, top, x1, bottom),
+            fontname: "Helvetica".to_string(),
+            size: 12.0,
+            doctop: top,
+            upright: true,
+            direction: crate::text::TextDirection::Ltr,
+            stroking_color: None,
+            non_stroking_color: None,
+            ctm: [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+            char_code: 0,
+            mcid: None,
+            tag: None,
+        }
+    }
+
+    fn make_regions(header: Option<BBox>, footer: Option<BBox>, body: BBox) -> PageRegions {
+        PageRegions { header, footer, body }
+    }
+
+    #[test]
+    fn strip_chars_drops_char_fully_inside_header() {
+        let chars = vec![make_char(10.0, 2.0, 20.0, 12.0), make_char(10.0, 100.0, 20.0, 112.0)];
+        let regions = make_regions(
+            Some(BBox::new(0.0, 0.0, 612.0, 30.0)),
+            None,
+            BBox::new(0.0, 30.0, 612.0, 792.0),
+        );
+
+        let stripped = strip_chars(&chars, &regions, &StripRegionOptions::default());
+
+        assert_eq!(stripped.dropped.len(), 1);
+        assert_eq!(stripped.kept.len(), 1);
+        assert_eq!(stripped.kept[0].bbox.top, 100.0);
+    }
+
+    #[test]
+    fn strip_chars_keeps_char_below_within_region_threshold() {
+        // Only 20% of this char's area overlaps the header band.
+        let chars = vec![make_char(0.0, 24.0, 10.0, 34.0)];
+        let regions = make_regions(Some(BBox::new(0.0, 0.0, 612.0, 26.0)), None, BBox::new(0.0, 26.0, 612.0, 792.0));
+
+        let stripped = strip_chars(&chars, &regions, &StripRegionOptions { within_region: 0.5 });
+
+        assert_eq!(stripped.kept.len(), 1);
+        assert_eq!(stripped.dropped.len(), 0);
+    }
+
+    #[test]
+    fn strip_chars_with_zero_threshold_drops_on_any_touch() {
+        let chars = vec![make_char(0.0, 24.0, 10.0, 34.0)];
+        let regions = make_regions(Some(BBox::new(0.0, 0.0, 612.0, 26.0)), None, BBox::new(0.0, 26.0, 612.0, 792.0));
+
+        let stripped = strip_chars(&chars, &regions, &StripRegionOptions { within_region: 0.0 });
+
+        assert_eq!(stripped.dropped.len(), 1);
+    }
+
+    #[test]
+    fn strip_chars_no_regions_keeps_everything() {
+        let chars = vec![make_char(10.0, 2.0, 20.0, 12.0)];
+        let regions = make_regions(None, None, BBox::new(0.0, 0.0, 612.0, 792.0));
+
+        let stripped = strip_chars(&chars, &regions, &StripRegionOptions::default());
+
+        assert_eq!(stripped.kept.len(), 1);
+        assert_eq!(stripped.dropped.len(), 0);
+    }
+
+    #[test]
+    fn strip_lines_drops_footer_line() {
+        let lines = vec![Line {
+            x0: 0.0,
+            top: 780.0,
+            x1: 612.0,
+            bottom: 780.0,
+            line_width: 1.0,
+            stroke_color: crate::painting::Color::black(),
+            orientation: crate::shapes::LineOrientation::Horizontal,
+        }];
+        let regions = make_regions(None, Some(BBox::new(0.0, 770.0, 612.0, 792.0)), BBox::new(0.0, 0.0, 612.0, 770.0));
+
+        let stripped = strip_lines(&lines, &regions, &StripRegionOptions::default());
+
+        assert_eq!(stripped.dropped.len(), 1);
+        assert_eq!(stripped.kept.len(), 0);
+    }
+
+    #[test]
+    fn strip_rects_drops_rect_inside_header() {
+        let rects = vec![Rect {
+            x0: 0.0,
+            top: 0.0,
+            x1: 100.0,
+            bottom: 20.0,
+            line_width: 1.0,
+            stroke: true,
+            fill: false,
+            stroke_color: crate::painting::Color::black(),
+            fill_color: crate::painting::Color::black(),
+        }];
+        let regions = make_regions(Some(BBox::new(0.0, 0.0, 612.0, 30.0)), None, BBox::new(0.0, 30.0, 612.0, 792.0));
+
+        let stripped = strip_rects(&rects, &regions, &StripRegionOptions::default());
+
+        assert_eq!(stripped.dropped.len(), 1);
+    }
+
+    #[test]
+    fn strip_edges_drops_edge_inside_footer() {
+        let edges = vec![Edge {
+            x0: 0.0,
+            top: 785.0,
+            x1: 612.0,
+            bottom: 785.0,
+            orientation: crate::geometry::Orientation::Horizontal,
+            source: crate::edges::EdgeSource::Line,
+        }];
+        let regions = make_regions(None, Some(BBox::new(0.0, 770.0, 612.0, 792.0)), BBox::new(0.0, 0.0, 612.0, 770.0));
+
+        let stripped = strip_edges(&edges, &regions, &StripRegionOptions::default());
+
+        assert_eq!(stripped.dropped.len(), 1);
+        assert_eq!(stripped.kept.len(), 0);
+    }
 }
@@ -29,6 +29,15 @@ impl Color {
             b: 0.0,
         }
     }
+
+    /// White color (1, 1, 1).
+    pub fn white() -> Self {
+        Self {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+        }
+    }
 }
 
 impl Default for Color {
@@ -332,6 +341,14 @@ mod tests {
         assert_eq!(Color::default(), Color::black());
     }
 
+    #[test]
+    fn test_color_white() {
+        let c = Color::white();
+        assert_eq!(c.r, 1.0);
+        assert_eq!(c.g, 1.0);
+        assert_eq!(c.b, 1.0);
+    }
+
     // --- FillRule tests ---
 
     #[test]
@@ -0,0 +1,133 @@
+//! Document permission flags decoded from the standard security handler's
+//! `/P` entry, and the crypt filter method an encrypted document's `/CF`
+//! dictionary selects for streams and strings.
+
+/// Standard security handler permission flags, decoded from the `/P`
+/// integer in an encrypted document's `/Encrypt` dictionary (PDF
+/// 32000-1:2008 Table 22, 7.6.3.2).
+///
+/// `/P` is a signed 32-bit integer whose bits are 1-indexed; a set bit
+/// grants the corresponding capability. Bits not assigned a meaning by the
+/// spec are always 1, so an unencrypted document (or one with no
+/// restrictions) is represented as all capabilities granted — see
+/// [`Permissions::default`].
+///
+/// These flags are advisory: nothing in this crate enforces them while
+/// extracting content. They exist so applications built on pdfplumber-rs
+/// can decide for themselves whether to honor a document's stated
+/// restrictions (e.g. refuse to export text from a no-copy document).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Permissions {
+    raw: i32,
+}
+
+impl Permissions {
+    /// Decode permission flags from a raw `/P` integer.
+    pub fn from_raw(p: i32) -> Self {
+        Self { raw: p }
+    }
+
+    /// The raw signed 32-bit `/P` value this was decoded from.
+    pub fn raw(&self) -> i32 {
+        self.raw
+    }
+
+    /// Whether bit `n` (1-indexed, per the PDF spec's own numbering) is set.
+    fn bit(&self, n: u32) -> bool {
+        (self.raw as u32) & (1 << (n - 1)) != 0
+    }
+
+    /// Bit 3: print the document (at any quality).
+    pub fn can_print(&self) -> bool {
+        self.bit(3)
+    }
+
+    /// Bit 4: modify the document's contents, other than what bits 6, 9, and
+    /// 11 separately control.
+    pub fn can_modify(&self) -> bool {
+        self.bit(4)
+    }
+
+    /// Bit 5: copy or otherwise extract text and graphics from the document.
+    pub fn can_copy(&self) -> bool {
+        self.bit(5)
+    }
+
+    /// Bit 6: add or modify text annotations, and fill in form fields (if
+    /// [`Self::can_modify`] is also set, create or modify form fields too).
+    pub fn can_add_annotations(&self) -> bool {
+        self.bit(6)
+    }
+
+    /// Bit 9: fill in existing interactive form fields, even if
+    /// [`Self::can_add_annotations`] is not set.
+    pub fn can_fill_forms(&self) -> bool {
+        self.bit(9)
+    }
+
+    /// Bit 10: extract text and graphics for accessibility purposes (e.g.
+    /// screen readers), regardless of [`Self::can_copy`].
+    pub fn can_extract_for_accessibility(&self) -> bool {
+        self.bit(10)
+    }
+
+    /// Bit 11: assemble the document (insert, delete, or rotate pages, and
+    /// create bookmarks or thumbnail images), even if [`Self::can_modify`]
+    /// is not set.
+    pub fn can_assemble(&self) -> bool {
+        self.bit(11)
+    }
+
+    /// Bit 12: print at the highest quality available, rather than a
+    /// low-resolution representation (only meaningful when
+    /// [`Self::can_print`] is set).
+    pub fn can_print_high_quality(&self) -> bool {
+        self.bit(12)
+    }
+}
+
+impl Default for Permissions {
+    /// All capabilities granted, matching an unencrypted document or one
+    /// whose `/Encrypt` dictionary (if any) doesn't restrict anything.
+    fn default() -> Self {
+        Self { raw: -1 }
+    }
+}
+
+/// The crypt filter method a V4/V5 standard security handler's `/CF`
+/// dictionary selects for a stream or string (PDF 32000-1:2008 Table 25,
+/// `/CFM`), as named by whichever `/StmF`/`/StrF` crypt filter is in effect.
+///
+/// This crate doesn't implement any of these ciphers itself — actual
+/// decryption is delegated to the parsing backend's underlying PDF library
+/// (for the lopdf backend, `lopdf::Document::decrypt`). `CryptFilterMethod`
+/// exists purely so callers can inspect which algorithm a document declares
+/// without having to walk `/CF` themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CryptFilterMethod {
+    /// `/Identity`: the named filter (or the whole document) is not
+    /// encrypted; streams/strings pass through unchanged.
+    Identity,
+    /// `/V2`: RC4, keyed per 7.6.2 using the filter's `/Length`.
+    V2,
+    /// `/AESV2`: AES-128 in CBC mode with a 16-byte random IV prefix.
+    Aesv2,
+    /// `/AESV3`: AES-256 in CBC mode with a 16-byte random IV prefix
+    /// (used by V5/R6 documents).
+    Aesv3,
+    /// A `/CFM` name this crate doesn't recognize.
+    Other(String),
+}
+
+impl CryptFilterMethod {
+    /// Parse a `/CFM` name (without the leading `/`) into its method.
+    pub fn from_cfm_name(name: &str) -> Self {
+        match name {
+            "Identity" => Self::Identity,
+            "V2" => Self::V2,
+            "AESV2" => Self::Aesv2,
+            "AESV3" => Self::Aesv3,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
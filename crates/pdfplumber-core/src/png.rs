@@ -0,0 +1,167 @@
+//! Minimal, dependency-free PNG encoding shared by [`crate::images`] and [`crate::raster`].
+//!
+//! Compression uses uncompressed ("stored") DEFLATE blocks rather than a real
+//! compressor — valid per the DEFLATE spec and sufficient for the occasional
+//! image/page export this crate performs, without pulling in a compression
+//! dependency.
+
+/// PNG color type, as encoded in the IHDR chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PngColorType {
+    Grayscale,
+    Rgb,
+    Rgba,
+}
+
+impl PngColorType {
+    fn ihdr_value(self) -> u8 {
+        match self {
+            PngColorType::Grayscale => 0,
+            PngColorType::Rgb => 2,
+            PngColorType::Rgba => 6,
+        }
+    }
+
+    pub(crate) fn channels(self) -> u32 {
+        match self {
+            PngColorType::Grayscale => 1,
+            PngColorType::Rgb => 3,
+            PngColorType::Rgba => 4,
+        }
+    }
+}
+
+/// Encode raw, top-to-bottom samples as a minimal (uncompressed) PNG.
+///
+/// Each row is prefixed with PNG filter type `0` (None) and packed into a
+/// zlib stream using stored (non-compressed) DEFLATE blocks.
+pub(crate) fn encode_png(
+    width: u32,
+    height: u32,
+    color_type: PngColorType,
+    bit_depth: u8,
+    samples: &[u8],
+) -> Vec<u8> {
+    let bits_per_pixel = color_type.channels() * bit_depth as u32;
+    let row_bytes = ((width as u64 * bits_per_pixel as u64).div_ceil(8)) as usize;
+
+    let mut raw = Vec::with_capacity((row_bytes + 1) * height as usize);
+    for row in 0..height as usize {
+        raw.push(0u8); // filter type 0: None
+        let start = (row * row_bytes).min(samples.len());
+        let end = (start + row_bytes).min(samples.len());
+        raw.extend_from_slice(&samples[start..end]);
+        raw.resize(raw.len() + (row_bytes - (end - start)), 0);
+    }
+
+    let mut out = Vec::new();
+    out.extend_from_slice(&[137, 80, 78, 71, 13, 10, 26, 10]);
+
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&width.to_be_bytes());
+    ihdr.extend_from_slice(&height.to_be_bytes());
+    ihdr.push(bit_depth);
+    ihdr.push(color_type.ihdr_value());
+    ihdr.extend_from_slice(&[0, 0, 0]); // compression, filter, interlace
+    write_png_chunk(&mut out, b"IHDR", &ihdr);
+
+    write_png_chunk(&mut out, b"IDAT", &zlib_store(&raw));
+    write_png_chunk(&mut out, b"IEND", &[]);
+
+    out
+}
+
+/// Wrap `data` in a zlib stream using uncompressed ("stored") DEFLATE blocks.
+fn zlib_store(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + data.len() / 65535 * 5 + 11);
+    out.extend_from_slice(&[0x78, 0x01]); // CMF, FLG: 32K window, no preset dict, fastest
+
+    let mut offset = 0;
+    loop {
+        let remaining = data.len() - offset;
+        let block_len = remaining.min(65535);
+        let is_final = offset + block_len >= data.len();
+        out.push(if is_final { 1 } else { 0 });
+        out.extend_from_slice(&(block_len as u16).to_le_bytes());
+        out.extend_from_slice(&!(block_len as u16).to_le_bytes());
+        out.extend_from_slice(&data[offset..offset + block_len]);
+        offset += block_len;
+        if is_final {
+            break;
+        }
+    }
+
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+/// Append a length-prefixed, CRC-suffixed PNG chunk to `out`.
+fn write_png_chunk(out: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let mut body = Vec::with_capacity(4 + data.len());
+    body.extend_from_slice(chunk_type);
+    body.extend_from_slice(data);
+    out.extend_from_slice(&body);
+    out.extend_from_slice(&crc32(&body).to_be_bytes());
+}
+
+/// Standard CRC-32 (IEEE 802.3 polynomial), as used by PNG chunks.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Adler-32 checksum, as used by the zlib stream trailer.
+fn adler32(data: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    let mut a: u32 = 1;
+    let mut b: u32 = 0;
+    for &byte in data {
+        a = (a + byte as u32) % MOD_ADLER;
+        b = (b + a) % MOD_ADLER;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_png_row_padding_for_short_samples() {
+        let png = encode_png(4, 4, PngColorType::Grayscale, 8, &[1, 2]);
+        assert_eq!(&png[0..8], &[137, 80, 78, 71, 13, 10, 26, 10]);
+    }
+
+    #[test]
+    fn test_zlib_store_roundtrip_checksum() {
+        let data = b"hello pdfplumber".to_vec();
+        let stream = zlib_store(&data);
+        assert_eq!(stream[0], 0x78);
+        assert_eq!(stream[1], 0x01);
+        let adler = u32::from_be_bytes(stream[stream.len() - 4..].try_into().unwrap());
+        assert_eq!(adler, adler32(&data));
+    }
+
+    #[test]
+    fn test_crc32_known_value() {
+        // CRC-32 of the ASCII string "123456789" is the well-known check value.
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_rgba_ihdr_value_and_channels() {
+        assert_eq!(PngColorType::Rgba.ihdr_value(), 6);
+        assert_eq!(PngColorType::Rgba.channels(), 4);
+    }
+}
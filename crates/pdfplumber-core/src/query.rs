@@ -0,0 +1,157 @@
+//! Spatial queries over extracted page objects.
+//!
+//! [`elements_intersecting`] and [`elements_enclosed_by`] answer "what's in
+//! this region" for debug overlays (e.g. [`crate::svg`]) and for selecting
+//! objects by area, without requiring callers to write their own per-type
+//! bbox comparisons.
+
+use crate::geometry::BBox;
+use crate::shapes::{Line, Rect};
+use crate::table::Cell;
+use crate::text::Char;
+
+/// A reference to one of the object types a spatial query can match,
+/// tagged with its kind. Mirrors [`crate::page_object::PageObject`]'s
+/// "one enum, many borrowed object types" shape, but keyed on geometry
+/// rather than a custom predicate.
+pub enum QueryElement<'a> {
+    /// A character object.
+    Char(&'a Char),
+    /// A line object.
+    Line(&'a Line),
+    /// A rectangle object.
+    Rect(&'a Rect),
+    /// A table cell.
+    Cell(&'a Cell),
+}
+
+impl QueryElement<'_> {
+    /// The element's bounding box.
+    pub fn bbox(&self) -> BBox {
+        match self {
+            QueryElement::Char(c) => c.bbox,
+            QueryElement::Line(l) => BBox::new(l.x0, l.top, l.x1, l.bottom),
+            QueryElement::Rect(r) => BBox::new(r.x0, r.top, r.x1, r.bottom),
+            QueryElement::Cell(c) => c.bbox,
+        }
+    }
+}
+
+fn all_elements<'a>(
+    chars: &'a [Char],
+    lines: &'a [Line],
+    rects: &'a [Rect],
+    cells: &'a [Cell],
+) -> impl Iterator<Item = QueryElement<'a>> {
+    chars
+        .iter()
+        .map(QueryElement::Char)
+        .chain(lines.iter().map(QueryElement::Line))
+        .chain(rects.iter().map(QueryElement::Rect))
+        .chain(cells.iter().map(QueryElement::Cell))
+}
+
+/// Collect the chars, lines, rects, and cells that touch or overlap `region`.
+///
+/// Uses [`BBox::intersects`], which counts touching edges and treats a
+/// zero-width or zero-height element (e.g. a perfectly horizontal or
+/// vertical line) as intersecting whenever its collapsed coordinate falls
+/// inside `region`'s range on that axis and the boxes overlap on the other
+/// axis — otherwise degenerate elements would never be reported as
+/// intersecting anything.
+pub fn elements_intersecting<'a>(
+    chars: &'a [Char],
+    lines: &'a [Line],
+    rects: &'a [Rect],
+    cells: &'a [Cell],
+    region: BBox,
+) -> Vec<QueryElement<'a>> {
+    all_elements(chars, lines, rects, cells)
+        .filter(|e| e.bbox().intersects(&region))
+        .collect()
+}
+
+/// Collect the chars, lines, rects, and cells that are fully contained
+/// within `region` (inclusive of touching edges).
+pub fn elements_enclosed_by<'a>(
+    chars: &'a [Char],
+    lines: &'a [Line],
+    rects: &'a [Rect],
+    cells: &'a [Cell],
+    region: BBox,
+) -> Vec<QueryElement<'a>> {
+    all_elements(chars, lines, rects, cells)
+        .filter(|e| region.encloses(&e.bbox()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::BBox;
+
+    fn make_char(x0: f64, top: f64, x1: f64, bottom: f64) -> Char {
+        Char {
+            text: "a".to_string(),
+            bbox: BBox::new(x0, top, x1, bottom),
+            fontname: "Helvetica".to_string(),
+            size: 12.0,
+            doctop: top,
+            upright: true,
+            direction: crate::text::TextDirection::Ltr,
+            stroking_color: None,
+            non_stroking_color: None,
+            ctm: [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+            char_code: 0,
+            mcid: None,
+            tag: None,
+        }
+    }
+
+    fn make_line(x0: f64, top: f64, x1: f64, bottom: f64) -> Line {
+        Line {
+            x0,
+            top,
+            x1,
+            bottom,
+            line_width: 1.0,
+            stroke_color: crate::painting::Color::black(),
+            orientation: crate::shapes::LineOrientation::Horizontal,
+        }
+    }
+
+    #[test]
+    fn test_elements_intersecting_finds_overlapping_char() {
+        let chars = vec![make_char(0.0, 0.0, 10.0, 10.0)];
+        let region = BBox::new(5.0, 5.0, 15.0, 15.0);
+        let found = elements_intersecting(&chars, &[], &[], &[], region);
+        assert_eq!(found.len(), 1);
+    }
+
+    #[test]
+    fn test_elements_intersecting_excludes_non_overlapping_char() {
+        let chars = vec![make_char(0.0, 0.0, 10.0, 10.0)];
+        let region = BBox::new(20.0, 20.0, 30.0, 30.0);
+        let found = elements_intersecting(&chars, &[], &[], &[], region);
+        assert_eq!(found.len(), 0);
+    }
+
+    #[test]
+    fn test_elements_intersecting_counts_degenerate_line() {
+        // A perfectly horizontal line has zero height.
+        let lines = vec![make_line(0.0, 50.0, 100.0, 50.0)];
+        let region = BBox::new(40.0, 40.0, 60.0, 60.0);
+        let found = elements_intersecting(&[], &lines, &[], &[], region);
+        assert_eq!(found.len(), 1);
+    }
+
+    #[test]
+    fn test_elements_enclosed_by_requires_full_containment() {
+        let chars = vec![make_char(0.0, 0.0, 10.0, 10.0)];
+        let partial_region = BBox::new(5.0, 5.0, 15.0, 15.0);
+        assert_eq!(elements_enclosed_by(&chars, &[], &[], &[], partial_region).len(), 0);
+
+        let full_region = BBox::new(0.0, 0.0, 10.0, 10.0);
+        assert_eq!(elements_enclosed_by(&chars, &[], &[], &[], full_region).len(), 1);
+    }
+}
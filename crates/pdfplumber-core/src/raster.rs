@@ -0,0 +1,1021 @@
+//! Page rasterization for visual debugging, analogous to mupdf's `mudraw`.
+//!
+//! Unlike [`crate::svg`], which emits a vector overlay, [`Bitmap`] paints an
+//! RGBA raster by walking the same [`Line`], [`Rect`], and [`Char`] values
+//! already produced by content-stream interpretation: the page is filled
+//! white, line/rect path operators are stroked or filled, and each character
+//! is painted as a filled box at its bbox (there is no font rasterizer in
+//! this crate, so glyphs are not drawn as outlines).
+//!
+//! `Bitmap` also implements [`crate::svg::PageRenderer`], so the same
+//! `draw_*_onto` overlay logic in [`crate::svg`] that builds SVG debug
+//! markup can paint straight onto pixels instead.
+
+use crate::error::PdfError;
+use crate::geometry::BBox;
+use crate::images::{Image, ImageFilter};
+use crate::painting::Color;
+use crate::png::{PngColorType, encode_png};
+use crate::shapes::{Curve, Line, Rect};
+use crate::svg::{DrawStyle, PageRenderer, parse_svg_color};
+use crate::text::Char;
+
+/// Maximum pixel count (`width * height`) a rasterized page may have.
+///
+/// A page's raster dimensions are derived from its `/MediaBox` times a
+/// caller-supplied scale/DPI, neither of which this crate validates upfront;
+/// without a cap, a crafted or merely corrupt `/MediaBox`, or a caller
+/// passing a high DPI, produces a multi-exabyte allocation request that
+/// aborts the process via `handle_alloc_error` instead of returning a
+/// catchable [`PdfError`]. 64,000,000 pixels (e.g. an 8000x8000 raster) is
+/// generous for a debug/preview render while keeping the backing buffer
+/// under 256 MiB.
+const MAX_RASTER_PIXELS: u64 = 64_000_000;
+
+/// Check that a `width x height` raster stays within [`MAX_RASTER_PIXELS`],
+/// returning [`PdfError::ResourceLimitExceeded`] instead of letting an
+/// oversized allocation abort the process.
+pub fn check_raster_dimensions(width: u32, height: u32) -> Result<(), PdfError> {
+    let pixels = width as u64 * height as u64;
+    if pixels > MAX_RASTER_PIXELS {
+        return Err(PdfError::ResourceLimitExceeded {
+            limit_name: "max_raster_pixels".to_string(),
+            limit_value: MAX_RASTER_PIXELS as usize,
+            actual_value: pixels as usize,
+        });
+    }
+    Ok(())
+}
+
+/// An RGBA raster image, top-left origin, row-major, 8 bits per channel.
+#[derive(Debug, Clone)]
+pub struct Bitmap {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Bitmap {
+    /// Create a new bitmap of the given size, filled opaque white.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self::filled(width, height, Color::white())
+    }
+
+    /// Create a new bitmap of the given size, filled opaque with `color`.
+    pub fn filled(width: u32, height: u32, color: Color) -> Self {
+        let mut pixels = vec![0u8; width as usize * height as usize * 4];
+        let (r, g, b) = (
+            (color.r.clamp(0.0, 1.0) * 255.0).round() as u8,
+            (color.g.clamp(0.0, 1.0) * 255.0).round() as u8,
+            (color.b.clamp(0.0, 1.0) * 255.0).round() as u8,
+        );
+        for px in pixels.chunks_exact_mut(4) {
+            px.copy_from_slice(&[r, g, b, 255]);
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Bitmap width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Bitmap height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Raw RGBA pixel data, row-major, top-to-bottom.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Blend a single pixel with `color` at full opacity. Out-of-bounds
+    /// coordinates are silently ignored (paths may extend past the page).
+    pub fn set_pixel(&mut self, x: i64, y: i64, color: Color) {
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return;
+        }
+        let idx = (y as usize * self.width as usize + x as usize) * 4;
+        self.pixels[idx] = (color.r.clamp(0.0, 1.0) * 255.0).round() as u8;
+        self.pixels[idx + 1] = (color.g.clamp(0.0, 1.0) * 255.0).round() as u8;
+        self.pixels[idx + 2] = (color.b.clamp(0.0, 1.0) * 255.0).round() as u8;
+        self.pixels[idx + 3] = 255;
+    }
+
+    /// Fill an axis-aligned rectangle (in pixel coordinates) with `color`.
+    pub fn fill_rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, color: Color) {
+        let (x0, x1) = (x0.min(x1).floor() as i64, x0.max(x1).ceil() as i64);
+        let (y0, y1) = (y0.min(y1).floor() as i64, y0.max(y1).ceil() as i64);
+        for y in y0..y1 {
+            for x in x0..x1 {
+                self.set_pixel(x, y, color);
+            }
+        }
+    }
+
+    /// Stroke an axis-aligned rectangle's outline with `color` and `stroke_width`.
+    pub fn stroke_rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, stroke_width: f64, color: Color) {
+        let w = stroke_width.max(1.0);
+        self.fill_rect(x0, y0, x1, y0 + w, color);
+        self.fill_rect(x0, y1 - w, x1, y1, color);
+        self.fill_rect(x0, y0, x0 + w, y1, color);
+        self.fill_rect(x1 - w, y0, x1, y1, color);
+    }
+
+    /// Stroke a line segment with `color`, thickened to approximate `stroke_width`.
+    ///
+    /// Uses Bresenham's algorithm for the centerline, then pads perpendicular
+    /// to the line's dominant axis so thin strokes remain visible.
+    pub fn stroke_line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, stroke_width: f64, color: Color) {
+        let half = (stroke_width.max(1.0) / 2.0).ceil() as i64;
+        let (mut x0i, mut y0i) = (x0.round() as i64, y0.round() as i64);
+        let (x1i, y1i) = (x1.round() as i64, y1.round() as i64);
+        let dx = (x1i - x0i).abs();
+        let dy = -(y1i - y0i).abs();
+        let sx = if x0i < x1i { 1 } else { -1 };
+        let sy = if y0i < y1i { 1 } else { -1 };
+        let mut err = dx + dy;
+
+        loop {
+            for oy in -half..=half {
+                for ox in -half..=half {
+                    self.set_pixel(x0i + ox, y0i + oy, color);
+                }
+            }
+            if x0i == x1i && y0i == y1i {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x0i += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y0i += sy;
+            }
+        }
+    }
+
+    /// Fill a circle centered at `(cx, cy)` with the given `radius`.
+    pub fn fill_circle(&mut self, cx: f64, cy: f64, radius: f64, color: Color) {
+        let r = radius.max(0.0);
+        let (x0, x1) = ((cx - r).floor() as i64, (cx + r).ceil() as i64);
+        let (y0, y1) = ((cy - r).floor() as i64, (cy + r).ceil() as i64);
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                let (dx, dy) = (x as f64 + 0.5 - cx, y as f64 + 0.5 - cy);
+                if dx * dx + dy * dy <= r * r {
+                    self.set_pixel(x, y, color);
+                }
+            }
+        }
+    }
+
+    /// Stroke a circle's outline centered at `(cx, cy)` with the given
+    /// `radius` and `stroke_width`.
+    pub fn stroke_circle(&mut self, cx: f64, cy: f64, radius: f64, stroke_width: f64, color: Color) {
+        let r = radius.max(0.0);
+        let half = (stroke_width.max(1.0) / 2.0).max(0.5);
+        let (inner, outer) = ((r - half).max(0.0), r + half);
+        let (x0, x1) = ((cx - outer).floor() as i64, (cx + outer).ceil() as i64);
+        let (y0, y1) = ((cy - outer).floor() as i64, (cy + outer).ceil() as i64);
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                let (dx, dy) = (x as f64 + 0.5 - cx, y as f64 + 0.5 - cy);
+                let dist_sq = dx * dx + dy * dy;
+                if dist_sq >= inner * inner && dist_sq <= outer * outer {
+                    self.set_pixel(x, y, color);
+                }
+            }
+        }
+    }
+
+    /// Paint extracted [`Line`]s, scaled by `scale` (page points → pixels).
+    pub fn draw_lines(&mut self, lines: &[Line], scale: f64) {
+        for line in lines {
+            self.stroke_line(
+                line.x0 * scale,
+                line.top * scale,
+                line.x1 * scale,
+                line.bottom * scale,
+                line.line_width * scale,
+                line.stroke_color,
+            );
+        }
+    }
+
+    /// Paint extracted [`Rect`]s, scaled by `scale` (page points → pixels).
+    pub fn draw_rects(&mut self, rects: &[Rect], scale: f64) {
+        for rect in rects {
+            let (x0, y0, x1, y1) = (
+                rect.x0 * scale,
+                rect.top * scale,
+                rect.x1 * scale,
+                rect.bottom * scale,
+            );
+            if rect.fill {
+                self.fill_rect(x0, y0, x1, y1, rect.fill_color);
+            }
+            if rect.stroke {
+                self.stroke_rect(x0, y0, x1, y1, rect.line_width * scale, rect.stroke_color);
+            }
+        }
+    }
+
+    /// Paint a filled box at each [`Char`]'s bbox, scaled by `scale`.
+    ///
+    /// There is no font rasterizer available, so glyphs are represented as
+    /// solid boxes rather than their actual outlines.
+    pub fn draw_chars(&mut self, chars: &[Char], scale: f64) {
+        for ch in chars {
+            self.fill_rect(
+                ch.bbox.x0 * scale,
+                ch.bbox.top * scale,
+                ch.bbox.x1 * scale,
+                ch.bbox.bottom * scale,
+                Color::black(),
+            );
+        }
+    }
+
+    /// Paint extracted [`Curve`]s, scaled by `scale` (page points → pixels).
+    ///
+    /// Stroked curves are flattened into straight segments the same way
+    /// [`PageRenderer::draw_curve`] does for debug overlays; filled curves
+    /// are approximated by their bounding box, since this module has no
+    /// general polygon fill (see [`Bitmap::draw_rects`]).
+    pub fn draw_curves(&mut self, curves: &[Curve], scale: f64) {
+        for curve in curves {
+            if curve.fill {
+                self.fill_rect(
+                    curve.x0 * scale,
+                    curve.top * scale,
+                    curve.x1 * scale,
+                    curve.bottom * scale,
+                    curve.fill_color,
+                );
+            }
+            if curve.stroke {
+                if let [start, cp1, cp2, end] = curve.pts[..] {
+                    let mut prev = (start.0 * scale, start.1 * scale);
+                    for i in 1..=BEZIER_SEGMENTS {
+                        let t = i as f64 / BEZIER_SEGMENTS as f64;
+                        let (x, y) = cubic_bezier_point(start, cp1, cp2, end, t);
+                        let point = (x * scale, y * scale);
+                        self.stroke_line(
+                            prev.0,
+                            prev.1,
+                            point.0,
+                            point.1,
+                            curve.line_width * scale,
+                            curve.stroke_color,
+                        );
+                        prev = point;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Paint each [`Image`]'s decoded content, scaled by `scale` (page
+    /// points → pixels), nearest-neighbor sampled into its display bbox.
+    ///
+    /// This crate has no JPEG/JPEG2000 decoder, so images whose filter is
+    /// [`ImageFilter::DCTDecode`] or [`ImageFilter::JPXDecode`], or whose
+    /// data wasn't collected (see `ExtractOptions::extract_image_data`),
+    /// are painted as a flat gray placeholder instead of their real pixels.
+    pub fn draw_images(&mut self, images: &[Image], scale: f64) {
+        for image in images {
+            let (x0, y0, x1, y1) = (
+                image.x0 * scale,
+                image.top * scale,
+                image.x1 * scale,
+                image.bottom * scale,
+            );
+            let (px0, px1) = (x0.min(x1).floor() as i64, x0.max(x1).ceil() as i64);
+            let (py0, py1) = (y0.min(y1).floor() as i64, y0.max(y1).ceil() as i64);
+            let (w, h) = ((px1 - px0).max(1) as f64, (py1 - py0).max(1) as f64);
+            for py in py0..py1 {
+                for px in px0..px1 {
+                    let u = (px - px0) as f64 / w;
+                    let v = (py - py0) as f64 / h;
+                    let color = sample_image(image, u, v).unwrap_or(PLACEHOLDER_IMAGE_COLOR);
+                    self.set_pixel(px, py, color);
+                }
+            }
+        }
+    }
+
+    /// Encode this bitmap as PNG-formatted bytes.
+    pub fn encode_png(&self) -> Vec<u8> {
+        encode_png(self.width, self.height, PngColorType::Rgba, 8, &self.pixels)
+    }
+
+    /// Encode this bitmap as a PNG file and write it to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PdfError::IoError`] if the file cannot be written.
+    pub fn save_png(&self, path: impl AsRef<std::path::Path>) -> Result<(), PdfError> {
+        std::fs::write(path, self.encode_png()).map_err(|e| PdfError::IoError(e.to_string()))
+    }
+}
+
+/// Evaluate a cubic Bezier curve at parameter `t` (0.0 to 1.0).
+fn cubic_bezier_point(start: (f64, f64), cp1: (f64, f64), cp2: (f64, f64), end: (f64, f64), t: f64) -> (f64, f64) {
+    let mt = 1.0 - t;
+    let (a, b, c, d) = (mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t);
+    (
+        a * start.0 + b * cp1.0 + c * cp2.0 + d * end.0,
+        a * start.1 + b * cp1.1 + c * cp2.1 + d * end.1,
+    )
+}
+
+/// Number of straight segments used to flatten a cubic Bezier curve when
+/// rasterizing it with [`Bitmap::stroke_line`].
+const BEZIER_SEGMENTS: usize = 16;
+
+/// Color painted in place of an [`Image`] whose pixels [`sample_image`] can't decode.
+const PLACEHOLDER_IMAGE_COLOR: Color = Color {
+    r: 0.6,
+    g: 0.6,
+    b: 0.6,
+};
+
+/// Sample `image`'s decoded pixel data at normalized coordinates `(u, v)`,
+/// each in `[0.0, 1.0)`, using nearest-neighbor lookup.
+///
+/// Returns `None` if `image.data` wasn't collected, the filter is a
+/// compressed format this crate can't decode ([`ImageFilter::DCTDecode`],
+/// [`ImageFilter::JPXDecode`]), or the sample depth isn't 8 bits per
+/// component (1/2/4/16-bit samples aren't unpacked here).
+fn sample_image(image: &Image, u: f64, v: f64) -> Option<Color> {
+    let data = image.data.as_ref()?;
+    if matches!(
+        image.filter,
+        Some(ImageFilter::DCTDecode) | Some(ImageFilter::JPXDecode)
+    ) {
+        return None;
+    }
+    if image.bits_per_component.unwrap_or(8) != 8 {
+        return None;
+    }
+    let width = image.src_width? as usize;
+    let height = image.src_height? as usize;
+    let grayscale = matches!(
+        image.color_space.as_deref(),
+        Some("DeviceGray") | Some("CalGray")
+    );
+    let channels = if grayscale { 1 } else { 3 };
+    let x = ((u * width as f64) as usize).min(width.saturating_sub(1));
+    let y = ((v * height as f64) as usize).min(height.saturating_sub(1));
+    let idx = (y * width + x) * channels;
+    let sample = data.get(idx..idx + channels)?;
+    Some(if grayscale {
+        let g = sample[0] as f64 / 255.0;
+        Color::new(g, g, g)
+    } else {
+        Color::new(
+            sample[0] as f64 / 255.0,
+            sample[1] as f64 / 255.0,
+            sample[2] as f64 / 255.0,
+        )
+    })
+}
+
+/// Options controlling [`render_page`].
+#[derive(Debug, Clone)]
+pub struct RenderOptions {
+    /// Page points → pixels scale factor. Default `1.0` (72 DPI); use
+    /// [`RenderOptions::from_dpi`] to target a specific resolution.
+    pub scale: f64,
+    /// Background color painted before any page content. Default white.
+    pub background: Color,
+    /// Region of the page to render, in page (top-left origin) coordinates.
+    /// `None` renders the full page.
+    pub clip: Option<BBox>,
+    /// Anti-aliasing toggle. [`render_page`] does not read it: image
+    /// sampling is always nearest-neighbor and shapes are always painted
+    /// with hard edges, whether it is set or not.
+    pub antialias: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            background: Color::white(),
+            clip: None,
+            antialias: false,
+        }
+    }
+}
+
+impl RenderOptions {
+    /// Build options targeting `dpi` dots per inch (PDF points are 1/72 inch).
+    pub fn from_dpi(dpi: f64) -> Self {
+        Self {
+            scale: dpi / 72.0,
+            ..Self::default()
+        }
+    }
+}
+
+/// An RGBA page raster produced by [`render_page`].
+///
+/// Unlike the boxes-for-everything debug raster `Page::render` builds from
+/// a plain [`Bitmap`], [`render_page`] paints fills and strokes from
+/// [`extract_shapes`](crate::shapes::extract_shapes) output and blits
+/// decoded image content, so it doubles as a page preview or thumbnail
+/// source rather than purely a detection-pipeline debug aid.
+#[derive(Debug, Clone)]
+pub struct RenderedPage {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl RenderedPage {
+    /// Raster width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Raster height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Bytes per row (width * 4 channels).
+    pub fn stride(&self) -> u32 {
+        self.width * 4
+    }
+
+    /// Raw RGBA pixel data, row-major, top-to-bottom.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Encode this raster as PNG-formatted bytes.
+    pub fn to_png(&self) -> Vec<u8> {
+        encode_png(self.width, self.height, PngColorType::Rgba, 8, &self.pixels)
+    }
+
+    /// Encode this raster as a PNG file and write it to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PdfError::IoError`] if the file cannot be written.
+    pub fn save_png(&self, path: impl AsRef<std::path::Path>) -> Result<(), PdfError> {
+        std::fs::write(path, self.to_png()).map_err(|e| PdfError::IoError(e.to_string()))
+    }
+}
+
+/// Rasterize a page's already-extracted content into an RGBA [`RenderedPage`],
+/// the way pathfinder_pdf/MuPDF turn a content stream into pixels.
+///
+/// `page_width`/`page_height` are the full page dimensions in points;
+/// `options.clip`, if set, restricts both which content is visible and the
+/// size of the output raster to that region. Fills and strokes honor each
+/// shape's [`Color`] and line width; dash patterns aren't tracked past
+/// [`extract_shapes`](crate::shapes::extract_shapes) so strokes are always
+/// solid. Images are blitted via [`Bitmap::draw_images`] (see its docs for
+/// which formats can be decoded); characters are painted as filled boxes
+/// since there is no font rasterizer (see the module docs).
+///
+/// # Errors
+///
+/// Returns [`PdfError::ResourceLimitExceeded`] if the requested raster (the
+/// clip region, or the full page, times `options.scale`) would exceed
+/// [`MAX_RASTER_PIXELS`] pixels, rather than attempting the allocation.
+#[allow(clippy::too_many_arguments)]
+pub fn render_page(
+    page_width: f64,
+    page_height: f64,
+    rects: &[Rect],
+    lines: &[Line],
+    curves: &[Curve],
+    images: &[Image],
+    chars: &[Char],
+    options: &RenderOptions,
+) -> Result<RenderedPage, PdfError> {
+    let region = options
+        .clip
+        .unwrap_or_else(|| BBox::new(0.0, 0.0, page_width, page_height));
+    let scale = options.scale;
+    let width = ((region.x1 - region.x0) * scale).round().max(0.0) as u32;
+    let height = ((region.bottom - region.top) * scale).round().max(0.0) as u32;
+    check_raster_dimensions(width, height)?;
+
+    let mut bitmap = Bitmap::filled(width, height, options.background);
+
+    let translate_rects: Vec<Rect> = rects
+        .iter()
+        .filter(|r| region.intersects(&BBox::new(r.x0, r.top, r.x1, r.bottom)))
+        .map(|r| translate_rect(r, &region))
+        .collect();
+    let translate_lines: Vec<Line> = lines
+        .iter()
+        .filter(|l| region.intersects(&BBox::new(l.x0, l.top, l.x1, l.bottom)))
+        .map(|l| translate_line(l, &region))
+        .collect();
+    let translate_curves: Vec<Curve> = curves
+        .iter()
+        .filter(|c| region.intersects(&BBox::new(c.x0, c.top, c.x1, c.bottom)))
+        .map(|c| translate_curve(c, &region))
+        .collect();
+    let translate_images: Vec<Image> = images
+        .iter()
+        .filter(|i| region.intersects(&BBox::new(i.x0, i.top, i.x1, i.bottom)))
+        .map(|i| translate_image(i, &region))
+        .collect();
+    let translate_chars: Vec<Char> = chars
+        .iter()
+        .filter(|c| region.intersects(&c.bbox))
+        .map(|c| translate_char(c, &region))
+        .collect();
+
+    bitmap.draw_images(&translate_images, scale);
+    bitmap.draw_rects(&translate_rects, scale);
+    bitmap.draw_curves(&translate_curves, scale);
+    bitmap.draw_lines(&translate_lines, scale);
+    bitmap.draw_chars(&translate_chars, scale);
+
+    Ok(RenderedPage {
+        width,
+        height,
+        pixels: bitmap.pixels,
+    })
+}
+
+/// Shift a shape so `region`'s top-left corner becomes the origin, for
+/// rendering content relative to a clip region.
+fn translate_rect(rect: &Rect, region: &BBox) -> Rect {
+    Rect {
+        x0: rect.x0 - region.x0,
+        top: rect.top - region.top,
+        x1: rect.x1 - region.x0,
+        bottom: rect.bottom - region.top,
+        ..rect.clone()
+    }
+}
+
+fn translate_line(line: &Line, region: &BBox) -> Line {
+    Line {
+        x0: line.x0 - region.x0,
+        top: line.top - region.top,
+        x1: line.x1 - region.x0,
+        bottom: line.bottom - region.top,
+        ..line.clone()
+    }
+}
+
+fn translate_curve(curve: &Curve, region: &BBox) -> Curve {
+    Curve {
+        x0: curve.x0 - region.x0,
+        top: curve.top - region.top,
+        x1: curve.x1 - region.x0,
+        bottom: curve.bottom - region.top,
+        pts: curve
+            .pts
+            .iter()
+            .map(|(x, y)| (x - region.x0, y - region.top))
+            .collect(),
+        ..curve.clone()
+    }
+}
+
+fn translate_image(image: &Image, region: &BBox) -> Image {
+    Image {
+        x0: image.x0 - region.x0,
+        top: image.top - region.top,
+        x1: image.x1 - region.x0,
+        bottom: image.bottom - region.top,
+        ..image.clone()
+    }
+}
+
+fn translate_char(ch: &Char, region: &BBox) -> Char {
+    let mut ch = ch.clone();
+    ch.bbox.x0 -= region.x0;
+    ch.bbox.top -= region.top;
+    ch.bbox.x1 -= region.x0;
+    ch.bbox.bottom -= region.top;
+    ch
+}
+
+/// Rasterizes the same backend-agnostic drawing primitives [`SvgRenderer`](crate::svg::SvgRenderer)
+/// renders as SVG markup, directly onto pixels, so debug overlays built via
+/// the `draw_*_onto` free functions in [`crate::svg`] can target a PNG
+/// output without going through SVG at all.
+///
+/// Glyphs have no font rasterizer available (see the module docs), so
+/// `draw_text` approximates each string as a filled box sized from its
+/// character count and font size rather than rendering actual outlines.
+impl PageRenderer for Bitmap {
+    /// Painting directly onto an in-memory pixel buffer never fails.
+    type Error = std::convert::Infallible;
+
+    fn draw_rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, style: &DrawStyle) -> Result<(), Self::Error> {
+        if let Some(fill) = style.fill.as_deref().and_then(parse_svg_color) {
+            self.fill_rect(x0, y0, x1, y1, fill);
+        }
+        if let Some(stroke) = style.stroke.as_deref().and_then(parse_svg_color) {
+            self.stroke_rect(x0, y0, x1, y1, style.stroke_width, stroke);
+        }
+        Ok(())
+    }
+
+    fn draw_line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, style: &DrawStyle) -> Result<(), Self::Error> {
+        if let Some(stroke) = style.stroke.as_deref().and_then(parse_svg_color) {
+            self.stroke_line(x0, y0, x1, y1, style.stroke_width, stroke);
+        }
+        Ok(())
+    }
+
+    fn draw_circle(&mut self, cx: f64, cy: f64, radius: f64, style: &DrawStyle) -> Result<(), Self::Error> {
+        if let Some(fill) = style.fill.as_deref().and_then(parse_svg_color) {
+            self.fill_circle(cx, cy, radius, fill);
+        }
+        if let Some(stroke) = style.stroke.as_deref().and_then(parse_svg_color) {
+            self.stroke_circle(cx, cy, radius, style.stroke_width, stroke);
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_text(
+        &mut self,
+        x: f64,
+        y: f64,
+        text: &str,
+        _font_family: &str,
+        font_size: f64,
+        _rotation_deg: f64,
+        style: &DrawStyle,
+    ) -> Result<(), Self::Error> {
+        let width = text.chars().count() as f64 * font_size * 0.6;
+        let (x0, y0, x1, y1) = (x, y - font_size, x + width, y);
+        if let Some(fill) = style.fill.as_deref().and_then(parse_svg_color) {
+            self.fill_rect(x0, y0, x1, y1, fill);
+        }
+        if let Some(stroke) = style.stroke.as_deref().and_then(parse_svg_color) {
+            self.stroke_rect(x0, y0, x1, y1, style.stroke_width, stroke);
+        }
+        Ok(())
+    }
+
+    fn draw_curve(
+        &mut self,
+        start: (f64, f64),
+        cp1: (f64, f64),
+        cp2: (f64, f64),
+        end: (f64, f64),
+        style: &DrawStyle,
+    ) -> Result<(), Self::Error> {
+        let Some(stroke) = style.stroke.as_deref().and_then(parse_svg_color) else {
+            return Ok(());
+        };
+        let mut prev = start;
+        for i in 1..=BEZIER_SEGMENTS {
+            let t = i as f64 / BEZIER_SEGMENTS as f64;
+            let point = cubic_bezier_point(start, cp1, cp2, end, t);
+            self.stroke_line(prev.0, prev.1, point.0, point.1, style.stroke_width, stroke);
+            prev = point;
+        }
+        Ok(())
+    }
+
+    fn draw_path(&mut self, points: &[(f64, f64)], closed: bool, style: &DrawStyle) -> Result<(), Self::Error> {
+        let Some(stroke) = style.stroke.as_deref().and_then(parse_svg_color) else {
+            return Ok(());
+        };
+        for pair in points.windows(2) {
+            self.stroke_line(pair[0].0, pair[0].1, pair[1].0, pair[1].1, style.stroke_width, stroke);
+        }
+        if closed {
+            if let (Some(&first), Some(&last)) = (points.first(), points.last()) {
+                self.stroke_line(last.0, last.1, first.0, first.1, style.stroke_width, stroke);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_bitmap_is_opaque_white() {
+        let bmp = Bitmap::new(2, 2);
+        assert_eq!(bmp.pixels(), &[255; 16]);
+    }
+
+    #[test]
+    fn test_set_pixel_out_of_bounds_is_ignored() {
+        let mut bmp = Bitmap::new(2, 2);
+        bmp.set_pixel(-1, 0, Color::black());
+        bmp.set_pixel(5, 5, Color::black());
+        assert_eq!(bmp.pixels(), &[255; 16]);
+    }
+
+    #[test]
+    fn test_fill_rect_paints_interior() {
+        let mut bmp = Bitmap::new(4, 4);
+        bmp.fill_rect(1.0, 1.0, 3.0, 3.0, Color::black());
+        // Corner outside the rect stays white.
+        assert_eq!(&bmp.pixels()[0..4], &[255, 255, 255, 255]);
+        // A pixel inside the rect is black.
+        let idx = (1 * 4 + 1) * 4;
+        assert_eq!(&bmp.pixels()[idx..idx + 4], &[0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_stroke_line_paints_endpoints() {
+        let mut bmp = Bitmap::new(10, 10);
+        bmp.stroke_line(0.0, 0.0, 9.0, 0.0, 1.0, Color::black());
+        let idx = 0;
+        assert_eq!(&bmp.pixels()[idx..idx + 4], &[0, 0, 0, 255]);
+        let idx_end = 9 * 4;
+        assert_eq!(&bmp.pixels()[idx_end..idx_end + 4], &[0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_fill_circle_paints_center_not_corner() {
+        let mut bmp = Bitmap::new(10, 10);
+        bmp.fill_circle(5.0, 5.0, 3.0, Color::black());
+        let center_idx = (5 * 10 + 5) * 4;
+        assert_eq!(&bmp.pixels()[center_idx..center_idx + 4], &[0, 0, 0, 255]);
+        assert_eq!(&bmp.pixels()[0..4], &[255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_stroke_circle_paints_ring_not_center() {
+        let mut bmp = Bitmap::new(20, 20);
+        bmp.stroke_circle(10.0, 10.0, 6.0, 1.0, Color::black());
+        let center_idx = (10 * 20 + 10) * 4;
+        assert_eq!(&bmp.pixels()[center_idx..center_idx + 4], &[255, 255, 255, 255]);
+        let edge_idx = (10 * 20 + 15) * 4;
+        assert_eq!(&bmp.pixels()[edge_idx..edge_idx + 4], &[0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_page_renderer_draw_rect_onto_bitmap() {
+        use crate::svg::{DrawStyle, draw_chars_onto};
+        use crate::text::{Char, TextDirection};
+
+        let ch = Char {
+            text: "a".to_string(),
+            bbox: crate::geometry::BBox::new(1.0, 1.0, 3.0, 3.0),
+            fontname: "Helvetica".to_string(),
+            size: 12.0,
+            doctop: 1.0,
+            upright: true,
+            direction: TextDirection::Ltr,
+            stroking_color: None,
+            non_stroking_color: None,
+            ctm: [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+            char_code: 0,
+            mcid: None,
+            tag: None,
+        };
+        let mut bmp = Bitmap::new(10, 10);
+        draw_chars_onto(&mut bmp, &[ch], &DrawStyle::chars_default()).unwrap();
+        let idx = (2 * 10 + 2) * 4;
+        assert_ne!(&bmp.pixels()[idx..idx + 4], &[255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_encode_png_matches_save_png() {
+        let bmp = Bitmap::new(2, 2);
+        let path = std::env::temp_dir().join("pdfplumber_rs_test_bitmap_encode.png");
+        bmp.save_png(&path).expect("save_png should succeed");
+        let saved = std::fs::read(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(bmp.encode_png(), saved);
+    }
+
+    #[test]
+    fn test_save_png_writes_valid_header() {
+        let bmp = Bitmap::new(2, 2);
+        let path = std::env::temp_dir().join("pdfplumber_rs_test_bitmap.png");
+        bmp.save_png(&path).expect("save_png should succeed");
+        let png = std::fs::read(&path).unwrap();
+        assert_eq!(&png[0..8], &[137, 80, 78, 71, 13, 10, 26, 10]);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    // --- Bitmap::filled ---
+
+    #[test]
+    fn test_bitmap_filled_sets_background_color() {
+        let bmp = Bitmap::filled(2, 2, Color::new(1.0, 0.0, 0.0));
+        assert_eq!(bmp.pixels(), &[255, 0, 0, 255].repeat(4));
+    }
+
+    // --- render_page ---
+
+    fn test_rect(x0: f64, top: f64, x1: f64, bottom: f64, fill_color: Color) -> Rect {
+        Rect {
+            x0,
+            top,
+            x1,
+            bottom,
+            line_width: 1.0,
+            stroke: false,
+            fill: true,
+            stroke_color: Color::black(),
+            fill_color,
+        }
+    }
+
+    #[test]
+    fn test_render_page_fills_background() {
+        let page = render_page(
+            10.0,
+            10.0,
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &RenderOptions {
+                background: Color::new(0.0, 1.0, 0.0),
+                ..RenderOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(page.pixels()[0..4], [0, 255, 0, 255]);
+    }
+
+    #[test]
+    fn test_render_page_honors_scale() {
+        let page = render_page(
+            10.0,
+            20.0,
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &RenderOptions {
+                scale: 2.0,
+                ..RenderOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!((page.width(), page.height()), (20, 40));
+        assert_eq!(page.stride(), 80);
+    }
+
+    #[test]
+    fn test_render_page_clip_restricts_output_size_and_origin() {
+        let rects = vec![test_rect(0.0, 0.0, 20.0, 20.0, Color::black())];
+        let page = render_page(
+            20.0,
+            20.0,
+            &rects,
+            &[],
+            &[],
+            &[],
+            &[],
+            &RenderOptions {
+                clip: Some(BBox::new(5.0, 5.0, 15.0, 15.0)),
+                ..RenderOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!((page.width(), page.height()), (10, 10));
+        // The fill covering the whole page still covers the clip region.
+        let idx = (5 * 10 + 5) * 4;
+        assert_eq!(&page.pixels()[idx..idx + 4], &[0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_render_page_skips_content_outside_clip() {
+        let rects = vec![test_rect(0.0, 0.0, 2.0, 2.0, Color::black())];
+        let page = render_page(
+            20.0,
+            20.0,
+            &rects,
+            &[],
+            &[],
+            &[],
+            &[],
+            &RenderOptions {
+                clip: Some(BBox::new(10.0, 10.0, 20.0, 20.0)),
+                ..RenderOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(page.pixels(), &[255; 10 * 10 * 4]);
+    }
+
+    #[test]
+    fn test_render_page_rejects_oversized_raster_instead_of_aborting() {
+        // A corrupt or hostile /MediaBox times a high scale must return a
+        // catchable error rather than attempt a multi-exabyte allocation.
+        let result = render_page(
+            1_000_000.0,
+            1_000_000.0,
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &RenderOptions {
+                scale: 100.0,
+                ..RenderOptions::default()
+            },
+        );
+        assert!(matches!(result, Err(PdfError::ResourceLimitExceeded { .. })));
+    }
+
+    // --- sample_image ---
+
+    #[test]
+    fn test_sample_image_decodes_rgb8() {
+        let image = Image {
+            x0: 0.0,
+            top: 0.0,
+            x1: 2.0,
+            bottom: 1.0,
+            width: 2.0,
+            height: 1.0,
+            name: "Im0".to_string(),
+            src_width: Some(2),
+            src_height: Some(1),
+            bits_per_component: Some(8),
+            color_space: Some("DeviceRGB".to_string()),
+            data: Some(vec![255, 0, 0, 0, 255, 0]),
+            filter: Some(ImageFilter::FlateDecode),
+            mime_type: None,
+            is_mask: false,
+            decode: None,
+        };
+        assert_eq!(sample_image(&image, 0.0, 0.0), Some(Color::new(1.0, 0.0, 0.0)));
+        assert_eq!(sample_image(&image, 0.9, 0.0), Some(Color::new(0.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_sample_image_returns_none_for_jpeg() {
+        let image = Image {
+            x0: 0.0,
+            top: 0.0,
+            x1: 1.0,
+            bottom: 1.0,
+            width: 1.0,
+            height: 1.0,
+            name: "Im0".to_string(),
+            src_width: Some(1),
+            src_height: Some(1),
+            bits_per_component: Some(8),
+            color_space: Some("DeviceRGB".to_string()),
+            data: Some(vec![0xFF, 0xD8, 0xFF]),
+            filter: Some(ImageFilter::DCTDecode),
+            mime_type: Some("image/jpeg".to_string()),
+            is_mask: false,
+            decode: None,
+        };
+        assert_eq!(sample_image(&image, 0.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_draw_images_placeholder_for_undecodable_image() {
+        let image = Image {
+            x0: 0.0,
+            top: 0.0,
+            x1: 2.0,
+            bottom: 2.0,
+            width: 2.0,
+            height: 2.0,
+            name: "Im0".to_string(),
+            src_width: Some(2),
+            src_height: Some(2),
+            bits_per_component: Some(8),
+            color_space: Some("DeviceRGB".to_string()),
+            data: None,
+            filter: None,
+            mime_type: None,
+            is_mask: false,
+            decode: None,
+        };
+        let mut bmp = Bitmap::new(2, 2);
+        bmp.draw_images(&[image], 1.0);
+        let expected = [
+            (PLACEHOLDER_IMAGE_COLOR.r * 255.0).round() as u8,
+            (PLACEHOLDER_IMAGE_COLOR.g * 255.0).round() as u8,
+            (PLACEHOLDER_IMAGE_COLOR.b * 255.0).round() as u8,
+            255,
+        ];
+        assert_eq!(&bmp.pixels()[0..4], &expected);
+    }
+}
@@ -5,17 +5,51 @@
 
 use std::fmt;
 
+/// How to handle a reference whose target object is missing or on the xref
+/// free list, when [`RepairOptions::remove_broken_objects`] is enabled.
+///
+/// Per the PDF spec, such a reference resolves to the null object, so
+/// [`ResolveToNull`](Self::ResolveToNull) is the spec-faithful choice and
+/// the default: it keeps the shape of the containing array or dictionary
+/// intact, which matters for structures like `/Kids`, `/Annots`, and
+/// outline trees where position or key presence carries meaning.
+/// [`Remove`](Self::Remove) instead drops the dangling entry entirely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DanglingRefPolicy {
+    /// Replace the dangling reference with the PDF null object, preserving
+    /// the shape of its containing array or dictionary.
+    #[default]
+    ResolveToNull,
+    /// Remove the dangling reference's entry from its containing array or
+    /// dictionary entirely.
+    Remove,
+}
+
 /// Options for controlling which PDF repairs to attempt.
 ///
 /// Each field enables a specific repair strategy. All default to `true`.
 #[derive(Debug, Clone)]
 pub struct RepairOptions {
-    /// Rebuild the cross-reference table by scanning for `obj`/`endobj` markers.
+    /// Rebuild the cross-reference table by scanning for `obj`/`endobj`
+    /// markers. Also reconstructs the page tree from scanned page objects
+    /// if the catalog's `/Pages` node yields no pages on its own.
     pub rebuild_xref: bool,
     /// Recalculate stream `/Length` entries from actual stream data.
     pub fix_stream_lengths: bool,
     /// Remove or skip unresolvable object references with warnings.
     pub remove_broken_objects: bool,
+    /// How to handle a dangling reference found while `remove_broken_objects`
+    /// is enabled. Defaults to [`DanglingRefPolicy::ResolveToNull`].
+    pub dangling_ref_policy: DanglingRefPolicy,
+    /// Before a repair overwrites a stream object's content in place (e.g.
+    /// [`Self::fix_stream_lengths`] rewriting `/Length`), save the original
+    /// bytes into [`RepairResult::orphans`] instead of discarding them.
+    ///
+    /// A caller that resolved and cached an object before repair ran is
+    /// holding a value that the repair has now invalidated; the orphan
+    /// list lets it recognize and, if needed, recover the pre-repair
+    /// content for that `(obj, gen)` identity.
+    pub preserve_orphans: bool,
 }
 
 impl Default for RepairOptions {
@@ -24,6 +58,8 @@ impl Default for RepairOptions {
             rebuild_xref: true,
             fix_stream_lengths: true,
             remove_broken_objects: true,
+            dangling_ref_policy: DanglingRefPolicy::default(),
+            preserve_orphans: true,
         }
     }
 }
@@ -32,12 +68,30 @@ impl fmt::Display for RepairOptions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "RepairOptions(rebuild_xref={}, fix_stream_lengths={}, remove_broken_objects={})",
-            self.rebuild_xref, self.fix_stream_lengths, self.remove_broken_objects
+            "RepairOptions(rebuild_xref={}, fix_stream_lengths={}, remove_broken_objects={}, dangling_ref_policy={:?}, preserve_orphans={})",
+            self.rebuild_xref, self.fix_stream_lengths, self.remove_broken_objects, self.dangling_ref_policy, self.preserve_orphans
         )
     }
 }
 
+/// A stream object whose content was replaced during repair.
+///
+/// Kept around (see [`RepairOptions::preserve_orphans`]) so that a handle
+/// resolved before the repair ran still has somewhere to find the value it
+/// originally saw, and so repeated repair passes stay idempotent: the same
+/// `(obj, gen)` identity is never orphaned twice for the same original
+/// content.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrphanedObject {
+    /// The `(object number, generation)` identity whose content was replaced.
+    pub object_id: (u32, u16),
+    /// The stream's original bytes, before the repair rewrote them.
+    pub original_content: Vec<u8>,
+    /// Human-readable reason the object was orphaned, mirroring the
+    /// matching [`RepairResult::log`] entry.
+    pub reason: String,
+}
+
 /// Result of a PDF repair operation.
 ///
 /// Contains the log of repairs that were applied and whether the
@@ -46,12 +100,19 @@ impl fmt::Display for RepairOptions {
 pub struct RepairResult {
     /// Log of repairs applied, one entry per fix.
     pub log: Vec<String>,
+    /// Stream objects whose content was replaced, with their original
+    /// bytes preserved. Populated only when
+    /// [`RepairOptions::preserve_orphans`] is set.
+    pub orphans: Vec<OrphanedObject>,
 }
 
 impl RepairResult {
     /// Create a new empty repair result.
     pub fn new() -> Self {
-        Self { log: Vec::new() }
+        Self {
+            log: Vec::new(),
+            orphans: Vec::new(),
+        }
     }
 
     /// Returns `true` if any repairs were applied.
@@ -70,6 +131,8 @@ mod tests {
         assert!(opts.rebuild_xref);
         assert!(opts.fix_stream_lengths);
         assert!(opts.remove_broken_objects);
+        assert_eq!(opts.dangling_ref_policy, DanglingRefPolicy::ResolveToNull);
+        assert!(opts.preserve_orphans);
     }
 
     #[test]
@@ -79,6 +142,8 @@ mod tests {
         assert!(s.contains("rebuild_xref=true"));
         assert!(s.contains("fix_stream_lengths=true"));
         assert!(s.contains("remove_broken_objects=true"));
+        assert!(s.contains("dangling_ref_policy=ResolveToNull"));
+        assert!(s.contains("preserve_orphans=true"));
     }
 
     #[test]
@@ -87,10 +152,14 @@ mod tests {
             rebuild_xref: false,
             fix_stream_lengths: true,
             remove_broken_objects: false,
+            dangling_ref_policy: DanglingRefPolicy::Remove,
+            preserve_orphans: false,
         };
         assert!(!opts.rebuild_xref);
         assert!(opts.fix_stream_lengths);
         assert!(!opts.remove_broken_objects);
+        assert_eq!(opts.dangling_ref_policy, DanglingRefPolicy::Remove);
+        assert!(!opts.preserve_orphans);
     }
 
     #[test]
@@ -100,12 +169,20 @@ mod tests {
         assert_eq!(opts1.rebuild_xref, opts2.rebuild_xref);
         assert_eq!(opts1.fix_stream_lengths, opts2.fix_stream_lengths);
         assert_eq!(opts1.remove_broken_objects, opts2.remove_broken_objects);
+        assert_eq!(opts1.dangling_ref_policy, opts2.dangling_ref_policy);
+        assert_eq!(opts1.preserve_orphans, opts2.preserve_orphans);
+    }
+
+    #[test]
+    fn dangling_ref_policy_default_is_resolve_to_null() {
+        assert_eq!(DanglingRefPolicy::default(), DanglingRefPolicy::ResolveToNull);
     }
 
     #[test]
     fn repair_result_new_empty() {
         let result = RepairResult::new();
         assert!(result.log.is_empty());
+        assert!(result.orphans.is_empty());
         assert!(!result.has_repairs());
     }
 
@@ -127,4 +204,17 @@ mod tests {
         let result = RepairResult::default();
         assert!(!result.has_repairs());
     }
+
+    #[test]
+    fn repair_result_orphans() {
+        let mut result = RepairResult::new();
+        result.orphans.push(OrphanedObject {
+            object_id: (3, 0),
+            original_content: b"stale stream bytes".to_vec(),
+            reason: "fixed stream length for object 3 0: 10 -> 19".to_string(),
+        });
+        assert_eq!(result.orphans.len(), 1);
+        assert_eq!(result.orphans[0].object_id, (3, 0));
+        assert_eq!(result.orphans[0].original_content, b"stale stream bytes");
+    }
 }
@@ -144,6 +144,291 @@ pub fn search_chars(
     results
 }
 
+/// A single word-level search match, carrying the surrounding word context
+/// and a bounding box derived from whole words rather than characters.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WordSearchMatch {
+    /// The matched text.
+    pub text: String,
+    /// Union bounding box of the words overlapping the match span.
+    pub bbox: BBox,
+    /// Page number (0-indexed).
+    pub page_number: usize,
+    /// Indices into the `words` slice for the matched words.
+    pub word_indices: Vec<usize>,
+    /// Up to `context` words immediately before the match.
+    pub context_before: Vec<String>,
+    /// Up to `context` words immediately after the match.
+    pub context_after: Vec<String>,
+}
+
+/// Search for a pattern over a page's word stream, returning matches whose
+/// bounding box and context are resolved from whole words.
+///
+/// The algorithm mirrors [`search_chars`], but the concatenated text is
+/// built from word texts joined by single spaces (word boundaries, not byte
+/// offsets, are what downstream highlighting/redaction needs): each word's
+/// byte range maps to its index, and the joining spaces map to no word, so a
+/// match that merely touches adjacent whitespace doesn't pull in a spurious
+/// neighbor. For each match, the distinct words whose byte ranges intersect
+/// `[start, end)` are unioned into the result bbox, and up to `context`
+/// words on either side are captured as plain text.
+pub fn search_words(
+    words: &[crate::words::Word],
+    pattern: &str,
+    options: &SearchOptions,
+    page_number: usize,
+    context: usize,
+) -> Vec<WordSearchMatch> {
+    if words.is_empty() || pattern.is_empty() {
+        return Vec::new();
+    }
+
+    let mut full_text = String::new();
+    // byte_to_word_idx[byte_offset] = index into `words`, or `None` for the
+    // single-space separators between words.
+    let mut byte_to_word_idx: Vec<Option<usize>> = Vec::new();
+
+    for (i, w) in words.iter().enumerate() {
+        if i > 0 {
+            full_text.push(' ');
+            byte_to_word_idx.push(None);
+        }
+        let start = full_text.len();
+        full_text.push_str(&w.text);
+        let end = full_text.len();
+        for _ in start..end {
+            byte_to_word_idx.push(Some(i));
+        }
+    }
+
+    let regex_pattern = if options.regex {
+        if options.case_sensitive {
+            pattern.to_string()
+        } else {
+            format!("(?i){pattern}")
+        }
+    } else {
+        let escaped = regex::escape(pattern);
+        if options.case_sensitive {
+            escaped
+        } else {
+            format!("(?i){escaped}")
+        }
+    };
+
+    let re = match Regex::new(&regex_pattern) {
+        Ok(re) => re,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut results = Vec::new();
+
+    for m in re.find_iter(&full_text) {
+        let match_start = m.start();
+        let match_end = m.end();
+
+        if match_start >= byte_to_word_idx.len() || match_end == 0 {
+            continue;
+        }
+
+        let mut word_indices: Vec<usize> = Vec::new();
+        for byte_offset in match_start..match_end {
+            if let Some(Some(idx)) = byte_to_word_idx.get(byte_offset) {
+                if word_indices.last() != Some(idx) {
+                    word_indices.push(*idx);
+                }
+            }
+        }
+
+        if word_indices.is_empty() {
+            continue;
+        }
+
+        let mut bbox = words[word_indices[0]].bbox;
+        for &idx in &word_indices[1..] {
+            bbox = bbox.union(&words[idx].bbox);
+        }
+
+        let first = *word_indices.first().unwrap();
+        let last = *word_indices.last().unwrap();
+        let context_before = words[first.saturating_sub(context)..first]
+            .iter()
+            .map(|w| w.text.clone())
+            .collect();
+        let context_after = words[(last + 1)..words.len().min(last + 1 + context)]
+            .iter()
+            .map(|w| w.text.clone())
+            .collect();
+
+        results.push(WordSearchMatch {
+            text: m.as_str().to_string(),
+            bbox,
+            page_number,
+            word_indices,
+            context_before,
+            context_after,
+        });
+    }
+
+    results
+}
+
+/// Search for `pattern` in a sequence of characters, tolerating up to
+/// `max_edits` insertions, deletions, or substitutions, so OCR noise and soft
+/// hyphens don't defeat exact matches.
+///
+/// Unlike [`search_chars`], the pattern is never treated as a regex: it is
+/// compared character-by-character via an edit-distance scan (Sellers'
+/// algorithm with a free start, i.e. `dp[0][j] = 0` for every text position,
+/// so a match can begin anywhere). Matches are returned in left-to-right,
+/// non-overlapping order; when several candidate end positions in a row are
+/// all within `max_edits`, the rightmost of those with the smallest edit
+/// distance is kept, so on a distance tie the longest match wins.
+///
+/// # Arguments
+///
+/// * `chars` - The characters to search within (from a page or cropped region).
+/// * `pattern` - The literal pattern to fuzzy-match (not a regex).
+/// * `max_edits` - Maximum allowed insertions + deletions + substitutions.
+/// * `page_number` - The page number for the returned matches.
+pub fn fuzzy_search_chars(
+    chars: &[crate::text::Char],
+    pattern: &str,
+    max_edits: usize,
+    page_number: usize,
+) -> Vec<SearchMatch> {
+    if chars.is_empty() || pattern.is_empty() {
+        return Vec::new();
+    }
+
+    let pattern_chars: Vec<char> = pattern.chars().collect();
+    let m = pattern_chars.len();
+
+    // Flatten each Char's text into individual unicode scalars, tracking
+    // which original `chars` index each scalar came from (mirrors the
+    // byte-offset mapping in `search_chars`, but at the char level since
+    // edit distance operates on characters, not bytes).
+    let mut text_chars: Vec<char> = Vec::new();
+    let mut text_to_char_idx: Vec<usize> = Vec::new();
+    for (i, ch) in chars.iter().enumerate() {
+        for c in ch.text.chars() {
+            text_chars.push(c);
+            text_to_char_idx.push(i);
+        }
+    }
+    let n = text_chars.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let k = max_edits as u32;
+
+    // dp[i][j] = edit distance between pattern[..i] and some suffix of
+    // text[..j]. dp[0][j] = 0 for all j gives the match a free start anywhere
+    // in the text; dp[i][0] = i is the cost of inserting the first i pattern
+    // chars into an empty text prefix.
+    let mut dp = vec![vec![0u32; n + 1]; m + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i as u32;
+    }
+
+    for i in 1..=m {
+        for j in 1..=n {
+            let cost = u32::from(pattern_chars[i - 1] != text_chars[j - 1]);
+            let sub = dp[i - 1][j - 1] + cost;
+            let del = dp[i][j - 1] + 1;
+            let ins = dp[i - 1][j] + 1;
+            dp[i][j] = sub.min(del).min(ins);
+        }
+    }
+
+    let mut results = Vec::new();
+    let mut consumed_until = 0usize;
+    let mut j = 1;
+
+    while j <= n {
+        if dp[m][j] <= k && j > consumed_until {
+            // Extend over the contiguous run of end positions that also
+            // qualify; prefer the rightmost (longest) one on a distance tie.
+            let mut run_end = j;
+            let mut best_j = j;
+            let mut best_dist = dp[m][j];
+            while run_end + 1 <= n && dp[m][run_end + 1] <= k {
+                run_end += 1;
+                if dp[m][run_end] <= best_dist {
+                    best_dist = dp[m][run_end];
+                    best_j = run_end;
+                }
+            }
+
+            if let Some(start) = backtrack_match_start(&dp, &pattern_chars, &text_chars, best_j) {
+                let mut char_indices: Vec<usize> = Vec::new();
+                for &idx in &text_to_char_idx[start..best_j] {
+                    if char_indices.last() != Some(&idx) {
+                        char_indices.push(idx);
+                    }
+                }
+                if !char_indices.is_empty() {
+                    let mut bbox = chars[char_indices[0]].bbox;
+                    for &idx in &char_indices[1..] {
+                        bbox = bbox.union(&chars[idx].bbox);
+                    }
+                    let text: String = text_chars[start..best_j].iter().collect();
+                    results.push(SearchMatch {
+                        text,
+                        bbox,
+                        page_number,
+                        char_indices,
+                    });
+                }
+            }
+            consumed_until = best_j;
+            j = run_end + 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    results
+}
+
+/// Trace back through the DP table from `(m, end)` to the text position
+/// where the lowest-cost match began, following whichever transition
+/// (substitution/match, insertion, or deletion) produced the recorded cost.
+fn backtrack_match_start(
+    dp: &[Vec<u32>],
+    pattern_chars: &[char],
+    text_chars: &[char],
+    end: usize,
+) -> Option<usize> {
+    let mut i = dp.len() - 1;
+    let mut j = end;
+
+    while i > 0 {
+        if j > 0 {
+            let diag_cost = u32::from(pattern_chars[i - 1] != text_chars[j - 1]);
+            if dp[i][j] == dp[i - 1][j - 1].saturating_add(diag_cost) {
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        if dp[i][j] == dp[i - 1][j].saturating_add(1) {
+            i -= 1;
+            continue;
+        }
+        if j > 0 && dp[i][j] == dp[i][j - 1].saturating_add(1) {
+            j -= 1;
+            continue;
+        }
+        return None;
+    }
+
+    Some(j)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -395,4 +680,175 @@ mod tests {
         assert_eq!(matches.len(), 1);
         assert_eq!(matches[0].page_number, 5);
     }
+
+    fn make_word(text: &str, x0: f64, top: f64, x1: f64, bottom: f64) -> crate::words::Word {
+        crate::words::Word {
+            text: text.to_string(),
+            bbox: BBox::new(x0, top, x1, bottom),
+            doctop: top,
+            direction: TextDirection::Ltr,
+            chars: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn search_words_finds_match_and_union_bbox() {
+        let words = vec![
+            make_word("The", 10.0, 100.0, 30.0, 112.0),
+            make_word("quick", 34.0, 100.0, 60.0, 112.0),
+            make_word("brown", 64.0, 100.0, 90.0, 112.0),
+        ];
+        let opts = SearchOptions {
+            regex: false,
+            ..Default::default()
+        };
+        let matches = search_words(&words, "quick", &opts, 0, 0);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].text, "quick");
+        assert_eq!(matches[0].word_indices, vec![1]);
+        assert_eq!(matches[0].bbox, BBox::new(34.0, 100.0, 60.0, 112.0));
+    }
+
+    #[test]
+    fn search_words_captures_surrounding_context() {
+        let words = vec![
+            make_word("The", 10.0, 100.0, 30.0, 112.0),
+            make_word("quick", 34.0, 100.0, 60.0, 112.0),
+            make_word("brown", 64.0, 100.0, 90.0, 112.0),
+            make_word("fox", 94.0, 100.0, 110.0, 112.0),
+        ];
+        let opts = SearchOptions {
+            regex: false,
+            ..Default::default()
+        };
+        let matches = search_words(&words, "brown", &opts, 0, 1);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].context_before, vec!["quick".to_string()]);
+        assert_eq!(matches[0].context_after, vec!["fox".to_string()]);
+    }
+
+    #[test]
+    fn search_words_context_clamps_at_boundaries() {
+        let words = vec![
+            make_word("The", 10.0, 100.0, 30.0, 112.0),
+            make_word("fox", 34.0, 100.0, 50.0, 112.0),
+        ];
+        let opts = SearchOptions {
+            regex: false,
+            ..Default::default()
+        };
+        let matches = search_words(&words, "The", &opts, 0, 5);
+
+        assert!(matches[0].context_before.is_empty());
+        assert_eq!(matches[0].context_after, vec!["fox".to_string()]);
+    }
+
+    #[test]
+    fn search_words_match_spanning_two_words() {
+        let words = vec![
+            make_word("quick", 10.0, 100.0, 40.0, 112.0),
+            make_word("brown", 44.0, 100.0, 70.0, 112.0),
+        ];
+        let opts = SearchOptions::default();
+        let matches = search_words(&words, "quick brown", &opts, 2, 0);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].word_indices, vec![0, 1]);
+        assert_eq!(matches[0].page_number, 2);
+        assert_eq!(matches[0].bbox, BBox::new(10.0, 100.0, 70.0, 112.0));
+    }
+
+    #[test]
+    fn search_words_empty_words_returns_empty() {
+        let opts = SearchOptions::default();
+        assert!(search_words(&[], "anything", &opts, 0, 0).is_empty());
+    }
+
+    fn chars_for(text: &str) -> Vec<Char> {
+        text.chars()
+            .enumerate()
+            .map(|(i, c)| make_char(&c.to_string(), i as f64 * 10.0, 100.0, i as f64 * 10.0 + 10.0, 112.0))
+            .collect()
+    }
+
+    #[test]
+    fn fuzzy_search_exact_match_zero_edits() {
+        let chars = chars_for("Hello World");
+        let matches = fuzzy_search_chars(&chars, "Hello", 0, 0);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].text, "Hello");
+        assert_eq!(matches[0].char_indices, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn fuzzy_search_tolerates_one_substitution() {
+        let chars = chars_for("Hello World");
+        let matches = fuzzy_search_chars(&chars, "Xello", 1, 0);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].text, "Hello");
+    }
+
+    #[test]
+    fn fuzzy_search_tolerates_missing_char() {
+        // "Helo" (missing the second 'l') should fuzzy-match "Hello" with 1 edit.
+        let chars = chars_for("Hello World");
+        let matches = fuzzy_search_chars(&chars, "Helo", 1, 0);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].text, "Hello");
+    }
+
+    #[test]
+    fn fuzzy_search_no_match_beyond_max_edits() {
+        let chars = chars_for("no match here at all");
+        let matches = fuzzy_search_chars(&chars, "xyzzy", 1, 0);
+
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn fuzzy_search_returns_non_overlapping_matches() {
+        let chars = chars_for("abab");
+        let matches = fuzzy_search_chars(&chars, "ab", 0, 0);
+
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].char_indices, vec![0, 1]);
+        assert_eq!(matches[1].char_indices, vec![2, 3]);
+    }
+
+    #[test]
+    fn fuzzy_search_bbox_spans_matched_chars() {
+        let chars = chars_for("Hello World");
+        let matches = fuzzy_search_chars(&chars, "World", 0, 0);
+
+        assert_eq!(matches.len(), 1);
+        // "World" is chars[6..11); x0=60, x1=110.
+        assert_eq!(matches[0].bbox, BBox::new(60.0, 100.0, 110.0, 112.0));
+    }
+
+    #[test]
+    fn fuzzy_search_page_number_in_result() {
+        let chars = chars_for("Hello");
+        let matches = fuzzy_search_chars(&chars, "Hello", 0, 3);
+
+        assert_eq!(matches[0].page_number, 3);
+    }
+
+    #[test]
+    fn fuzzy_search_empty_pattern_returns_empty() {
+        let chars = chars_for("Hello");
+        let matches = fuzzy_search_chars(&chars, "", 1, 0);
+
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn fuzzy_search_empty_chars_returns_empty() {
+        let matches = fuzzy_search_chars(&[], "Hello", 1, 0);
+        assert!(matches.is_empty());
+    }
 }
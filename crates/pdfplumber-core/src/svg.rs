@@ -5,12 +5,182 @@
 //! edges, tables). This is pdfplumber's visual debugging system â€” Python
 //! pdfplumber's most unique feature.
 
+use std::io::{self, Write};
+
 use crate::edges::Edge;
 use crate::geometry::BBox;
-use crate::shapes::{Line, Rect};
+use crate::painting::Color;
+use crate::raster::Bitmap;
+use crate::shapes::{Curve, Line, Rect};
 use crate::table::{Cell, Intersection, Table};
 use crate::text::Char;
 
+/// Escape the characters XML requires escaping in text content and
+/// attribute values: `&`, `<`, `>`, and `"`.
+fn escape_xml(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Backend-agnostic drawing primitives for rendering page overlays.
+///
+/// Modeled on the `plotters` crate's `DrawingBackend` trait: each primitive
+/// takes resolved geometry (top-left origin, already CTM/scale-transformed)
+/// plus a [`DrawStyle`], and returns a `Result` so a fallible backend (a
+/// file writer, a rasterizer) can propagate an error instead of the caller
+/// assuming every draw call succeeds. It is implemented by [`SvgRenderer`]
+/// and by [`Bitmap`]; the `draw_*` methods on `SvgRenderer` are themselves
+/// built on top of these primitives, so both backends share that overlay
+/// logic through the `draw_*_onto` free functions instead of duplicating it.
+pub trait PageRenderer {
+    /// Error type a draw call can fail with.
+    type Error;
+
+    /// Draw an axis-aligned rectangle spanning `(x0, y0)` to `(x1, y1)`.
+    fn draw_rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, style: &DrawStyle) -> Result<(), Self::Error>;
+
+    /// Draw a straight line segment from `(x0, y0)` to `(x1, y1)`.
+    fn draw_line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, style: &DrawStyle) -> Result<(), Self::Error>;
+
+    /// Draw a circle centered at `(cx, cy)` with the given `radius`.
+    fn draw_circle(&mut self, cx: f64, cy: f64, radius: f64, style: &DrawStyle) -> Result<(), Self::Error>;
+
+    /// Draw `text` at `(x, y)` using `font_family`/`font_size`, rotated
+    /// `rotation_deg` degrees around `(x, y)` (`0.0` for upright text).
+    #[allow(clippy::too_many_arguments)]
+    fn draw_text(
+        &mut self,
+        x: f64,
+        y: f64,
+        text: &str,
+        font_family: &str,
+        font_size: f64,
+        rotation_deg: f64,
+        style: &DrawStyle,
+    ) -> Result<(), Self::Error>;
+
+    /// Draw a single cubic Bezier curve segment from `start` to `end` with
+    /// control points `cp1`/`cp2`.
+    fn draw_curve(
+        &mut self,
+        start: (f64, f64),
+        cp1: (f64, f64),
+        cp2: (f64, f64),
+        end: (f64, f64),
+        style: &DrawStyle,
+    ) -> Result<(), Self::Error>;
+
+    /// Draw a straight-edged path through `points`. If `closed`, the last
+    /// vertex is connected back to the first.
+    fn draw_path(&mut self, points: &[(f64, f64)], closed: bool, style: &DrawStyle) -> Result<(), Self::Error>;
+}
+
+/// Draw character bounding boxes onto any [`PageRenderer`] backend.
+///
+/// If `style.stroke_from_object` is set, each box is outlined with the
+/// char's own `stroking_color` (falling back to `style.stroke` when the
+/// char has none) instead of a single uniform color.
+pub fn draw_chars_onto<R: PageRenderer>(renderer: &mut R, chars: &[Char], style: &DrawStyle) -> Result<(), R::Error> {
+    for ch in chars {
+        let resolved = style.resolve_for(None, ch.stroking_color.as_ref());
+        renderer.draw_rect(ch.bbox.x0, ch.bbox.top, ch.bbox.x1, ch.bbox.bottom, &resolved)?;
+    }
+    Ok(())
+}
+
+/// Draw detected edges onto any [`PageRenderer`] backend.
+pub fn draw_edges_onto<R: PageRenderer>(renderer: &mut R, edges: &[Edge], style: &DrawStyle) -> Result<(), R::Error> {
+    for e in edges {
+        renderer.draw_line(e.x0, e.top, e.x1, e.bottom, style)?;
+    }
+    Ok(())
+}
+
+/// Draw intersection points as small circles onto any [`PageRenderer`] backend.
+pub fn draw_intersections_onto<R: PageRenderer>(
+    renderer: &mut R,
+    intersections: &[Intersection],
+    style: &DrawStyle,
+) -> Result<(), R::Error> {
+    let radius = 3.0;
+    for pt in intersections {
+        renderer.draw_circle(pt.x, pt.y, radius, style)?;
+    }
+    Ok(())
+}
+
+/// Draw cell boundaries as rectangles onto any [`PageRenderer`] backend.
+pub fn draw_cells_onto<R: PageRenderer>(renderer: &mut R, cells: &[Cell], style: &DrawStyle) -> Result<(), R::Error> {
+    for cell in cells {
+        renderer.draw_rect(cell.bbox.x0, cell.bbox.top, cell.bbox.x1, cell.bbox.bottom, style)?;
+    }
+    Ok(())
+}
+
+/// Draw table cell boundaries onto any [`PageRenderer`] backend.
+pub fn draw_tables_onto<R: PageRenderer>(renderer: &mut R, tables: &[Table], style: &DrawStyle) -> Result<(), R::Error> {
+    for table in tables {
+        for cell in &table.cells {
+            renderer.draw_rect(cell.bbox.x0, cell.bbox.top, cell.bbox.x1, cell.bbox.bottom, style)?;
+        }
+    }
+    Ok(())
+}
+
+/// How a stroked line ends. Mirrors SVG's `stroke-linecap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineCap {
+    /// Square off exactly at the endpoint, no extension. SVG default.
+    #[default]
+    Butt,
+    /// Round off with a semicircle of radius `stroke_width / 2`.
+    Round,
+    /// Square off, extended past the endpoint by `stroke_width / 2`.
+    Square,
+}
+
+impl LineCap {
+    fn as_svg_str(self) -> &'static str {
+        match self {
+            LineCap::Butt => "butt",
+            LineCap::Round => "round",
+            LineCap::Square => "square",
+        }
+    }
+}
+
+/// How two stroked segments are joined at a vertex. Mirrors SVG's
+/// `stroke-linejoin`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineJoin {
+    /// Sharp corner, extended to a point. SVG default.
+    #[default]
+    Miter,
+    /// Rounded corner of radius `stroke_width / 2`.
+    Round,
+    /// Corner flattened with a straight line across the join.
+    Bevel,
+}
+
+impl LineJoin {
+    fn as_svg_str(self) -> &'static str {
+        match self {
+            LineJoin::Miter => "miter",
+            LineJoin::Round => "round",
+            LineJoin::Bevel => "bevel",
+        }
+    }
+}
+
 /// Style options for drawing overlays on the SVG page.
 #[derive(Debug, Clone)]
 pub struct DrawStyle {
@@ -22,6 +192,22 @@ pub struct DrawStyle {
     pub stroke_width: f64,
     /// Opacity (0.0 = fully transparent, 1.0 = fully opaque).
     pub opacity: f64,
+    /// When `true`, use the drawn object's own fill color (e.g.
+    /// `Rect.fill_color`) instead of `fill`, falling back to `fill` if the
+    /// object has no fill color of its own.
+    pub fill_from_object: bool,
+    /// When `true`, use the drawn object's own stroke color (e.g.
+    /// `Rect.stroke_color`, `Char.stroking_color`) instead of `stroke`,
+    /// falling back to `stroke` if the object has no stroke color of its
+    /// own.
+    pub stroke_from_object: bool,
+    /// Dash pattern (alternating on/off lengths, in points) for the
+    /// stroke. `None` draws a solid line.
+    pub stroke_dasharray: Option<Vec<f32>>,
+    /// How stroked lines are capped.
+    pub stroke_linecap: LineCap,
+    /// How stroked line segments are joined.
+    pub stroke_linejoin: LineJoin,
 }
 
 impl Default for DrawStyle {
@@ -31,6 +217,11 @@ impl Default for DrawStyle {
             stroke: Some("black".to_string()),
             stroke_width: 0.5,
             opacity: 1.0,
+            fill_from_object: false,
+            stroke_from_object: false,
+            stroke_dasharray: None,
+            stroke_linecap: LineCap::default(),
+            stroke_linejoin: LineJoin::default(),
         }
     }
 }
@@ -43,6 +234,11 @@ impl DrawStyle {
             stroke: Some("blue".to_string()),
             stroke_width: 0.3,
             opacity: 0.7,
+            fill_from_object: false,
+            stroke_from_object: false,
+            stroke_dasharray: None,
+            stroke_linecap: LineCap::default(),
+            stroke_linejoin: LineJoin::default(),
         }
     }
 
@@ -53,6 +249,11 @@ impl DrawStyle {
             stroke: Some("red".to_string()),
             stroke_width: 1.0,
             opacity: 0.8,
+            fill_from_object: false,
+            stroke_from_object: false,
+            stroke_dasharray: None,
+            stroke_linecap: LineCap::default(),
+            stroke_linejoin: LineJoin::default(),
         }
     }
 
@@ -63,6 +264,11 @@ impl DrawStyle {
             stroke: Some("green".to_string()),
             stroke_width: 0.5,
             opacity: 0.8,
+            fill_from_object: false,
+            stroke_from_object: false,
+            stroke_dasharray: None,
+            stroke_linecap: LineCap::default(),
+            stroke_linejoin: LineJoin::default(),
         }
     }
 
@@ -73,6 +279,11 @@ impl DrawStyle {
             stroke: Some("orange".to_string()),
             stroke_width: 0.5,
             opacity: 0.8,
+            fill_from_object: false,
+            stroke_from_object: false,
+            stroke_dasharray: None,
+            stroke_linecap: LineCap::default(),
+            stroke_linejoin: LineJoin::default(),
         }
     }
 
@@ -83,6 +294,11 @@ impl DrawStyle {
             stroke: Some("steelblue".to_string()),
             stroke_width: 0.5,
             opacity: 0.3,
+            fill_from_object: false,
+            stroke_from_object: false,
+            stroke_dasharray: None,
+            stroke_linecap: LineCap::default(),
+            stroke_linejoin: LineJoin::default(),
         }
     }
 
@@ -93,6 +309,11 @@ impl DrawStyle {
             stroke: Some("darkred".to_string()),
             stroke_width: 0.5,
             opacity: 0.9,
+            fill_from_object: false,
+            stroke_from_object: false,
+            stroke_dasharray: None,
+            stroke_linecap: LineCap::default(),
+            stroke_linejoin: LineJoin::default(),
         }
     }
 
@@ -103,6 +324,23 @@ impl DrawStyle {
             stroke: Some("magenta".to_string()),
             stroke_width: 0.5,
             opacity: 0.6,
+            fill_from_object: false,
+            stroke_from_object: false,
+            stroke_dasharray: Some(vec![4.0, 2.0]),
+            stroke_linecap: LineCap::default(),
+            stroke_linejoin: LineJoin::default(),
+        }
+    }
+
+    /// Like [`rects_default`](Self::rects_default), but with
+    /// `fill_from_object`/`stroke_from_object` set so `draw_rects` and
+    /// `draw_chars` render each object's own PDF color instead of a
+    /// uniform green outline.
+    pub fn from_object_colors() -> Self {
+        Self {
+            fill_from_object: true,
+            stroke_from_object: true,
+            ..Self::rects_default()
         }
     }
 
@@ -110,12 +348,22 @@ impl DrawStyle {
     fn to_svg_style(&self) -> String {
         let mut parts = Vec::new();
         match &self.fill {
-            Some(color) => parts.push(format!("fill:{color}")),
+            Some(color) => parts.push(format!("fill:{}", escape_xml(color))),
             None => parts.push("fill:none".to_string()),
         }
         if let Some(color) = &self.stroke {
-            parts.push(format!("stroke:{color}"));
+            parts.push(format!("stroke:{}", escape_xml(color)));
             parts.push(format!("stroke-width:{}", self.stroke_width));
+            if let Some(dasharray) = &self.stroke_dasharray {
+                let dash_str = dasharray.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(",");
+                parts.push(format!("stroke-dasharray:{dash_str}"));
+            }
+            if self.stroke_linecap != LineCap::default() {
+                parts.push(format!("stroke-linecap:{}", self.stroke_linecap.as_svg_str()));
+            }
+            if self.stroke_linejoin != LineJoin::default() {
+                parts.push(format!("stroke-linejoin:{}", self.stroke_linejoin.as_svg_str()));
+            }
         } else {
             parts.push("stroke:none".to_string());
         }
@@ -124,6 +372,72 @@ impl DrawStyle {
         }
         parts.join(";")
     }
+
+    /// Resolve this style for one object, substituting `object_fill`/
+    /// `object_stroke` (the object's own resolved colors, if any) for this
+    /// style's fixed colors wherever the corresponding `*_from_object` flag
+    /// is set.
+    fn resolve_for(&self, object_fill: Option<&Color>, object_stroke: Option<&Color>) -> DrawStyle {
+        let fill = if self.fill_from_object {
+            object_fill.map(color_to_svg_hex).or_else(|| self.fill.clone())
+        } else {
+            self.fill.clone()
+        };
+        let stroke = if self.stroke_from_object {
+            object_stroke.map(color_to_svg_hex).or_else(|| self.stroke.clone())
+        } else {
+            self.stroke.clone()
+        };
+
+        Self {
+            fill,
+            stroke,
+            stroke_width: self.stroke_width,
+            opacity: self.opacity,
+            fill_from_object: false,
+            stroke_from_object: false,
+            stroke_dasharray: self.stroke_dasharray.clone(),
+            stroke_linecap: self.stroke_linecap,
+            stroke_linejoin: self.stroke_linejoin,
+        }
+    }
+}
+
+/// Convert a resolved RGB [`Color`] (components in `0.0..=1.0`) to an SVG
+/// `#RRGGBB` hex color string.
+pub fn color_to_svg_hex(color: &Color) -> String {
+    let channel = |c: f64| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        channel(color.r),
+        channel(color.g),
+        channel(color.b)
+    )
+}
+
+/// Sort `coords` and collapse values within `tolerance` of the previous
+/// kept value into one, used by [`SvgRenderer::draw_table_grid`] to turn
+/// per-cell edge coordinates into a deduplicated set of grid lines.
+///
+/// Uses [`f64::total_cmp`] rather than `partial_cmp(...).unwrap()`: degenerate
+/// upstream geometry (e.g. a zero-area or malformed table cell) can produce a
+/// `NaN` bbox coordinate, and this is a debug-rendering path that should
+/// degrade gracefully (NaNs sort together, by `total_cmp`'s total order)
+/// rather than panic.
+fn unique_sorted_coords(coords: impl Iterator<Item = f64>, tolerance: f64) -> Vec<f64> {
+    let mut sorted: Vec<f64> = coords.collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mut out: Vec<f64> = Vec::new();
+    for c in sorted {
+        let keep = match out.last() {
+            Some(&last) => (c - last).abs() > tolerance,
+            None => true,
+        };
+        if keep {
+            out.push(c);
+        }
+    }
+    out
 }
 
 /// Options for SVG generation.
@@ -192,6 +506,91 @@ pub struct SvgRenderer {
     elements: Vec<String>,
 }
 
+impl PageRenderer for SvgRenderer {
+    /// SVG markup generation never fails; `SvgRenderer` just appends to an
+    /// in-memory `Vec<String>`.
+    type Error = std::convert::Infallible;
+
+    fn draw_rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, style: &DrawStyle) -> Result<(), Self::Error> {
+        let style_attr = style.to_svg_style();
+        self.elements.push(format!(
+            "  <rect x=\"{x0}\" y=\"{y0}\" width=\"{}\" height=\"{}\" style=\"{style_attr}\"/>\n",
+            x1 - x0,
+            y1 - y0,
+        ));
+        Ok(())
+    }
+
+    fn draw_line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, style: &DrawStyle) -> Result<(), Self::Error> {
+        let style_attr = style.to_svg_style();
+        self.elements.push(format!(
+            "  <line x1=\"{x0}\" y1=\"{y0}\" x2=\"{x1}\" y2=\"{y1}\" style=\"{style_attr}\"/>\n",
+        ));
+        Ok(())
+    }
+
+    fn draw_circle(&mut self, cx: f64, cy: f64, radius: f64, style: &DrawStyle) -> Result<(), Self::Error> {
+        let style_attr = style.to_svg_style();
+        self.elements.push(format!(
+            "  <circle cx=\"{cx}\" cy=\"{cy}\" r=\"{radius}\" style=\"{style_attr}\"/>\n",
+        ));
+        Ok(())
+    }
+
+    fn draw_text(
+        &mut self,
+        x: f64,
+        y: f64,
+        text: &str,
+        font_family: &str,
+        font_size: f64,
+        rotation_deg: f64,
+        style: &DrawStyle,
+    ) -> Result<(), Self::Error> {
+        let style_attr = style.to_svg_style();
+        let transform = if rotation_deg == 0.0 {
+            String::new()
+        } else {
+            format!(" transform=\"rotate({rotation_deg} {x} {y})\"")
+        };
+        self.elements.push(format!(
+            "  <text x=\"{x}\" y=\"{y}\" font-family=\"{}\" font-size=\"{font_size}\" style=\"{style_attr}\"{transform}>{}</text>\n",
+            escape_xml(font_family),
+            escape_xml(text),
+        ));
+        Ok(())
+    }
+
+    fn draw_curve(
+        &mut self,
+        start: (f64, f64),
+        cp1: (f64, f64),
+        cp2: (f64, f64),
+        end: (f64, f64),
+        style: &DrawStyle,
+    ) -> Result<(), Self::Error> {
+        let style_attr = style.to_svg_style();
+        self.elements.push(format!(
+            "  <path d=\"M {} {} C {} {} {} {} {} {}\" style=\"{style_attr}\"/>\n",
+            start.0, start.1, cp1.0, cp1.1, cp2.0, cp2.1, end.0, end.1,
+        ));
+        Ok(())
+    }
+
+    fn draw_path(&mut self, points: &[(f64, f64)], closed: bool, style: &DrawStyle) -> Result<(), Self::Error> {
+        let style_attr = style.to_svg_style();
+        let pts = points
+            .iter()
+            .map(|(x, y)| format!("{x},{y}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let tag = if closed { "polygon" } else { "polyline" };
+        self.elements
+            .push(format!("  <{tag} points=\"{pts}\" style=\"{style_attr}\"/>\n"));
+        Ok(())
+    }
+}
+
 impl SvgRenderer {
     /// Create a new `SvgRenderer` for a page with the given dimensions.
     pub fn new(page_width: f64, page_height: f64) -> Self {
@@ -205,140 +604,383 @@ impl SvgRenderer {
     }
 
     /// Draw character bounding boxes onto the SVG.
+    ///
+    /// If `style.stroke_from_object` is set, each box is outlined with the
+    /// char's own `stroking_color` (falling back to `style.stroke` when the
+    /// char has none) instead of a single uniform color.
     pub fn draw_chars(&mut self, chars: &[Char], style: &DrawStyle) {
-        let style_attr = style.to_svg_style();
+        let _: Result<(), std::convert::Infallible> = draw_chars_onto(self, chars, style);
+    }
+
+    /// Draw the actual glyph text of each character onto the SVG, as a
+    /// faithful visual reproduction rather than a bounding-box diagram.
+    ///
+    /// Each `Char` becomes a `<text>` element positioned at `bbox.x0` and
+    /// the bbox's bottom edge (used as the baseline), with `font-family`
+    /// and `font-size` taken from `fontname`/`size`. Non-upright characters
+    /// get a `rotate(angle cx cy)` transform, with the angle derived from
+    /// the char's `ctm` as `atan2(b, a)` in degrees. All text content is
+    /// XML-escaped.
+    pub fn draw_char_text(&mut self, chars: &[Char], style: &DrawStyle) {
         for ch in chars {
-            self.elements.push(format!(
-                "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" style=\"{style_attr}\"/>\n",
+            let rotation_deg = if ch.upright {
+                0.0
+            } else {
+                let [a, b, ..] = ch.ctm;
+                b.atan2(a).to_degrees()
+            };
+            let _: Result<(), std::convert::Infallible> = self.draw_text(
                 ch.bbox.x0,
-                ch.bbox.top,
-                ch.bbox.width(),
-                ch.bbox.height(),
-            ));
+                ch.bbox.bottom,
+                &ch.text,
+                &ch.fontname,
+                ch.size,
+                rotation_deg,
+                style,
+            );
         }
     }
 
     /// Draw rectangle outlines/fills onto the SVG.
+    ///
+    /// If `style.fill_from_object`/`style.stroke_from_object` are set, each
+    /// rect is drawn using its own `fill_color`/`stroke_color` (when it is
+    /// actually filled/stroked) instead of `style`'s fixed colors.
     pub fn draw_rects(&mut self, rects: &[Rect], style: &DrawStyle) {
-        let style_attr = style.to_svg_style();
         for r in rects {
-            self.elements.push(format!(
-                "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" style=\"{style_attr}\"/>\n",
-                r.x0,
-                r.top,
-                r.x1 - r.x0,
-                r.bottom - r.top,
-            ));
+            let fill_color = r.fill.then_some(&r.fill_color);
+            let stroke_color = r.stroke.then_some(&r.stroke_color);
+            let resolved = style.resolve_for(fill_color, stroke_color);
+            let _: Result<(), std::convert::Infallible> =
+                self.draw_rect(r.x0, r.top, r.x1, r.bottom, &resolved);
         }
     }
 
     /// Draw line segments onto the SVG.
+    ///
+    /// If `style.stroke_from_object` is set, each line is drawn using its
+    /// own `stroke_color` instead of `style.stroke`.
     pub fn draw_lines(&mut self, lines: &[Line], style: &DrawStyle) {
-        let style_attr = style.to_svg_style();
         for l in lines {
-            self.elements.push(format!(
-                "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" style=\"{style_attr}\"/>\n",
-                l.x0, l.top, l.x1, l.bottom,
-            ));
+            let resolved = style.resolve_for(None, Some(&l.stroke_color));
+            let _: Result<(), std::convert::Infallible> =
+                self.draw_line(l.x0, l.top, l.x1, l.bottom, &resolved);
+        }
+    }
+
+    /// Draw extracted Bezier curves onto the SVG.
+    ///
+    /// Each `Curve` is a single cubic Bezier segment (`pts` holds
+    /// `[start, cp1, cp2, end]` in top-left origin); it is emitted as a
+    /// `<path>` with an `M`/`C` command pair. If `style.fill_from_object`/
+    /// `style.stroke_from_object` are set, each curve is drawn using its
+    /// own `fill_color`/`stroke_color` (when actually filled/stroked).
+    pub fn draw_curves(&mut self, curves: &[Curve], style: &DrawStyle) {
+        for c in curves {
+            let (start, cp1, cp2, end) = match c.pts.as_slice() {
+                [start, cp1, cp2, end] => (*start, *cp1, *cp2, *end),
+                _ => continue,
+            };
+            let fill_color = c.fill.then_some(&c.fill_color);
+            let stroke_color = c.stroke.then_some(&c.stroke_color);
+            let resolved = style.resolve_for(fill_color, stroke_color);
+            let _: Result<(), std::convert::Infallible> =
+                self.draw_curve(start, cp1, cp2, end, &resolved);
+        }
+    }
+
+    /// Draw closed straight-edged multi-point shapes as `<polygon>` elements.
+    ///
+    /// Each entry in `polygons` is a sequence of `(x, y)` vertices in
+    /// top-left origin; the SVG renderer connects the last vertex back to
+    /// the first.
+    pub fn draw_polygons(&mut self, polygons: &[Vec<(f64, f64)>], style: &DrawStyle) {
+        for poly in polygons {
+            let _: Result<(), std::convert::Infallible> = self.draw_path(poly, true, style);
+        }
+    }
+
+    /// Draw open straight-edged multi-point paths as `<polyline>` elements.
+    ///
+    /// Each entry in `polylines` is a sequence of `(x, y)` vertices in
+    /// top-left origin; unlike [`draw_polygons`](Self::draw_polygons), the
+    /// last vertex is not connected back to the first.
+    pub fn draw_polylines(&mut self, polylines: &[Vec<(f64, f64)>], style: &DrawStyle) {
+        for line in polylines {
+            let _: Result<(), std::convert::Infallible> = self.draw_path(line, false, style);
         }
     }
 
     /// Draw detected edges onto the SVG.
     pub fn draw_edges(&mut self, edges: &[Edge], style: &DrawStyle) {
-        let style_attr = style.to_svg_style();
-        for e in edges {
-            self.elements.push(format!(
-                "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" style=\"{style_attr}\"/>\n",
-                e.x0, e.top, e.x1, e.bottom,
-            ));
-        }
+        let _: Result<(), std::convert::Infallible> = draw_edges_onto(self, edges, style);
     }
 
     /// Draw intersection points as small circles onto the SVG.
     pub fn draw_intersections(&mut self, intersections: &[Intersection], style: &DrawStyle) {
-        let style_attr = style.to_svg_style();
-        let radius = 3.0;
-        for pt in intersections {
-            self.elements.push(format!(
-                "  <circle cx=\"{}\" cy=\"{}\" r=\"{radius}\" style=\"{style_attr}\"/>\n",
-                pt.x, pt.y,
-            ));
-        }
+        let _: Result<(), std::convert::Infallible> = draw_intersections_onto(self, intersections, style);
     }
 
-    /// Draw cell boundaries as dashed rectangles onto the SVG.
+    /// Draw cell boundaries as rectangles onto the SVG. Dashed by default
+    /// via [`DrawStyle::cells_default`]'s `stroke_dasharray`.
     pub fn draw_cells(&mut self, cells: &[Cell], style: &DrawStyle) {
-        let style_attr = style.to_svg_style();
-        for cell in cells {
-            self.elements.push(format!(
-                "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" style=\"{style_attr}\" stroke-dasharray=\"4,2\"/>\n",
-                cell.bbox.x0,
-                cell.bbox.top,
-                cell.bbox.width(),
-                cell.bbox.height(),
-            ));
-        }
+        let _: Result<(), std::convert::Infallible> = draw_cells_onto(self, cells, style);
     }
 
     /// Draw table cell boundaries onto the SVG.
     pub fn draw_tables(&mut self, tables: &[Table], style: &DrawStyle) {
-        let style_attr = style.to_svg_style();
-        for table in tables {
-            // Draw each cell
-            for cell in &table.cells {
-                self.elements.push(format!(
-                    "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" style=\"{style_attr}\"/>\n",
-                    cell.bbox.x0,
-                    cell.bbox.top,
-                    cell.bbox.width(),
-                    cell.bbox.height(),
-                ));
+        let _: Result<(), std::convert::Infallible> = draw_tables_onto(self, tables, style);
+    }
+
+    /// Draw a faithful row/column grid for `table`, instead of
+    /// [`draw_tables`]'s one rect per cell.
+    ///
+    /// Reconstructs the grid from the distinct x-edges (`bbox.x0`/`bbox.x1`)
+    /// and y-edges (`bbox.top`/`bbox.bottom`) across all of `table.cells`,
+    /// draws a separator line for each, and marks every (x, y) crossing
+    /// with a small corner marker. A cell whose bbox spans more than one
+    /// grid interval on an axis is a merged/spanning cell; the separator
+    /// segments that would otherwise fall strictly inside such a cell are
+    /// suppressed so the overlay reflects the actual spans instead of
+    /// drawing separators the detected table doesn't have.
+    pub fn draw_table_grid(&mut self, table: &Table, style: &DrawStyle) {
+        const TOLERANCE: f64 = 0.5;
+        let xs = unique_sorted_coords(table.cells.iter().flat_map(|c| [c.bbox.x0, c.bbox.x1]), TOLERANCE);
+        let ys = unique_sorted_coords(table.cells.iter().flat_map(|c| [c.bbox.top, c.bbox.bottom]), TOLERANCE);
+
+        for &x in &xs {
+            for w in ys.windows(2) {
+                let (y0, y1) = (w[0], w[1]);
+                let spanned = table.cells.iter().any(|c| {
+                    c.bbox.x0 < x - TOLERANCE
+                        && c.bbox.x1 > x + TOLERANCE
+                        && c.bbox.top <= y0 + TOLERANCE
+                        && c.bbox.bottom >= y1 - TOLERANCE
+                });
+                if !spanned {
+                    let _: Result<(), std::convert::Infallible> = self.draw_line(x, y0, x, y1, style);
+                }
+            }
+        }
+
+        for &y in &ys {
+            for w in xs.windows(2) {
+                let (x0, x1) = (w[0], w[1]);
+                let spanned = table.cells.iter().any(|c| {
+                    c.bbox.top < y - TOLERANCE
+                        && c.bbox.bottom > y + TOLERANCE
+                        && c.bbox.x0 <= x0 + TOLERANCE
+                        && c.bbox.x1 >= x1 - TOLERANCE
+                });
+                if !spanned {
+                    let _: Result<(), std::convert::Infallible> = self.draw_line(x0, y, x1, y, style);
+                }
+            }
+        }
+
+        let corner_radius = 1.5;
+        for &x in &xs {
+            for &y in &ys {
+                let _: Result<(), std::convert::Infallible> = self.draw_circle(x, y, corner_radius, style);
             }
         }
     }
 
-    /// Generate SVG markup for the page.
+    /// Write SVG markup for the page directly to `w`, without building an
+    /// intermediate `String` of the full document the way [`to_svg`] does.
     ///
     /// The output is a complete, valid SVG 1.1 document including:
     /// - Proper `viewBox` matching page dimensions
     /// - Page boundary rectangle
     /// - All overlay elements added via `draw_*` methods
     /// - SVG coordinate system matching top-left origin
-    pub fn to_svg(&self, options: &SvgOptions) -> String {
+    pub fn to_writer<W: Write>(&self, w: &mut W, options: &SvgOptions) -> io::Result<()> {
         let view_width = self.page_width;
         let view_height = self.page_height;
 
         let svg_width = options.width.unwrap_or(self.page_width * options.scale);
         let svg_height = options.height.unwrap_or(self.page_height * options.scale);
 
-        let mut svg = String::new();
-
-        // SVG header
-        svg.push_str(&format!(
+        write!(
+            w,
             "<svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" \
              width=\"{svg_width}\" height=\"{svg_height}\" \
              viewBox=\"0 0 {view_width} {view_height}\">\n"
-        ));
+        )?;
 
-        // Page boundary rectangle
-        svg.push_str(&format!(
+        write!(
+            w,
             "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
              fill=\"white\" stroke=\"black\" stroke-width=\"0.5\"/>\n",
             self.page_bbox.x0,
             self.page_bbox.top,
             self.page_bbox.width(),
             self.page_bbox.height(),
-        ));
+        )?;
+
+        for element in &self.elements {
+            w.write_all(element.as_bytes())?;
+        }
+
+        w.write_all(b"</svg>\n")
+    }
+
+    /// Write SVG markup for the page to a file at `path`, creating it if
+    /// needed or truncating it if it already exists. A convenience wrapper
+    /// around [`to_writer`] using a buffered file writer.
+    pub fn write_to_file<P: AsRef<std::path::Path>>(
+        &self,
+        path: P,
+        options: &SvgOptions,
+    ) -> io::Result<()> {
+        let file = std::fs::File::create(path)?;
+        let mut writer = io::BufWriter::new(file);
+        self.to_writer(&mut writer, options)?;
+        writer.flush()
+    }
+
+    /// Generate SVG markup for the page as a `String`.
+    ///
+    /// An eager convenience wrapper around [`to_writer`] for callers that
+    /// want the whole document in memory; prefer `to_writer`/`write_to_file`
+    /// for pages with many overlay elements.
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        let mut buf = Vec::new();
+        self.to_writer(&mut buf, options)
+            .expect("writing SVG to an in-memory buffer is infallible");
+        String::from_utf8(buf).expect("SVG output is valid UTF-8")
+    }
+
+    /// Rasterize the page boundary and buffered overlay elements to
+    /// PNG-encoded bytes, at `options.width`/`options.height`/`options.scale`
+    /// resolution (same sizing rules as [`to_writer`](Self::to_writer)).
+    ///
+    /// This paints onto a [`Bitmap`] by re-parsing the `<rect>` and `<line>`
+    /// elements this renderer already generated, rather than depending on an
+    /// external SVG rasterizer. Elements added by
+    /// [`draw_char_text`](Self::draw_char_text), [`draw_curves`](Self::draw_curves),
+    /// [`draw_polygons`](Self::draw_polygons), [`draw_polylines`](Self::draw_polylines),
+    /// and [`draw_intersections`](Self::draw_intersections) (`<text>`, `<path>`,
+    /// `<polygon>`, `<polyline>`, `<circle>`) are skipped: only `<rect>` and
+    /// `<line>` markup is rasterized here.
+    ///
+    /// This coexists with `Bitmap`'s own [`PageRenderer`] implementation: that
+    /// one paints fresh from typed objects (`Char`, `Edge`, `Table`, ...) via
+    /// the `draw_*_onto` free functions, for callers building a raster debug
+    /// view from scratch; this one rasterizes an `SvgRenderer` that already
+    /// has SVG markup buffered, without needing to re-run the overlay logic
+    /// against a second backend.
+    pub fn to_png(&self, options: &SvgOptions) -> Vec<u8> {
+        let svg_width = options.width.unwrap_or(self.page_width * options.scale);
+        let svg_height = options.height.unwrap_or(self.page_height * options.scale);
+        let scale = svg_width / self.page_width;
+
+        let mut bitmap = Bitmap::new(svg_width.round() as u32, svg_height.round() as u32);
+        bitmap.stroke_rect(
+            self.page_bbox.x0 * scale,
+            self.page_bbox.top * scale,
+            self.page_bbox.x1 * scale,
+            self.page_bbox.bottom * scale,
+            0.5 * scale,
+            Color::black(),
+        );
 
-        // Overlay elements
         for element in &self.elements {
-            svg.push_str(element);
+            rasterize_element(&mut bitmap, element, scale);
         }
 
-        // Close SVG
-        svg.push_str("</svg>\n");
+        bitmap.encode_png()
+    }
+}
+
+/// Extract the `f64` value of attribute `name="..."` from an SVG tag string.
+fn svg_attr_f64(elem: &str, name: &str) -> Option<f64> {
+    let pat = format!("{name}=\"");
+    let start = elem.find(&pat)? + pat.len();
+    let rest = &elem[start..];
+    let end = rest.find('"')?;
+    rest[..end].parse().ok()
+}
 
-        svg
+/// Extract the value of `key` from an SVG tag's `style="..."` attribute
+/// (as built by [`DrawStyle::to_svg_style`]).
+fn svg_style_value<'a>(elem: &'a str, key: &str) -> Option<&'a str> {
+    let style_start = elem.find("style=\"")? + "style=\"".len();
+    let style_end = style_start + elem[style_start..].find('"')?;
+    let style = &elem[style_start..style_end];
+    let pat = format!("{key}:");
+    style.split(';').find_map(|part| part.strip_prefix(&pat))
+}
+
+/// Resolve an SVG color string (as emitted by `DrawStyle::to_svg_style`,
+/// i.e. `#rrggbb` or one of the named colors this module's `*_default()`
+/// constructors use) to a [`Color`]. Returns `None` for `"none"` or any
+/// color this module doesn't itself emit.
+///
+/// `pub(crate)` so [`crate::raster`]'s `PageRenderer` implementation can
+/// resolve the same `DrawStyle` color strings without duplicating this table.
+pub(crate) fn parse_svg_color(s: &str) -> Option<Color> {
+    if let Some(hex) = s.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some(Color::new(
+            channel(0)? as f64 / 255.0,
+            channel(2)? as f64 / 255.0,
+            channel(4)? as f64 / 255.0,
+        ));
+    }
+    match s {
+        "black" => Some(Color::black()),
+        "white" => Some(Color::new(1.0, 1.0, 1.0)),
+        "red" => Some(Color::new(1.0, 0.0, 0.0)),
+        "green" => Some(Color::new(0.0, 0.502, 0.0)),
+        "blue" => Some(Color::new(0.0, 0.0, 1.0)),
+        "orange" => Some(Color::new(1.0, 0.647, 0.0)),
+        "magenta" => Some(Color::new(1.0, 0.0, 1.0)),
+        "steelblue" => Some(Color::new(0.275, 0.510, 0.706)),
+        "lightblue" => Some(Color::new(0.678, 0.847, 0.902)),
+        "darkred" => Some(Color::new(0.545, 0.0, 0.0)),
+        "yellow" => Some(Color::new(1.0, 1.0, 0.0)),
+        "purple" => Some(Color::new(0.502, 0.0, 0.502)),
+        _ => None,
+    }
+}
+
+/// Paint one `<rect>` or `<line>` element onto `bitmap`, scaled by `scale`.
+/// Any other element (`<text>`, `<path>`, `<polygon>`, `<polyline>`,
+/// `<circle>`) is left unrasterized.
+fn rasterize_element(bitmap: &mut Bitmap, elem: &str, scale: f64) {
+    let trimmed = elem.trim_start();
+    if trimmed.starts_with("<rect") {
+        let x = svg_attr_f64(trimmed, "x").unwrap_or(0.0);
+        let y = svg_attr_f64(trimmed, "y").unwrap_or(0.0);
+        let width = svg_attr_f64(trimmed, "width").unwrap_or(0.0);
+        let height = svg_attr_f64(trimmed, "height").unwrap_or(0.0);
+        let (x0, y0, x1, y1) = (x * scale, y * scale, (x + width) * scale, (y + height) * scale);
+
+        if let Some(fill) = svg_style_value(trimmed, "fill").and_then(parse_svg_color) {
+            bitmap.fill_rect(x0, y0, x1, y1, fill);
+        }
+        if let Some(stroke) = svg_style_value(trimmed, "stroke").and_then(parse_svg_color) {
+            let stroke_width = svg_style_value(trimmed, "stroke-width")
+                .and_then(|w| w.parse().ok())
+                .unwrap_or(1.0);
+            bitmap.stroke_rect(x0, y0, x1, y1, stroke_width * scale, stroke);
+        }
+    } else if trimmed.starts_with("<line") {
+        let x1v = svg_attr_f64(trimmed, "x1").unwrap_or(0.0);
+        let y1v = svg_attr_f64(trimmed, "y1").unwrap_or(0.0);
+        let x2v = svg_attr_f64(trimmed, "x2").unwrap_or(0.0);
+        let y2v = svg_attr_f64(trimmed, "y2").unwrap_or(0.0);
+
+        if let Some(stroke) = svg_style_value(trimmed, "stroke").and_then(parse_svg_color) {
+            let stroke_width = svg_style_value(trimmed, "stroke-width")
+                .and_then(|w| w.parse().ok())
+                .unwrap_or(1.0);
+            bitmap.stroke_line(x1v * scale, y1v * scale, x2v * scale, y2v * scale, stroke_width * scale, stroke);
+        }
     }
 }
 
@@ -515,6 +1157,11 @@ mod tests {
             stroke: Some("blue".to_string()),
             stroke_width: 2.0,
             opacity: 0.5,
+            fill_from_object: false,
+            stroke_from_object: false,
+            stroke_dasharray: None,
+            stroke_linecap: LineCap::default(),
+            stroke_linejoin: LineJoin::default(),
         };
         let s = style.to_svg_style();
         assert!(s.contains("fill:red"));
@@ -530,6 +1177,11 @@ mod tests {
             stroke: Some("black".to_string()),
             stroke_width: 1.0,
             opacity: 1.0,
+            fill_from_object: false,
+            stroke_from_object: false,
+            stroke_dasharray: None,
+            stroke_linecap: LineCap::default(),
+            stroke_linejoin: LineJoin::default(),
         };
         let s = style.to_svg_style();
         assert!(s.contains("fill:none"));
@@ -588,6 +1240,107 @@ mod tests {
         assert!(svg.contains("height=\"15\"")); // 35 - 20
     }
 
+    // --- chunk109-1 tests: draw_char_text ---
+
+    #[test]
+    fn test_draw_char_text_adds_text_elements() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let chars = vec![
+            make_char("A", 10.0, 20.0, 18.0, 32.0),
+            make_char("B", 20.0, 20.0, 28.0, 32.0),
+        ];
+        renderer.draw_char_text(&chars, &DrawStyle::chars_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert_eq!(svg.matches("<text").count(), 2);
+        assert!(svg.contains(">A</text>"));
+        assert!(svg.contains(">B</text>"));
+    }
+
+    #[test]
+    fn test_draw_char_text_sets_font_attributes() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let chars = vec![make_char("X", 10.0, 20.0, 25.0, 35.0)];
+        renderer.draw_char_text(&chars, &DrawStyle::chars_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert!(svg.contains("font-family=\"Helvetica\""));
+        assert!(svg.contains("font-size=\"12\""));
+        assert!(svg.contains("x=\"10\""));
+        assert!(svg.contains("y=\"35\"")); // baseline at bbox bottom
+    }
+
+    #[test]
+    fn test_draw_char_text_escapes_special_characters() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let chars = vec![make_char("<A & B>", 10.0, 20.0, 25.0, 35.0)];
+        renderer.draw_char_text(&chars, &DrawStyle::chars_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert!(svg.contains("&lt;A &amp; B&gt;"));
+        assert!(!svg.contains("<A & B>"));
+    }
+
+    #[test]
+    fn test_draw_char_text_upright_has_no_rotation() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let chars = vec![make_char("A", 10.0, 20.0, 18.0, 32.0)];
+        renderer.draw_char_text(&chars, &DrawStyle::chars_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert!(!svg.contains("rotate"));
+    }
+
+    #[test]
+    fn test_draw_char_text_rotated_char_gets_transform() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let mut ch = make_char("A", 10.0, 20.0, 18.0, 32.0);
+        ch.upright = false;
+        // 90-degree rotation: a=0, b=1
+        ch.ctm = [0.0, 1.0, -1.0, 0.0, 0.0, 0.0];
+        renderer.draw_char_text(&[ch], &DrawStyle::chars_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert!(svg.contains("transform=\"rotate(90"));
+    }
+
+    #[test]
+    fn test_draw_char_text_empty_slice() {
+        let mut renderer = SvgRenderer::new(100.0, 100.0);
+        renderer.draw_char_text(&[], &DrawStyle::chars_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert_eq!(svg.matches("<text").count(), 0);
+    }
+
+    // --- chunk109-2 tests: to_writer / write_to_file ---
+
+    #[test]
+    fn test_to_writer_matches_to_svg() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let chars = vec![make_char("A", 10.0, 20.0, 18.0, 32.0)];
+        renderer.draw_chars(&chars, &DrawStyle::chars_default());
+
+        let mut buf = Vec::new();
+        renderer.to_writer(&mut buf, &SvgOptions::default()).unwrap();
+        let written = String::from_utf8(buf).unwrap();
+
+        assert_eq!(written, renderer.to_svg(&SvgOptions::default()));
+    }
+
+    #[test]
+    fn test_write_to_file_roundtrip() {
+        let renderer = SvgRenderer::new(100.0, 100.0);
+        let path = std::env::temp_dir().join("pdfplumber_rs_test_svg_write_to_file.svg");
+
+        renderer.write_to_file(&path, &SvgOptions::default()).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+
+        assert!(contents.starts_with("<svg"));
+        assert!(contents.trim_end().ends_with("</svg>"));
+        let _ = std::fs::remove_file(&path);
+    }
+
     // --- US-068 tests: draw_rects ---
 
     fn make_rect(x0: f64, top: f64, x1: f64, bottom: f64) -> Rect {
@@ -658,6 +1411,341 @@ mod tests {
         assert!(svg.contains("y2=\"50\""));
     }
 
+    // --- chunk109-3 tests: per-object colors ---
+
+    #[test]
+    fn test_color_to_svg_hex_basic() {
+        assert_eq!(color_to_svg_hex(&Color::new(1.0, 0.0, 0.0)), "#ff0000");
+        assert_eq!(color_to_svg_hex(&Color::new(0.0, 1.0, 0.0)), "#00ff00");
+        assert_eq!(color_to_svg_hex(&Color::new(0.0, 0.0, 1.0)), "#0000ff");
+        assert_eq!(color_to_svg_hex(&Color::black()), "#000000");
+    }
+
+    #[test]
+    fn test_color_to_svg_hex_clamps_out_of_range() {
+        assert_eq!(color_to_svg_hex(&Color::new(-1.0, 2.0, 0.5)), "#00ff80");
+    }
+
+    #[test]
+    fn test_draw_rects_from_object_colors() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let mut r = make_rect(50.0, 50.0, 150.0, 100.0);
+        r.fill = true;
+        r.fill_color = Color::new(1.0, 0.0, 0.0);
+        r.stroke_color = Color::new(0.0, 0.0, 1.0);
+        renderer.draw_rects(&[r], &DrawStyle::from_object_colors());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert!(svg.contains("fill:#ff0000"));
+        assert!(svg.contains("stroke:#0000ff"));
+    }
+
+    #[test]
+    fn test_draw_rects_from_object_colors_falls_back_when_not_stroked() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let mut r = make_rect(50.0, 50.0, 150.0, 100.0);
+        r.stroke = false;
+        let style = DrawStyle::from_object_colors();
+        let fallback_stroke = style.stroke.clone();
+        renderer.draw_rects(&[r], &style);
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert!(svg.contains(&format!("stroke:{}", fallback_stroke.unwrap())));
+    }
+
+    #[test]
+    fn test_draw_rects_without_from_object_colors_uses_style() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let mut r = make_rect(50.0, 50.0, 150.0, 100.0);
+        r.fill = true;
+        r.fill_color = Color::new(1.0, 0.0, 0.0);
+        renderer.draw_rects(&[r], &DrawStyle::rects_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert!(!svg.contains("#ff0000"));
+        assert!(svg.contains("stroke:green"));
+    }
+
+    #[test]
+    fn test_draw_lines_from_object_colors() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let mut l = make_line(10.0, 50.0, 190.0, 50.0);
+        l.stroke_color = Color::new(0.0, 1.0, 0.0);
+        let style = DrawStyle {
+            stroke_from_object: true,
+            ..DrawStyle::lines_default()
+        };
+        renderer.draw_lines(&[l], &style);
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert!(svg.contains("stroke:#00ff00"));
+    }
+
+    #[test]
+    fn test_draw_chars_from_object_colors() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let mut ch = make_char("A", 5.0, 5.0, 15.0, 17.0);
+        ch.stroking_color = Some(Color::new(1.0, 1.0, 0.0));
+        let style = DrawStyle {
+            stroke_from_object: true,
+            ..DrawStyle::chars_default()
+        };
+        renderer.draw_chars(&[ch], &style);
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert!(svg.contains("stroke:#ffff00"));
+    }
+
+    #[test]
+    fn test_draw_chars_from_object_colors_falls_back_without_stroking_color() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let ch = make_char("A", 5.0, 5.0, 15.0, 17.0);
+        let style = DrawStyle {
+            stroke_from_object: true,
+            ..DrawStyle::chars_default()
+        };
+        renderer.draw_chars(&[ch], &style);
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert!(svg.contains("stroke:blue"));
+    }
+
+    // --- chunk109-4 tests: draw_curves / draw_polygons / draw_polylines ---
+
+    fn make_curve(start: (f64, f64), cp1: (f64, f64), cp2: (f64, f64), end: (f64, f64)) -> Curve {
+        let xs = [start.0, cp1.0, cp2.0, end.0];
+        let ys = [start.1, cp1.1, cp2.1, end.1];
+        Curve {
+            x0: xs.iter().cloned().fold(f64::INFINITY, f64::min),
+            top: ys.iter().cloned().fold(f64::INFINITY, f64::min),
+            x1: xs.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            bottom: ys.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            pts: vec![start, cp1, cp2, end],
+            line_width: 1.0,
+            stroke: true,
+            fill: false,
+            stroke_color: Color::new(0.0, 0.0, 0.0),
+            fill_color: Color::new(1.0, 1.0, 1.0),
+        }
+    }
+
+    #[test]
+    fn test_draw_curves_adds_path_elements() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let curves = vec![make_curve(
+            (10.0, 10.0),
+            (20.0, 0.0),
+            (30.0, 20.0),
+            (40.0, 10.0),
+        )];
+        renderer.draw_curves(&curves, &DrawStyle::lines_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert_eq!(svg.matches("<path").count(), 1);
+        assert!(svg.contains("d=\"M 10 10 C 20 0 30 20 40 10\""));
+    }
+
+    #[test]
+    fn test_draw_curves_from_object_colors() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let mut c = make_curve((10.0, 10.0), (20.0, 0.0), (30.0, 20.0), (40.0, 10.0));
+        c.stroke_color = Color::new(1.0, 0.0, 0.0);
+        let style = DrawStyle {
+            stroke_from_object: true,
+            ..DrawStyle::lines_default()
+        };
+        renderer.draw_curves(&[c], &style);
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert!(svg.contains("stroke:#ff0000"));
+    }
+
+    #[test]
+    fn test_draw_polygons_adds_polygon_elements() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let polygons = vec![vec![(10.0, 10.0), (50.0, 10.0), (30.0, 40.0)]];
+        renderer.draw_polygons(&polygons, &DrawStyle::rects_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert_eq!(svg.matches("<polygon").count(), 1);
+        assert!(svg.contains("points=\"10,10 50,10 30,40\""));
+    }
+
+    #[test]
+    fn test_draw_polylines_adds_polyline_elements() {
+        let mut renderer = SvgRenderer::new(200.0, 200.0);
+        let polylines = vec![vec![(10.0, 10.0), (50.0, 10.0), (30.0, 40.0)]];
+        renderer.draw_polylines(&polylines, &DrawStyle::lines_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert_eq!(svg.matches("<polyline").count(), 1);
+        assert!(svg.contains("points=\"10,10 50,10 30,40\""));
+    }
+
+    // --- chunk109-5 tests: to_png ---
+
+    #[test]
+    fn test_to_png_writes_valid_header() {
+        let renderer = SvgRenderer::new(100.0, 100.0);
+        let png = renderer.to_png(&SvgOptions::default());
+        assert_eq!(&png[0..8], &[137, 80, 78, 71, 13, 10, 26, 10]);
+    }
+
+    #[test]
+    fn test_to_png_size_matches_scale() {
+        let renderer = SvgRenderer::new(100.0, 50.0);
+        let png = renderer.to_png(&SvgOptions {
+            scale: 2.0,
+            ..SvgOptions::default()
+        });
+
+        // IHDR width/height are the big-endian u32s at bytes 16..24.
+        let width = u32::from_be_bytes(png[16..20].try_into().unwrap());
+        let height = u32::from_be_bytes(png[20..24].try_into().unwrap());
+        assert_eq!(width, 200);
+        assert_eq!(height, 100);
+    }
+
+    #[test]
+    fn test_to_png_paints_filled_rect() {
+        let mut renderer = SvgRenderer::new(20.0, 20.0);
+        let mut r = make_rect(5.0, 5.0, 15.0, 15.0);
+        r.fill = true;
+        r.fill_color = Color::new(1.0, 0.0, 0.0);
+        renderer.draw_rects(&[r], &DrawStyle::from_object_colors());
+
+        let png = renderer.to_png(&SvgOptions::default());
+        assert_eq!(&png[0..8], &[137, 80, 78, 71, 13, 10, 26, 10]);
+    }
+
+    #[test]
+    fn test_parse_svg_color_hex_and_named() {
+        assert_eq!(parse_svg_color("#ff0000"), Some(Color::new(1.0, 0.0, 0.0)));
+        assert_eq!(parse_svg_color("black"), Some(Color::black()));
+        assert_eq!(parse_svg_color("none"), None);
+    }
+
+    #[test]
+    fn test_svg_attr_f64_and_style_value() {
+        let elem = "<rect x=\"1.5\" y=\"2\" width=\"3\" height=\"4\" style=\"fill:#ff0000;stroke:none\"/>";
+        assert_eq!(svg_attr_f64(elem, "x"), Some(1.5));
+        assert_eq!(svg_attr_f64(elem, "width"), Some(3.0));
+        assert_eq!(svg_style_value(elem, "fill"), Some("#ff0000"));
+        assert_eq!(svg_style_value(elem, "stroke"), Some("none"));
+    }
+
+    // --- chunk109-6 tests: PageRenderer trait ---
+
+    /// Exercises a renderer purely through the `PageRenderer` trait, showing
+    /// that callers needing backend-agnostic overlay code don't need to name
+    /// `SvgRenderer` directly.
+    fn paint_onto<R: PageRenderer>(r: &mut R, style: &DrawStyle) -> Result<(), R::Error> {
+        r.draw_rect(0.0, 0.0, 10.0, 10.0, style)?;
+        r.draw_line(0.0, 0.0, 10.0, 10.0, style)?;
+        r.draw_circle(5.0, 5.0, 2.0, style)?;
+        r.draw_text(1.0, 1.0, "hi", "Helvetica", 12.0, 0.0, style)?;
+        r.draw_curve((0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0), style)?;
+        r.draw_path(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)], true, style)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_page_renderer_trait_generic_over_svg_renderer() {
+        let mut renderer = SvgRenderer::new(100.0, 100.0);
+        paint_onto(&mut renderer, &DrawStyle::rects_default()).unwrap();
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        assert!(svg.contains("<rect"));
+        assert!(svg.contains("<line"));
+        assert!(svg.contains("<circle"));
+        assert!(svg.contains("<text"));
+        assert!(svg.contains("<path"));
+        assert!(svg.contains("<polygon"));
+    }
+
+    #[test]
+    fn test_page_renderer_draw_rect_matches_inherent_method() {
+        let mut via_trait = SvgRenderer::new(50.0, 50.0);
+        PageRenderer::draw_rect(&mut via_trait, 1.0, 2.0, 3.0, 4.0, &DrawStyle::rects_default()).unwrap();
+
+        let mut via_inherent = SvgRenderer::new(50.0, 50.0);
+        via_inherent.draw_rects(&[make_rect(1.0, 2.0, 3.0, 4.0)], &DrawStyle::rects_default());
+
+        assert_eq!(
+            via_trait.to_svg(&SvgOptions::default()),
+            via_inherent.to_svg(&SvgOptions::default())
+        );
+    }
+
+    #[test]
+    fn test_page_renderer_draw_path_open_emits_polyline() {
+        let mut renderer = SvgRenderer::new(50.0, 50.0);
+        renderer
+            .draw_path(&[(0.0, 0.0), (1.0, 1.0)], false, &DrawStyle::lines_default())
+            .unwrap();
+        let svg = renderer.to_svg(&SvgOptions::default());
+        assert!(svg.contains("<polyline"));
+        assert!(!svg.contains("<polygon"));
+    }
+
+    // --- chunk110-1 tests: dasharray / linecap / linejoin ---
+
+    #[test]
+    fn test_to_svg_style_omits_dash_cap_join_by_default() {
+        let style = DrawStyle::lines_default();
+        let s = style.to_svg_style();
+        assert!(!s.contains("stroke-dasharray"));
+        assert!(!s.contains("stroke-linecap"));
+        assert!(!s.contains("stroke-linejoin"));
+    }
+
+    #[test]
+    fn test_to_svg_style_includes_dasharray() {
+        let style = DrawStyle {
+            stroke_dasharray: Some(vec![6.0, 3.0]),
+            ..DrawStyle::lines_default()
+        };
+        assert!(style.to_svg_style().contains("stroke-dasharray:6,3"));
+    }
+
+    #[test]
+    fn test_to_svg_style_includes_linecap_and_linejoin() {
+        let style = DrawStyle {
+            stroke_linecap: LineCap::Round,
+            stroke_linejoin: LineJoin::Bevel,
+            ..DrawStyle::lines_default()
+        };
+        let s = style.to_svg_style();
+        assert!(s.contains("stroke-linecap:round"));
+        assert!(s.contains("stroke-linejoin:bevel"));
+    }
+
+    #[test]
+    fn test_cells_default_carries_dasharray_through_draw_cells() {
+        let mut renderer = SvgRenderer::new(100.0, 100.0);
+        let cells = vec![Cell {
+            bbox: BBox::new(10.0, 10.0, 50.0, 30.0),
+            text: None,
+        }];
+        renderer.draw_cells(&cells, &DrawStyle::cells_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+        assert!(svg.contains("stroke-dasharray:4,2"));
+    }
+
+    #[test]
+    fn test_resolve_for_preserves_dash_cap_join() {
+        let style = DrawStyle {
+            stroke_dasharray: Some(vec![1.0, 1.0]),
+            stroke_linecap: LineCap::Square,
+            stroke_linejoin: LineJoin::Round,
+            ..DrawStyle::rects_default()
+        };
+        let resolved = style.resolve_for(None, None);
+        assert_eq!(resolved.stroke_dasharray, Some(vec![1.0, 1.0]));
+        assert_eq!(resolved.stroke_linecap, LineCap::Square);
+        assert_eq!(resolved.stroke_linejoin, LineJoin::Round);
+    }
+
     // --- US-068 tests: draw_edges ---
 
     fn make_edge(x0: f64, top: f64, x1: f64, bottom: f64) -> Edge {
@@ -724,6 +1812,81 @@ mod tests {
         assert!(svg.contains("fill:lightblue"));
     }
 
+    // --- chunk110-3 tests: draw_table_grid ---
+
+    #[test]
+    fn test_draw_table_grid_draws_separators_and_corners() {
+        let mut renderer = SvgRenderer::new(300.0, 200.0);
+        renderer.draw_table_grid(&make_table(), &DrawStyle::cells_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        // A 2x2 grid has 3 distinct x-lines and 3 distinct y-lines; each
+        // is split into 2 segments by the 2 intervals on the other axis,
+        // and none are suppressed (no cell spans more than one interval):
+        // 3*2 vertical + 3*2 horizontal = 12 <line>s.
+        assert_eq!(svg.matches("<line").count(), 12);
+        // 3x3 grid crossings.
+        assert_eq!(svg.matches("<circle").count(), 9);
+    }
+
+    fn make_table_with_merged_cell() -> Table {
+        Table {
+            bbox: BBox::new(10.0, 10.0, 200.0, 100.0),
+            cells: vec![
+                // Spans the full width across both columns.
+                Cell {
+                    bbox: BBox::new(10.0, 10.0, 200.0, 50.0),
+                    text: Some("A".to_string()),
+                },
+                Cell {
+                    bbox: BBox::new(10.0, 50.0, 100.0, 100.0),
+                    text: Some("C".to_string()),
+                },
+                Cell {
+                    bbox: BBox::new(100.0, 50.0, 200.0, 100.0),
+                    text: Some("D".to_string()),
+                },
+            ],
+            rows: vec![],
+            columns: vec![],
+        }
+    }
+
+    #[test]
+    fn test_draw_table_grid_suppresses_separator_inside_merged_cell() {
+        let mut renderer = SvgRenderer::new(300.0, 200.0);
+        renderer.draw_table_grid(&make_table_with_merged_cell(), &DrawStyle::cells_default());
+        let svg = renderer.to_svg(&SvgOptions::default());
+
+        // Same xs/ys grid as the unmerged 2x2 case (12 segments), but the
+        // middle vertical separator's top-row segment (x=100, y=10..50)
+        // falls strictly inside cell A, which spans the full width of the
+        // top row, so it is suppressed: 12 - 1 = 11.
+        assert_eq!(svg.matches("<line").count(), 11);
+    }
+
+    #[test]
+    fn test_draw_table_grid_does_not_panic_on_nan_bbox_coordinate() {
+        // Degenerate upstream geometry (e.g. a zero-area or malformed cell)
+        // can produce a NaN bbox coordinate; draw_table_grid must degrade
+        // gracefully instead of panicking in unique_sorted_coords's sort.
+        let table = Table {
+            bbox: BBox::new(10.0, 10.0, 200.0, 100.0),
+            cells: vec![Cell {
+                bbox: BBox::new(f64::NAN, 10.0, 100.0, 50.0),
+                text: Some("A".to_string()),
+            }],
+            rows: vec![],
+            columns: vec![],
+        };
+        let mut renderer = SvgRenderer::new(300.0, 200.0);
+        renderer.draw_table_grid(&table, &DrawStyle::cells_default());
+        // No panic is the assertion; also confirm the renderer still
+        // produces well-formed output.
+        let svg = renderer.to_svg(&SvgOptions::default());
+        assert!(svg.starts_with("<svg"));
+    }
+
     // --- US-068 tests: mixed overlays ---
 
     #[test]
@@ -760,6 +1923,11 @@ mod tests {
             stroke: Some("purple".to_string()),
             stroke_width: 3.0,
             opacity: 0.5,
+            fill_from_object: false,
+            stroke_from_object: false,
+            stroke_dasharray: None,
+            stroke_linecap: LineCap::default(),
+            stroke_linejoin: LineJoin::default(),
         };
         renderer.draw_chars(&chars, &custom_style);
         let svg = renderer.to_svg(&SvgOptions::default());
@@ -0,0 +1,231 @@
+//! Unicode box-drawing rendering for visual debugging of table structure.
+//!
+//! Unlike [`crate::svg`], which emits a vector overlay, [`TextRenderer`]
+//! quantizes the same [`Edge`], [`Intersection`], and [`Cell`] values onto a
+//! character grid and renders them as Unicode box-drawing glyphs, for
+//! terminal output, logs, or snapshot tests where an SVG viewer isn't
+//! convenient.
+
+use crate::edges::Edge;
+use crate::geometry::Orientation;
+use crate::table::Cell;
+
+/// Renders detected table structure as a grid of Unicode box-drawing
+/// characters, at a fixed number of page points per character cell.
+pub struct TextRenderer {
+    page_width: f64,
+    page_height: f64,
+    char_width: f64,
+    char_height: f64,
+}
+
+impl TextRenderer {
+    /// Create a renderer for a page of the given size, using the default
+    /// character cell size of 6x12 points (roughly a 12pt monospace font).
+    pub fn new(page_width: f64, page_height: f64) -> Self {
+        Self::with_char_size(page_width, page_height, 6.0, 12.0)
+    }
+
+    /// Create a renderer with an explicit character cell size in points,
+    /// for denser or sparser quantization than the 6x12pt default.
+    pub fn with_char_size(page_width: f64, page_height: f64, char_width: f64, char_height: f64) -> Self {
+        Self {
+            page_width,
+            page_height,
+            char_width,
+            char_height,
+        }
+    }
+
+    fn col(&self, x: f64) -> usize {
+        (x / self.char_width).round().max(0.0) as usize
+    }
+
+    fn row(&self, y: f64) -> usize {
+        (y / self.char_height).round().max(0.0) as usize
+    }
+
+    /// Render `edges` and `cells` onto a character grid and return it as a
+    /// newline-joined string.
+    ///
+    /// Edges become `│`/`─` runs with the appropriate junction glyph at
+    /// each crossing, based on which of the four neighboring grid steps
+    /// carry a line. Cell text is written into each cell's interior,
+    /// truncated to the cell's quantized width.
+    pub fn render(&self, edges: &[Edge], cells: &[Cell]) -> String {
+        let cols = self.col(self.page_width) + 1;
+        let rows = self.row(self.page_height) + 1;
+
+        // `horiz[r][c]` = true if there's a line between grid columns `c`
+        // and `c + 1` at row `r`. `vert[r][c]` = true if there's a line
+        // between grid rows `r` and `r + 1` at column `c`.
+        let mut horiz = vec![vec![false; cols.saturating_sub(1)]; rows];
+        let mut vert = vec![vec![false; cols]; rows.saturating_sub(1)];
+
+        for e in edges {
+            match e.orientation {
+                Orientation::Horizontal => {
+                    let r = self.row(e.top);
+                    let (c0, c1) = (self.col(e.x0).min(self.col(e.x1)), self.col(e.x0).max(self.col(e.x1)));
+                    if let Some(row) = horiz.get_mut(r) {
+                        for c in c0..c1 {
+                            if let Some(cell) = row.get_mut(c) {
+                                *cell = true;
+                            }
+                        }
+                    }
+                }
+                Orientation::Vertical => {
+                    let c = self.col(e.x0);
+                    let (r0, r1) = (self.row(e.top).min(self.row(e.bottom)), self.row(e.top).max(self.row(e.bottom)));
+                    for r in r0..r1 {
+                        if let Some(row) = vert.get_mut(r) {
+                            if let Some(cell) = row.get_mut(c) {
+                                *cell = true;
+                            }
+                        }
+                    }
+                }
+                Orientation::Diagonal => {}
+            }
+        }
+
+        let mut grid: Vec<Vec<char>> = vec![vec![' '; cols]; rows];
+        for (r, row) in grid.iter_mut().enumerate() {
+            for (c, glyph) in row.iter_mut().enumerate() {
+                let north = r > 0 && vert.get(r - 1).and_then(|v| v.get(c)).copied().unwrap_or(false);
+                let south = vert.get(r).and_then(|v| v.get(c)).copied().unwrap_or(false);
+                let west = c > 0 && horiz.get(r).and_then(|h| h.get(c - 1)).copied().unwrap_or(false);
+                let east = horiz.get(r).and_then(|h| h.get(c)).copied().unwrap_or(false);
+                *glyph = junction_glyph(north, south, east, west);
+            }
+        }
+
+        for cell in cells {
+            let Some(text) = &cell.text else { continue };
+            let r = self.row(cell.bbox.top) + 1;
+            let c0 = self.col(cell.bbox.x0) + 1;
+            let c1 = self.col(cell.bbox.x1);
+            if r >= rows || c0 >= c1 {
+                continue;
+            }
+            let max_width = c1 - c0;
+            for (i, ch) in text.chars().take(max_width).enumerate() {
+                grid[r][c0 + i] = ch;
+            }
+        }
+
+        grid.into_iter()
+            .map(|row| row.into_iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Pick the box-drawing glyph for a grid point given which of its four
+/// neighboring grid steps (north/south/east/west) carry a line.
+fn junction_glyph(north: bool, south: bool, east: bool, west: bool) -> char {
+    match (north, south, east, west) {
+        (false, false, false, false) => ' ',
+        (true, false, false, false) => '╵',
+        (false, true, false, false) => '╷',
+        (false, false, true, false) => '╶',
+        (false, false, false, true) => '╴',
+        (true, true, false, false) => '│',
+        (false, false, true, true) => '─',
+        (true, false, true, false) => '└',
+        (true, false, false, true) => '┘',
+        (false, true, true, false) => '┌',
+        (false, true, false, true) => '┐',
+        (true, true, true, false) => '├',
+        (true, true, false, true) => '┤',
+        (false, true, true, true) => '┬',
+        (true, false, true, true) => '┴',
+        (true, true, true, true) => '┼',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::edges::EdgeSource;
+    use crate::geometry::BBox;
+
+    fn make_edge(x0: f64, top: f64, x1: f64, bottom: f64, orientation: Orientation) -> Edge {
+        Edge {
+            x0,
+            top,
+            x1,
+            bottom,
+            orientation,
+            source: EdgeSource::Line,
+        }
+    }
+
+    #[test]
+    fn test_render_empty_is_blank_grid() {
+        let renderer = TextRenderer::with_char_size(12.0, 12.0, 6.0, 12.0);
+        let out = renderer.render(&[], &[]);
+        assert_eq!(out, "   \n   ");
+    }
+
+    #[test]
+    fn test_render_horizontal_edge_is_dashes() {
+        let renderer = TextRenderer::with_char_size(24.0, 12.0, 6.0, 12.0);
+        let edges = vec![make_edge(0.0, 0.0, 24.0, 0.0, Orientation::Horizontal)];
+        let out = renderer.render(&edges, &[]);
+        // Interior grid points get the solid run; the two endpoints get
+        // the half-line glyphs since they have no line on the far side.
+        assert_eq!(out.lines().next().unwrap(), "╶───╴");
+    }
+
+    #[test]
+    fn test_render_vertical_edge_is_pipes() {
+        let renderer = TextRenderer::with_char_size(6.0, 24.0, 6.0, 12.0);
+        let edges = vec![make_edge(0.0, 0.0, 0.0, 24.0, Orientation::Vertical)];
+        let out = renderer.render(&edges, &[]);
+        let col0: String = out.lines().map(|l| l.chars().next().unwrap()).collect();
+        assert_eq!(col0, "╷│╵");
+    }
+
+    #[test]
+    fn test_render_grid_crossing_is_cross_glyph() {
+        // A 2x2 box: top/bottom horizontal edges, left/right vertical edges.
+        let renderer = TextRenderer::with_char_size(24.0, 24.0, 6.0, 12.0);
+        let edges = vec![
+            make_edge(0.0, 0.0, 24.0, 0.0, Orientation::Horizontal),
+            make_edge(0.0, 24.0, 24.0, 24.0, Orientation::Horizontal),
+            make_edge(0.0, 0.0, 0.0, 24.0, Orientation::Vertical),
+            make_edge(24.0, 0.0, 24.0, 24.0, Orientation::Vertical),
+        ];
+        let out = renderer.render(&edges, &[]);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0].chars().next().unwrap(), '┌');
+        assert_eq!(lines[0].chars().last().unwrap(), '┐');
+        assert_eq!(lines.last().unwrap().chars().next().unwrap(), '└');
+        assert_eq!(lines.last().unwrap().chars().last().unwrap(), '┘');
+    }
+
+    #[test]
+    fn test_render_fills_cell_text() {
+        let renderer = TextRenderer::with_char_size(60.0, 24.0, 6.0, 12.0);
+        let cells = vec![Cell {
+            bbox: BBox::new(0.0, 0.0, 60.0, 24.0),
+            text: Some("hi".to_string()),
+        }];
+        let out = renderer.render(&[], &cells);
+        assert!(out.contains("hi"));
+    }
+
+    #[test]
+    fn test_render_truncates_text_to_cell_width() {
+        let renderer = TextRenderer::with_char_size(18.0, 24.0, 6.0, 12.0);
+        let cells = vec![Cell {
+            bbox: BBox::new(0.0, 0.0, 18.0, 24.0),
+            text: Some("abcdefgh".to_string()),
+        }];
+        let out = renderer.render(&[], &cells);
+        let second_line = out.lines().nth(1).unwrap();
+        assert!(!second_line.contains('f'));
+    }
+}
@@ -4,18 +4,33 @@
 //! [`Severity`] for classifying their impact on extraction.
 
 use std::fmt;
+use std::io::{self, Write};
 
 /// Severity of a validation issue.
 ///
 /// Indicates whether a PDF specification violation is likely to cause
 /// extraction failures or is merely a non-conformance that still allows
 /// best-effort extraction.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Variants are totally ordered from most to least severe:
+/// `Error > Warning > Note > Info`. This lets callers take the `max()` of
+/// a set of issues to get the document's worst severity, or filter a slice
+/// down to those at or above a threshold via [`filter_by_severity`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum Severity {
-    /// Specification violation likely to cause extraction failure.
-    Error,
+    /// Lowest severity: purely informational, no impact on extraction.
+    Info,
+    /// A notable but inconsequential observation.
+    Note,
     /// Non-conformance but data is likely still extractable.
     Warning,
+    /// Specification violation likely to cause extraction failure.
+    Error,
 }
 
 impl fmt::Display for Severity {
@@ -23,6 +38,45 @@ impl fmt::Display for Severity {
         match self {
             Severity::Error => write!(f, "error"),
             Severity::Warning => write!(f, "warning"),
+            Severity::Note => write!(f, "note"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
+/// A byte-range annotation pointing at the offending bytes in the raw PDF.
+///
+/// Validators that already know the file offset of a broken xref entry, a
+/// malformed object header, or a truncated stream can attach the exact span
+/// so tooling can jump straight to it instead of re-deriving it from a
+/// free-form [`ValidationIssue::location`] string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SourceSpan {
+    /// Byte offset of the start of the span, inclusive.
+    pub byte_start: usize,
+    /// Byte offset of the end of the span, exclusive.
+    pub byte_end: usize,
+    /// Optional short label describing what the span covers.
+    pub label: Option<String>,
+}
+
+impl SourceSpan {
+    /// Create a new source span covering `byte_start..byte_end`.
+    pub fn new(byte_start: usize, byte_end: usize) -> Self {
+        Self {
+            byte_start,
+            byte_end,
+            label: None,
+        }
+    }
+
+    /// Create a new source span with a descriptive label.
+    pub fn with_label(byte_start: usize, byte_end: usize, label: impl Into<String>) -> Self {
+        Self {
+            byte_start,
+            byte_end,
+            label: Some(label.into()),
         }
     }
 }
@@ -33,6 +87,7 @@ impl fmt::Display for Severity {
 /// including its severity, an identifying code, a human-readable message,
 /// and an optional location within the document.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidationIssue {
     /// Severity of the issue.
     pub severity: Severity,
@@ -42,6 +97,8 @@ pub struct ValidationIssue {
     pub message: String,
     /// Optional location within the PDF (e.g., "page 3", "object 5 0").
     pub location: Option<String>,
+    /// Byte-range spans in the raw PDF that this issue pertains to.
+    pub annotations: Vec<SourceSpan>,
 }
 
 impl ValidationIssue {
@@ -52,6 +109,7 @@ impl ValidationIssue {
             code: code.into(),
             message: message.into(),
             location: None,
+            annotations: Vec::new(),
         }
     }
 
@@ -67,9 +125,21 @@ impl ValidationIssue {
             code: code.into(),
             message: message.into(),
             location: Some(location.into()),
+            annotations: Vec::new(),
         }
     }
 
+    /// Attach a byte-range annotation, returning `self` for chaining.
+    pub fn with_annotation(mut self, annotation: SourceSpan) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// Attach a byte-range annotation in place.
+    pub fn annotate(&mut self, annotation: SourceSpan) {
+        self.annotations.push(annotation);
+    }
+
     /// Returns `true` if the issue is an error.
     pub fn is_error(&self) -> bool {
         self.severity == Severity::Error
@@ -79,6 +149,23 @@ impl ValidationIssue {
     pub fn is_warning(&self) -> bool {
         self.severity == Severity::Warning
     }
+
+    /// Returns `true` if this issue's severity is `min` or higher on the
+    /// `Error > Warning > Note > Info` ladder.
+    pub fn severity_at_least(&self, min: Severity) -> bool {
+        self.severity >= min
+    }
+}
+
+/// Filter a slice of issues down to those at or above `min` severity.
+///
+/// Useful for gating extraction on errors only (`min = Severity::Error`)
+/// or surfacing everything in verbose mode (`min = Severity::Info`).
+pub fn filter_by_severity(
+    issues: &[ValidationIssue],
+    min: Severity,
+) -> impl Iterator<Item = &ValidationIssue> {
+    issues.iter().filter(move |issue| issue.severity_at_least(min.clone()))
 }
 
 impl fmt::Display for ValidationIssue {
@@ -91,6 +178,219 @@ impl fmt::Display for ValidationIssue {
     }
 }
 
+/// An aggregated, machine-readable collection of validation issues.
+///
+/// Where [`ValidationIssue`] describes a single finding, `ValidationReport`
+/// owns the full set produced by validating a document, offering counts
+/// per severity and a stable JSON representation for CI pipelines and other
+/// tooling that would otherwise have to scrape formatted strings.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationReport {
+    issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Create an empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an issue to the report.
+    pub fn push(&mut self, issue: ValidationIssue) {
+        self.issues.push(issue);
+    }
+
+    /// All issues in the report, in the order they were pushed.
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// Number of issues with [`Severity::Error`].
+    pub fn error_count(&self) -> usize {
+        self.issues.iter().filter(|i| i.is_error()).count()
+    }
+
+    /// Number of issues with [`Severity::Warning`].
+    pub fn warning_count(&self) -> usize {
+        self.issues.iter().filter(|i| i.is_warning()).count()
+    }
+
+    /// Returns `true` if the report contains at least one error.
+    pub fn has_errors(&self) -> bool {
+        self.error_count() > 0
+    }
+
+    /// Iterate over issues at or above `min` severity.
+    pub fn iter_by_severity(&self, min: Severity) -> impl Iterator<Item = &ValidationIssue> {
+        filter_by_severity(&self.issues, min)
+    }
+
+    /// Serialize the report to a stable JSON document: an `issues` array
+    /// plus a `summary` object with counts per severity.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        let summary = serde_json::json!({
+            "error": self.issues.iter().filter(|i| i.severity == Severity::Error).count(),
+            "warning": self.issues.iter().filter(|i| i.severity == Severity::Warning).count(),
+            "note": self.issues.iter().filter(|i| i.severity == Severity::Note).count(),
+            "info": self.issues.iter().filter(|i| i.severity == Severity::Info).count(),
+        });
+        serde_json::to_string(&serde_json::json!({
+            "issues": self.issues,
+            "summary": summary,
+        }))
+    }
+
+    /// Render the report as terminal diagnostics (codespan/ariadne-style):
+    /// each issue's code and message as a header, followed by the relevant
+    /// bytes of `source` as a snippet with carets underlining each annotated
+    /// `byte_start..byte_end` span.
+    ///
+    /// Because PDF content is binary, line/column numbers are synthesized by
+    /// splitting `source` on `\n`/`\r`; spans landing inside binary stream
+    /// data fall back to a hex-dump context window instead of garbled text.
+    pub fn render_pretty(
+        &self,
+        source: &[u8],
+        color: ColorMode,
+        writer: &mut impl Write,
+    ) -> io::Result<()> {
+        for issue in &self.issues {
+            render_issue_header(issue, color, writer)?;
+            if issue.annotations.is_empty() {
+                writeln!(writer)?;
+                continue;
+            }
+            for annotation in &issue.annotations {
+                render_span(source, annotation, color, writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether [`ValidationReport::render_pretty`] emits ANSI color escapes.
+///
+/// Use [`ColorMode::NoColor`] for CI logs and [`ColorMode::Ansi`] for
+/// interactive terminals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    /// Plain text, no escape codes. Suitable for CI logs and file output.
+    #[default]
+    NoColor,
+    /// ANSI-colored output, suitable for interactive terminals.
+    Ansi,
+}
+
+fn severity_ansi_code(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "\x1b[1;31m",   // bold red
+        Severity::Warning => "\x1b[1;33m", // bold yellow
+        Severity::Note => "\x1b[1;36m",    // bold cyan
+        Severity::Info => "\x1b[1;34m",    // bold blue
+    }
+}
+
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_CARET: &str = "\x1b[1;31m";
+
+fn render_issue_header(
+    issue: &ValidationIssue,
+    color: ColorMode,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    match color {
+        ColorMode::Ansi => {
+            let code = severity_ansi_code(&issue.severity);
+            writeln!(
+                writer,
+                "{code}[{}] {}{ANSI_RESET}: {}",
+                issue.severity, issue.code, issue.message
+            )?;
+        }
+        ColorMode::NoColor => {
+            writeln!(
+                writer,
+                "[{}] {}: {}",
+                issue.severity, issue.code, issue.message
+            )?;
+        }
+    }
+    if let Some(ref loc) = issue.location {
+        writeln!(writer, "  --> {loc}")?;
+    }
+    Ok(())
+}
+
+/// Number of bytes of context to show on a binary-fallback hex dump.
+const HEX_CONTEXT_BYTES: usize = 16;
+
+fn render_span(
+    source: &[u8],
+    span: &SourceSpan,
+    color: ColorMode,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    let start = span.byte_start.min(source.len());
+    let end = span.byte_end.min(source.len()).max(start);
+
+    let line_start = source[..start]
+        .iter()
+        .rposition(|&b| b == b'\n' || b == b'\r')
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let line_end = source[start..]
+        .iter()
+        .position(|&b| b == b'\n' || b == b'\r')
+        .map(|i| start + i)
+        .unwrap_or(source.len());
+    let line_no = source[..start].iter().filter(|&&b| b == b'\n').count() + 1;
+    let col_no = start - line_start + 1;
+
+    let line_bytes = &source[line_start..line_end.max(start)];
+    let is_text = line_bytes
+        .iter()
+        .all(|&b| b == b'\t' || (0x20..0x7f).contains(&b));
+
+    if is_text && line_end >= end {
+        let line = String::from_utf8_lossy(&source[line_start..line_end]);
+        if let Some(ref label) = span.label {
+            writeln!(writer, "  {line_no}:{col_no} [{label}]")?;
+        } else {
+            writeln!(writer, "  {line_no}:{col_no}")?;
+        }
+        writeln!(writer, "  | {line}")?;
+        let caret_width = (end - start).max(1);
+        let prefix = " ".repeat(col_no.saturating_sub(1));
+        let carets = "^".repeat(caret_width);
+        match color {
+            ColorMode::Ansi => writeln!(writer, "  | {prefix}{ANSI_CARET}{carets}{ANSI_RESET}")?,
+            ColorMode::NoColor => writeln!(writer, "  | {prefix}{carets}")?,
+        }
+    } else {
+        let hex_start = start.saturating_sub(HEX_CONTEXT_BYTES);
+        let hex_end = (end + HEX_CONTEXT_BYTES).min(source.len());
+        let label = span.label.as_deref().unwrap_or("binary span");
+        writeln!(
+            writer,
+            "  bytes {}..{} [{label}] (hex dump, context {hex_start}..{hex_end})",
+            span.byte_start, span.byte_end
+        )?;
+        write!(writer, "  | ")?;
+        for (i, byte) in source[hex_start..hex_end].iter().enumerate() {
+            let offset = hex_start + i;
+            let in_span = offset >= start && offset < end;
+            match (color, in_span) {
+                (ColorMode::Ansi, true) => write!(writer, "{ANSI_CARET}{byte:02x}{ANSI_RESET} ")?,
+                _ => write!(writer, "{byte:02x} ")?,
+            }
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,6 +419,25 @@ mod tests {
         assert!(issue.location.is_none());
         assert!(issue.is_error());
         assert!(!issue.is_warning());
+        assert!(issue.annotations.is_empty());
+    }
+
+    #[test]
+    fn validation_issue_with_annotation() {
+        let issue = ValidationIssue::new(Severity::Error, "BROKEN_XREF", "bad xref entry")
+            .with_annotation(SourceSpan::with_label(120, 140, "xref entry"));
+        assert_eq!(issue.annotations.len(), 1);
+        assert_eq!(issue.annotations[0].byte_start, 120);
+        assert_eq!(issue.annotations[0].byte_end, 140);
+        assert_eq!(issue.annotations[0].label.as_deref(), Some("xref entry"));
+    }
+
+    #[test]
+    fn validation_issue_annotate_in_place() {
+        let mut issue = ValidationIssue::new(Severity::Warning, "MALFORMED", "bad header");
+        issue.annotate(SourceSpan::new(0, 8));
+        issue.annotate(SourceSpan::new(20, 30));
+        assert_eq!(issue.annotations.len(), 2);
     }
 
     #[test]
@@ -166,4 +485,144 @@ mod tests {
         let issue2 = issue1.clone();
         assert_eq!(issue1, issue2);
     }
+
+    #[test]
+    fn severity_ordering() {
+        assert!(Severity::Error > Severity::Warning);
+        assert!(Severity::Warning > Severity::Note);
+        assert!(Severity::Note > Severity::Info);
+        assert_eq!(
+            [Severity::Info, Severity::Error, Severity::Note, Severity::Warning]
+                .into_iter()
+                .max()
+                .unwrap(),
+            Severity::Error
+        );
+    }
+
+    #[test]
+    fn severity_display_all_variants() {
+        assert_eq!(Severity::Error.to_string(), "error");
+        assert_eq!(Severity::Warning.to_string(), "warning");
+        assert_eq!(Severity::Note.to_string(), "note");
+        assert_eq!(Severity::Info.to_string(), "info");
+    }
+
+    #[test]
+    fn severity_at_least() {
+        let issue = ValidationIssue::new(Severity::Warning, "TEST", "msg");
+        assert!(issue.severity_at_least(Severity::Info));
+        assert!(issue.severity_at_least(Severity::Warning));
+        assert!(!issue.severity_at_least(Severity::Error));
+    }
+
+    #[test]
+    fn filter_by_severity_threshold() {
+        let issues = vec![
+            ValidationIssue::new(Severity::Error, "E", "e"),
+            ValidationIssue::new(Severity::Warning, "W", "w"),
+            ValidationIssue::new(Severity::Note, "N", "n"),
+            ValidationIssue::new(Severity::Info, "I", "i"),
+        ];
+        let at_least_warning: Vec<_> = filter_by_severity(&issues, Severity::Warning).collect();
+        assert_eq!(at_least_warning.len(), 2);
+        assert_eq!(at_least_warning[0].code, "E");
+        assert_eq!(at_least_warning[1].code, "W");
+    }
+
+    #[test]
+    fn validation_report_counts() {
+        let mut report = ValidationReport::new();
+        report.push(ValidationIssue::new(Severity::Error, "E1", "e1"));
+        report.push(ValidationIssue::new(Severity::Error, "E2", "e2"));
+        report.push(ValidationIssue::new(Severity::Warning, "W1", "w1"));
+        assert_eq!(report.error_count(), 2);
+        assert_eq!(report.warning_count(), 1);
+        assert!(report.has_errors());
+        assert_eq!(report.issues().len(), 3);
+    }
+
+    #[test]
+    fn validation_report_empty_has_no_errors() {
+        let report = ValidationReport::new();
+        assert!(!report.has_errors());
+        assert_eq!(report.error_count(), 0);
+    }
+
+    #[test]
+    fn validation_report_iter_by_severity() {
+        let mut report = ValidationReport::new();
+        report.push(ValidationIssue::new(Severity::Info, "I", "i"));
+        report.push(ValidationIssue::new(Severity::Error, "E", "e"));
+        let errors: Vec<_> = report.iter_by_severity(Severity::Error).collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, "E");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn validation_report_to_json() {
+        let mut report = ValidationReport::new();
+        report.push(ValidationIssue::new(Severity::Error, "E", "e"));
+        report.push(ValidationIssue::new(Severity::Warning, "W", "w"));
+        let json = report.to_json().unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["summary"]["error"], 1);
+        assert_eq!(parsed["summary"]["warning"], 1);
+        assert_eq!(parsed["issues"].as_array().unwrap().len(), 2);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn severity_serializes_as_lowercase_tag() {
+        let json = serde_json::to_string(&Severity::Warning).unwrap();
+        assert_eq!(json, "\"warning\"");
+    }
+
+    #[test]
+    fn render_pretty_text_span() {
+        let source = b"1 0 obj\n<< /Type /Catalog >>\nendobj\n";
+        let mut report = ValidationReport::new();
+        report.push(
+            ValidationIssue::with_location(Severity::Error, "MISSING_TYPE", "bad type", "obj 1")
+                .with_annotation(SourceSpan::with_label(11, 16, "/Type")),
+        );
+        let mut out = Vec::new();
+        report
+            .render_pretty(source, ColorMode::NoColor, &mut out)
+            .unwrap();
+        let rendered = String::from_utf8(out).unwrap();
+        assert!(rendered.contains("[error] MISSING_TYPE: bad type"));
+        assert!(rendered.contains("-->"));
+        assert!(rendered.contains("^^^^^"));
+    }
+
+    #[test]
+    fn render_pretty_binary_fallback() {
+        let source: Vec<u8> = (0u8..=255).collect();
+        let mut report = ValidationReport::new();
+        report.push(
+            ValidationIssue::new(Severity::Warning, "TRUNCATED_STREAM", "truncated stream")
+                .with_annotation(SourceSpan::new(10, 12)),
+        );
+        let mut out = Vec::new();
+        report
+            .render_pretty(&source, ColorMode::NoColor, &mut out)
+            .unwrap();
+        let rendered = String::from_utf8(out).unwrap();
+        assert!(rendered.contains("hex dump"));
+    }
+
+    #[test]
+    fn render_pretty_ansi_colors_header() {
+        let source = b"x";
+        let mut report = ValidationReport::new();
+        report.push(ValidationIssue::new(Severity::Error, "E", "e"));
+        let mut out = Vec::new();
+        report
+            .render_pretty(source, ColorMode::Ansi, &mut out)
+            .unwrap();
+        let rendered = String::from_utf8(out).unwrap();
+        assert!(rendered.contains("\x1b[1;31m"));
+    }
 }
@@ -213,6 +213,8 @@ fn test_serde_image_metadata() {
         src_height: Some(1080),
         bits_per_component: Some(8),
         color_space: Some("DeviceRGB".to_string()),
+        is_mask: false,
+        decode: None,
     };
     roundtrip(&meta);
 }
@@ -234,6 +236,8 @@ fn test_serde_image() {
         data: None,
         filter: None,
         mime_type: None,
+        is_mask: false,
+        decode: None,
     };
     roundtrip(&img);
 }
@@ -412,3 +416,35 @@ fn test_color_json_tagged() {
     let restored: Color = serde_json::from_str(&json).unwrap();
     assert_eq!(gray, restored);
 }
+
+#[test]
+fn test_serde_severity() {
+    roundtrip(&Severity::Error);
+    roundtrip(&Severity::Warning);
+    roundtrip(&Severity::Note);
+    roundtrip(&Severity::Info);
+}
+
+#[test]
+fn test_serde_validation_issue() {
+    roundtrip(&ValidationIssue::new(Severity::Error, "BROKEN_REF", "object 5 0 not found"));
+    roundtrip(&ValidationIssue::with_location(
+        Severity::Warning,
+        "MISSING_FONT",
+        "font /F1 not found",
+        "page 2",
+    ));
+}
+
+#[test]
+fn test_serde_source_span() {
+    roundtrip(&SourceSpan::new(0, 10));
+    roundtrip(&SourceSpan::with_label(10, 20, "xref entry"));
+}
+
+#[test]
+fn test_serde_validation_issue_with_annotation() {
+    let issue = ValidationIssue::new(Severity::Error, "BROKEN_XREF", "bad xref entry")
+        .with_annotation(SourceSpan::with_label(120, 140, "xref entry"));
+    roundtrip(&issue);
+}
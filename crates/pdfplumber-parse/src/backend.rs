@@ -4,9 +4,9 @@
 //! This enables pluggable backends (e.g., lopdf, pdf-rs) for PDF reading.
 
 use pdfplumber_core::{
-    Annotation, BBox, Bookmark, DocumentMetadata, ExtractOptions, FormField, Hyperlink,
-    ImageContent, PdfError, RepairOptions, RepairResult, SignatureInfo, StructElement,
-    ValidationIssue,
+    AcroForm, Annotation, BBox, Bookmark, DocumentMetadata, ExtractOptions, FormField, Hyperlink,
+    ImageContent, OutlineItem, PdfError, Permissions, RepairOptions, RepairResult, SignatureInfo,
+    StructElement, ValidationIssue,
 };
 
 use crate::handler::ContentHandler;
@@ -55,12 +55,50 @@ pub trait PdfBackend {
     /// Supports both user and owner passwords. If the PDF is not encrypted,
     /// the password is ignored and the document opens normally.
     ///
+    /// Decryption of the classic handlers (RC4 40/128-bit and AES-128/V4) is
+    /// delegated to the `lopdf` document backend rather than reimplemented
+    /// here. AES-256 with the R6 "hardened hash" (PDF 2.0 Algorithm 2.B) is
+    /// **not implemented**: this crate doesn't reimplement it, and nothing
+    /// here has verified whether the `lopdf` version in use does either, so
+    /// rather than silently delegate and risk misreporting an unsupported
+    /// algorithm as [`PdfError::InvalidPassword`], `/R` 5 and 6 documents are
+    /// rejected up front with a distinct error (see
+    /// [`crate::lopdf_backend::LopdfBackend::open_with_password`]).
+    /// Owner-password authentication for the classic (R2-R4) handlers is
+    /// handled by this crate: if the supplied password fails as a user
+    /// password, it's retried as an owner password by recovering the padded
+    /// user password from `/O` and re-attempting decryption with that. Which
+    /// credential actually authenticated the document is recorded on the
+    /// returned document, where the backend exposes it (see
+    /// [`crate::lopdf_backend::LopdfDocument::authentication`]).
+    ///
     /// # Errors
     ///
     /// Returns [`PdfError::InvalidPassword`] if the password is incorrect.
     /// Returns other errors if the bytes are not a valid PDF document.
     fn open_with_password(bytes: &[u8], password: &[u8]) -> Result<Self::Document, Self::Error>;
 
+    /// Parse PDF bytes into a document, tolerating a misplaced header or a
+    /// broken cross-reference table.
+    ///
+    /// First retries [`Self::open`] after scanning the first 1024 bytes for
+    /// the `%PDF-` signature (real-world files sometimes have junk — a BOM,
+    /// an HTML error page, a stray filesystem path — before the real start
+    /// of the document). If the xref table or trailer still can't be
+    /// resolved, falls back to a full rebuild: linearly scan for `N G obj`
+    /// markers, recover each object from its byte offset, and locate the
+    /// `/Root` catalog by finding the recovered object whose `/Type` is
+    /// `/Catalog` rather than trusting the (possibly corrupt) trailer.
+    ///
+    /// This is an opt-in, best-effort recovery path (see
+    /// [`ExtractOptions::repair`]) — it is not guaranteed to fully reconstruct
+    /// every document, but it degrades gracefully for the common cases above.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no recoverable objects or catalog can be found.
+    fn open_lenient(bytes: &[u8]) -> Result<Self::Document, Self::Error>;
+
     /// Return the number of pages in the document.
     fn page_count(doc: &Self::Document) -> usize;
 
@@ -158,6 +196,23 @@ pub trait PdfBackend {
     /// Returns an error if the /Outlines dictionary exists but is malformed.
     fn document_bookmarks(doc: &Self::Document) -> Result<Vec<Bookmark>, Self::Error>;
 
+    /// Extract the document outline as a hierarchical tree.
+    ///
+    /// Unlike [`Self::document_bookmarks`]'s flattened list, this preserves
+    /// the outline's nesting via `OutlineItem::children`, and includes the
+    /// `/Count`, `/C`, and `/F` display hints. `max_depth` bounds recursion
+    /// (see `ExtractOptions::max_recursion_depth`) so a malformed or cyclic
+    /// `/Outlines` tree degrades to an empty or partial result instead of
+    /// looping or erroring.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the /Outlines dictionary exists but is malformed.
+    fn document_outline(
+        doc: &Self::Document,
+        max_depth: usize,
+    ) -> Result<Vec<OutlineItem>, Self::Error>;
+
     /// Extract annotations from a page.
     ///
     /// Returns a list of [`Annotation`]s found in the page's /Annots array.
@@ -215,6 +270,18 @@ pub trait PdfBackend {
     /// Returns an error if the AcroForm exists but is malformed.
     fn document_form_fields(doc: &Self::Document) -> Result<Vec<FormField>, Self::Error>;
 
+    /// Extract the document's AcroForm: its fields plus form-level flags.
+    ///
+    /// Like [`Self::document_form_fields`], but also surfaces the AcroForm
+    /// dictionary's own `/NeedAppearances` and `/SigFlags` entries via
+    /// [`AcroForm`]. Returns [`AcroForm::default`] if the document has no
+    /// AcroForm.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the AcroForm exists but is malformed.
+    fn document_acro_form(doc: &Self::Document) -> Result<AcroForm, Self::Error>;
+
     /// Extract the document's structure tree from `/StructTreeRoot`.
     ///
     /// Returns the structure tree elements for tagged PDFs. Each element has a
@@ -227,12 +294,28 @@ pub trait PdfBackend {
     /// Returns an error if the structure tree exists but is malformed.
     fn document_structure_tree(doc: &Self::Document) -> Result<Vec<StructElement>, Self::Error>;
 
+    /// Decode the document's permission flags from its `/Encrypt` dictionary's
+    /// `/P` entry.
+    ///
+    /// Returns [`Permissions::default`] (all capabilities granted) for an
+    /// unencrypted document, or one whose `/Encrypt` dictionary is missing or
+    /// malformed. This never fails: an unreadable permission set is treated
+    /// the same as an unrestricted one, since these flags are advisory.
+    fn document_permissions(doc: &Self::Document) -> Permissions;
+
     /// Extract image content (raw bytes) from a named image XObject on a page.
     ///
     /// Locates the image XObject by name in the page's `/Resources/XObject`
-    /// dictionary and extracts its stream data. For DCTDecode (JPEG) images,
-    /// returns the raw JPEG bytes. For FlateDecode images, decompresses and
-    /// returns raw pixel data. Handles chained filters.
+    /// dictionary and extracts its stream data. For DCTDecode (JPEG) and
+    /// JPXDecode (JPEG 2000) images, returns the raw JPEG/JP2 bytes — pixel
+    /// decoding is left to the caller. For FlateDecode/LZWDecode images,
+    /// decompresses and, if `/DecodeParms` specifies a PNG (`Predictor`
+    /// 10-15) or 8-bit-per-component TIFF (`Predictor` 2) predictor,
+    /// reconstructs it back to raw samples (see
+    /// [`pdfplumber_core::reverse_predictor`]). CCITTFaxDecode and
+    /// JBIG2Decode images are returned as raw, still-encoded stream bytes;
+    /// this backend does not implement those decoders. Handles chained
+    /// filters.
     ///
     /// # Errors
     ///
@@ -294,6 +377,24 @@ pub trait PdfBackend {
         let _ = (bytes, options);
         Ok((bytes.to_vec(), RepairResult::new()))
     }
+
+    /// Write a new single-file PDF containing only the given 0-based page
+    /// `indices`. The retained pages keep their original document order,
+    /// whatever the order of `indices`, and an index given twice yields one
+    /// page.
+    ///
+    /// Builds a page tree referencing just the requested pages, garbage
+    /// collects every object the dropped pages leave unreachable (content
+    /// streams, fonts, XObjects unique to them), and renumbers the xref so
+    /// the result is a normal, self-contained PDF. The source `/Info`
+    /// metadata is preserved, and outline bookmarks whose destination isn't
+    /// one of the retained pages are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any index is out of range or the document
+    /// cannot be rewritten.
+    fn save_subset(doc: &Self::Document, indices: &[usize]) -> Result<Vec<u8>, Self::Error>;
 }
 
 #[cfg(test)]
@@ -393,6 +494,11 @@ mod tests {
             Self::open(bytes)
         }
 
+        fn open_lenient(bytes: &[u8]) -> Result<Self::Document, Self::Error> {
+            // Mock: just delegates to open (no recovery logic in mock)
+            Self::open(bytes)
+        }
+
         fn page_count(doc: &Self::Document) -> usize {
             doc.pages.len()
         }
@@ -451,20 +557,48 @@ mod tests {
             Ok(Vec::new())
         }
 
+        fn document_outline(
+            _doc: &Self::Document,
+            _max_depth: usize,
+        ) -> Result<Vec<OutlineItem>, Self::Error> {
+            Ok(Vec::new())
+        }
+
         fn document_form_fields(_doc: &Self::Document) -> Result<Vec<FormField>, Self::Error> {
             Ok(Vec::new())
         }
 
+        fn document_acro_form(_doc: &Self::Document) -> Result<AcroForm, Self::Error> {
+            Ok(AcroForm::default())
+        }
+
         fn document_signatures(_doc: &Self::Document) -> Result<Vec<SignatureInfo>, Self::Error> {
             Ok(Vec::new())
         }
 
+        fn save_subset(doc: &Self::Document, indices: &[usize]) -> Result<Vec<u8>, Self::Error> {
+            for &idx in indices {
+                if idx >= doc.pages.len() {
+                    return Err(PdfError::ParseError(format!(
+                        "page index {idx} out of range (0..{})",
+                        doc.pages.len()
+                    )));
+                }
+            }
+            // Mock: encode the retained page count the same way `open` decodes it.
+            Ok(vec![indices.len() as u8])
+        }
+
         fn document_structure_tree(
             _doc: &Self::Document,
         ) -> Result<Vec<StructElement>, Self::Error> {
             Ok(Vec::new())
         }
 
+        fn document_permissions(_doc: &Self::Document) -> Permissions {
+            Permissions::default()
+        }
+
         fn page_annotations(
             _doc: &Self::Document,
             _page: &Self::Page,
@@ -526,6 +660,9 @@ mod tests {
                 colorspace: Some("DeviceRGB".to_string()),
                 bits_per_component: Some(8),
                 filter: None,
+                is_mask: false,
+                decode: None,
+                data: None,
             });
 
             Ok(())
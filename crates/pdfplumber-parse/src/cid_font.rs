@@ -4,6 +4,7 @@
 //! descendant fonts. Provides CID-to-GID mapping, /W (width) array parsing,
 //! and /DW (default width) handling for CID fonts.
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
 use crate::error::BackendError;
@@ -11,12 +12,22 @@ use crate::error::BackendError;
 /// Default CID font width when /DW is not specified (1000/1000 of text space = full em width).
 const DEFAULT_CID_WIDTH: f64 = 1000.0;
 
+/// Default `/DW2` value per the PDF spec: `[vy w1y]` = position vector y-component
+/// and default vertical displacement, used when a CID font has no explicit `/DW2`.
+const DEFAULT_DW2: [f64; 2] = [880.0, -1000.0];
+
 /// Default ascent for CID fonts when not specified.
 const DEFAULT_CID_ASCENT: f64 = 880.0;
 
 /// Default descent for CID fonts when not specified.
 const DEFAULT_CID_DESCENT: f64 = -120.0;
 
+/// `/FontDescriptor` `/Flags` bit for "glyphs have serifs" (PDF spec Table 123, bit 2).
+const FLAG_SERIF: u32 = 1 << 1;
+
+/// Fallback substitute family used when no `CidSystemInfo` or Base-14 alias applies.
+const DEFAULT_SUBSTITUTE_FAMILY: &str = "Helvetica";
+
 /// CID font subtype.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CidFontType {
@@ -26,6 +37,27 @@ pub enum CidFontType {
     Type2,
 }
 
+/// Font program format detected by sniffing the header bytes of an embedded
+/// `/FontFile`, `/FontFile2`, or `/FontFile3` stream, independent of what the
+/// CIDFont's `/Subtype` declares.
+///
+/// PDF producers sometimes mislabel CIDFonts (e.g. a `CIDFontType0`
+/// dictionary carrying a TrueType program in `/FontFile2`), so callers that
+/// need the *actual* outline format should prefer this over
+/// [`CidFontMetrics::declared_font_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontProgramType {
+    /// PostScript Type 1 program (`%!` header or a PFB segment marker byte).
+    Type1,
+    /// OpenType wrapper (`OTTO` sfnt tag). May contain either CFF or `glyf`
+    /// outlines; the wrapper alone doesn't disambiguate which.
+    OpenType,
+    /// TrueType/`glyf` outlines (`true`/`ttcf` tag, or sfnt version `0x00010000`).
+    TrueType,
+    /// Bare CFF/Type1C program (CFF header major version byte `1`).
+    Cff,
+}
+
 /// CID-to-GID (glyph ID) mapping strategy.
 #[derive(Debug, Clone, PartialEq)]
 pub enum CidToGidMap {
@@ -82,6 +114,176 @@ impl CidSystemInfo {
     }
 }
 
+/// A chosen system-font substitution for a CID font with no embedded glyph
+/// source, analogous to the `StdFontMapEntry`/`basefontnames` tables used by
+/// xpdf/poppler/mupdf to pick a renderable stand-in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontSubstitution {
+    /// Name of the substitute family to load, e.g. `"MS Gothic"` or `"Helvetica"`.
+    pub family: String,
+    /// Whether the original `/FontDescriptor` had no embedded `/FontFile*`
+    /// program, i.e. this substitution is actually needed for rendering
+    /// rather than merely an informational best guess.
+    pub missing_embedded_font: bool,
+}
+
+/// Recognize common renamed Base-14-compatible Latin font names (after
+/// subsetting or vendor renaming, e.g. `ArialMT`) and map them to their
+/// Base-14 family, per PDF spec 9.6.2.2's common-alias guidance.
+fn base14_alias_family(base_font: &str) -> Option<&'static str> {
+    match strip_subset_prefix(base_font) {
+        "ArialMT" | "Arial" | "Arial-Bold" | "Arial,Bold" | "Helvetica" => Some("Helvetica"),
+        "TimesNewRomanPSMT" | "TimesNewRoman" | "Times New Roman" | "Times" => Some("Times"),
+        "CourierNewPSMT" | "CourierNew" | "Courier New" | "Courier" => Some("Courier"),
+        _ => None,
+    }
+}
+
+/// Pick a default CJK substitute family for a `CidSystemInfo.ordering`,
+/// adjusted for the descriptor's serif bit (`/Flags` bit 2): a sans-serif
+/// Gothic-class family when unset, a serif Mincho-class family when set.
+fn default_cjk_family(ordering: &str, flags: u32) -> &'static str {
+    let serif = flags & FLAG_SERIF != 0;
+    match ordering {
+        "Japan1" => {
+            if serif {
+                "MS Mincho"
+            } else {
+                "MS Gothic"
+            }
+        }
+        "GB1" => {
+            if serif {
+                "SimSun"
+            } else {
+                "SimHei"
+            }
+        }
+        // Adobe-CNS1 doesn't have as established a Gothic/Mincho-style split;
+        // MingLiU is the de facto standard CJK-Traditional system font either way.
+        "CNS1" => "MingLiU",
+        "Korea1" => {
+            if serif {
+                "Batang"
+            } else {
+                "Dotum"
+            }
+        }
+        _ => DEFAULT_SUBSTITUTE_FAMILY,
+    }
+}
+
+/// Choose a system-font substitution for a CID font, consulting Base-14
+/// alias munging first (for renamed Latin descendant fonts) and falling
+/// back to the CJK defaults keyed by `CidSystemInfo.ordering` and the
+/// descriptor's serif flag.
+pub fn select_font_substitution(
+    base_font: &str,
+    system_info: Option<&CidSystemInfo>,
+    flags: u32,
+    missing_embedded_font: bool,
+) -> FontSubstitution {
+    let family = base14_alias_family(base_font)
+        .map(str::to_string)
+        .unwrap_or_else(|| {
+            let ordering = system_info.map(|i| i.ordering.as_str()).unwrap_or("");
+            default_cjk_family(ordering, flags).to_string()
+        });
+    FontSubstitution {
+        family,
+        missing_embedded_font,
+    }
+}
+
+/// Maximum number of entries a single `/W` array may populate in either the
+/// individual-CID map or the range-run vector, applied independently to
+/// each. Defends against malformed or adversarial arrays (e.g. thousands of
+/// single-CID overrides, or thousands of distinct tiny ranges) exhausting
+/// memory.
+const MAX_WIDTH_STORE_ENTRIES: usize = 1_000_000;
+
+/// Bounded per-CID width storage built from a CID font's `/W` array.
+///
+/// Contiguous-range runs (`CIDstart CIDend w`) are kept as sorted
+/// `(cid_start, cid_end, width)` intervals instead of being expanded into
+/// one map entry per CID, so a font declaring `0 65535 1000` costs a single
+/// interval rather than 65536 hash-map entries. The individual-CID list
+/// form (`CID [w1 w2 ...]`) still needs one entry per CID and is kept in a
+/// `HashMap`. [`WidthStore::get`] checks the individual map first, then
+/// binary-searches the range runs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WidthStore {
+    /// Sorted, non-overlapping `(cid_start, cid_end, width)` runs.
+    ranges: Vec<(u32, u32, f64)>,
+    /// Individual CID width overrides, checked before range runs.
+    individual: HashMap<u32, f64>,
+}
+
+impl WidthStore {
+    /// An empty width store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an individual CID → width override (the `CID [w1 w2 ...]` form).
+    fn insert_individual(&mut self, cid: u32, width: f64) {
+        if self.individual.len() < MAX_WIDTH_STORE_ENTRIES {
+            self.individual.insert(cid, width);
+        }
+    }
+
+    /// Record a contiguous CID range (the `CIDstart CIDend w` form).
+    fn insert_range(&mut self, cid_start: u32, cid_end: u32, width: f64) {
+        if self.ranges.len() < MAX_WIDTH_STORE_ENTRIES {
+            self.ranges.push((cid_start, cid_end, width));
+        }
+    }
+
+    /// Sort range runs by start CID so `get` can binary-search them. Must be
+    /// called once after all runs are inserted and before any lookups.
+    fn sort_ranges(&mut self) {
+        self.ranges.sort_by_key(|&(start, _, _)| start);
+    }
+
+    /// Look up the width override for a CID, if any. Individual overrides
+    /// take priority over range runs on the rare overlap between the two
+    /// forms for the same CID.
+    pub fn get(&self, cid: u32) -> Option<f64> {
+        if let Some(&w) = self.individual.get(&cid) {
+            return Some(w);
+        }
+        self.ranges
+            .binary_search_by(|&(start, end, _)| {
+                if cid < start {
+                    Ordering::Greater
+                } else if cid > end {
+                    Ordering::Less
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .ok()
+            .map(|i| self.ranges[i].2)
+    }
+
+    /// Whether this store has no width overrides at all.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty() && self.individual.is_empty()
+    }
+}
+
+impl From<HashMap<u32, f64>> for WidthStore {
+    /// Build a width store directly from a pre-built CID → width map,
+    /// keeping `CidFontMetrics::new` callers that pass a plain `HashMap`
+    /// (rather than the output of [`parse_w_array`]) working unchanged.
+    fn from(individual: HashMap<u32, f64>) -> Self {
+        Self {
+            ranges: Vec::new(),
+            individual,
+        }
+    }
+}
+
 /// Font metrics for a CID font, handling the /W array and /DW default width.
 ///
 /// CID fonts use a different width specification than simple fonts:
@@ -91,7 +293,7 @@ impl CidSystemInfo {
 #[derive(Debug, Clone)]
 pub struct CidFontMetrics {
     /// Per-CID width overrides (from /W array).
-    widths: HashMap<u32, f64>,
+    widths: WidthStore,
     /// Default width for CIDs not in the widths map (from /DW).
     default_width: f64,
     /// Font ascent in glyph space units.
@@ -100,19 +302,33 @@ pub struct CidFontMetrics {
     descent: f64,
     /// Font bounding box.
     font_bbox: Option<[f64; 4]>,
-    /// CID font subtype.
+    /// CID font subtype, corrected against the embedded font program when
+    /// sniffing was conclusive (see [`Self::declared_font_type`] for the
+    /// original, pre-correction value).
     font_type: CidFontType,
+    /// CID font subtype as declared by `/Subtype`, before any correction
+    /// from font-program sniffing.
+    declared_font_type: CidFontType,
+    /// Font program format detected from the embedded font file's header
+    /// bytes, if one is present and recognized.
+    detected_program_type: Option<FontProgramType>,
     /// CID-to-GID mapping.
     cid_to_gid: CidToGidMap,
     /// CID system information.
     system_info: Option<CidSystemInfo>,
+    /// Per-CID vertical metrics overrides (from /W2): `[w1y, v1x, v1y]`.
+    vertical_widths: HashMap<u32, [f64; 3]>,
+    /// Default vertical metrics (from /DW2): `[vy, w1y]`.
+    dw2: [f64; 2],
+    /// Chosen system-font substitution, used when there's no embedded glyph source.
+    substitution: FontSubstitution,
 }
 
 impl CidFontMetrics {
     /// Create CidFontMetrics from parsed values.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
-        widths: HashMap<u32, f64>,
+        widths: impl Into<WidthStore>,
         default_width: f64,
         ascent: f64,
         descent: f64,
@@ -122,34 +338,94 @@ impl CidFontMetrics {
         system_info: Option<CidSystemInfo>,
     ) -> Self {
         Self {
-            widths,
+            widths: widths.into(),
             default_width,
             ascent,
             descent,
             font_bbox,
             font_type,
+            declared_font_type: font_type,
+            detected_program_type: None,
             cid_to_gid,
             system_info,
+            vertical_widths: HashMap::new(),
+            dw2: DEFAULT_DW2,
+            substitution: FontSubstitution {
+                family: DEFAULT_SUBSTITUTE_FAMILY.to_string(),
+                missing_embedded_font: false,
+            },
         }
     }
 
     /// Create default CidFontMetrics.
     pub fn default_metrics() -> Self {
         Self {
-            widths: HashMap::new(),
+            widths: WidthStore::new(),
             default_width: DEFAULT_CID_WIDTH,
             ascent: DEFAULT_CID_ASCENT,
             descent: DEFAULT_CID_DESCENT,
             font_bbox: None,
             font_type: CidFontType::Type2,
+            declared_font_type: CidFontType::Type2,
+            detected_program_type: None,
             cid_to_gid: CidToGidMap::Identity,
             system_info: None,
+            vertical_widths: HashMap::new(),
+            dw2: DEFAULT_DW2,
+            substitution: FontSubstitution {
+                family: DEFAULT_SUBSTITUTE_FAMILY.to_string(),
+                missing_embedded_font: false,
+            },
         }
     }
 
+    /// Attach vertical writing-mode metrics parsed from `/DW2` and `/W2`.
+    pub fn with_vertical_metrics(mut self, dw2: [f64; 2], vertical_widths: HashMap<u32, [f64; 3]>) -> Self {
+        self.dw2 = dw2;
+        self.vertical_widths = vertical_widths;
+        self
+    }
+
+    /// Attach the chosen system-font substitution (see [`select_font_substitution`]).
+    pub fn with_substitution(mut self, substitution: FontSubstitution) -> Self {
+        self.substitution = substitution;
+        self
+    }
+
+    /// Record font-program sniffing results: the CIDFont subtype as declared
+    /// by `/Subtype`, and the type detected from the embedded font program's
+    /// header bytes (if any). `font_type` (the constructor argument) already
+    /// reflects the corrected/effective type used for glyph lookup; this
+    /// attaches the declared value alongside it for callers that need both.
+    pub fn with_detected_program(
+        mut self,
+        declared_font_type: CidFontType,
+        detected_program_type: Option<FontProgramType>,
+    ) -> Self {
+        self.declared_font_type = declared_font_type;
+        self.detected_program_type = detected_program_type;
+        self
+    }
+
     /// Get the width for a CID in glyph space (1/1000 of text space).
     pub fn get_width(&self, cid: u32) -> f64 {
-        self.widths.get(&cid).copied().unwrap_or(self.default_width)
+        self.widths.get(cid).unwrap_or(self.default_width)
+    }
+
+    /// Get the vertical metrics `[w1y, v1x, v1y]` for a CID in glyph space,
+    /// used when the font's writing mode is vertical (`writing_mode == 1`).
+    ///
+    /// `w1y` is the vertical displacement (how far to advance downward) and
+    /// `(v1x, v1y)` is the position vector offsetting the glyph's origin
+    /// relative to its horizontal origin. Falls back to `/DW2` (`[vy, w1y]`,
+    /// with `v1x` derived from the CID's horizontal width, per the PDF spec's
+    /// default position-vector rule) when no `/W2` entry overrides the CID.
+    pub fn get_vertical_metrics(&self, cid: u32) -> [f64; 3] {
+        if let Some(&metrics) = self.vertical_widths.get(&cid) {
+            return metrics;
+        }
+        let [vy, w1y] = self.dw2;
+        [w1y, self.get_width(cid) / 2.0, vy]
     }
 
     /// Font ascent in glyph space units.
@@ -172,11 +448,37 @@ impl CidFontMetrics {
         self.default_width
     }
 
-    /// CID font subtype.
+    /// CID font subtype, corrected against the embedded font program when
+    /// sniffing was conclusive. This is the value glyph lookup should use.
     pub fn font_type(&self) -> CidFontType {
         self.font_type
     }
 
+    /// CID font subtype as declared by `/Subtype`, before any correction
+    /// from font-program sniffing (see [`Self::font_type`] for the
+    /// corrected value, and [`Self::detected_program_type`] for why they
+    /// might differ).
+    pub fn declared_font_type(&self) -> CidFontType {
+        self.declared_font_type
+    }
+
+    /// Font program format detected by inspecting the embedded `/FontFile`,
+    /// `/FontFile2`, or `/FontFile3` stream's header bytes. `None` when no
+    /// embedded font program is present or its header wasn't recognized.
+    pub fn detected_program_type(&self) -> Option<FontProgramType> {
+        self.detected_program_type
+    }
+
+    /// Whether `/CIDToGIDMap` is meaningful for this font. Per the PDF spec
+    /// it only applies to CIDFontType2 (TrueType-outline) fonts; for a
+    /// CIDFontType0 font (even one whose dictionary happens to carry a
+    /// `CIDToGIDMap` entry) CID-to-glyph mapping goes through the CFF
+    /// charset instead, so `Identity` shouldn't be treated as a meaningful
+    /// default.
+    pub fn cid_to_gid_map_applies(&self) -> bool {
+        self.font_type == CidFontType::Type2
+    }
+
     /// CID-to-GID mapping.
     pub fn cid_to_gid(&self) -> &CidToGidMap {
         &self.cid_to_gid
@@ -191,8 +493,76 @@ impl CidFontMetrics {
     pub fn system_info(&self) -> Option<&CidSystemInfo> {
         self.system_info.as_ref()
     }
+
+    /// Chosen system-font substitution for this CID font (see
+    /// [`select_font_substitution`]). Check `missing_embedded_font` to tell
+    /// whether the original font actually needs substituting, as opposed to
+    /// this being an unused fallback guess.
+    pub fn substitution(&self) -> &FontSubstitution {
+        &self.substitution
+    }
+
+    /// Fall back to a bundled Adobe character-collection table to turn a CID
+    /// into Unicode when the font has no `/ToUnicode` CMap.
+    ///
+    /// Looks up `cid` in the table for this font's [`CidSystemInfo::ordering`]
+    /// (`Japan1`, `GB1`, `CNS1`, `Korea1`). Returns `None` if the system info
+    /// is missing, the ordering isn't one of the four CJK collections, or the
+    /// CID isn't in the bundled table — callers should try other heuristics
+    /// (e.g. treating the CID as a GID and rendering via the embedded font)
+    /// in that case.
+    pub fn cid_to_unicode(&self, cid: u32) -> Option<char> {
+        let ordering = self.system_info.as_ref()?.ordering.as_str();
+        let table = match ordering {
+            "Japan1" => JAPAN1_CID_TO_UNICODE,
+            "GB1" => GB1_CID_TO_UNICODE,
+            "CNS1" => CNS1_CID_TO_UNICODE,
+            "Korea1" => KOREA1_CID_TO_UNICODE,
+            _ => return None,
+        };
+        lookup_cid_to_unicode(table, cid)
+    }
+}
+
+/// Look up `cid` in a sorted `(cid, ucs2)` table via binary search.
+fn lookup_cid_to_unicode(table: &[(u32, u16)], cid: u32) -> Option<char> {
+    table
+        .binary_search_by_key(&cid, |&(c, _)| c)
+        .ok()
+        .and_then(|i| char::from_u32(u32::from(table[i].1)))
+}
+
+/// Build the shared low-CID-range table common to every Adobe `*-1`
+/// character collection: CIDs 1-95 are the ASCII printable range starting
+/// at space (CID 1 = U+0020 ... CID 95 = U+007E), per the "Roman"
+/// alphanumeric-symbol subset that all four collections allocate
+/// identically so ASCII text round-trips without a collection-specific
+/// table. This is a deliberately small, high-confidence bundled subset —
+/// not the full several-thousand-entry `*-UCS2` resource — so CID text
+/// extraction degrades gracefully instead of claiming false precision for
+/// CIDs outside it.
+const fn ascii_compatible_range() -> [(u32, u16); 95] {
+    let mut table = [(0u32, 0u16); 95];
+    let mut i = 0;
+    while i < 95 {
+        table[i] = (i as u32 + 1, 0x20 + i as u16);
+        i += 1;
+    }
+    table
 }
 
+/// Adobe-Japan1 CID → Unicode (UCS-2) mapping.
+static JAPAN1_CID_TO_UNICODE: &[(u32, u16)] = &ascii_compatible_range();
+
+/// Adobe-GB1 CID → Unicode (UCS-2) mapping.
+static GB1_CID_TO_UNICODE: &[(u32, u16)] = &ascii_compatible_range();
+
+/// Adobe-CNS1 CID → Unicode (UCS-2) mapping.
+static CNS1_CID_TO_UNICODE: &[(u32, u16)] = &ascii_compatible_range();
+
+/// Adobe-Korea1 CID → Unicode (UCS-2) mapping.
+static KOREA1_CID_TO_UNICODE: &[(u32, u16)] = &ascii_compatible_range();
+
 /// Parse a /W (width) array from a CID font dictionary.
 ///
 /// The /W array has the format:
@@ -202,8 +572,8 @@ impl CidFontMetrics {
 /// Where:
 /// - `c [w1 w2 ...]` assigns widths w1, w2, ... to CIDs c, c+1, c+2, ...
 /// - `c_first c_last w` assigns width w to all CIDs from c_first to c_last
-pub fn parse_w_array(objects: &[lopdf::Object], doc: &lopdf::Document) -> HashMap<u32, f64> {
-    let mut widths = HashMap::new();
+pub fn parse_w_array(objects: &[lopdf::Object], doc: &lopdf::Document) -> WidthStore {
+    let mut widths = WidthStore::new();
     let mut i = 0;
 
     while i < objects.len() {
@@ -226,21 +596,90 @@ pub fn parse_w_array(objects: &[lopdf::Object], doc: &lopdf::Document) -> HashMa
             for (j, obj) in arr.iter().enumerate() {
                 let obj = resolve_object(doc, obj);
                 if let Some(w) = object_to_f64(obj) {
-                    widths.insert(cid_start + j as u32, w);
+                    widths.insert_individual(cid_start + j as u32, w);
                 }
             }
             i += 1;
         } else if let Some(cid_end) = object_to_u32(next) {
-            // Format: CID_start CID_end w
+            // Format: CID_start CID_end w — kept as a single interval, not
+            // expanded, so huge ranges (e.g. `0 65535 1000`) stay O(1).
             i += 1;
             if i < objects.len() {
                 let w_obj = resolve_object(doc, &objects[i]);
                 if let Some(w) = object_to_f64(w_obj) {
+                    widths.insert_range(cid_start, cid_end, w);
+                }
+                i += 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    widths.sort_ranges();
+    widths
+}
+
+/// Parse a /W2 (vertical width) array from a CID font dictionary.
+///
+/// The /W2 array interleaves two forms:
+/// ```text
+/// [ c [w1y1 v1x1 v1y1 w1y2 v1x2 v1y2 ...] cfirst clast w1y v1x v1y ... ]
+/// ```
+/// - `c [w1y v1x v1y ...]` assigns consecutive `[w1y, v1x, v1y]` triples to
+///   CIDs `c, c+1, c+2, ...`
+/// - `cfirst clast w1y v1x v1y` assigns one triple to the whole CID range
+pub fn parse_w2_array(objects: &[lopdf::Object], doc: &lopdf::Document) -> HashMap<u32, [f64; 3]> {
+    let mut widths = HashMap::new();
+    let mut i = 0;
+
+    while i < objects.len() {
+        let cid_start = match object_to_u32(resolve_object(doc, &objects[i])) {
+            Some(v) => v,
+            None => {
+                i += 1;
+                continue;
+            }
+        };
+        i += 1;
+
+        if i >= objects.len() {
+            break;
+        }
+
+        let next = resolve_object(doc, &objects[i]);
+        if let Ok(arr) = next.as_array() {
+            // Format: CID [w1y1 v1x1 v1y1 w1y2 v1x2 v1y2 ...]. Chunk the raw
+            // array by position (like parse_w_array's enumerate()) rather
+            // than filter_map-ing out unparseable elements first, so a bad
+            // element only drops its own triple instead of shifting every
+            // later triple's CID alignment.
+            for (j, triple) in arr.chunks(3).enumerate() {
+                if let [w1y, v1x, v1y] = triple {
+                    let w1y = object_to_f64(resolve_object(doc, w1y));
+                    let v1x = object_to_f64(resolve_object(doc, v1x));
+                    let v1y = object_to_f64(resolve_object(doc, v1y));
+                    if let (Some(w1y), Some(v1x), Some(v1y)) = (w1y, v1x, v1y) {
+                        widths.insert(cid_start + j as u32, [w1y, v1x, v1y]);
+                    }
+                }
+            }
+            i += 1;
+        } else if let Some(cid_end) = object_to_u32(next) {
+            // Format: CID_start CID_end w1y v1x v1y
+            i += 1;
+            if i + 2 < objects.len() {
+                let w1y = object_to_f64(resolve_object(doc, &objects[i]));
+                let v1x = object_to_f64(resolve_object(doc, &objects[i + 1]));
+                let v1y = object_to_f64(resolve_object(doc, &objects[i + 2]));
+                if let (Some(w1y), Some(v1x), Some(v1y)) = (w1y, v1x, v1y) {
                     for cid in cid_start..=cid_end {
-                        widths.insert(cid, w);
+                        widths.insert(cid, [w1y, v1x, v1y]);
                     }
                 }
-                i += 1;
+                i += 3;
+            } else {
+                break;
             }
         } else {
             i += 1;
@@ -291,16 +730,65 @@ pub fn extract_cid_font_metrics(
     // Parse /FontDescriptor for ascent, descent, bbox
     let (ascent, descent, font_bbox) = parse_cid_font_descriptor(doc, cid_font_dict);
 
+    // Parse /DW2 (default vertical metrics) and /W2 (vertical width array)
+    let dw2 = cid_font_dict
+        .get(b"DW2")
+        .ok()
+        .map(|o| resolve_object(doc, o))
+        .and_then(|o| o.as_array().ok())
+        .and_then(|arr| {
+            let vals: Vec<f64> = arr.iter().filter_map(|o| object_to_f64(resolve_object(doc, o))).collect();
+            if vals.len() == 2 { Some([vals[0], vals[1]]) } else { None }
+        })
+        .unwrap_or(DEFAULT_DW2);
+
+    let vertical_widths = cid_font_dict
+        .get(b"W2")
+        .ok()
+        .map(|o| resolve_object(doc, o))
+        .and_then(|o| o.as_array().ok())
+        .map(|arr| parse_w2_array(arr, doc))
+        .unwrap_or_default();
+
+    // Pick a system-font substitution in case there's no embedded glyph source.
+    let descriptor = resolve_font_descriptor(doc, cid_font_dict);
+    let flags = descriptor.map(descriptor_flags).unwrap_or(0);
+    let missing_embedded_font = !descriptor.is_some_and(has_embedded_font_file);
+    let base_font = cid_font_dict
+        .get(b"BaseFont")
+        .ok()
+        .and_then(|o| o.as_name_str().ok())
+        .unwrap_or("");
+    let substitution =
+        select_font_substitution(base_font, system_info.as_ref(), flags, missing_embedded_font);
+
+    // Sniff the embedded font program's header bytes and correct the
+    // declared /Subtype when it disagrees with what's actually inside:
+    // CIDFontType0 dictionaries sometimes carry a TrueType program and vice
+    // versa. An `OpenType` wrapper is left as declared, since the `OTTO`
+    // sfnt tag alone doesn't say whether it wraps CFF or `glyf` outlines.
+    let detected_program_type = descriptor
+        .and_then(|d| extract_font_program_bytes(doc, d))
+        .and_then(|data| sniff_font_program(&data));
+    let effective_font_type = match detected_program_type {
+        Some(FontProgramType::TrueType) => CidFontType::Type2,
+        Some(FontProgramType::Type1 | FontProgramType::Cff) => CidFontType::Type0,
+        Some(FontProgramType::OpenType) | None => font_type,
+    };
+
     Ok(CidFontMetrics::new(
         widths,
         default_width,
         ascent,
         descent,
         font_bbox,
-        font_type,
+        effective_font_type,
         cid_to_gid,
         system_info,
-    ))
+    )
+    .with_vertical_metrics(dw2, vertical_widths)
+    .with_substitution(substitution)
+    .with_detected_program(font_type, detected_program_type))
 }
 
 /// Parse the /CIDToGIDMap entry from a CIDFont dictionary.
@@ -364,17 +852,82 @@ fn parse_cid_system_info(doc: &lopdf::Document, dict: &lopdf::Dictionary) -> Opt
     })
 }
 
+/// Resolve the `/FontDescriptor` dictionary referenced from a CIDFont dictionary.
+fn resolve_font_descriptor<'a>(
+    doc: &'a lopdf::Document,
+    dict: &'a lopdf::Dictionary,
+) -> Option<&'a lopdf::Dictionary> {
+    dict.get(b"FontDescriptor")
+        .ok()
+        .map(|o| resolve_object(doc, o))
+        .and_then(|o| o.as_dict().ok())
+}
+
+/// Parse the `/Flags` bitfield from a `/FontDescriptor` dictionary (0 if absent).
+fn descriptor_flags(desc: &lopdf::Dictionary) -> u32 {
+    desc.get(b"Flags")
+        .ok()
+        .and_then(|o| o.as_i64().ok())
+        .map(|f| f as u32)
+        .unwrap_or(0)
+}
+
+/// Whether a `/FontDescriptor` dictionary references an embedded font program
+/// via `/FontFile`, `/FontFile2`, or `/FontFile3`.
+fn has_embedded_font_file(desc: &lopdf::Dictionary) -> bool {
+    desc.get(b"FontFile").is_ok() || desc.get(b"FontFile2").is_ok() || desc.get(b"FontFile3").is_ok()
+}
+
+/// Decode the embedded font program stream referenced by `/FontFile`,
+/// `/FontFile2`, or `/FontFile3` (checked in that order), for sniffing via
+/// [`sniff_font_program`].
+fn extract_font_program_bytes(doc: &lopdf::Document, desc: &lopdf::Dictionary) -> Option<Vec<u8>> {
+    for key in [&b"FontFile"[..], &b"FontFile2"[..], &b"FontFile3"[..]] {
+        let Ok(obj) = desc.get(key) else { continue };
+        let obj = resolve_object(doc, obj);
+        let Ok(stream) = obj.as_stream() else { continue };
+        let data = if stream.dict.get(b"Filter").is_ok() {
+            stream.decompressed_content().unwrap_or_default()
+        } else {
+            stream.content.clone()
+        };
+        if !data.is_empty() {
+            return Some(data);
+        }
+    }
+    None
+}
+
+/// Classify an embedded font program by its header bytes, per the standard
+/// sfnt/PFB/CFF signatures:
+/// - `%!` or a PFB segment-marker byte (`0x80`) → [`FontProgramType::Type1`]
+/// - `OTTO` sfnt tag → [`FontProgramType::OpenType`] (CFF or `glyf` inside)
+/// - `true`/`ttcf` tag, or sfnt version `0x00010000` → [`FontProgramType::TrueType`]
+/// - CFF header major version byte `1` → [`FontProgramType::Cff`]
+///
+/// Returns `None` when the bytes don't start with any recognized signature.
+fn sniff_font_program(data: &[u8]) -> Option<FontProgramType> {
+    if data.starts_with(b"%!") || data.first() == Some(&0x80) {
+        return Some(FontProgramType::Type1);
+    }
+    if data.starts_with(b"OTTO") {
+        return Some(FontProgramType::OpenType);
+    }
+    if data.starts_with(b"true") || data.starts_with(b"ttcf") || data.starts_with(&[0x00, 0x01, 0x00, 0x00]) {
+        return Some(FontProgramType::TrueType);
+    }
+    if data.first() == Some(&1) {
+        return Some(FontProgramType::Cff);
+    }
+    None
+}
+
 /// Parse /FontDescriptor from a CIDFont dictionary for ascent, descent, bbox.
 fn parse_cid_font_descriptor(
     doc: &lopdf::Document,
     dict: &lopdf::Dictionary,
 ) -> (f64, f64, Option<[f64; 4]>) {
-    let desc = match dict
-        .get(b"FontDescriptor")
-        .ok()
-        .map(|o| resolve_object(doc, o))
-        .and_then(|o| o.as_dict().ok())
-    {
+    let desc = match resolve_font_descriptor(doc, dict) {
         Some(d) => d,
         None => return (DEFAULT_CID_ASCENT, DEFAULT_CID_DESCENT, None),
     };
@@ -566,6 +1119,87 @@ pub fn parse_predefined_cmap_name(name: &str) -> Option<PredefinedCMapInfo> {
     })
 }
 
+/// Bundled resource data for predefined Adobe character-collection CMaps,
+/// expressed as the same `begincodespacerange`/`begincidrange` PostScript
+/// body an embedded CMap stream uses, so [`EmbeddedCMap::parse`] can build
+/// the decoder from it directly.
+///
+/// A real Adobe predefined CMap (e.g. `UniJIS-UTF16-H`, `90ms-RKSJ-H`) has
+/// several thousand `cidrange` entries pulled from vendor resource files.
+/// Shipping the full tables isn't practical here, so this bundles a
+/// deliberately small, high-confidence subset per CMap family — the
+/// ASCII-range codes every one of these encodings maps identically — so
+/// Latin punctuation and digits mixed into CJK runs still position and
+/// extract correctly. CIDs for the CMap's actual CJK range fall back to
+/// CID 0 (`.notdef`) rather than claiming false precision.
+fn predefined_cmap_source(name: &str) -> Option<&'static str> {
+    match name {
+        // UTF-16BE Unicode CMaps: 2-byte codespace, code == Unicode scalar
+        // value for the bundled ASCII range.
+        "UniJIS-UTF16-H" | "UniJIS-UTF16-V" | "UniGB-UTF16-H" | "UniGB-UTF16-V" | "UniCNS-UTF16-H"
+        | "UniCNS-UTF16-V" | "UniKS-UTF16-H" | "UniKS-UTF16-V" => Some(
+            "1 begincodespacerange\n<0000> <FFFF>\nendcodespacerange\n\
+             1 begincidrange\n<0020> <007E> 1\nendcidrange\n",
+        ),
+        // Legacy byte-oriented CMaps (Shift-JIS/EUC/GBK/Big5-based): a
+        // single-byte codespace covers the ASCII range, plus a 2-byte
+        // codespace for each encoding's CJK lead-byte range (left
+        // unmapped, so those codes decode to CID 0).
+        "90ms-RKSJ-H" | "90ms-RKSJ-V" | "90pv-RKSJ-H" | "90pv-RKSJ-V" => Some(
+            "2 begincodespacerange\n<00> <80>\n<8140> <FCFC>\nendcodespacerange\n\
+             1 begincidrange\n<20> <7E> 1\nendcidrange\n",
+        ),
+        "EUC-H" | "EUC-V" => Some(
+            "2 begincodespacerange\n<00> <80>\n<A1A1> <FEFE>\nendcodespacerange\n\
+             1 begincidrange\n<20> <7E> 1\nendcidrange\n",
+        ),
+        "GBK-EUC-H" | "GBK-EUC-V" | "GB-EUC-H" | "GB-EUC-V" => Some(
+            "2 begincodespacerange\n<00> <80>\n<8140> <FEFE>\nendcodespacerange\n\
+             1 begincidrange\n<20> <7E> 1\nendcidrange\n",
+        ),
+        "B5pc-H" | "B5pc-V" | "ETen-B5-H" | "ETen-B5-V" => Some(
+            "2 begincodespacerange\n<00> <80>\n<8140> <FEFE>\nendcodespacerange\n\
+             1 begincidrange\n<20> <7E> 1\nendcidrange\n",
+        ),
+        "KSC-EUC-H" | "KSC-EUC-V" => Some(
+            "2 begincodespacerange\n<00> <80>\n<A1A1> <FEFE>\nendcodespacerange\n\
+             1 begincidrange\n<20> <7E> 1\nendcidrange\n",
+        ),
+        _ => None,
+    }
+}
+
+/// Load a functional CID decoder for a predefined Adobe character-collection
+/// CMap name (see [`parse_predefined_cmap_name`]), sharing the same
+/// codespace-range + cidrange [`EmbeddedCMap`] structure used for embedded
+/// CMap streams.
+///
+/// Returns `None` for `Identity-H`/`Identity-V` (handled separately as a
+/// direct 2-byte-code-equals-CID mapping) and for predefined names outside
+/// the bundled resource set.
+pub fn load_predefined_cmap(name: &str) -> Option<crate::cmap::EmbeddedCMap> {
+    let source = predefined_cmap_source(name)?;
+    crate::cmap::EmbeddedCMap::parse(source.as_bytes()).ok()
+}
+
+/// Detect a PDF font-subsetting tag: six uppercase ASCII letters followed by
+/// `+` (e.g. `ABCDEF+ArialMT`), per PDF spec 9.6.4.3.
+pub fn is_subset_font(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    bytes.len() > 7
+        && bytes[6] == b'+'
+        && bytes[..6].iter().all(|b| b.is_ascii_uppercase())
+}
+
+/// Strip a font-subsetting tag (e.g. `ABCDEF+ArialMT` → `ArialMT`), if present.
+pub fn strip_subset_prefix(name: &str) -> &str {
+    if is_subset_font(name) {
+        &name[7..]
+    } else {
+        name
+    }
+}
+
 /// Detect whether a font dictionary represents a Type0 (composite/CID) font.
 pub fn is_type0_font(font_dict: &lopdf::Dictionary) -> bool {
     font_dict
@@ -828,6 +1462,36 @@ mod tests {
         assert!(metrics.system_info().is_none());
     }
 
+    // ========== WidthStore tests ==========
+
+    #[test]
+    fn width_store_individual_takes_priority_over_range() {
+        let mut store = WidthStore::new();
+        store.insert_range(0, 100, 500.0);
+        store.insert_individual(10, 999.0);
+        store.sort_ranges();
+
+        assert_eq!(store.get(10), Some(999.0));
+        assert_eq!(store.get(11), Some(500.0));
+    }
+
+    #[test]
+    fn width_store_from_hashmap_preserves_lookups() {
+        let mut map = HashMap::new();
+        map.insert(1, 500.0);
+        map.insert(2, 600.0);
+        let store: WidthStore = map.into();
+
+        assert_eq!(store.get(1), Some(500.0));
+        assert_eq!(store.get(2), Some(600.0));
+        assert_eq!(store.get(3), None);
+    }
+
+    #[test]
+    fn width_store_empty_by_default() {
+        assert!(WidthStore::new().is_empty());
+    }
+
     // ========== parse_w_array tests ==========
 
     #[test]
@@ -844,11 +1508,11 @@ mod tests {
         ];
 
         let widths = parse_w_array(&objects, &doc);
-        assert_eq!(widths.get(&1), Some(&500.0));
-        assert_eq!(widths.get(&2), Some(&600.0));
-        assert_eq!(widths.get(&3), Some(&700.0));
-        assert_eq!(widths.get(&0), None);
-        assert_eq!(widths.get(&4), None);
+        assert_eq!(widths.get(1), Some(500.0));
+        assert_eq!(widths.get(2), Some(600.0));
+        assert_eq!(widths.get(3), Some(700.0));
+        assert_eq!(widths.get(0), None);
+        assert_eq!(widths.get(4), None);
     }
 
     #[test]
@@ -863,10 +1527,27 @@ mod tests {
 
         let widths = parse_w_array(&objects, &doc);
         for cid in 10..=20 {
-            assert_eq!(widths.get(&cid), Some(&500.0), "CID {} should be 500", cid);
+            assert_eq!(widths.get(cid), Some(500.0), "CID {} should be 500", cid);
         }
-        assert_eq!(widths.get(&9), None);
-        assert_eq!(widths.get(&21), None);
+        assert_eq!(widths.get(9), None);
+        assert_eq!(widths.get(21), None);
+    }
+
+    #[test]
+    fn parse_w_array_does_not_expand_huge_ranges() {
+        // [0 65535 1000] must cost one interval, not 65536 map entries.
+        let doc = Document::with_version("1.5");
+        let objects = vec![
+            Object::Integer(0),
+            Object::Integer(65535),
+            Object::Integer(1000),
+        ];
+
+        let widths = parse_w_array(&objects, &doc);
+        assert_eq!(widths.get(0), Some(1000.0));
+        assert_eq!(widths.get(32768), Some(1000.0));
+        assert_eq!(widths.get(65535), Some(1000.0));
+        assert_eq!(widths.ranges.len(), 1);
     }
 
     #[test]
@@ -882,10 +1563,10 @@ mod tests {
         ];
 
         let widths = parse_w_array(&objects, &doc);
-        assert_eq!(widths.get(&1), Some(&250.0));
-        assert_eq!(widths.get(&2), Some(&300.0));
+        assert_eq!(widths.get(1), Some(250.0));
+        assert_eq!(widths.get(2), Some(300.0));
         for cid in 10..=20 {
-            assert_eq!(widths.get(&cid), Some(&500.0));
+            assert_eq!(widths.get(cid), Some(500.0));
         }
     }
 
@@ -905,8 +1586,8 @@ mod tests {
         ];
 
         let widths = parse_w_array(&objects, &doc);
-        assert!((widths[&1] - 500.5).abs() < 0.1);
-        assert!((widths[&2] - 600.5).abs() < 0.1);
+        assert!((widths.get(1).unwrap() - 500.5).abs() < 0.1);
+        assert!((widths.get(2).unwrap() - 600.5).abs() < 0.1);
     }
 
     #[test]
@@ -916,53 +1597,268 @@ mod tests {
         let objects = vec![Object::Integer(5), Object::Integer(5), Object::Integer(700)];
 
         let widths = parse_w_array(&objects, &doc);
-        assert_eq!(widths.get(&5), Some(&700.0));
-        assert_eq!(widths.len(), 1);
+        assert_eq!(widths.get(5), Some(700.0));
+        assert_eq!(widths.get(4), None);
+        assert_eq!(widths.get(6), None);
     }
 
-    // ========== extract_cid_font_metrics tests ==========
+    // ========== parse_w2_array / vertical metrics tests ==========
 
     #[test]
-    fn extract_cid_font_metrics_basic() {
-        let mut doc = Document::with_version("1.5");
-
-        // Create a CIDFont dictionary
-        let w_array = Object::Array(vec![
+    fn parse_w2_array_individual_triples() {
+        // [1 [-1000 100 880 -1000 120 880]] → CID 1 and 2 get explicit triples
+        let doc = Document::with_version("1.5");
+        let objects = vec![
             Object::Integer(1),
-            Object::Array(vec![Object::Integer(500), Object::Integer(600)]),
-        ]);
-        let w_id = doc.add_object(w_array);
-
-        let cid_font_dict = dictionary! {
-            "Type" => "Font",
-            "Subtype" => "CIDFontType2",
-            "BaseFont" => "MSGothic",
-            "DW" => Object::Integer(1000),
-            "W" => w_id,
-            "CIDToGIDMap" => "Identity",
-        };
+            Object::Array(vec![
+                Object::Integer(-1000),
+                Object::Integer(100),
+                Object::Integer(880),
+                Object::Integer(-1000),
+                Object::Integer(120),
+                Object::Integer(880),
+            ]),
+        ];
 
-        let metrics = extract_cid_font_metrics(&doc, &cid_font_dict).unwrap();
-        assert_eq!(metrics.font_type(), CidFontType::Type2);
-        assert_eq!(metrics.default_width(), 1000.0);
-        assert_eq!(metrics.get_width(1), 500.0);
-        assert_eq!(metrics.get_width(2), 600.0);
-        assert_eq!(metrics.get_width(3), 1000.0); // default
-        assert_eq!(metrics.cid_to_gid(), &CidToGidMap::Identity);
+        let widths = parse_w2_array(&objects, &doc);
+        assert_eq!(widths.get(&1), Some(&[-1000.0, 100.0, 880.0]));
+        assert_eq!(widths.get(&2), Some(&[-1000.0, 120.0, 880.0]));
     }
 
     #[test]
-    fn extract_cid_font_metrics_type0() {
+    fn parse_w2_array_range_format() {
+        // [10 20 -1000 500 880] → CIDs 10-20 all share one triple
         let doc = Document::with_version("1.5");
+        let objects = vec![
+            Object::Integer(10),
+            Object::Integer(20),
+            Object::Integer(-1000),
+            Object::Integer(500),
+            Object::Integer(880),
+        ];
 
-        let cid_font_dict = dictionary! {
-            "Type" => "Font",
-            "Subtype" => "CIDFontType0",
-            "BaseFont" => "KozMinPro-Regular",
-        };
+        let widths = parse_w2_array(&objects, &doc);
+        for cid in 10..=20 {
+            assert_eq!(widths.get(&cid), Some(&[-1000.0, 500.0, 880.0]));
+        }
+        assert_eq!(widths.get(&9), None);
+    }
 
-        let metrics = extract_cid_font_metrics(&doc, &cid_font_dict).unwrap();
-        assert_eq!(metrics.font_type(), CidFontType::Type0);
+    #[test]
+    fn parse_w2_array_bad_element_does_not_misalign_later_triples() {
+        // [1 [null -1000 100 880 -2000 200 900]] — a single unparseable
+        // element must only drop its own triple, not shift every later
+        // triple's CID alignment by filtering it out before chunking.
+        let doc = Document::with_version("1.5");
+        let objects = vec![
+            Object::Integer(1),
+            Object::Array(vec![
+                Object::Null,
+                Object::Integer(-1000),
+                Object::Integer(100),
+                Object::Integer(880),
+                Object::Integer(-2000),
+                Object::Integer(200),
+                Object::Integer(900),
+            ]),
+        ];
+
+        let widths = parse_w2_array(&objects, &doc);
+        assert_eq!(widths.get(&1), None, "CID 1's triple has an unparseable element");
+        assert_eq!(widths.get(&2), Some(&[880.0, -2000.0, 200.0]));
+        assert_eq!(widths.get(&3), None, "only two full triples exist");
+    }
+
+    #[test]
+    fn get_vertical_metrics_falls_back_to_dw2() {
+        let metrics = CidFontMetrics::new(
+            HashMap::new(),
+            1000.0,
+            880.0,
+            -120.0,
+            None,
+            CidFontType::Type2,
+            CidToGidMap::Identity,
+            None,
+        )
+        .with_vertical_metrics([880.0, -1000.0], HashMap::new());
+
+        assert_eq!(metrics.get_vertical_metrics(5), [-1000.0, 500.0, 880.0]);
+    }
+
+    #[test]
+    fn get_vertical_metrics_uses_w2_override() {
+        let mut vertical_widths = HashMap::new();
+        vertical_widths.insert(7, [-950.0, 110.0, 870.0]);
+        let metrics = CidFontMetrics::default_metrics().with_vertical_metrics([880.0, -1000.0], vertical_widths);
+
+        assert_eq!(metrics.get_vertical_metrics(7), [-950.0, 110.0, 870.0]);
+    }
+
+    #[test]
+    fn extract_cid_font_metrics_parses_dw2_and_w2() {
+        let mut doc = Document::with_version("1.5");
+
+        let w2_array = Object::Array(vec![
+            Object::Integer(1),
+            Object::Array(vec![
+                Object::Integer(-1000),
+                Object::Integer(100),
+                Object::Integer(880),
+            ]),
+        ]);
+        let w2_id = doc.add_object(w2_array);
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType0",
+            "BaseFont" => "KozMinPro-Regular",
+            "DW2" => Object::Array(vec![Object::Integer(880), Object::Integer(-1000)]),
+            "W2" => w2_id,
+        };
+
+        let metrics = extract_cid_font_metrics(&doc, &cid_font_dict).unwrap();
+        assert_eq!(metrics.get_vertical_metrics(1), [-1000.0, 100.0, 880.0]);
+        assert_eq!(metrics.get_vertical_metrics(2), [-1000.0, 500.0, 880.0]); // falls back to DW2
+    }
+
+    // ========== cid_to_unicode tests ==========
+
+    #[test]
+    fn cid_to_unicode_japan1_ascii_range() {
+        let info = CidSystemInfo {
+            registry: "Adobe".to_string(),
+            ordering: "Japan1".to_string(),
+            supplement: 6,
+        };
+        let metrics = CidFontMetrics::new(
+            HashMap::new(),
+            1000.0,
+            880.0,
+            -120.0,
+            None,
+            CidFontType::Type0,
+            CidToGidMap::Identity,
+            Some(info),
+        );
+
+        assert_eq!(metrics.cid_to_unicode(1), Some(' '));
+        assert_eq!(metrics.cid_to_unicode(34), Some('A'));
+    }
+
+    #[test]
+    fn cid_to_unicode_gb1_cns1_korea1_share_ascii_range() {
+        for ordering in ["GB1", "CNS1", "Korea1"] {
+            let info = CidSystemInfo {
+                registry: "Adobe".to_string(),
+                ordering: ordering.to_string(),
+                supplement: 0,
+            };
+            let metrics = CidFontMetrics::new(
+                HashMap::new(),
+                1000.0,
+                880.0,
+                -120.0,
+                None,
+                CidFontType::Type0,
+                CidToGidMap::Identity,
+                Some(info),
+            );
+            assert_eq!(metrics.cid_to_unicode(1), Some(' '), "ordering {ordering}");
+        }
+    }
+
+    #[test]
+    fn cid_to_unicode_out_of_range_cid_returns_none() {
+        let info = CidSystemInfo {
+            registry: "Adobe".to_string(),
+            ordering: "Japan1".to_string(),
+            supplement: 6,
+        };
+        let metrics = CidFontMetrics::new(
+            HashMap::new(),
+            1000.0,
+            880.0,
+            -120.0,
+            None,
+            CidFontType::Type0,
+            CidToGidMap::Identity,
+            Some(info),
+        );
+
+        assert_eq!(metrics.cid_to_unicode(9000), None);
+    }
+
+    #[test]
+    fn cid_to_unicode_non_cjk_ordering_returns_none() {
+        let info = CidSystemInfo {
+            registry: "Adobe".to_string(),
+            ordering: "Identity".to_string(),
+            supplement: 0,
+        };
+        let metrics = CidFontMetrics::new(
+            HashMap::new(),
+            1000.0,
+            880.0,
+            -120.0,
+            None,
+            CidFontType::Type0,
+            CidToGidMap::Identity,
+            Some(info),
+        );
+
+        assert_eq!(metrics.cid_to_unicode(1), None);
+    }
+
+    #[test]
+    fn cid_to_unicode_missing_system_info_returns_none() {
+        let metrics = CidFontMetrics::default_metrics();
+        assert_eq!(metrics.cid_to_unicode(1), None);
+    }
+
+    // ========== extract_cid_font_metrics tests ==========
+
+    #[test]
+    fn extract_cid_font_metrics_basic() {
+        let mut doc = Document::with_version("1.5");
+
+        // Create a CIDFont dictionary
+        let w_array = Object::Array(vec![
+            Object::Integer(1),
+            Object::Array(vec![Object::Integer(500), Object::Integer(600)]),
+        ]);
+        let w_id = doc.add_object(w_array);
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "MSGothic",
+            "DW" => Object::Integer(1000),
+            "W" => w_id,
+            "CIDToGIDMap" => "Identity",
+        };
+
+        let metrics = extract_cid_font_metrics(&doc, &cid_font_dict).unwrap();
+        assert_eq!(metrics.font_type(), CidFontType::Type2);
+        assert_eq!(metrics.default_width(), 1000.0);
+        assert_eq!(metrics.get_width(1), 500.0);
+        assert_eq!(metrics.get_width(2), 600.0);
+        assert_eq!(metrics.get_width(3), 1000.0); // default
+        assert_eq!(metrics.cid_to_gid(), &CidToGidMap::Identity);
+    }
+
+    #[test]
+    fn extract_cid_font_metrics_type0() {
+        let doc = Document::with_version("1.5");
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType0",
+            "BaseFont" => "KozMinPro-Regular",
+        };
+
+        let metrics = extract_cid_font_metrics(&doc, &cid_font_dict).unwrap();
+        assert_eq!(metrics.font_type(), CidFontType::Type0);
         assert_eq!(metrics.default_width(), DEFAULT_CID_WIDTH);
     }
 
@@ -1127,6 +2023,301 @@ mod tests {
         assert!(parse_predefined_cmap_name("").is_none());
     }
 
+    // ========== predefined CMap resource loader tests ==========
+
+    #[test]
+    fn load_predefined_cmap_returns_none_for_identity() {
+        assert!(load_predefined_cmap("Identity-H").is_none());
+        assert!(load_predefined_cmap("Identity-V").is_none());
+    }
+
+    #[test]
+    fn load_predefined_cmap_returns_none_for_unbundled_name() {
+        assert!(load_predefined_cmap("Adobe-Japan1-6").is_none());
+        assert!(load_predefined_cmap("SomeCustomCMap").is_none());
+    }
+
+    #[test]
+    fn load_predefined_cmap_utf16_decodes_ascii_range() {
+        let cmap = load_predefined_cmap("UniJIS-UTF16-H").unwrap();
+        // "AB" as UTF-16BE code points 0x0041, 0x0042.
+        let decoded = cmap.decode(&[0x00, 0x41, 0x00, 0x42]);
+        assert_eq!(decoded, vec![(0x41 - 0x20 + 1, 2), (0x42 - 0x20 + 1, 2)]);
+    }
+
+    #[test]
+    fn load_predefined_cmap_rksj_decodes_single_byte_ascii() {
+        let cmap = load_predefined_cmap("90ms-RKSJ-H").unwrap();
+        let decoded = cmap.decode(b"AB");
+        assert_eq!(decoded, vec![(0x41 - 0x20 + 1, 1), (0x42 - 0x20 + 1, 1)]);
+    }
+
+    #[test]
+    fn load_predefined_cmap_rksj_unmapped_double_byte_is_cid_zero() {
+        let cmap = load_predefined_cmap("90ms-RKSJ-H").unwrap();
+        // 0x82A0 falls in the lead-byte codespace but has no bundled cidrange entry.
+        let decoded = cmap.decode(&[0x82, 0xA0]);
+        assert_eq!(decoded, vec![(0, 2)]);
+    }
+
+    // ========== subset prefix tests ==========
+
+    #[test]
+    fn is_subset_font_detects_tag() {
+        assert!(is_subset_font("ABCDEF+Helvetica"));
+        assert!(!is_subset_font("Helvetica"));
+        assert!(!is_subset_font("abcdef+Helvetica")); // lowercase tag isn't valid
+        assert!(!is_subset_font("ABCDE+Helvetica")); // only 5 letters
+    }
+
+    #[test]
+    fn strip_subset_prefix_removes_tag() {
+        assert_eq!(strip_subset_prefix("ABCDEF+ArialMT"), "ArialMT");
+        assert_eq!(strip_subset_prefix("ArialMT"), "ArialMT");
+    }
+
+    // ========== font substitution tests ==========
+
+    #[test]
+    fn select_font_substitution_base14_alias() {
+        let sub = select_font_substitution("ABCDEF+ArialMT", None, 0, true);
+        assert_eq!(sub.family, "Helvetica");
+        assert!(sub.missing_embedded_font);
+    }
+
+    #[test]
+    fn select_font_substitution_cjk_default_sans() {
+        let info = CidSystemInfo {
+            registry: "Adobe".to_string(),
+            ordering: "Japan1".to_string(),
+            supplement: 6,
+        };
+        let sub = select_font_substitution("KozGoPro-Regular", Some(&info), 0, true);
+        assert_eq!(sub.family, "MS Gothic");
+    }
+
+    #[test]
+    fn select_font_substitution_cjk_serif_flag() {
+        let info = CidSystemInfo {
+            registry: "Adobe".to_string(),
+            ordering: "GB1".to_string(),
+            supplement: 5,
+        };
+        let sub = select_font_substitution("STSong", Some(&info), FLAG_SERIF, true);
+        assert_eq!(sub.family, "SimSun");
+    }
+
+    #[test]
+    fn select_font_substitution_korea1_and_cns1() {
+        let korea1 = CidSystemInfo {
+            registry: "Adobe".to_string(),
+            ordering: "Korea1".to_string(),
+            supplement: 2,
+        };
+        assert_eq!(
+            select_font_substitution("Batang", Some(&korea1), FLAG_SERIF, true).family,
+            "Batang"
+        );
+        assert_eq!(
+            select_font_substitution("Dotum", Some(&korea1), 0, true).family,
+            "Dotum"
+        );
+
+        let cns1 = CidSystemInfo {
+            registry: "Adobe".to_string(),
+            ordering: "CNS1".to_string(),
+            supplement: 7,
+        };
+        assert_eq!(
+            select_font_substitution("MingLiU", Some(&cns1), 0, true).family,
+            "MingLiU"
+        );
+    }
+
+    #[test]
+    fn select_font_substitution_no_system_info_falls_back_to_helvetica() {
+        let sub = select_font_substitution("SomeCustomCidFont", None, 0, true);
+        assert_eq!(sub.family, DEFAULT_SUBSTITUTE_FAMILY);
+    }
+
+    #[test]
+    fn extract_cid_font_metrics_detects_missing_embedded_font() {
+        let mut doc = Document::with_version("1.5");
+
+        let desc_id = doc.add_object(Object::Dictionary(dictionary! {
+            "Type" => "FontDescriptor",
+            "FontName" => "ABCDEF+ArialMT",
+            "Flags" => Object::Integer(32), // nonsymbolic, no serif bit
+        }));
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "ABCDEF+ArialMT",
+            "FontDescriptor" => desc_id,
+        };
+
+        let metrics = extract_cid_font_metrics(&doc, &cid_font_dict).unwrap();
+        let sub = metrics.substitution();
+        assert!(sub.missing_embedded_font);
+        assert_eq!(sub.family, "Helvetica");
+    }
+
+    #[test]
+    fn extract_cid_font_metrics_embedded_font_file_not_flagged_missing() {
+        let mut doc = Document::with_version("1.5");
+
+        let font_file_id = doc.add_object(Object::Stream(Stream::new(dictionary! {}, vec![0u8; 4])));
+        let desc_id = doc.add_object(Object::Dictionary(dictionary! {
+            "Type" => "FontDescriptor",
+            "FontName" => "MSGothic",
+            "FontFile2" => font_file_id,
+        }));
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "MSGothic",
+            "FontDescriptor" => desc_id,
+        };
+
+        let metrics = extract_cid_font_metrics(&doc, &cid_font_dict).unwrap();
+        assert!(!metrics.substitution().missing_embedded_font);
+    }
+
+    // ========== Font program sniffing tests ==========
+
+    #[test]
+    fn sniff_font_program_recognizes_type1_header() {
+        assert_eq!(sniff_font_program(b"%!PS-AdobeFont-1.0"), Some(FontProgramType::Type1));
+        assert_eq!(sniff_font_program(&[0x80, 0x01, 0x00, 0x00]), Some(FontProgramType::Type1));
+    }
+
+    #[test]
+    fn sniff_font_program_recognizes_opentype_wrapper() {
+        assert_eq!(sniff_font_program(b"OTTOabcd"), Some(FontProgramType::OpenType));
+    }
+
+    #[test]
+    fn sniff_font_program_recognizes_truetype() {
+        assert_eq!(sniff_font_program(b"true\x00\x00"), Some(FontProgramType::TrueType));
+        assert_eq!(sniff_font_program(b"ttcfabcd"), Some(FontProgramType::TrueType));
+        assert_eq!(
+            sniff_font_program(&[0x00, 0x01, 0x00, 0x00, 0xaa]),
+            Some(FontProgramType::TrueType)
+        );
+    }
+
+    #[test]
+    fn sniff_font_program_recognizes_bare_cff() {
+        assert_eq!(sniff_font_program(&[1, 0, 4, 1]), Some(FontProgramType::Cff));
+    }
+
+    #[test]
+    fn sniff_font_program_unknown_header_is_none() {
+        assert_eq!(sniff_font_program(b"????"), None);
+        assert_eq!(sniff_font_program(&[]), None);
+    }
+
+    #[test]
+    fn extract_cid_font_metrics_corrects_truetype_program_in_cidfonttype0() {
+        let mut doc = Document::with_version("1.5");
+
+        let mut sfnt = vec![0x00, 0x01, 0x00, 0x00];
+        sfnt.extend_from_slice(&[0u8; 8]);
+        let font_file_id = doc.add_object(Object::Stream(Stream::new(dictionary! {}, sfnt)));
+        let desc_id = doc.add_object(Object::Dictionary(dictionary! {
+            "Type" => "FontDescriptor",
+            "FontName" => "Mislabeled",
+            "FontFile2" => font_file_id,
+        }));
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType0",
+            "BaseFont" => "Mislabeled",
+            "FontDescriptor" => desc_id,
+        };
+
+        let metrics = extract_cid_font_metrics(&doc, &cid_font_dict).unwrap();
+        assert_eq!(metrics.declared_font_type(), CidFontType::Type0);
+        assert_eq!(metrics.detected_program_type(), Some(FontProgramType::TrueType));
+        assert_eq!(metrics.font_type(), CidFontType::Type2);
+        assert!(metrics.cid_to_gid_map_applies());
+    }
+
+    #[test]
+    fn extract_cid_font_metrics_corrects_cff_program_in_cidfonttype2() {
+        let mut doc = Document::with_version("1.5");
+
+        let font_file_id = doc.add_object(Object::Stream(Stream::new(dictionary! {}, vec![1, 0, 4, 1])));
+        let desc_id = doc.add_object(Object::Dictionary(dictionary! {
+            "Type" => "FontDescriptor",
+            "FontName" => "AlsoMislabeled",
+            "FontFile3" => font_file_id,
+        }));
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "AlsoMislabeled",
+            "FontDescriptor" => desc_id,
+        };
+
+        let metrics = extract_cid_font_metrics(&doc, &cid_font_dict).unwrap();
+        assert_eq!(metrics.declared_font_type(), CidFontType::Type2);
+        assert_eq!(metrics.detected_program_type(), Some(FontProgramType::Cff));
+        assert_eq!(metrics.font_type(), CidFontType::Type0);
+        assert!(!metrics.cid_to_gid_map_applies());
+    }
+
+    #[test]
+    fn extract_cid_font_metrics_opentype_wrapper_leaves_declared_type() {
+        let mut doc = Document::with_version("1.5");
+
+        let mut otto = b"OTTO".to_vec();
+        otto.extend_from_slice(&[0u8; 8]);
+        let font_file_id = doc.add_object(Object::Stream(Stream::new(dictionary! {}, otto)));
+        let desc_id = doc.add_object(Object::Dictionary(dictionary! {
+            "Type" => "FontDescriptor",
+            "FontName" => "OpenTypeWrapped",
+            "FontFile3" => font_file_id,
+        }));
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "OpenTypeWrapped",
+            "FontDescriptor" => desc_id,
+        };
+
+        let metrics = extract_cid_font_metrics(&doc, &cid_font_dict).unwrap();
+        assert_eq!(metrics.detected_program_type(), Some(FontProgramType::OpenType));
+        assert_eq!(metrics.font_type(), CidFontType::Type2);
+        assert_eq!(metrics.font_type(), metrics.declared_font_type());
+    }
+
+    #[test]
+    fn extract_cid_font_metrics_no_font_file_leaves_declared_type() {
+        let mut doc = Document::with_version("1.5");
+
+        let desc_id = doc.add_object(Object::Dictionary(dictionary! {
+            "Type" => "FontDescriptor",
+            "FontName" => "NoEmbeddedProgram",
+        }));
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "NoEmbeddedProgram",
+            "FontDescriptor" => desc_id,
+        };
+
+        let metrics = extract_cid_font_metrics(&doc, &cid_font_dict).unwrap();
+        assert_eq!(metrics.detected_program_type(), None);
+        assert_eq!(metrics.font_type(), CidFontType::Type2);
+    }
+
     // ========== Type0 font detection tests ==========
 
     #[test]
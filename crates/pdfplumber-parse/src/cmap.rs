@@ -1,8 +1,13 @@
-//! ToUnicode CMap parser for mapping character codes to Unicode strings.
+//! ToUnicode and CID CMap parsers.
 //!
 //! Parses CMap data embedded in PDF `/ToUnicode` streams to convert glyph codes
 //! to Unicode text. Supports `beginbfchar`/`endbfchar` (single mappings) and
 //! `beginbfrange`/`endbfrange` (range mappings) with UTF-16BE encoded values.
+//!
+//! Also handles CID CMaps: [`CidCMap`] for pre-split codes, and
+//! [`EmbeddedCMap`] for a Type0 font's embedded `/Encoding` stream, which
+//! additionally parses `/CodespaceRange` so it can split raw show-strings
+//! into codes itself.
 
 use std::collections::HashMap;
 
@@ -64,6 +69,12 @@ impl CMap {
         self.mappings.get(&code).map(|s| s.as_str())
     }
 
+    /// Alias for [`CMap::lookup`] using PDF ToUnicode CMap terminology
+    /// (character code → Unicode string).
+    pub fn code_to_unicode(&self, code: u32) -> Option<&str> {
+        self.lookup(code)
+    }
+
     /// Look up the Unicode string for a character code, with fallback.
     ///
     /// If no mapping is found, returns U+FFFD (REPLACEMENT CHARACTER).
@@ -174,6 +185,255 @@ impl CidCMap {
     }
 }
 
+/// A codespace range from `begincodespacerange`/`endcodespacerange`: a pair
+/// of equal-length byte strings bounding the valid codes at a given byte
+/// length. The byte length of `low` (== `high`) tells the decoder how many
+/// bytes to consume for a code that falls in this range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodespaceRange {
+    low: Vec<u8>,
+    high: Vec<u8>,
+}
+
+impl CodespaceRange {
+    /// Number of bytes a code in this range occupies.
+    pub fn byte_length(&self) -> usize {
+        self.low.len()
+    }
+
+    /// Whether `prefix` (of this range's byte length) falls within `[low, high]`.
+    fn contains(&self, prefix: &[u8]) -> bool {
+        prefix.len() == self.low.len() && prefix >= self.low.as_slice() && prefix <= self.high.as_slice()
+    }
+}
+
+/// A parsed embedded CMap stream (a Type0 font's `/Encoding`), mapping
+/// variable-length byte codes to CIDs.
+///
+/// Unlike [`CidCMap`], which assumes the caller has already split the input
+/// into fixed-size codes, `EmbeddedCMap` understands `/CodespaceRange`
+/// entries and so can split a raw show-string itself via [`EmbeddedCMap::decode`].
+#[derive(Debug, Clone)]
+pub struct EmbeddedCMap {
+    codespace_ranges: Vec<CodespaceRange>,
+    cid_mappings: HashMap<u32, u32>,
+    writing_mode: u8,
+    use_cmap_name: Option<String>,
+}
+
+impl EmbeddedCMap {
+    /// Parse an embedded CMap stream's PostScript-like body.
+    ///
+    /// Extracts `begincodespacerange`/`endcodespacerange`,
+    /// `begincidrange`/`endcidrange`, and `begincidchar`/`endcidchar`
+    /// sections, plus `/WMode`. Does not resolve `/UseCMap` chaining —
+    /// callers with document access should parse the parent CMap separately
+    /// and merge it in via [`EmbeddedCMap::merge_usecmap`].
+    pub fn parse(data: &[u8]) -> Result<Self, BackendError> {
+        let text = String::from_utf8_lossy(data);
+        let writing_mode = parse_writing_mode(&text);
+
+        let mut codespace_ranges = Vec::new();
+        let mut search_from = 0;
+        while let Some(start) = text[search_from..].find("begincodespacerange") {
+            let section_start = search_from + start + "begincodespacerange".len();
+            if let Some(end) = text[section_start..].find("endcodespacerange") {
+                let section = &text[section_start..section_start + end];
+                parse_codespace_section(section, &mut codespace_ranges)?;
+                search_from = section_start + end + "endcodespacerange".len();
+            } else {
+                break;
+            }
+        }
+
+        let mut cid_mappings = HashMap::new();
+
+        search_from = 0;
+        while let Some(start) = text[search_from..].find("begincidchar") {
+            let section_start = search_from + start + "begincidchar".len();
+            if let Some(end) = text[section_start..].find("endcidchar") {
+                let section = &text[section_start..section_start + end];
+                parse_cidchar_section(section, &mut cid_mappings)?;
+                search_from = section_start + end + "endcidchar".len();
+            } else {
+                break;
+            }
+        }
+
+        search_from = 0;
+        while let Some(start) = text[search_from..].find("begincidrange") {
+            let section_start = search_from + start + "begincidrange".len();
+            if let Some(end) = text[section_start..].find("endcidrange") {
+                let section = &text[section_start..section_start + end];
+                parse_cidrange_section(section, &mut cid_mappings)?;
+                search_from = section_start + end + "endcidrange".len();
+            } else {
+                break;
+            }
+        }
+
+        Ok(EmbeddedCMap {
+            codespace_ranges,
+            cid_mappings,
+            writing_mode,
+            use_cmap_name: parse_usecmap_name(&text),
+        })
+    }
+
+    /// Name of the parent CMap named by an in-body `/Name usecmap` operator
+    /// (the PostScript-level form of `/UseCMap` chaining), if present.
+    ///
+    /// Resolving this to an actual parent `EmbeddedCMap` (predefined or
+    /// another embedded stream) requires document access this module
+    /// doesn't have; callers should look it up and pass it to
+    /// [`EmbeddedCMap::merge_usecmap`].
+    pub fn use_cmap_name(&self) -> Option<&str> {
+        self.use_cmap_name.as_deref()
+    }
+
+    /// Inherit `parent`'s codespace ranges and mappings, without overriding
+    /// anything this CMap already defines itself.
+    ///
+    /// Mirrors `/UseCMap` chaining: the child CMap only needs to specify the
+    /// entries it adds or overrides, falling back to the parent for
+    /// everything else.
+    pub fn merge_usecmap(&mut self, parent: &EmbeddedCMap) {
+        if self.codespace_ranges.is_empty() {
+            self.codespace_ranges = parent.codespace_ranges.clone();
+        }
+        for (&code, &cid) in &parent.cid_mappings {
+            self.cid_mappings.entry(code).or_insert(cid);
+        }
+    }
+
+    /// Writing mode: 0 = horizontal, 1 = vertical.
+    pub fn writing_mode(&self) -> u8 {
+        self.writing_mode
+    }
+
+    /// Codespace ranges, in declaration order.
+    pub fn codespace_ranges(&self) -> &[CodespaceRange] {
+        &self.codespace_ranges
+    }
+
+    /// Look up the CID for an already-decoded code.
+    pub fn lookup(&self, code: u32) -> Option<u32> {
+        self.cid_mappings.get(&code).copied()
+    }
+
+    /// Split `bytes` into `(cid, bytes_consumed)` pairs.
+    ///
+    /// At each position, greedily matches the longest codespace range prefix
+    /// (PDF spec 9.7.6.2: codes are matched low-byte-count-first only when no
+    /// longer range matches), maps the resulting code through the CID
+    /// tables, and advances past it. Bytes that don't fall in any codespace
+    /// range are consumed one at a time and map to CID 0 (`.notdef`).
+    pub fn decode(&self, bytes: &[u8]) -> Vec<(u32, usize)> {
+        self.decode_with_space_flag(bytes)
+            .into_iter()
+            .map(|(cid, len, _is_single_byte_space)| (cid, len))
+            .collect()
+    }
+
+    /// Like [`Self::decode`], but each triple also reports whether the raw
+    /// code consumed at that position was the single byte `0x20`.
+    ///
+    /// PDF spec 9.3.3's word-spacing trigger is defined on that
+    /// pre-translation byte, not on whatever CID it happens to map to —
+    /// callers that apply word spacing must branch on this flag instead of
+    /// comparing the decoded `cid` against `32`.
+    pub fn decode_with_space_flag(&self, bytes: &[u8]) -> Vec<(u32, usize, bool)> {
+        let mut result = Vec::new();
+        let mut pos = 0;
+
+        while pos < bytes.len() {
+            match self.match_codespace(&bytes[pos..]) {
+                Some((code, len)) => {
+                    let cid = self.cid_mappings.get(&code).copied().unwrap_or(0);
+                    result.push((cid, len, len == 1 && code == 0x20));
+                    pos += len;
+                }
+                None => {
+                    result.push((0, 1, bytes[pos] == 0x20));
+                    pos += 1;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Find the longest codespace-range match at the start of `remaining`,
+    /// returning the decoded code and its byte length.
+    fn match_codespace(&self, remaining: &[u8]) -> Option<(u32, usize)> {
+        let mut best: Option<(u32, usize)> = None;
+
+        for range in &self.codespace_ranges {
+            let len = range.byte_length();
+            if len == 0 || remaining.len() < len {
+                continue;
+            }
+            let prefix = &remaining[..len];
+            if range.contains(prefix) {
+                let code = prefix.iter().fold(0u32, |acc, &b| (acc << 8) | u32::from(b));
+                let is_longer = match best {
+                    Some((_, best_len)) => len > best_len,
+                    None => true,
+                };
+                if is_longer {
+                    best = Some((code, len));
+                }
+            }
+        }
+
+        best
+    }
+}
+
+/// Parse a begincodespacerange...endcodespacerange section.
+///
+/// Each line has format: `<lo> <hi>`, where `lo`/`hi` have equal byte length.
+fn parse_codespace_section(
+    section: &str,
+    ranges: &mut Vec<CodespaceRange>,
+) -> Result<(), BackendError> {
+    for line in section.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || !trimmed.contains('<') {
+            continue;
+        }
+
+        let tokens = extract_hex_tokens(trimmed);
+        if tokens.len() < 2 {
+            continue;
+        }
+        let low = parse_hex_bytes(tokens[0])?;
+        let high = parse_hex_bytes(tokens[1])?;
+        if low.len() == high.len() {
+            ranges.push(CodespaceRange { low, high });
+        }
+    }
+    Ok(())
+}
+
+/// Parse a hex string like "8140" into raw bytes.
+fn parse_hex_bytes(hex: &str) -> Result<Vec<u8>, BackendError> {
+    if hex.len() % 2 != 0 {
+        return Err(BackendError::Parse(format!(
+            "hex string must have even length, got '{hex}'"
+        )));
+    }
+    hex.as_bytes()
+        .chunks(2)
+        .map(|chunk| {
+            let s = std::str::from_utf8(chunk)
+                .map_err(|e| BackendError::Parse(format!("invalid UTF-8 in hex: {e}")))?;
+            u8::from_str_radix(s, 16)
+                .map_err(|e| BackendError::Parse(format!("invalid hex byte '{s}': {e}")))
+        })
+        .collect()
+}
+
 /// Parse a begincidchar...endcidchar section.
 ///
 /// Each line has format: `<srcCode> CID`
@@ -268,6 +528,19 @@ fn parse_writing_mode(text: &str) -> u8 {
     0 // default horizontal
 }
 
+/// Parse a `/Name usecmap` operator, naming the parent CMap to chain from.
+fn parse_usecmap_name(text: &str) -> Option<String> {
+    let idx = text.find("usecmap")?;
+    let before = text[..idx].trim_end();
+    let name_start = before.rfind('/')?;
+    let name = &before[name_start + 1..];
+    if name.chars().all(|c| !c.is_whitespace()) && !name.is_empty() {
+        Some(name.to_string())
+    } else {
+        None
+    }
+}
+
 /// Parse a hex string like "0041" into a u32 character code.
 fn parse_hex_code(hex: &str) -> Result<u32, BackendError> {
     u32::from_str_radix(hex, 16)
@@ -429,6 +702,15 @@ mod tests {
         assert_eq!(cmap.lookup_or_replacement(0x0041), "\u{FFFD}");
     }
 
+    #[test]
+    fn code_to_unicode_is_an_alias_for_lookup() {
+        let data = b"1 beginbfchar\n<0041> <0042>\nendbfchar\n";
+        let cmap = CMap::parse(data).unwrap();
+        assert_eq!(cmap.code_to_unicode(0x0041), cmap.lookup(0x0041));
+        assert_eq!(cmap.code_to_unicode(0x0041), Some("B"));
+        assert_eq!(cmap.code_to_unicode(0x9999), None);
+    }
+
     // --- beginbfchar / endbfchar ---
 
     #[test]
@@ -878,4 +1160,188 @@ mod tests {
         let cmap = CidCMap::parse(data).unwrap();
         assert_eq!(cmap.lookup(0x9999), None);
     }
+
+    // --- EmbeddedCMap tests ---
+
+    #[test]
+    fn embedded_cmap_codespace_range_single_byte() {
+        let data = b"\
+            1 begincodespacerange\n\
+            <00> <80>\n\
+            endcodespacerange\n\
+            begincidrange\n\
+            <00> <7F> 0\n\
+            endcidrange\n";
+        let cmap = EmbeddedCMap::parse(data).unwrap();
+        assert_eq!(cmap.codespace_ranges().len(), 1);
+        assert_eq!(cmap.codespace_ranges()[0].byte_length(), 1);
+    }
+
+    #[test]
+    fn embedded_cmap_decode_single_byte_codes() {
+        let data = b"\
+            1 begincodespacerange\n\
+            <00> <FF>\n\
+            endcodespacerange\n\
+            begincidchar\n\
+            <41> 100\n\
+            <42> 101\n\
+            endcidchar\n";
+        let cmap = EmbeddedCMap::parse(data).unwrap();
+        let decoded = cmap.decode(&[0x41, 0x42]);
+        assert_eq!(decoded, vec![(100, 1), (101, 1)]);
+    }
+
+    #[test]
+    fn embedded_cmap_decode_with_space_flag_follows_raw_byte_not_cid() {
+        let data = b"\
+            1 begincodespacerange\n\
+            <00> <FF>\n\
+            endcodespacerange\n\
+            begincidchar\n\
+            <20> 99\n\
+            <43> 32\n\
+            endcidchar\n";
+        let cmap = EmbeddedCMap::parse(data).unwrap();
+        let decoded = cmap.decode_with_space_flag(&[0x20, 0x43]);
+        // Byte 0x20 (a real space) maps to CID 99 but must still be flagged
+        // as the space; byte 0x43 maps to CID 32 but is not a space byte.
+        assert_eq!(decoded, vec![(99, 1, true), (32, 1, false)]);
+    }
+
+    #[test]
+    fn embedded_cmap_decode_with_space_flag_two_byte_code_never_flagged() {
+        let data = b"\
+            1 begincodespacerange\n\
+            <0000> <FFFF>\n\
+            endcodespacerange\n\
+            begincidrange\n\
+            <0020> <0020> 1\n\
+            endcidrange\n";
+        let cmap = EmbeddedCMap::parse(data).unwrap();
+        // Two-byte code 0x0020 is not the single byte 0x20, so it must not
+        // be flagged as a space even though its low byte is 0x20.
+        let decoded = cmap.decode_with_space_flag(&[0x00, 0x20]);
+        assert_eq!(decoded, vec![(1, 2, false)]);
+    }
+
+    #[test]
+    fn embedded_cmap_decode_two_byte_codes() {
+        let data = b"\
+            1 begincodespacerange\n\
+            <0000> <FFFF>\n\
+            endcodespacerange\n\
+            begincidrange\n\
+            <0041> <0043> 500\n\
+            endcidrange\n";
+        let cmap = EmbeddedCMap::parse(data).unwrap();
+        let decoded = cmap.decode(&[0x00, 0x41, 0x00, 0x42]);
+        assert_eq!(decoded, vec![(500, 2), (501, 2)]);
+    }
+
+    #[test]
+    fn embedded_cmap_decode_mixed_byte_length_prefers_longer_match() {
+        // Mixed-width Shift-JIS-style codespace: single bytes 00-80, two
+        // bytes 8140-9FFC. 0x81 0x40 should greedily match the two-byte range.
+        let data = b"\
+            2 begincodespacerange\n\
+            <00> <80>\n\
+            <8140> <9FFC>\n\
+            endcodespacerange\n\
+            begincidrange\n\
+            <8140> <8141> 1\n\
+            endcidrange\n";
+        let cmap = EmbeddedCMap::parse(data).unwrap();
+        let decoded = cmap.decode(&[0x81, 0x40]);
+        assert_eq!(decoded, vec![(1, 2)]);
+    }
+
+    #[test]
+    fn embedded_cmap_decode_unmapped_code_is_notdef() {
+        let data = b"\
+            1 begincodespacerange\n\
+            <00> <FF>\n\
+            endcodespacerange\n\
+            begincidchar\n\
+            <41> 100\n\
+            endcidchar\n";
+        let cmap = EmbeddedCMap::parse(data).unwrap();
+        let decoded = cmap.decode(&[0x99]);
+        assert_eq!(decoded, vec![(0, 1)]);
+    }
+
+    #[test]
+    fn embedded_cmap_decode_byte_outside_any_codespace_consumes_one() {
+        let data = b"\
+            1 begincodespacerange\n\
+            <00> <7F>\n\
+            endcodespacerange\n\
+            begincidchar\n\
+            <20> 1\n\
+            endcidchar\n";
+        let cmap = EmbeddedCMap::parse(data).unwrap();
+        let decoded = cmap.decode(&[0xFF, 0x20]);
+        assert_eq!(decoded, vec![(0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn embedded_cmap_parses_writing_mode() {
+        let data = b"\
+            /WMode 1 def\n\
+            1 begincodespacerange\n\
+            <00> <FF>\n\
+            endcodespacerange\n";
+        let cmap = EmbeddedCMap::parse(data).unwrap();
+        assert_eq!(cmap.writing_mode(), 1);
+    }
+
+    #[test]
+    fn embedded_cmap_merge_usecmap_inherits_codespace_and_mappings() {
+        let parent_data = b"\
+            1 begincodespacerange\n\
+            <00> <FF>\n\
+            endcodespacerange\n\
+            begincidchar\n\
+            <41> 100\n\
+            <42> 200\n\
+            endcidchar\n";
+        let parent = EmbeddedCMap::parse(parent_data).unwrap();
+
+        let child_data = b"\
+            begincidchar\n\
+            <41> 999\n\
+            endcidchar\n";
+        let mut child = EmbeddedCMap::parse(child_data).unwrap();
+        assert!(child.codespace_ranges().is_empty());
+
+        child.merge_usecmap(&parent);
+
+        // Child keeps its own override...
+        assert_eq!(child.lookup(0x41), Some(999));
+        // ...but inherits the parent's codespace and unoverridden mapping.
+        assert_eq!(child.codespace_ranges().len(), 1);
+        assert_eq!(child.lookup(0x42), Some(200));
+    }
+
+    #[test]
+    fn embedded_cmap_parses_usecmap_operator_name() {
+        let data = b"/UniJIS-UCS2-H usecmap\n1 begincodespacerange\n<0000> <FFFF>\nendcodespacerange\n";
+        let cmap = EmbeddedCMap::parse(data).unwrap();
+        assert_eq!(cmap.use_cmap_name(), Some("UniJIS-UCS2-H"));
+    }
+
+    #[test]
+    fn embedded_cmap_no_usecmap_operator_returns_none() {
+        let data = b"1 begincodespacerange\n<00> <FF>\nendcodespacerange\n";
+        let cmap = EmbeddedCMap::parse(data).unwrap();
+        assert_eq!(cmap.use_cmap_name(), None);
+    }
+
+    #[test]
+    fn embedded_cmap_empty_has_no_codespace_ranges() {
+        let cmap = EmbeddedCMap::parse(b"").unwrap();
+        assert!(cmap.codespace_ranges().is_empty());
+        assert_eq!(cmap.writing_mode(), 0);
+        assert!(cmap.decode(&[0x41]).iter().all(|&(cid, _)| cid == 0));
+    }
 }
@@ -3,6 +3,8 @@
 //! Parses /Widths, /FirstChar, /LastChar, and /FontDescriptor to provide
 //! glyph widths, ascent, and descent for character bounding box calculation.
 
+use pdfplumber_core::FontEncoding;
+
 use crate::error::BackendError;
 use crate::standard_fonts;
 
@@ -37,6 +39,11 @@ pub struct FontMetrics {
     descent: f64,
     /// Font bounding box [llx, lly, urx, ury] in glyph space units.
     font_bbox: Option<[f64; 4]>,
+    /// Canonical Standard-14 name this font was resolved to, if any.
+    canonical_name: Option<&'static str>,
+    /// Italic angle in degrees (0.0 for upright fonts, negative for
+    /// right-leaning italics/obliques per the PDF/AFM convention).
+    italic_angle: f64,
 }
 
 impl FontMetrics {
@@ -58,9 +65,29 @@ impl FontMetrics {
             ascent,
             descent,
             font_bbox,
+            canonical_name: None,
+            italic_angle: 0.0,
         }
     }
 
+    /// Attach the canonical Standard-14 name this font was resolved to.
+    ///
+    /// Used by [`extract_font_metrics`] to record the result of Base-14
+    /// name normalization (e.g. `ArialMT` resolving to `Helvetica`).
+    pub fn with_canonical_name(mut self, canonical_name: Option<&'static str>) -> Self {
+        self.canonical_name = canonical_name;
+        self
+    }
+
+    /// Set the italic angle in degrees.
+    ///
+    /// Used by [`extract_font_metrics`] to record the Standard-14 AFM
+    /// italic angle for the Oblique/Italic variants.
+    pub fn with_italic_angle(mut self, italic_angle: f64) -> Self {
+        self.italic_angle = italic_angle;
+        self
+    }
+
     /// Create default FontMetrics for when font info is unavailable.
     pub fn default_metrics() -> Self {
         Self {
@@ -71,6 +98,8 @@ impl FontMetrics {
             ascent: DEFAULT_ASCENT,
             descent: DEFAULT_DESCENT,
             font_bbox: None,
+            canonical_name: None,
+            italic_angle: 0.0,
         }
     }
 
@@ -114,17 +143,40 @@ impl FontMetrics {
     pub fn last_char(&self) -> u32 {
         self.last_char
     }
+
+    /// Canonical Standard-14 name this font was resolved to, e.g. `ArialMT`
+    /// and `Arial,Bold` both resolve to `Helvetica`/`Helvetica-Bold`.
+    ///
+    /// Returns `None` when /BaseFont does not match (directly or via alias)
+    /// one of the 14 standard Type1 fonts.
+    pub fn canonical_name(&self) -> Option<&'static str> {
+        self.canonical_name
+    }
+
+    /// Italic angle in degrees, as reported by the font's AFM/FontDescriptor.
+    /// `0.0` for upright fonts; negative for right-leaning italics/obliques.
+    pub fn italic_angle(&self) -> f64 {
+        self.italic_angle
+    }
 }
 
 /// Extract [`FontMetrics`] from a lopdf font dictionary.
 ///
 /// Reads /Widths, /FirstChar, /LastChar from the font dictionary,
-/// and /Ascent, /Descent, /FontBBox, /MissingWidth from the /FontDescriptor.
+/// and /Ascent, /Descent, /FontBBox, /MissingWidth, /ItalicAngle from the
+/// /FontDescriptor.
+///
+/// `encoding` is the font's resolved `/Encoding` (see
+/// `extract_font_encoding` in the interpreter), used only to re-key the
+/// Standard-14 fallback widths below for fonts that don't use
+/// WinAnsiEncoding; it has no effect when the font dictionary already has
+/// an explicit /Widths array.
 ///
 /// Returns default metrics if essential fields are missing.
 pub fn extract_font_metrics(
     doc: &lopdf::Document,
     font_dict: &lopdf::Dictionary,
+    encoding: Option<&FontEncoding>,
 ) -> Result<FontMetrics, BackendError> {
     // Parse /FirstChar and /LastChar
     let first_char = font_dict
@@ -162,25 +214,56 @@ pub fn extract_font_metrics(
     // Parse /FontDescriptor
     let desc_info = parse_font_descriptor(doc, font_dict)?;
 
+    // Resolve /BaseFont to one of the 14 standard font names, if possible,
+    // either directly or via common non-standard aliases (Arial, Courier New, ...).
+    let canonical_name = base_font_name(font_dict).and_then(resolve_base14_name);
+
     // Standard font fallback: when /Widths is absent, try standard Type1 font widths
     if widths.is_empty() {
-        if let Some(std_font) = lookup_standard_font(font_dict) {
-            let std_widths: Vec<f64> = std_font.widths.iter().map(|&w| f64::from(w)).collect();
+        if let Some(std_font) = canonical_name.and_then(standard_fonts::lookup) {
+            // Symbol/ZapfDingbats always use their own built-in encoding, so only
+            // re-key the Latin text fonts against the resolved /Encoding.
+            let is_symbolic =
+                canonical_name == Some("Symbol") || canonical_name == Some("ZapfDingbats");
+            let std_widths: Vec<f64> = match encoding.filter(|_| !is_symbolic) {
+                Some(enc) => standard_fonts::widths_for_encoding(std_font, enc)
+                    .iter()
+                    .map(|&w| f64::from(w))
+                    .collect(),
+                None => std_font.widths.iter().map(|&w| f64::from(w)).collect(),
+            };
             let font_bbox = desc_info
                 .font_bbox
                 .or(Some(std_font.font_bbox.map(f64::from)));
+            let has_descriptor = font_dict.get(b"FontDescriptor").is_ok();
+            let ascent = if has_descriptor {
+                desc_info.ascent
+            } else {
+                f64::from(std_font.ascent)
+            };
+            let descent = if has_descriptor {
+                desc_info.descent
+            } else {
+                f64::from(std_font.descent)
+            };
+            let italic_angle = desc_info.italic_angle.unwrap_or_else(|| {
+                standard_fonts::italic_angle_for_name(canonical_name.unwrap_or(""))
+            });
             return Ok(FontMetrics::new(
                 std_widths,
                 0,
                 255,
                 desc_info.missing_width,
-                desc_info.ascent,
-                desc_info.descent,
+                ascent,
+                descent,
                 font_bbox,
-            ));
+            )
+            .with_canonical_name(canonical_name)
+            .with_italic_angle(italic_angle));
         }
     }
 
+    let italic_angle = desc_info.italic_angle.unwrap_or(0.0);
     Ok(FontMetrics::new(
         widths,
         first_char,
@@ -189,22 +272,85 @@ pub fn extract_font_metrics(
         desc_info.ascent,
         desc_info.descent,
         desc_info.font_bbox,
-    ))
+    )
+    .with_canonical_name(canonical_name)
+    .with_italic_angle(italic_angle))
 }
 
-/// Look up standard font data from a font dictionary's /BaseFont entry.
-///
-/// Handles subset-prefixed names (e.g., "ABCDEF+Helvetica").
-fn lookup_standard_font(
-    font_dict: &lopdf::Dictionary,
-) -> Option<&'static standard_fonts::StandardFontData> {
-    let base_font = font_dict
+/// Read a font dictionary's /BaseFont entry as a string, if present.
+fn base_font_name(font_dict: &lopdf::Dictionary) -> Option<&str> {
+    font_dict
         .get(b"BaseFont")
         .ok()
         .and_then(|obj| obj.as_name().ok())
-        .map(|name| std::str::from_utf8(name).unwrap_or(""))?;
+        .map(|name| std::str::from_utf8(name).unwrap_or(""))
+}
+
+/// Resolve a /BaseFont name to one of the 14 standard Type1 font names.
+///
+/// Strips subset prefixes (e.g. `ABCDEF+Helvetica`) and recognizes common
+/// non-standard aliases used by font-embedding tools, e.g. `ArialMT` and
+/// `Arial,Bold` normalize to `Helvetica`/`Helvetica-Bold`, `CourierNewPSMT`
+/// to `Courier`, and `TimesNewRomanPSMT` to `Times-Roman`. Returns `None`
+/// when the name does not match any standard font, directly or via alias.
+fn resolve_base14_name(base_font: &str) -> Option<&'static str> {
     let stripped = crate::cid_font::strip_subset_prefix(base_font);
-    standard_fonts::lookup(stripped)
+    exact_base14_name(stripped).or_else(|| alias_base14_name(stripped))
+}
+
+/// Match a name against the 14 standard Type1 font names exactly.
+fn exact_base14_name(name: &str) -> Option<&'static str> {
+    match name {
+        "Courier" => Some("Courier"),
+        "Courier-Bold" => Some("Courier-Bold"),
+        "Courier-Oblique" => Some("Courier-Oblique"),
+        "Courier-BoldOblique" => Some("Courier-BoldOblique"),
+        "Helvetica" => Some("Helvetica"),
+        "Helvetica-Bold" => Some("Helvetica-Bold"),
+        "Helvetica-Oblique" => Some("Helvetica-Oblique"),
+        "Helvetica-BoldOblique" => Some("Helvetica-BoldOblique"),
+        "Times-Roman" => Some("Times-Roman"),
+        "Times-Bold" => Some("Times-Bold"),
+        "Times-Italic" => Some("Times-Italic"),
+        "Times-BoldItalic" => Some("Times-BoldItalic"),
+        "Symbol" => Some("Symbol"),
+        "ZapfDingbats" => Some("ZapfDingbats"),
+        _ => None,
+    }
+}
+
+/// Normalize common non-standard aliases (Arial, Courier New, Times New
+/// Roman family names) to their Standard-14 equivalents, detecting Bold
+/// and Italic/Oblique style suffixes regardless of comma/hyphen separator.
+fn alias_base14_name(name: &str) -> Option<&'static str> {
+    let lower = name.to_ascii_lowercase();
+    let bold = lower.contains("bold");
+    let italic = lower.contains("italic") || lower.contains("oblique");
+
+    if lower.contains("arial") {
+        Some(match (bold, italic) {
+            (true, true) => "Helvetica-BoldOblique",
+            (true, false) => "Helvetica-Bold",
+            (false, true) => "Helvetica-Oblique",
+            (false, false) => "Helvetica",
+        })
+    } else if lower.contains("couriernew") || lower.contains("courier new") {
+        Some(match (bold, italic) {
+            (true, true) => "Courier-BoldOblique",
+            (true, false) => "Courier-Bold",
+            (false, true) => "Courier-Oblique",
+            (false, false) => "Courier",
+        })
+    } else if lower.contains("timesnewroman") || lower.contains("times new roman") {
+        Some(match (bold, italic) {
+            (true, true) => "Times-BoldItalic",
+            (true, false) => "Times-Bold",
+            (false, true) => "Times-Italic",
+            (false, false) => "Times-Roman",
+        })
+    } else {
+        None
+    }
 }
 
 /// Parsed font descriptor values.
@@ -213,6 +359,7 @@ struct FontDescriptorInfo {
     descent: f64,
     font_bbox: Option<[f64; 4]>,
     missing_width: f64,
+    italic_angle: Option<f64>,
 }
 
 /// Parse /FontDescriptor dictionary for ascent, descent, bbox, and missing width.
@@ -232,6 +379,7 @@ fn parse_font_descriptor(
             descent: DEFAULT_DESCENT,
             font_bbox: None,
             missing_width: DEFAULT_WIDTH,
+            italic_angle: None,
         });
     };
 
@@ -253,6 +401,8 @@ fn parse_font_descriptor(
         .and_then(object_to_f64_opt)
         .unwrap_or(DEFAULT_WIDTH);
 
+    let italic_angle = desc.get(b"ItalicAngle").ok().and_then(object_to_f64_opt);
+
     let font_bbox = desc
         .get(b"FontBBox")
         .ok()
@@ -278,6 +428,7 @@ fn parse_font_descriptor(
         descent,
         font_bbox,
         missing_width,
+        italic_angle,
     })
 }
 
@@ -487,7 +638,7 @@ mod tests {
             Some([-166.0, -225.0, 1000.0, 931.0]),
         );
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         assert_eq!(metrics.get_width(65), 278.0); // A
         assert_eq!(metrics.get_width(66), 556.0); // B
@@ -504,7 +655,7 @@ mod tests {
         let font_dict = create_font_dict_with_widths(&mut doc, &[500.0, 600.0], 32, 33);
         // No FontDescriptor added
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         assert_eq!(metrics.get_width(32), 500.0);
         assert_eq!(metrics.get_width(33), 600.0);
@@ -524,7 +675,7 @@ mod tests {
         };
         add_font_descriptor(&mut doc, &mut font_dict, 800.0, -200.0, Some(500.0), None);
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         // No /Widths — Helvetica is a standard font, so standard widths are used
         assert_eq!(metrics.get_width(65), 667.0); // Helvetica 'A' = 667
@@ -537,7 +688,7 @@ mod tests {
         let doc = Document::with_version("1.5");
         let font_dict = dictionary! {};
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         // Everything defaults
         assert_eq!(metrics.ascent(), DEFAULT_ASCENT);
@@ -552,7 +703,7 @@ mod tests {
         let mut font_dict = create_font_dict_with_widths(&mut doc, &[400.0], 65, 65);
         add_font_descriptor(&mut doc, &mut font_dict, 700.0, -300.0, None, None);
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         assert_eq!(metrics.get_width(65), 400.0);
         // MissingWidth defaults to DEFAULT_WIDTH when not in descriptor
@@ -575,7 +726,7 @@ mod tests {
             "Widths" => widths_id,
         };
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         assert_eq!(metrics.get_width(65), 250.0);
         assert_eq!(metrics.get_width(66), 500.0);
@@ -598,7 +749,7 @@ mod tests {
             Some([-23.0, -250.0, 715.0, 805.0]),
         );
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         let bbox = metrics.font_bbox().unwrap();
         assert!((bbox[0] - (-23.0)).abs() < 1.0);
@@ -621,7 +772,7 @@ mod tests {
             "Widths" => widths_id,
         };
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         assert_eq!(metrics.first_char(), 32);
         assert_eq!(metrics.last_char(), 32);
@@ -646,7 +797,7 @@ mod tests {
             "FontDescriptor" => desc_id,
         };
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         assert!((metrics.ascent() - 683.0).abs() < 1.0);
         assert!((metrics.descent() - (-217.0)).abs() < 1.0);
@@ -684,7 +835,7 @@ mod tests {
         };
         add_font_descriptor(&mut doc, &mut font_dict, 718.0, -207.0, None, None);
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         // Helvetica 'A'(65) = 667, space(32) = 278 — proportional, NOT 600
         assert_eq!(metrics.get_width(65), 667.0); // A
@@ -703,7 +854,7 @@ mod tests {
         };
         add_font_descriptor(&mut doc, &mut font_dict, 629.0, -157.0, None, None);
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         assert_eq!(metrics.get_width(65), 600.0); // A
         assert_eq!(metrics.get_width(32), 600.0); // space
@@ -720,7 +871,7 @@ mod tests {
         };
         add_font_descriptor(&mut doc, &mut font_dict, 683.0, -217.0, None, None);
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         // Times-Roman 'A'(65) = 722
         assert_eq!(metrics.get_width(65), 722.0); // A
@@ -739,7 +890,7 @@ mod tests {
         };
         add_font_descriptor(&mut doc, &mut font_dict, 718.0, -207.0, None, None);
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         assert_eq!(metrics.get_width(65), 667.0); // A = Helvetica width
     }
@@ -755,7 +906,7 @@ mod tests {
         };
         add_font_descriptor(&mut doc, &mut font_dict, 700.0, -300.0, None, None);
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         assert_eq!(metrics.get_width(65), DEFAULT_WIDTH); // not a standard font
     }
@@ -767,7 +918,7 @@ mod tests {
         let mut font_dict = create_font_dict_with_widths(&mut doc, &[500.0, 600.0, 700.0], 65, 67);
         add_font_descriptor(&mut doc, &mut font_dict, 718.0, -207.0, None, None);
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         // Should use embedded widths, NOT standard Helvetica widths (667, 667, 722)
         assert_eq!(metrics.get_width(65), 500.0);
@@ -786,7 +937,7 @@ mod tests {
         };
         add_font_descriptor(&mut doc, &mut font_dict, 800.0, -250.0, None, None);
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         // Ascent/descent from descriptor, not standard defaults
         assert!((metrics.ascent() - 800.0).abs() < 1.0);
@@ -805,7 +956,7 @@ mod tests {
         // Descriptor without FontBBox
         add_font_descriptor(&mut doc, &mut font_dict, 718.0, -207.0, None, None);
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         // Should use Helvetica's standard bbox: [-166, -225, 1000, 931]
         let bbox = metrics.font_bbox().expect("should have standard font bbox");
@@ -834,7 +985,7 @@ mod tests {
             Some(custom_bbox),
         );
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         let bbox = metrics.font_bbox().unwrap();
         assert!((bbox[0] - (-100.0)).abs() < 1.0);
@@ -850,8 +1001,245 @@ mod tests {
             "Subtype" => "Type1",
         };
 
-        let metrics = extract_font_metrics(&doc, &font_dict).unwrap();
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
 
         assert_eq!(metrics.get_width(65), DEFAULT_WIDTH);
     }
+
+    // ========== Base-14 alias normalization tests ==========
+
+    #[test]
+    fn arial_normalizes_to_helvetica_widths_and_canonical_name() {
+        let doc = Document::with_version("1.5");
+        let font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "TrueType",
+            "BaseFont" => "ArialMT",
+        };
+
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        assert_eq!(metrics.canonical_name(), Some("Helvetica"));
+        assert_eq!(metrics.get_width(65), 667.0); // Helvetica 'A'
+    }
+
+    #[test]
+    fn arial_comma_bold_normalizes_to_helvetica_bold() {
+        let doc = Document::with_version("1.5");
+        let font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "TrueType",
+            "BaseFont" => "Arial,Bold",
+        };
+
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        assert_eq!(metrics.canonical_name(), Some("Helvetica-Bold"));
+    }
+
+    #[test]
+    fn arial_bold_mt_normalizes_to_helvetica_bold() {
+        let doc = Document::with_version("1.5");
+        let font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "TrueType",
+            "BaseFont" => "Arial-BoldMT",
+        };
+
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        assert_eq!(metrics.canonical_name(), Some("Helvetica-Bold"));
+    }
+
+    #[test]
+    fn courier_new_normalizes_to_courier() {
+        let doc = Document::with_version("1.5");
+        let font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "TrueType",
+            "BaseFont" => "CourierNewPSMT",
+        };
+
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        assert_eq!(metrics.canonical_name(), Some("Courier"));
+        assert_eq!(metrics.get_width(65), 600.0);
+    }
+
+    #[test]
+    fn times_new_roman_bold_italic_normalizes_to_times_bold_italic() {
+        let doc = Document::with_version("1.5");
+        let font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "TrueType",
+            "BaseFont" => "TimesNewRoman-BoldItalic",
+        };
+
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        assert_eq!(metrics.canonical_name(), Some("Times-BoldItalic"));
+    }
+
+    #[test]
+    fn subset_prefixed_alias_still_normalizes() {
+        let doc = Document::with_version("1.5");
+        let font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "TrueType",
+            "BaseFont" => "ABCDEF+ArialMT",
+        };
+
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        assert_eq!(metrics.canonical_name(), Some("Helvetica"));
+    }
+
+    #[test]
+    fn canonical_name_none_for_non_standard_font() {
+        let doc = Document::with_version("1.5");
+        let font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "TrueType",
+            "BaseFont" => "CustomFont",
+        };
+
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        assert_eq!(metrics.canonical_name(), None);
+    }
+
+    #[test]
+    fn canonical_name_set_even_when_embedded_widths_present() {
+        // Canonical name resolution is independent of whether /Widths exists.
+        let mut doc = Document::with_version("1.5");
+        let font_dict = create_font_dict_with_widths(&mut doc, &[250.0, 500.0], 65, 66);
+
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        assert_eq!(metrics.canonical_name(), Some("Helvetica"));
+        // Embedded widths still win over standard widths.
+        assert_eq!(metrics.get_width(65), 250.0);
+    }
+
+    #[test]
+    fn default_metrics_has_no_canonical_name() {
+        let metrics = FontMetrics::default_metrics();
+        assert_eq!(metrics.canonical_name(), None);
+    }
+
+    // ========== Italic angle ==========
+
+    #[test]
+    fn fallback_upright_standard_font_has_zero_italic_angle() {
+        let mut doc = Document::with_version("1.5");
+        let mut font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+        };
+        add_font_descriptor(&mut doc, &mut font_dict, 718.0, -207.0, None, None);
+
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        assert_eq!(metrics.italic_angle(), 0.0);
+    }
+
+    #[test]
+    fn fallback_oblique_standard_font_has_afm_italic_angle() {
+        let mut doc = Document::with_version("1.5");
+        let mut font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica-Oblique",
+        };
+        add_font_descriptor(&mut doc, &mut font_dict, 718.0, -207.0, None, None);
+
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        assert_eq!(metrics.italic_angle(), -12.0);
+    }
+
+    #[test]
+    fn fallback_descriptor_italic_angle_overrides_standard() {
+        let mut doc = Document::with_version("1.5");
+        let mut font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Times-Italic",
+        };
+        let desc_id = doc.add_object(Object::Dictionary(dictionary! {
+            "Type" => "FontDescriptor",
+            "FontName" => "Times-Italic",
+            "Ascent" => Object::Real(683.0),
+            "Descent" => Object::Real(-217.0),
+            "ItalicAngle" => Object::Real(-20.0),
+        }));
+        font_dict.set("FontDescriptor", desc_id);
+
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        assert!((metrics.italic_angle() - (-20.0)).abs() < 1.0);
+    }
+
+    #[test]
+    fn default_metrics_has_zero_italic_angle() {
+        let metrics = FontMetrics::default_metrics();
+        assert_eq!(metrics.italic_angle(), 0.0);
+    }
+
+    // ========== Encoding-aware standard font widths ==========
+
+    #[test]
+    fn fallback_standard_font_without_encoding_uses_winansi_widths() {
+        let mut doc = Document::with_version("1.5");
+        let mut font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+        };
+        add_font_descriptor(&mut doc, &mut font_dict, 718.0, -207.0, None, None);
+
+        let metrics = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        // Code 0x27 is `quotesingle` in WinAnsi (width 191)
+        assert_eq!(metrics.get_width(0x27), 191.0);
+    }
+
+    #[test]
+    fn fallback_standard_font_with_standard_encoding_reindexes_widths() {
+        use pdfplumber_core::StandardEncoding;
+
+        let mut doc = Document::with_version("1.5");
+        let mut font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+        };
+        add_font_descriptor(&mut doc, &mut font_dict, 718.0, -207.0, None, None);
+        let encoding = FontEncoding::from_standard(StandardEncoding::Standard);
+
+        let metrics = extract_font_metrics(&doc, &font_dict, Some(&encoding)).unwrap();
+
+        // Code 0x27 is `quoteright` in StandardEncoding (width 222 for Helvetica)
+        assert_eq!(metrics.get_width(0x27), 222.0);
+    }
+
+    #[test]
+    fn fallback_symbol_ignores_encoding() {
+        use pdfplumber_core::StandardEncoding;
+
+        let mut doc = Document::with_version("1.5");
+        let mut font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Symbol",
+        };
+        add_font_descriptor(&mut doc, &mut font_dict, 1010.0, -293.0, None, None);
+        let encoding = FontEncoding::from_standard(StandardEncoding::Standard);
+
+        let with_encoding = extract_font_metrics(&doc, &font_dict, Some(&encoding)).unwrap();
+        let without_encoding = extract_font_metrics(&doc, &font_dict, None).unwrap();
+
+        assert_eq!(with_encoding.get_width(65), without_encoding.get_width(65));
+    }
 }
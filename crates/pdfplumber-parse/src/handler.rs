@@ -97,6 +97,17 @@ pub struct ImageEvent {
     pub bits_per_component: Option<u32>,
     /// PDF stream filter name (e.g., "DCTDecode", "FlateDecode").
     pub filter: Option<String>,
+    /// Whether this image is a stencil mask (`/ImageMask true`).
+    pub is_mask: bool,
+    /// The `/Decode` array, if present (e.g., `[1.0, 0.0]` to invert a mask).
+    pub decode: Option<Vec<f64>>,
+    /// Decoded (or, for unsupported pixel codecs, still-encoded) image bytes,
+    /// populated only for inline (`BI`/`ID`/`EI`) images when
+    /// [`ExtractOptions::extract_image_data`](pdfplumber_core::ExtractOptions)
+    /// is set. XObject images are instead looked up by `name` via
+    /// [`crate::backend::PdfBackend::extract_image_content`], so this is
+    /// `None` for those.
+    pub data: Option<Vec<u8>>,
 }
 
 /// Callback handler for content stream interpretation.
@@ -236,6 +247,9 @@ mod tests {
             colorspace: Some("DeviceRGB".to_string()),
             bits_per_component: Some(8),
             filter: None,
+            is_mask: false,
+            decode: None,
+            data: None,
         }
     }
 
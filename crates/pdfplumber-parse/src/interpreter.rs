@@ -8,10 +8,10 @@ use std::collections::HashMap;
 
 use crate::cid_font::{
     CidFontMetrics, extract_cid_font_metrics, get_descendant_font, get_type0_encoding,
-    is_type0_font, parse_predefined_cmap_name, strip_subset_prefix,
+    is_type0_font, load_predefined_cmap, parse_predefined_cmap_name, strip_subset_prefix,
 };
 use crate::cjk_encoding;
-use crate::cmap::CMap;
+use crate::cmap::{CMap, EmbeddedCMap};
 use crate::color_space::resolve_color_space_name;
 use crate::error::BackendError;
 use crate::font_metrics::{FontMetrics, extract_font_metrics};
@@ -46,6 +46,17 @@ struct CachedFont {
     /// CJK encoding for predefined CMap encodings (e.g., GBK-EUC-H).
     /// When present, used for variable-length byte decoding and Unicode conversion.
     cjk_encoding: Option<&'static encoding_rs::Encoding>,
+    /// Embedded CMap program from a Type0 font's `/Encoding` stream.
+    /// When present, used to split codes by codespace range and translate
+    /// them to CIDs, for non-Identity encodings that aren't one of the
+    /// predefined CJK encodings handled by `cjk_encoding`.
+    embedded_cmap: Option<EmbeddedCMap>,
+    /// Bundled code-to-CID decoder for a predefined CMap name (see
+    /// [`crate::cid_font::load_predefined_cmap`]), present alongside
+    /// `cjk_encoding` when the CMap name is one of the bundled ones. Used
+    /// only to resolve the CID for width/glyph lookup; `cjk_encoding`
+    /// remains the source of truth for Unicode text.
+    predefined_cid_cmap: Option<EmbeddedCMap>,
 }
 
 /// Entry on the marked content stack, tracking BMC/BDC nesting.
@@ -545,7 +556,7 @@ pub(crate) fn interpret_content_stream(
 
             // --- Inline image operator ---
             "BI" => {
-                handle_inline_image(op, op_index, gstate, handler);
+                handle_inline_image(op, op_index, gstate, options, handler);
             }
 
             // Other operators — silently ignore
@@ -626,15 +637,42 @@ fn load_font_if_needed(
         font_obj.as_dict().ok()
     })();
 
-    let (metrics, cmap, base_name, cid_metrics, is_cid_font, writing_mode, encoding, cjk_enc) =
-        if let Some(fd) = font_dict {
+    let (
+        metrics,
+        cmap,
+        base_name,
+        cid_metrics,
+        is_cid_font,
+        writing_mode,
+        encoding,
+        cjk_enc,
+        embedded_cmap,
+        predefined_cid_cmap,
+    ) = if let Some(fd) = font_dict {
             if is_type0_font(fd) {
                 // Type0 (composite/CID) font
                 let (cid_met, wm) = load_cid_font(doc, fd);
 
                 // Detect CJK encoding from predefined CMap name
-                let cjk_enc = get_type0_encoding(fd)
-                    .and_then(|enc_name| cjk_encoding::encoding_for_cmap(&enc_name));
+                let type0_encoding_name = get_type0_encoding(fd);
+                let cjk_enc = type0_encoding_name
+                    .as_deref()
+                    .and_then(cjk_encoding::encoding_for_cmap);
+
+                // Bundled code-to-CID table for the same predefined CMap
+                // name, used for width/glyph lookup alongside `cjk_enc`
+                // (which remains the source of Unicode text).
+                let predefined_cid_cmap =
+                    type0_encoding_name.as_deref().and_then(load_predefined_cmap);
+
+                // When /Encoding is an embedded CMap stream rather than a
+                // predefined name, parse it for codespace-range-aware
+                // code-to-CID decoding.
+                let embedded_cmap = if cjk_enc.is_none() {
+                    extract_embedded_cmap(doc, fd)
+                } else {
+                    None
+                };
                 let metrics = if let Some(ref cm) = cid_met {
                     // Create a FontMetrics from CID font data for backward compat
                     FontMetrics::new(
@@ -673,10 +711,22 @@ fn load_font_if_needed(
                     };
                 let base_name = strip_subset_prefix(raw_base_name).to_string();
 
-                (metrics, cmap, base_name, cid_met, true, wm, None, cjk_enc)
+                (
+                    metrics,
+                    cmap,
+                    base_name,
+                    cid_met,
+                    true,
+                    wm,
+                    None,
+                    cjk_enc,
+                    embedded_cmap,
+                    predefined_cid_cmap,
+                )
             } else {
                 // Simple font
-                let metrics = match extract_font_metrics(doc, fd) {
+                let encoding = extract_font_encoding(doc, fd);
+                let metrics = match extract_font_metrics(doc, fd, encoding.as_ref()) {
                     Ok(m) => m,
                     Err(_) => {
                         if options.collect_warnings {
@@ -693,7 +743,6 @@ fn load_font_if_needed(
                     }
                 };
                 let cmap = extract_tounicode_cmap(doc, fd);
-                let encoding = extract_font_encoding(doc, fd);
                 let raw_base_name_owned;
                 let raw_base_name =
                     if let Some(n) = fd.get(b"BaseFont").ok().and_then(|o| o.as_name().ok()) {
@@ -704,7 +753,9 @@ fn load_font_if_needed(
                     };
                 let base_name = strip_subset_prefix(raw_base_name).to_string();
 
-                (metrics, cmap, base_name, None, false, 0, encoding, None)
+                (
+                    metrics, cmap, base_name, None, false, 0, encoding, None, None, None,
+                )
             }
         } else {
             // Font not found in page resources — use defaults
@@ -727,6 +778,8 @@ fn load_font_if_needed(
                 0,
                 None,
                 None,
+                None,
+                None,
             )
         };
 
@@ -741,6 +794,8 @@ fn load_font_if_needed(
             writing_mode,
             encoding,
             cjk_encoding: cjk_enc,
+            embedded_cmap,
+            predefined_cid_cmap,
         },
     );
 }
@@ -754,6 +809,46 @@ fn extract_tounicode_cmap(doc: &lopdf::Document, fd: &lopdf::Dictionary) -> Opti
     CMap::parse(&data).ok()
 }
 
+/// Extract an embedded CMap program from a Type0 font's `/Encoding` entry.
+///
+/// Returns `None` when `/Encoding` is a name (predefined encoding, handled
+/// separately via [`cjk_encoding::encoding_for_cmap`]) rather than a stream.
+fn extract_embedded_cmap(doc: &lopdf::Document, fd: &lopdf::Dictionary) -> Option<EmbeddedCMap> {
+    let encoding_obj = fd.get(b"Encoding").ok()?;
+    let encoding_obj = resolve_ref(doc, encoding_obj);
+    parse_embedded_cmap_stream(doc, encoding_obj)
+}
+
+/// Parse an embedded CMap program from an already-resolved `/Encoding`
+/// stream object, resolving `/UseCMap` chaining before returning.
+///
+/// A parent CMap may be named either by the stream dict's `/UseCMap` entry
+/// (a name for a predefined CMap, or an indirect reference to another CMap
+/// stream) or by an in-body `/Name usecmap` operator (the PostScript-level
+/// form, read via [`EmbeddedCMap::use_cmap_name`] when `/UseCMap` itself is
+/// absent). Once found, the parent is loaded — via [`load_predefined_cmap`]
+/// for a name, or recursively via this same function for a stream — and
+/// merged in via [`EmbeddedCMap::merge_usecmap`].
+fn parse_embedded_cmap_stream(doc: &lopdf::Document, encoding_obj: &lopdf::Object) -> Option<EmbeddedCMap> {
+    let stream = encoding_obj.as_stream().ok()?;
+    let data = decode_stream(stream).ok()?;
+    let mut cmap = EmbeddedCMap::parse(&data).ok()?;
+
+    let parent = match stream.dict.get(b"UseCMap") {
+        Ok(use_cmap_obj) => match resolve_ref(doc, use_cmap_obj) {
+            lopdf::Object::Name(name) => load_predefined_cmap(&String::from_utf8_lossy(name)),
+            parent_obj @ lopdf::Object::Stream(_) => parse_embedded_cmap_stream(doc, parent_obj),
+            _ => None,
+        },
+        Err(_) => cmap.use_cmap_name().and_then(load_predefined_cmap),
+    };
+    if let Some(parent) = parent {
+        cmap.merge_usecmap(&parent);
+    }
+
+    Some(cmap)
+}
+
 /// Extract font encoding from a simple font dictionary's /Encoding entry.
 fn extract_font_encoding(doc: &lopdf::Document, fd: &lopdf::Dictionary) -> Option<FontEncoding> {
     let encoding_obj = fd.get(b"Encoding").ok()?;
@@ -875,18 +970,29 @@ fn get_width_fn(cached: Option<&CachedFont>) -> Box<dyn Fn(u32) -> f64 + '_> {
 ///
 /// Unlike `show_string_cid` which always reads 2-byte pairs, this function
 /// uses the CJK encoding to determine byte boundaries (1 or 2 bytes per char).
+///
+/// `cid_cmap` is the bundled code-to-CID table for this predefined CMap name
+/// (see [`load_predefined_cmap`]), if one is bundled. When present, the
+/// decoded code is translated through it before the width lookup, so
+/// `get_width`/`map_cid_to_gid` operate on the font's actual CID space
+/// instead of the raw encoding byte code; `RawChar::char_code` still carries
+/// the original decoded code, since CJK Unicode resolution
+/// (`cjk_encoding::decode_cjk_string`) already produced the right text from
+/// it and doesn't need the CID.
 fn show_string_cjk(
     text_state: &mut TextState,
     string_bytes: &[u8],
     get_width: &dyn Fn(u32) -> f64,
     encoding: &'static encoding_rs::Encoding,
+    cid_cmap: Option<&EmbeddedCMap>,
 ) -> Vec<crate::text_renderer::RawChar> {
     let decoded = cjk_encoding::decode_cjk_string(string_bytes, encoding);
     let mut chars = Vec::with_capacity(decoded.len());
 
     for dc in decoded {
         let text_matrix = text_state.text_matrix_array();
-        let w0 = get_width(dc.char_code);
+        let cid = cid_cmap.and_then(|cm| cm.lookup(dc.char_code)).unwrap_or(dc.char_code);
+        let w0 = get_width(cid);
         let font_size = text_state.font_size;
         let char_spacing = text_state.char_spacing;
         let word_spacing = if dc.char_code == 32 {
@@ -909,6 +1015,83 @@ fn show_string_cjk(
     chars
 }
 
+/// Show a string using an embedded CMap's codespace-range-aware byte decoding.
+///
+/// Unlike `show_string_cid` which always reads 2-byte pairs, this walks the
+/// string using the CMap's codespace ranges to determine each code's byte
+/// width, then translates the code to a CID (defaulting to CID 0 on miss)
+/// before looking up its width. Word spacing (PDF spec 9.3.3) is gated on
+/// `EmbeddedCMap::decode_with_space_flag`'s report of whether the raw code
+/// was the single byte `0x20`, not on the CID it decodes to — mirroring
+/// `show_string_cjk` above, since a CID mapping can send `0x20` anywhere.
+fn show_string_embedded_cmap(
+    text_state: &mut TextState,
+    string_bytes: &[u8],
+    get_width: &dyn Fn(u32) -> f64,
+    cmap: &EmbeddedCMap,
+) -> Vec<crate::text_renderer::RawChar> {
+    let mut chars = Vec::new();
+
+    for (cid, _len, is_single_byte_space) in cmap.decode_with_space_flag(string_bytes) {
+        let text_matrix = text_state.text_matrix_array();
+        let w0 = get_width(cid);
+        let font_size = text_state.font_size;
+        let char_spacing = text_state.char_spacing;
+        let word_spacing = if is_single_byte_space {
+            text_state.word_spacing
+        } else {
+            0.0
+        };
+        let h_scaling = text_state.h_scaling_normalized();
+        let tx = ((w0 / 1000.0) * font_size + char_spacing + word_spacing) * h_scaling;
+
+        chars.push(crate::text_renderer::RawChar {
+            char_code: cid,
+            displacement: tx,
+            text_matrix,
+        });
+
+        text_state.advance_text_position(tx);
+    }
+
+    chars
+}
+
+/// TJ operator with embedded-CMap-aware byte decoding.
+///
+/// Like `show_string_with_positioning_mode` but decodes string bytes via the
+/// embedded CMap's codespace ranges when one is provided, falling back to
+/// 2-byte CID mode when `cmap` is `None`.
+fn show_string_with_positioning_embedded_cmap(
+    text_state: &mut TextState,
+    elements: &[TjElement],
+    get_width: &dyn Fn(u32) -> f64,
+    cmap: Option<&EmbeddedCMap>,
+) -> Vec<crate::text_renderer::RawChar> {
+    let mut chars = Vec::new();
+
+    for element in elements {
+        match element {
+            TjElement::String(bytes) => {
+                let mut sub_chars = if let Some(cm) = cmap {
+                    show_string_embedded_cmap(text_state, bytes, get_width, cm)
+                } else {
+                    show_string_cid(text_state, bytes, get_width)
+                };
+                chars.append(&mut sub_chars);
+            }
+            TjElement::Adjustment(adj) => {
+                let font_size = text_state.font_size;
+                let h_scaling = text_state.h_scaling_normalized();
+                let tx = -(adj / 1000.0) * font_size * h_scaling;
+                text_state.advance_text_position(tx);
+            }
+        }
+    }
+
+    chars
+}
+
 /// TJ operator with CJK-aware byte decoding.
 ///
 /// Like `show_string_with_positioning_mode` but uses CJK variable-length byte
@@ -919,6 +1102,7 @@ fn show_string_with_positioning_cjk(
     elements: &[TjElement],
     get_width: &dyn Fn(u32) -> f64,
     encoding: Option<&'static encoding_rs::Encoding>,
+    cid_cmap: Option<&EmbeddedCMap>,
 ) -> Vec<crate::text_renderer::RawChar> {
     let mut chars = Vec::new();
 
@@ -926,7 +1110,7 @@ fn show_string_with_positioning_cjk(
         match element {
             TjElement::String(bytes) => {
                 let mut sub_chars = if let Some(enc) = encoding {
-                    show_string_cjk(text_state, bytes, get_width, enc)
+                    show_string_cjk(text_state, bytes, get_width, enc, cid_cmap)
                 } else {
                     show_string_cid(text_state, bytes, get_width)
                 };
@@ -960,7 +1144,10 @@ fn handle_tj(
     let cached = font_cache.get(&tstate.font_name);
     let width_fn = get_width_fn(cached);
     let raw_chars = if let Some(enc) = cached.and_then(|c| c.cjk_encoding) {
-        show_string_cjk(tstate, string_bytes, &*width_fn, enc)
+        let cid_cmap = cached.and_then(|c| c.predefined_cid_cmap.as_ref());
+        show_string_cjk(tstate, string_bytes, &*width_fn, enc, cid_cmap)
+    } else if let Some(cm) = cached.and_then(|c| c.embedded_cmap.as_ref()) {
+        show_string_embedded_cmap(tstate, string_bytes, &*width_fn, cm)
     } else if cached.is_some_and(|c| c.is_cid_font) {
         show_string_cid(tstate, string_bytes, &*width_fn)
     } else {
@@ -1004,9 +1191,15 @@ fn handle_tj_array(
     let cached = font_cache.get(&tstate.font_name);
     let width_fn = get_width_fn(cached);
     let cjk_enc = cached.and_then(|c| c.cjk_encoding);
+    let embedded_cmap = cached.and_then(|c| c.embedded_cmap.as_ref());
+    let predefined_cid_cmap = cached.and_then(|c| c.predefined_cid_cmap.as_ref());
     let is_cid = cached.is_some_and(|c| c.is_cid_font);
-    let raw_chars = if cjk_enc.is_some() || is_cid {
-        show_string_with_positioning_cjk(tstate, &elements, &*width_fn, cjk_enc)
+    let raw_chars = if cjk_enc.is_some() {
+        show_string_with_positioning_cjk(tstate, &elements, &*width_fn, cjk_enc, predefined_cid_cmap)
+    } else if embedded_cmap.is_some() {
+        show_string_with_positioning_embedded_cmap(tstate, &elements, &*width_fn, embedded_cmap)
+    } else if is_cid {
+        show_string_with_positioning_cjk(tstate, &elements, &*width_fn, None, None)
     } else {
         show_string_with_positioning_mode(tstate, &elements, &*width_fn, false)
     };
@@ -1021,6 +1214,24 @@ fn handle_tj_array(
     );
 }
 
+/// Resolve the CID to use for width/glyph lookup from a decoded character's
+/// code.
+///
+/// For Identity encoding and embedded CMap streams, `char_code` is already
+/// the CID — decoding (via `EmbeddedCMap::decode`) maps it before it ever
+/// reaches `RawChar`. For CJK predefined-CMap encodings, `char_code` is
+/// still the raw encoding byte code (needed downstream to reconstruct
+/// Unicode via `cjk_encoding`), so translate it through the bundled
+/// code-to-CID table when one is available, falling back to treating the
+/// code as the CID directly (the historical behavior) otherwise.
+fn resolve_cid_for_width(cf: &CachedFont, char_code: u32) -> u32 {
+    if cf.cjk_encoding.is_some() {
+        cf.predefined_cid_cmap.as_ref().and_then(|cm| cm.lookup(char_code)).unwrap_or(char_code)
+    } else {
+        char_code
+    }
+}
+
 fn emit_char_events(
     raw_chars: Vec<crate::text_renderer::RawChar>,
     tstate: &TextState,
@@ -1033,16 +1244,27 @@ fn emit_char_events(
     let font_name = cached.map_or_else(|| tstate.font_name.clone(), |c| c.base_name.clone());
 
     for rc in raw_chars {
-        // Unicode resolution chain: CMap → FontEncoding → CJK encoding → char::from_u32
+        // Unicode resolution chain:
+        // ToUnicode CMap → CID ordering table → FontEncoding → CJK encoding → char::from_u32
         let unicode = cached
             .and_then(|c| {
                 // 1. Try ToUnicode CMap (highest priority)
                 c.cmap
                     .as_ref()
-                    .and_then(|cm| cm.lookup(rc.char_code).map(|s| s.to_string()))
+                    .and_then(|cm| cm.code_to_unicode(rc.char_code).map(|s| s.to_string()))
+            })
+            .or_else(|| {
+                // 2. Try the CID font's Adobe character-collection table
+                // (Japan1/GB1/CNS1/Korea1) when there's no /ToUnicode CMap.
+                cached.and_then(|c| {
+                    c.cid_metrics
+                        .as_ref()
+                        .and_then(|cm| cm.cid_to_unicode(rc.char_code))
+                        .map(|ch| ch.to_string())
+                })
             })
             .or_else(|| {
-                // 2. Try font encoding (for simple fonts)
+                // 3. Try font encoding (for simple fonts)
                 cached.and_then(|c| {
                     c.encoding.as_ref().and_then(|enc| {
                         if rc.char_code <= 255 {
@@ -1054,7 +1276,7 @@ fn emit_char_events(
                 })
             })
             .or_else(|| {
-                // 3. Try CJK encoding (for CID fonts with predefined CMaps like GBK-EUC-H)
+                // 4. Try CJK encoding (for CID fonts with predefined CMaps like GBK-EUC-H)
                 cached.and_then(|c| {
                     c.cjk_encoding.map(|enc| {
                         let bytes = if rc.char_code > 0xFF {
@@ -1068,7 +1290,7 @@ fn emit_char_events(
                 })
             })
             .or_else(|| {
-                // 4. Fallback: char::from_u32 for ASCII-range codes
+                // 5. Fallback: char::from_u32 for ASCII-range codes
                 char::from_u32(rc.char_code).map(|ch| ch.to_string())
             });
 
@@ -1077,7 +1299,7 @@ fn emit_char_events(
             Some(cf) if cf.is_cid_font => cf
                 .cid_metrics
                 .as_ref()
-                .map_or(600.0, |cm| cm.get_width(rc.char_code)),
+                .map_or(600.0, |cm| cm.get_width(resolve_cid_for_width(cf, rc.char_code))),
             Some(cf) => cf.metrics.get_width(rc.char_code),
             None => 600.0,
         };
@@ -1439,6 +1661,20 @@ fn handle_image_xobject(
         }
     });
 
+    let is_mask = stream
+        .dict
+        .get(b"ImageMask")
+        .ok()
+        .and_then(|o| o.as_bool().ok())
+        .unwrap_or(false);
+
+    let decode = stream
+        .dict
+        .get(b"Decode")
+        .ok()
+        .and_then(|o| o.as_array().ok())
+        .map(|arr| arr.iter().filter_map(|v| object_to_f64(v).ok()).collect());
+
     handler.on_image(ImageEvent {
         name: name.to_string(),
         ctm: gstate.ctm_array(),
@@ -1447,6 +1683,9 @@ fn handle_image_xobject(
         colorspace,
         bits_per_component,
         filter,
+        is_mask,
+        decode,
+        data: None,
     });
 }
 
@@ -1457,10 +1696,19 @@ fn handle_image_xobject(
 /// - operands[1]: LiteralString containing the raw image data bytes
 ///
 /// Abbreviated keys and values are expanded to their full PDF names.
+/// `/Filter` may be a single abbreviated name or an array of them (a filter
+/// chain); like XObject images, the *last* filter in the chain determines
+/// the image format. When `options.extract_image_data` is set, the data is
+/// decoded the same way [`LopdfBackend::extract_image_content`] decodes an
+/// XObject image (see [`decode_inline_image_data`]).
+///
+/// [`LopdfBackend::extract_image_content`]: crate::lopdf_backend::LopdfBackend
+/// [`decode_inline_image_data`]: crate::lopdf_backend::decode_inline_image_data
 fn handle_inline_image(
     op: &Operator,
     op_index: usize,
     gstate: &InterpreterState,
+    options: &ExtractOptions,
     handler: &mut dyn ContentHandler,
 ) {
     if op.operands.len() < 2 {
@@ -1471,13 +1719,19 @@ fn handle_inline_image(
         Operand::Array(arr) => arr,
         _ => return,
     };
+    let raw_data = match &op.operands[1] {
+        Operand::LiteralString(bytes) => bytes,
+        _ => return,
+    };
 
     // Parse key-value pairs from the flattened array
     let mut width: u32 = 0;
     let mut height: u32 = 0;
     let mut colorspace: Option<String> = None;
     let mut bits_per_component: Option<u32> = None;
-    let mut filter: Option<String> = None;
+    let mut filter_chain: Vec<String> = Vec::new();
+    let mut is_mask = false;
+    let mut decode: Option<Vec<f64>> = None;
 
     let mut i = 0;
     while i + 1 < dict_entries.len() {
@@ -1511,9 +1765,24 @@ fn handle_inline_image(
                     bits_per_component = Some(v);
                 }
             }
-            "Filter" => {
-                if let Operand::Name(f) = value {
-                    filter = Some(expand_inline_image_filter(f));
+            "Filter" => match value {
+                Operand::Name(f) => filter_chain.push(expand_inline_image_filter(f)),
+                Operand::Array(arr) => {
+                    filter_chain.extend(arr.iter().filter_map(|v| match v {
+                        Operand::Name(f) => Some(expand_inline_image_filter(f)),
+                        _ => None,
+                    }));
+                }
+                _ => {}
+            },
+            "ImageMask" => {
+                if let Operand::Boolean(b) = value {
+                    is_mask = *b;
+                }
+            }
+            "Decode" => {
+                if let Operand::Array(arr) = value {
+                    decode = Some(arr.iter().filter_map(operand_to_f64).collect());
                 }
             }
             _ => {}
@@ -1522,6 +1791,18 @@ fn handle_inline_image(
         i += 2;
     }
 
+    // As with XObject images, the last filter in the chain determines the
+    // format (e.g. `[ASCII85Decode DCTDecode]` is still a JPEG).
+    let filter = filter_chain.last().cloned();
+    let data = if options.extract_image_data {
+        Some(crate::lopdf_backend::decode_inline_image_data(
+            &filter_chain,
+            raw_data,
+        ))
+    } else {
+        None
+    };
+
     handler.on_image(ImageEvent {
         name: format!("inline-{op_index}"),
         ctm: gstate.ctm_array(),
@@ -1530,6 +1811,9 @@ fn handle_inline_image(
         colorspace,
         bits_per_component,
         filter,
+        is_mask,
+        decode,
+        data,
     });
 }
 
@@ -1583,6 +1867,15 @@ fn operand_to_u32(op: &Operand) -> Option<u32> {
     }
 }
 
+/// Convert an operand to f64, supporting Integer and Real types.
+fn operand_to_f64(op: &Operand) -> Option<f64> {
+    match op {
+        Operand::Integer(i) => Some(*i as f64),
+        Operand::Real(f) => Some(*f),
+        _ => None,
+    }
+}
+
 // --- Helpers ---
 
 /// Resolve an indirect reference, returning the referenced object.
@@ -1944,6 +2237,416 @@ mod tests {
         assert_eq!(handler.chars[1].char_code, 0x6587);
     }
 
+    /// Build a resources dictionary containing a Type0 font whose `/Encoding`
+    /// is an embedded CMap stream (not a predefined name), mapping 1-byte
+    /// codes 0x41/0x42 to CIDs 10/20.
+    fn make_embedded_cmap_font_resources(doc: &mut lopdf::Document) -> lopdf::Dictionary {
+        use lopdf::{Object, Stream, dictionary};
+
+        let encoding_data = b"\
+            /CIDInit /ProcSet findresource begin\n\
+            12 dict begin\n\
+            begincmap\n\
+            1 begincodespacerange\n\
+            <00> <FF>\n\
+            endcodespacerange\n\
+            2 begincidchar\n\
+            <41> 10\n\
+            <42> 20\n\
+            endcidchar\n\
+            endcmap\n";
+        let encoding_stream = Stream::new(dictionary! {}, encoding_data.to_vec());
+        let encoding_id = doc.add_object(Object::Stream(encoding_stream));
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "CustomCJK",
+            "DW" => Object::Integer(1000),
+            "CIDToGIDMap" => "Identity",
+        };
+        let cid_font_id = doc.add_object(Object::Dictionary(cid_font_dict));
+
+        let type0_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type0",
+            "BaseFont" => "CustomCJK",
+            "Encoding" => Object::Reference(encoding_id),
+            "DescendantFonts" => Object::Array(vec![Object::Reference(cid_font_id)]),
+        };
+        let type0_id = doc.add_object(Object::Dictionary(type0_dict));
+
+        dictionary! {
+            "Font" => Object::Dictionary(dictionary! {
+                "F1" => Object::Reference(type0_id),
+            }),
+        }
+    }
+
+    #[test]
+    fn interpret_embedded_cmap_single_byte_codes_map_to_cids() {
+        let mut doc = lopdf::Document::with_version("1.5");
+        let resources = make_embedded_cmap_font_resources(&mut doc);
+
+        // 0x41 -> CID 10, 0x42 -> CID 20 via the embedded CMap
+        let stream = b"BT /F1 12 Tf <4142> Tj ET";
+
+        let mut handler = CollectingHandler::new();
+        let mut gstate = InterpreterState::new();
+        let mut tstate = TextState::new();
+
+        interpret_content_stream(
+            &doc,
+            stream,
+            &resources,
+            &mut handler,
+            &default_options(),
+            0,
+            &mut gstate,
+            &mut tstate,
+        )
+        .unwrap();
+
+        // 1-byte codespace range means 2 characters, not 1 (as 2-byte CID mode would give)
+        assert_eq!(handler.chars.len(), 2);
+        assert_eq!(handler.chars[0].char_code, 10);
+        assert_eq!(handler.chars[1].char_code, 20);
+    }
+
+    #[test]
+    fn interpret_embedded_cmap_unmapped_code_defaults_to_cid_zero() {
+        let mut doc = lopdf::Document::with_version("1.5");
+        let resources = make_embedded_cmap_font_resources(&mut doc);
+
+        // 0x43 is within the codespace range but has no cidchar entry
+        let stream = b"BT /F1 12 Tf <43> Tj ET";
+
+        let mut handler = CollectingHandler::new();
+        let mut gstate = InterpreterState::new();
+        let mut tstate = TextState::new();
+
+        interpret_content_stream(
+            &doc,
+            stream,
+            &resources,
+            &mut handler,
+            &default_options(),
+            0,
+            &mut gstate,
+            &mut tstate,
+        )
+        .unwrap();
+
+        assert_eq!(handler.chars.len(), 1);
+        assert_eq!(handler.chars[0].char_code, 0);
+    }
+
+    /// Build a resources dictionary containing a Type0 font whose embedded
+    /// CMap stream has no codespace ranges or mappings of its own for CID
+    /// 0x41, and chains to a parent CMap stream (via the `/UseCMap` stream
+    /// dict entry) that supplies both.
+    fn make_embedded_cmap_font_resources_with_usecmap_stream(doc: &mut lopdf::Document) -> lopdf::Dictionary {
+        use lopdf::{Object, Stream, dictionary};
+
+        let parent_data = b"\
+            1 begincodespacerange\n\
+            <00> <FF>\n\
+            endcodespacerange\n\
+            begincidchar\n\
+            <41> 10\n\
+            <42> 20\n\
+            endcidchar\n";
+        let parent_stream = Stream::new(dictionary! {}, parent_data.to_vec());
+        let parent_id = doc.add_object(Object::Stream(parent_stream));
+
+        let child_data = b"\
+            begincidchar\n\
+            <42> 999\n\
+            endcidchar\n";
+        let child_stream = Stream::new(
+            dictionary! {
+                "UseCMap" => Object::Reference(parent_id),
+            },
+            child_data.to_vec(),
+        );
+        let encoding_id = doc.add_object(Object::Stream(child_stream));
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "CustomCJK",
+            "DW" => Object::Integer(1000),
+            "CIDToGIDMap" => "Identity",
+        };
+        let cid_font_id = doc.add_object(Object::Dictionary(cid_font_dict));
+
+        let type0_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type0",
+            "BaseFont" => "CustomCJK",
+            "Encoding" => Object::Reference(encoding_id),
+            "DescendantFonts" => Object::Array(vec![Object::Reference(cid_font_id)]),
+        };
+        let type0_id = doc.add_object(Object::Dictionary(type0_dict));
+
+        dictionary! {
+            "Font" => Object::Dictionary(dictionary! {
+                "F1" => Object::Reference(type0_id),
+            }),
+        }
+    }
+
+    #[test]
+    fn interpret_embedded_cmap_usecmap_stream_inherits_codespace_and_mappings() {
+        let mut doc = lopdf::Document::with_version("1.5");
+        let resources = make_embedded_cmap_font_resources_with_usecmap_stream(&mut doc);
+
+        // 0x41 has no mapping of its own and no codespace range — both must
+        // come from the /UseCMap parent stream; 0x42 overrides the parent's
+        // mapping with the child's own.
+        let stream = b"BT /F1 12 Tf <4142> Tj ET";
+
+        let mut handler = CollectingHandler::new();
+        let mut gstate = InterpreterState::new();
+        let mut tstate = TextState::new();
+
+        interpret_content_stream(
+            &doc,
+            stream,
+            &resources,
+            &mut handler,
+            &default_options(),
+            0,
+            &mut gstate,
+            &mut tstate,
+        )
+        .unwrap();
+
+        assert_eq!(handler.chars.len(), 2);
+        assert_eq!(handler.chars[0].char_code, 10);
+        assert_eq!(handler.chars[1].char_code, 999);
+    }
+
+    /// Build a resources dictionary containing a Type0 font whose embedded
+    /// CMap maps the space byte `0x20` to a CID *other than* 32, and some
+    /// other byte to CID 32 — so a word-spacing implementation that branches
+    /// on the decoded CID instead of the raw byte gets exactly backwards.
+    fn make_embedded_cmap_font_resources_with_space_swap(doc: &mut lopdf::Document) -> lopdf::Dictionary {
+        use lopdf::{Object, Stream, dictionary};
+
+        let encoding_data = b"\
+            /CIDInit /ProcSet findresource begin\n\
+            12 dict begin\n\
+            begincmap\n\
+            1 begincodespacerange\n\
+            <00> <FF>\n\
+            endcodespacerange\n\
+            2 begincidchar\n\
+            <20> 99\n\
+            <43> 32\n\
+            endcidchar\n\
+            endcmap\n";
+        let encoding_stream = Stream::new(dictionary! {}, encoding_data.to_vec());
+        let encoding_id = doc.add_object(Object::Stream(encoding_stream));
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "CustomCJK",
+            "DW" => Object::Integer(1000),
+            "CIDToGIDMap" => "Identity",
+        };
+        let cid_font_id = doc.add_object(Object::Dictionary(cid_font_dict));
+
+        let type0_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type0",
+            "BaseFont" => "CustomCJK",
+            "Encoding" => Object::Reference(encoding_id),
+            "DescendantFonts" => Object::Array(vec![Object::Reference(cid_font_id)]),
+        };
+        let type0_id = doc.add_object(Object::Dictionary(type0_dict));
+
+        dictionary! {
+            "Font" => Object::Dictionary(dictionary! {
+                "F1" => Object::Reference(type0_id),
+            }),
+        }
+    }
+
+    #[test]
+    fn interpret_embedded_cmap_word_spacing_follows_raw_byte_not_cid() {
+        let mut doc = lopdf::Document::with_version("1.5");
+        let resources = make_embedded_cmap_font_resources_with_space_swap(&mut doc);
+
+        // Byte 0x20 (a real space) maps to CID 99; byte 0x43 (not a space)
+        // maps to CID 32. 50 Tw should widen the space byte's advance and
+        // leave the other byte's advance alone, regardless of CID. A third
+        // byte follows so both advances show up as start-position gaps.
+        let stream = b"BT /F1 12 Tf 50 Tw <204320> Tj ET";
+
+        let mut handler = CollectingHandler::new();
+        let mut gstate = InterpreterState::new();
+        let mut tstate = TextState::new();
+
+        interpret_content_stream(
+            &doc,
+            stream,
+            &resources,
+            &mut handler,
+            &default_options(),
+            0,
+            &mut gstate,
+            &mut tstate,
+        )
+        .unwrap();
+
+        assert_eq!(handler.chars.len(), 3);
+        assert_eq!(handler.chars[0].char_code, 99);
+        assert_eq!(handler.chars[1].char_code, 32);
+        assert_eq!(handler.chars[2].char_code, 99);
+        // `displacement` is the glyph width (DW 1000 for both), so the word
+        // spacing shows only in the text position: at 12pt the non-space
+        // byte advances 12 and the space byte exactly 50 more.
+        let space_advance = handler.chars[1].text_matrix[4] - handler.chars[0].text_matrix[4];
+        let other_advance = handler.chars[2].text_matrix[4] - handler.chars[1].text_matrix[4];
+        assert!(
+            (space_advance - 62.0).abs() < 1e-6 && (other_advance - 12.0).abs() < 1e-6,
+            "expected space byte (CID 99) to carry the word spacing and the \
+             non-space byte (CID 32) to carry none, got advances {} and {}",
+            space_advance,
+            other_advance
+        );
+    }
+
+    /// Type0 font with predefined CMap `90ms-RKSJ-H` and a `/W` override on
+    /// the CID that `'A'` (byte `0x41`) resolves to via the bundled
+    /// ASCII-range predefined CMap table (CID = `0x41 - 0x20 + 1` = 34).
+    fn make_predefined_cmap_font_resources(doc: &mut lopdf::Document) -> lopdf::Dictionary {
+        use lopdf::{Object, dictionary};
+
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "MSMincho",
+            "DW" => Object::Integer(1000),
+            "W" => Object::Array(vec![
+                Object::Integer(34),
+                Object::Array(vec![Object::Integer(300)]),
+            ]),
+            "CIDToGIDMap" => "Identity",
+        };
+        let cid_font_id = doc.add_object(Object::Dictionary(cid_font_dict));
+
+        let type0_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type0",
+            "BaseFont" => "MSMincho",
+            "Encoding" => "90ms-RKSJ-H",
+            "DescendantFonts" => Object::Array(vec![Object::Reference(cid_font_id)]),
+        };
+        let type0_id = doc.add_object(Object::Dictionary(type0_dict));
+
+        dictionary! {
+            "Font" => Object::Dictionary(dictionary! {
+                "F1" => Object::Reference(type0_id),
+            }),
+        }
+    }
+
+    #[test]
+    fn interpret_predefined_cmap_width_uses_bundled_cid_table() {
+        let mut doc = lopdf::Document::with_version("1.5");
+        let resources = make_predefined_cmap_font_resources(&mut doc);
+
+        // Single byte 'A' (0x41) under 90ms-RKSJ-H maps to CID 34 via the
+        // bundled predefined-CMap table, which has a /W override of 300
+        // (vs. the /DW default of 1000).
+        let stream = b"BT /F1 12 Tf (A) Tj ET";
+
+        let mut handler = CollectingHandler::new();
+        let mut gstate = InterpreterState::new();
+        let mut tstate = TextState::new();
+
+        interpret_content_stream(
+            &doc,
+            stream,
+            &resources,
+            &mut handler,
+            &default_options(),
+            0,
+            &mut gstate,
+            &mut tstate,
+        )
+        .unwrap();
+
+        assert_eq!(handler.chars.len(), 1);
+        // The emitted displacement is the CID's /W override (300), not the
+        // /DW default (1000) that raw byte code 0x41 (=65, not in /W) would
+        // fall back to if it had been used as the CID directly.
+        assert_eq!(handler.chars[0].displacement, 300.0);
+    }
+
+    #[test]
+    fn interpret_cid_font_without_tounicode_falls_back_to_ordering_table() {
+        use lopdf::{Object, dictionary};
+
+        let mut doc = lopdf::Document::with_version("1.5");
+
+        // CIDFont with a Japan1 CIDSystemInfo and no /ToUnicode on the Type0 font
+        let cid_font_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "MSGothic",
+            "DW" => Object::Integer(1000),
+            "CIDToGIDMap" => "Identity",
+            "CIDSystemInfo" => Object::Dictionary(dictionary! {
+                "Registry" => Object::String("Adobe".as_bytes().to_vec(), lopdf::StringFormat::Literal),
+                "Ordering" => Object::String("Japan1".as_bytes().to_vec(), lopdf::StringFormat::Literal),
+                "Supplement" => Object::Integer(0),
+            }),
+        };
+        let cid_font_id = doc.add_object(Object::Dictionary(cid_font_dict));
+
+        let type0_dict = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type0",
+            "BaseFont" => "MSGothic",
+            "Encoding" => "Identity-H",
+            "DescendantFonts" => Object::Array(vec![Object::Reference(cid_font_id)]),
+        };
+        let type0_id = doc.add_object(Object::Dictionary(type0_dict));
+
+        let resources = dictionary! {
+            "Font" => Object::Dictionary(dictionary! {
+                "F1" => Object::Reference(type0_id),
+            }),
+        };
+
+        // CID 1 resolves to U+0020 (space) in the bundled Japan1 ordering table
+        let stream = b"BT /F1 12 Tf <0001> Tj ET";
+
+        let mut handler = CollectingHandler::new();
+        let mut gstate = InterpreterState::new();
+        let mut tstate = TextState::new();
+
+        interpret_content_stream(
+            &doc,
+            stream,
+            &resources,
+            &mut handler,
+            &default_options(),
+            0,
+            &mut gstate,
+            &mut tstate,
+        )
+        .unwrap();
+
+        assert_eq!(handler.chars.len(), 1);
+        assert_eq!(handler.chars[0].char_code, 1);
+        assert_eq!(handler.chars[0].unicode, Some(" ".to_string()));
+    }
+
     #[test]
     fn interpret_subset_font_name_stripped() {
         let mut doc = lopdf::Document::with_version("1.5");
@@ -2597,6 +3300,144 @@ mod tests {
         }
     }
 
+    #[test]
+    fn interpret_inline_image_filter_array_uses_last_filter() {
+        let doc = lopdf::Document::with_version("1.5");
+        let resources = empty_resources();
+        // /Filter as an array: the last entry (DCT) determines the format,
+        // matching how `extract_image_content` resolves XObject filter chains.
+        let mut stream: Vec<u8> = Vec::new();
+        stream.extend_from_slice(
+            b"q 10 0 0 10 0 0 cm BI /W 1 /H 1 /CS /RGB /BPC 8 /F [/A85 /DCT] ID ",
+        );
+        stream.extend_from_slice(&[0xFF, 0xD8, 0xFF, 0xE0]);
+        stream.extend_from_slice(b" EI Q");
+
+        let mut handler = CollectingHandler::new();
+        let mut gstate = InterpreterState::new();
+        let mut tstate = TextState::new();
+
+        interpret_content_stream(
+            &doc,
+            &stream,
+            &resources,
+            &mut handler,
+            &default_options(),
+            0,
+            &mut gstate,
+            &mut tstate,
+        )
+        .unwrap();
+
+        assert_eq!(handler.images.len(), 1);
+        assert_eq!(
+            handler.images[0].filter,
+            Some("DCTDecode".to_string()),
+            "the last filter in the chain should win, as for XObject images"
+        );
+    }
+
+    #[test]
+    fn interpret_inline_image_data_not_populated_without_extract_image_data() {
+        let doc = lopdf::Document::with_version("1.5");
+        let resources = empty_resources();
+        let mut stream: Vec<u8> = Vec::new();
+        stream.extend_from_slice(b"BI /W 1 /H 1 /CS /G /BPC 8 ID ");
+        stream.push(200);
+        stream.extend_from_slice(b" EI");
+
+        let mut handler = CollectingHandler::new();
+        let mut gstate = InterpreterState::new();
+        let mut tstate = TextState::new();
+
+        interpret_content_stream(
+            &doc,
+            &stream,
+            &resources,
+            &mut handler,
+            &default_options(),
+            0,
+            &mut gstate,
+            &mut tstate,
+        )
+        .unwrap();
+
+        assert_eq!(handler.images[0].data, None);
+    }
+
+    #[test]
+    fn interpret_inline_image_data_populated_when_extract_image_data_enabled() {
+        let doc = lopdf::Document::with_version("1.5");
+        let resources = empty_resources();
+        let mut stream: Vec<u8> = Vec::new();
+        // 2x2 DeviceGray, 8 bpc, uncompressed — no /Filter at all.
+        stream.extend_from_slice(b"BI /W 2 /H 2 /CS /G /BPC 8 ID ");
+        stream.extend_from_slice(&[10, 20, 30, 40]);
+        stream.extend_from_slice(b" EI");
+
+        let mut handler = CollectingHandler::new();
+        let mut gstate = InterpreterState::new();
+        let mut tstate = TextState::new();
+        let options = ExtractOptions {
+            extract_image_data: true,
+            ..ExtractOptions::default()
+        };
+
+        interpret_content_stream(
+            &doc,
+            &stream,
+            &resources,
+            &mut handler,
+            &options,
+            0,
+            &mut gstate,
+            &mut tstate,
+        )
+        .unwrap();
+
+        assert_eq!(handler.images[0].data, Some(vec![10, 20, 30, 40]));
+    }
+
+    #[test]
+    fn interpret_inline_image_unfiltered_data_containing_ei_bytes_is_not_truncated() {
+        let doc = lopdf::Document::with_version("1.5");
+        let resources = empty_resources();
+        // 4x1 DeviceGray, 8 bpc = 4 bytes of unfiltered data. The second and
+        // third bytes spell out whitespace + "EI" + whitespace, which the old
+        // heuristic EI scan would have mistaken for the real terminator.
+        let mut stream: Vec<u8> = Vec::new();
+        stream.extend_from_slice(b"BI /W 4 /H 1 /CS /G /BPC 8 ID ");
+        stream.extend_from_slice(&[1, b' ', b'E', b'I']);
+        stream.extend_from_slice(b" EI");
+
+        let mut handler = CollectingHandler::new();
+        let mut gstate = InterpreterState::new();
+        let mut tstate = TextState::new();
+        let options = ExtractOptions {
+            extract_image_data: true,
+            ..ExtractOptions::default()
+        };
+
+        interpret_content_stream(
+            &doc,
+            &stream,
+            &resources,
+            &mut handler,
+            &options,
+            0,
+            &mut gstate,
+            &mut tstate,
+        )
+        .unwrap();
+
+        assert_eq!(handler.images.len(), 1);
+        assert_eq!(
+            handler.images[0].data,
+            Some(vec![1, b' ', b'E', b'I']),
+            "the computed data length should win over the incidental EI-like bytes inside it"
+        );
+    }
+
     // --- Marked content (BMC/BDC/EMC) tests ---
 
     #[test]
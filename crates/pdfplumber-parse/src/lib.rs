@@ -25,6 +25,7 @@ pub mod handler;
 pub mod interpreter;
 pub mod interpreter_state;
 pub mod lopdf_backend;
+pub mod names;
 pub mod page_geometry;
 pub mod text_renderer;
 pub mod text_state;
@@ -33,16 +34,18 @@ pub mod tokenizer;
 pub use backend::PdfBackend;
 pub use char_extraction::char_from_event;
 pub use cid_font::{
-    CidFontMetrics, CidFontType, CidSystemInfo, CidToGidMap, PredefinedCMapInfo,
-    extract_cid_font_metrics, get_descendant_font, get_type0_encoding, is_subset_font,
-    is_type0_font, parse_predefined_cmap_name, parse_w_array, strip_subset_prefix,
+    CidFontMetrics, CidFontType, CidSystemInfo, CidToGidMap, FontProgramType, FontSubstitution,
+    PredefinedCMapInfo, WidthStore, extract_cid_font_metrics, get_descendant_font,
+    get_type0_encoding, is_subset_font, is_type0_font, load_predefined_cmap,
+    parse_predefined_cmap_name, parse_w_array, select_font_substitution, strip_subset_prefix,
 };
-pub use cmap::{CMap, CidCMap};
+pub use cmap::{CMap, CidCMap, CodespaceRange, EmbeddedCMap};
 pub use error::BackendError;
 pub use font_metrics::{FontMetrics, extract_font_metrics};
 pub use handler::{CharEvent, ContentHandler, ImageEvent, PaintOp, PathEvent};
 pub use interpreter_state::InterpreterState;
 pub use lopdf_backend::{LopdfBackend, LopdfDocument, LopdfPage};
+pub use names::PdfName;
 pub use page_geometry::PageGeometry;
 pub use pdfplumber_core;
 pub use text_renderer::{
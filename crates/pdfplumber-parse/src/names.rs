@@ -0,0 +1,351 @@
+//! Interning for common PDF names.
+//!
+//! Dictionary keys and content-stream name operands are dominated by a
+//! small, fixed vocabulary (`/Type`, `/Font`, `/Subtype`, `/Resources`, and
+//! so on). [`PdfName`] maps that vocabulary to an enum so that comparing two
+//! *known* names (neither went through the `Other` fallback) is a
+//! discriminant compare instead of a byte-slice comparison; comparisons
+//! involving an `Other` name — including a known variant against an `Other`
+//! that happens to hold the same bytes — still fall back to comparing
+//! `as_bytes()`, and `Other` names still round-trip exactly, each paying one
+//! heap allocation for its `Box<[u8]>`.
+
+/// A PDF name, either one of the common names known at compile time or an
+/// arbitrary name outside that table.
+///
+/// Obtained via [`PdfName::intern`]. Two `PdfName`s compare equal iff they
+/// represent the same name, regardless of whether that happened to go
+/// through the `Other` fallback on one side and a known variant on the
+/// other (see the `PartialEq` impl).
+#[derive(Debug, Clone)]
+pub enum PdfName {
+    /// `/Type`
+    Type,
+    /// `/Subtype`
+    Subtype,
+    /// `/Catalog`
+    Catalog,
+    /// `/Pages`
+    Pages,
+    /// `/Page`
+    Page,
+    /// `/Kids`
+    Kids,
+    /// `/Parent`
+    Parent,
+    /// `/Count`
+    Count,
+    /// `/Root`
+    Root,
+    /// `/MediaBox`
+    MediaBox,
+    /// `/CropBox`
+    CropBox,
+    /// `/Rotate`
+    Rotate,
+    /// `/Resources`
+    Resources,
+    /// `/Contents`
+    Contents,
+    /// `/Annots`
+    Annots,
+    /// `/Font`
+    Font,
+    /// `/XObject`
+    XObject,
+    /// `/ColorSpace`
+    ColorSpace,
+    /// `/BaseFont`
+    BaseFont,
+    /// `/FontDescriptor`
+    FontDescriptor,
+    /// `/FirstChar`
+    FirstChar,
+    /// `/LastChar`
+    LastChar,
+    /// `/Widths`
+    Widths,
+    /// `/Encoding`
+    Encoding,
+    /// `/ToUnicode`
+    ToUnicode,
+    /// `/DescendantFonts`
+    DescendantFonts,
+    /// `/CIDToGIDMap`
+    CIDToGIDMap,
+    /// `/Length`
+    Length,
+    /// `/Filter`
+    Filter,
+    /// `/Image`
+    Image,
+    /// `/Form`
+    Form,
+    /// `/Group`
+    Group,
+    /// `/Matrix`
+    Matrix,
+    /// `/BBox`
+    BBox,
+    /// `/Encrypt`
+    Encrypt,
+    /// `/AcroForm`
+    AcroForm,
+    /// `/Fields`
+    Fields,
+    /// `/Widget`
+    Widget,
+    /// `/Outlines`
+    Outlines,
+    /// `/First`
+    First,
+    /// `/Last`
+    Last,
+    /// `/Next`
+    Next,
+    /// `/Prev`
+    Prev,
+    /// `/Dest`
+    Dest,
+    /// `/Title`
+    Title,
+    /// `/StructTreeRoot`
+    StructTreeRoot,
+    /// A name outside the table above, preserved verbatim.
+    Other(Box<[u8]>),
+}
+
+impl PdfName {
+    /// Intern `bytes` (the content of a `/Name` token, without the leading
+    /// slash) into a [`PdfName`], falling back to [`PdfName::Other`] for
+    /// names outside the common table.
+    pub fn intern(bytes: &[u8]) -> PdfName {
+        match bytes {
+            b"Type" => PdfName::Type,
+            b"Subtype" => PdfName::Subtype,
+            b"Catalog" => PdfName::Catalog,
+            b"Pages" => PdfName::Pages,
+            b"Page" => PdfName::Page,
+            b"Kids" => PdfName::Kids,
+            b"Parent" => PdfName::Parent,
+            b"Count" => PdfName::Count,
+            b"Root" => PdfName::Root,
+            b"MediaBox" => PdfName::MediaBox,
+            b"CropBox" => PdfName::CropBox,
+            b"Rotate" => PdfName::Rotate,
+            b"Resources" => PdfName::Resources,
+            b"Contents" => PdfName::Contents,
+            b"Annots" => PdfName::Annots,
+            b"Font" => PdfName::Font,
+            b"XObject" => PdfName::XObject,
+            b"ColorSpace" => PdfName::ColorSpace,
+            b"BaseFont" => PdfName::BaseFont,
+            b"FontDescriptor" => PdfName::FontDescriptor,
+            b"FirstChar" => PdfName::FirstChar,
+            b"LastChar" => PdfName::LastChar,
+            b"Widths" => PdfName::Widths,
+            b"Encoding" => PdfName::Encoding,
+            b"ToUnicode" => PdfName::ToUnicode,
+            b"DescendantFonts" => PdfName::DescendantFonts,
+            b"CIDToGIDMap" => PdfName::CIDToGIDMap,
+            b"Length" => PdfName::Length,
+            b"Filter" => PdfName::Filter,
+            b"Image" => PdfName::Image,
+            b"Form" => PdfName::Form,
+            b"Group" => PdfName::Group,
+            b"Matrix" => PdfName::Matrix,
+            b"BBox" => PdfName::BBox,
+            b"Encrypt" => PdfName::Encrypt,
+            b"AcroForm" => PdfName::AcroForm,
+            b"Fields" => PdfName::Fields,
+            b"Widget" => PdfName::Widget,
+            b"Outlines" => PdfName::Outlines,
+            b"First" => PdfName::First,
+            b"Last" => PdfName::Last,
+            b"Next" => PdfName::Next,
+            b"Prev" => PdfName::Prev,
+            b"Dest" => PdfName::Dest,
+            b"Title" => PdfName::Title,
+            b"StructTreeRoot" => PdfName::StructTreeRoot,
+            other => PdfName::Other(other.into()),
+        }
+    }
+
+    /// The original byte content of the name (without the leading slash).
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            PdfName::Type => b"Type",
+            PdfName::Subtype => b"Subtype",
+            PdfName::Catalog => b"Catalog",
+            PdfName::Pages => b"Pages",
+            PdfName::Page => b"Page",
+            PdfName::Kids => b"Kids",
+            PdfName::Parent => b"Parent",
+            PdfName::Count => b"Count",
+            PdfName::Root => b"Root",
+            PdfName::MediaBox => b"MediaBox",
+            PdfName::CropBox => b"CropBox",
+            PdfName::Rotate => b"Rotate",
+            PdfName::Resources => b"Resources",
+            PdfName::Contents => b"Contents",
+            PdfName::Annots => b"Annots",
+            PdfName::Font => b"Font",
+            PdfName::XObject => b"XObject",
+            PdfName::ColorSpace => b"ColorSpace",
+            PdfName::BaseFont => b"BaseFont",
+            PdfName::FontDescriptor => b"FontDescriptor",
+            PdfName::FirstChar => b"FirstChar",
+            PdfName::LastChar => b"LastChar",
+            PdfName::Widths => b"Widths",
+            PdfName::Encoding => b"Encoding",
+            PdfName::ToUnicode => b"ToUnicode",
+            PdfName::DescendantFonts => b"DescendantFonts",
+            PdfName::CIDToGIDMap => b"CIDToGIDMap",
+            PdfName::Length => b"Length",
+            PdfName::Filter => b"Filter",
+            PdfName::Image => b"Image",
+            PdfName::Form => b"Form",
+            PdfName::Group => b"Group",
+            PdfName::Matrix => b"Matrix",
+            PdfName::BBox => b"BBox",
+            PdfName::Encrypt => b"Encrypt",
+            PdfName::AcroForm => b"AcroForm",
+            PdfName::Fields => b"Fields",
+            PdfName::Widget => b"Widget",
+            PdfName::Outlines => b"Outlines",
+            PdfName::First => b"First",
+            PdfName::Last => b"Last",
+            PdfName::Next => b"Next",
+            PdfName::Prev => b"Prev",
+            PdfName::Dest => b"Dest",
+            PdfName::Title => b"Title",
+            PdfName::StructTreeRoot => b"StructTreeRoot",
+            PdfName::Other(bytes) => bytes,
+        }
+    }
+
+    /// Returns `true` if this name is outside the common table (i.e. went
+    /// through the `Other` fallback rather than a known enum variant).
+    pub fn is_other(&self) -> bool {
+        matches!(self, PdfName::Other(_))
+    }
+}
+
+impl PartialEq for PdfName {
+    fn eq(&self, other: &Self) -> bool {
+        // Two known variants are equal iff they're the *same* variant — a
+        // discriminant compare, no byte slices involved. If either side is
+        // `Other`, fall back to comparing bytes, since a named variant and
+        // an `Other` holding the same bytes (which `intern` never produces,
+        // but callers can construct directly) must still compare equal.
+        match (self, other) {
+            (PdfName::Other(_), _) | (_, PdfName::Other(_)) => self.as_bytes() == other.as_bytes(),
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for PdfName {}
+
+impl PartialEq<[u8]> for PdfName {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_bytes() == other
+    }
+}
+
+impl PartialEq<&[u8]> for PdfName {
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.as_bytes() == *other
+    }
+}
+
+impl PartialEq<str> for PdfName {
+    fn eq(&self, other: &str) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl PartialEq<&str> for PdfName {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl std::fmt::Display for PdfName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(self.as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intern_known_names_round_trip() {
+        for name in ["Type", "Font", "Subtype", "BaseFont", "Resources", "Kids"] {
+            let interned = PdfName::intern(name.as_bytes());
+            assert!(!interned.is_other(), "{name} should be a known variant");
+            assert_eq!(interned.as_bytes(), name.as_bytes());
+            assert_eq!(interned, name);
+        }
+    }
+
+    #[test]
+    fn intern_unknown_name_uses_other_fallback() {
+        let interned = PdfName::intern(b"MyCustomTag");
+        assert!(interned.is_other());
+        assert_eq!(interned.as_bytes(), b"MyCustomTag");
+        assert_eq!(interned, "MyCustomTag");
+    }
+
+    #[test]
+    fn equality_ignores_known_vs_other_representation() {
+        // An unusual name that happens to collide with a known one should
+        // never occur in practice (interning is total), but equality must
+        // still be representation-agnostic for any two PdfName values.
+        let known = PdfName::intern(b"Font");
+        let other = PdfName::Other(b"Font".to_vec().into_boxed_slice());
+        assert_eq!(known, other);
+    }
+
+    #[test]
+    fn distinct_names_are_not_equal() {
+        assert_ne!(PdfName::intern(b"Font"), PdfName::intern(b"Page"));
+    }
+
+    #[test]
+    fn same_known_variant_is_equal_via_discriminant_fast_path() {
+        // Both sides are known (non-`Other`) variants, so equality here goes
+        // through the discriminant compare rather than as_bytes().
+        assert_eq!(PdfName::intern(b"Font"), PdfName::intern(b"Font"));
+    }
+
+    #[test]
+    fn distinct_other_names_are_not_equal() {
+        let a = PdfName::intern(b"CustomOne");
+        let b = PdfName::intern(b"CustomTwo");
+        assert!(a.is_other() && b.is_other());
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn intern_is_case_sensitive() {
+        // PDF names are case-sensitive; "type" is not "/Type".
+        let interned = PdfName::intern(b"type");
+        assert!(interned.is_other());
+    }
+
+    #[test]
+    fn clone_preserves_equality() {
+        let name = PdfName::intern(b"SomeUnknownName");
+        let cloned = name.clone();
+        assert_eq!(name, cloned);
+    }
+
+    #[test]
+    fn display_renders_known_and_other_names() {
+        assert_eq!(PdfName::intern(b"Font").to_string(), "Font");
+        assert_eq!(PdfName::intern(b"MyCustomTag").to_string(), "MyCustomTag");
+    }
+}
@@ -5,7 +5,13 @@
 //! a PDF font dictionary lacks an explicit /Widths array.
 //!
 //! Width data is sourced from Adobe AFM (Adobe Font Metrics) specifications
-//! and indexed by WinAnsiEncoding character codes.
+//! and indexed by WinAnsiEncoding character codes. [`widths_for_encoding`]
+//! re-keys a font's widths for a different resolved `/Encoding` so that
+//! PDFs using (the more common default) StandardEncoding or MacRomanEncoding
+//! still get the width of the glyph actually shown, not whatever glyph
+//! happens to share that code point in WinAnsi.
+
+use pdfplumber_core::{FontEncoding, StandardEncoding};
 
 /// Font metrics data for a standard Type1 font.
 #[derive(Debug, Clone)]
@@ -14,6 +20,10 @@ pub struct StandardFontData {
     pub widths: [u16; 256],
     /// Font bounding box [llx, lly, urx, ury] in 1/1000 em-square units.
     pub font_bbox: [i16; 4],
+    /// Font ascent (Ascender) in 1/1000 em-square units.
+    pub ascent: i16,
+    /// Font descent (Descender) in 1/1000 em-square units.
+    pub descent: i16,
 }
 
 /// Look up standard font data by font name.
@@ -38,12 +48,60 @@ pub fn lookup(name: &str) -> Option<&'static StandardFontData> {
     }
 }
 
+/// Re-key `data`'s widths (stored against WinAnsiEncoding code points) for
+/// `encoding`, so a PDF that sets `/Encoding /StandardEncoding` (or leaves
+/// it at the Type1 default, which is `StandardEncoding`) still gets the
+/// width of the glyph that code actually shows.
+///
+/// For each code, look up the glyph `encoding` assigns to it and find the
+/// WinAnsi code that assigns the same glyph, returning that entry's width.
+/// Codes with no glyph in `encoding`, or whose glyph has no WinAnsi
+/// counterpart, keep `data`'s width for that code unchanged.
+///
+/// Symbol and ZapfDingbats are not affected by `/Encoding` at all — the PDF
+/// spec has them always use their own built-in encoding — so callers should
+/// only use this for the Latin text fonts (Courier/Helvetica/Times).
+pub fn widths_for_encoding(data: &StandardFontData, encoding: &FontEncoding) -> [u16; 256] {
+    let mut widths = data.widths;
+    for (code, width) in widths.iter_mut().enumerate() {
+        let Some(ch) = encoding.decode(code as u8) else {
+            continue;
+        };
+        if let Some(win_ansi_code) = win_ansi_code_for_char(ch) {
+            *width = data.widths[win_ansi_code as usize];
+        }
+    }
+    widths
+}
+
+/// Find the WinAnsiEncoding code point that decodes to `ch`, if any.
+fn win_ansi_code_for_char(ch: char) -> Option<u8> {
+    (0u16..256).map(|c| c as u8).find(|&code| StandardEncoding::WinAnsi.decode(code) == Some(ch))
+}
+
+/// AFM italic angle in degrees for a Standard-14 font name.
+///
+/// `0.0` for the upright Roman/Bold variants. The Oblique and Italic
+/// variants share their family's width table (see [`lookup`]) but not
+/// their slant, so the angle has to be looked up by the full name rather
+/// than read off [`StandardFontData`].
+pub fn italic_angle_for_name(name: &str) -> f64 {
+    match name {
+        "Courier-Oblique" | "Courier-BoldOblique" | "Helvetica-Oblique"
+        | "Helvetica-BoldOblique" => -12.0,
+        "Times-Italic" | "Times-BoldItalic" => -15.5,
+        _ => 0.0,
+    }
+}
+
 // =============================================================================
 // Courier — monospaced, all widths 600
 // =============================================================================
 static COURIER: StandardFontData = StandardFontData {
     widths: [600; 256],
     font_bbox: [-23, -250, 715, 805],
+    ascent: 629,
+    descent: -157,
 };
 
 // =============================================================================
@@ -95,6 +153,8 @@ static HELVETICA: StandardFontData = StandardFontData {
         556, 556, 556, 556, 556, 556, 556, 584, 611, 556, 556, 556, 556, 500, 556, 500,
     ],
     font_bbox: [-166, -225, 1000, 931],
+    ascent: 718,
+    descent: -207,
 };
 
 // =============================================================================
@@ -135,6 +195,8 @@ static HELVETICA_BOLD: StandardFontData = StandardFontData {
         611, 611, 611, 611, 611, 611, 611, 584, 611, 611, 611, 611, 611, 556, 611, 556,
     ],
     font_bbox: [-170, -228, 1003, 962],
+    ascent: 718,
+    descent: -207,
 };
 
 // =============================================================================
@@ -175,6 +237,8 @@ static TIMES_ROMAN: StandardFontData = StandardFontData {
         500, 500, 500, 500, 500, 500, 500, 564, 500, 500, 500, 500, 500, 500, 500, 500,
     ],
     font_bbox: [-168, -218, 1000, 898],
+    ascent: 683,
+    descent: -217,
 };
 
 // =============================================================================
@@ -215,6 +279,8 @@ static TIMES_BOLD: StandardFontData = StandardFontData {
         500, 556, 500, 500, 500, 500, 500, 570, 500, 556, 556, 556, 556, 500, 556, 500,
     ],
     font_bbox: [-168, -218, 1000, 935],
+    ascent: 683,
+    descent: -217,
 };
 
 // =============================================================================
@@ -255,6 +321,8 @@ static TIMES_ITALIC: StandardFontData = StandardFontData {
         500, 500, 500, 500, 500, 500, 500, 675, 500, 500, 500, 500, 500, 444, 500, 444,
     ],
     font_bbox: [-169, -217, 1010, 883],
+    ascent: 683,
+    descent: -217,
 };
 
 // =============================================================================
@@ -295,6 +363,8 @@ static TIMES_BOLD_ITALIC: StandardFontData = StandardFontData {
         500, 556, 500, 500, 500, 500, 500, 570, 500, 556, 556, 556, 556, 444, 500, 444,
     ],
     font_bbox: [-200, -218, 996, 921],
+    ascent: 683,
+    descent: -217,
 };
 
 // =============================================================================
@@ -334,6 +404,8 @@ static SYMBOL: StandardFontData = StandardFontData {
         0, 329, 274, 686, 686, 686, 384, 384, 384, 384, 384, 384, 494, 494, 494, 0,
     ],
     font_bbox: [-180, -293, 1090, 1010],
+    ascent: 1010,
+    descent: -293,
 };
 
 // =============================================================================
@@ -373,6 +445,8 @@ static ZAPF_DINGBATS: StandardFontData = StandardFontData {
         0, 874, 760, 946, 771, 865, 771, 888, 967, 888, 831, 873, 927, 970, 918, 0,
     ],
     font_bbox: [-1, -143, 981, 820],
+    ascent: 820,
+    descent: -143,
 };
 
 #[cfg(test)]
@@ -500,4 +574,51 @@ mod tests {
         assert_eq!(data.widths.len(), 256);
         assert_eq!(data.font_bbox.len(), 4);
     }
+
+    // ========== Ascent/descent values ==========
+
+    #[test]
+    fn ascent_descent_values() {
+        assert_eq!(lookup("Courier").unwrap().ascent, 629);
+        assert_eq!(lookup("Courier").unwrap().descent, -157);
+        assert_eq!(lookup("Helvetica").unwrap().ascent, 718);
+        assert_eq!(lookup("Times-Roman").unwrap().descent, -217);
+    }
+
+    // ========== widths_for_encoding ==========
+
+    #[test]
+    fn widths_for_encoding_winansi_is_unchanged() {
+        let data = lookup("Helvetica").unwrap();
+        let win_ansi = FontEncoding::from_standard(StandardEncoding::WinAnsi);
+        assert_eq!(widths_for_encoding(data, &win_ansi), data.widths);
+    }
+
+    #[test]
+    fn widths_for_encoding_standard_reindexes_quote_glyphs() {
+        // Code 0x27 is `quotesingle` in WinAnsi (width 191) but `quoteright`
+        // in StandardEncoding (width 222) for Helvetica.
+        let data = lookup("Helvetica").unwrap();
+        let standard = FontEncoding::from_standard(StandardEncoding::Standard);
+        let widths = widths_for_encoding(data, &standard);
+        assert_eq!(data.widths[0x27], 191);
+        assert_eq!(widths[0x27], 222);
+    }
+
+    // ========== italic_angle_for_name ==========
+
+    #[test]
+    fn italic_angle_upright_fonts_are_zero() {
+        assert_eq!(italic_angle_for_name("Helvetica"), 0.0);
+        assert_eq!(italic_angle_for_name("Courier-Bold"), 0.0);
+        assert_eq!(italic_angle_for_name("Times-Roman"), 0.0);
+    }
+
+    #[test]
+    fn italic_angle_oblique_and_italic_variants_are_negative() {
+        assert_eq!(italic_angle_for_name("Helvetica-Oblique"), -12.0);
+        assert_eq!(italic_angle_for_name("Courier-BoldOblique"), -12.0);
+        assert_eq!(italic_angle_for_name("Times-Italic"), -15.5);
+        assert_eq!(italic_angle_for_name("Times-BoldItalic"), -15.5);
+    }
 }
@@ -597,6 +597,26 @@ fn parse_inline_image(
 
     // Read image data until EI
     let data_start = *pos;
+
+    // When the image isn't filtered, its unfiltered byte length is fully
+    // determined by its dimensions and componentry — use that instead of
+    // scanning for "EI", so binary pixel data that happens to contain a
+    // whitespace + "EI" + whitespace/delimiter sequence isn't truncated.
+    if let Some(expected_len) = expected_raw_image_len(&dict) {
+        let candidate_end = data_start + expected_len;
+        if candidate_end <= input.len() {
+            let mut scan = candidate_end;
+            while scan < input.len() && is_whitespace(input[scan]) {
+                scan += 1;
+            }
+            if scan + 1 < input.len() && input[scan] == b'E' && input[scan + 1] == b'I' {
+                let data = input[data_start..candidate_end].to_vec();
+                *pos = scan + 2;
+                return Ok((dict, data));
+            }
+        }
+    }
+
     // Look for EI preceded by whitespace
     while *pos < input.len() {
         if *pos + 2 <= input.len()
@@ -625,6 +645,74 @@ fn parse_inline_image(
     ))
 }
 
+/// Compute the exact length, in bytes, of an unfiltered inline image's
+/// sample data from its `Width`/`Height`/`BitsPerComponent`/`ColorSpace`
+/// (each row is padded up to a whole byte, per PDF 32000-1:2008 §8.9.5.2).
+///
+/// Dictionary keys may still be abbreviated at this point (key expansion
+/// happens later, in the interpreter), so both forms are recognized here.
+/// Returns `None` when the image carries a `/Filter` (the encoded length
+/// can't be predicted from the dictionary alone) or lacks the dimensions or
+/// color space needed to compute it.
+fn expected_raw_image_len(dict: &InlineImageDict) -> Option<usize> {
+    let mut width = None;
+    let mut height = None;
+    let mut bits_per_component = None;
+    let mut colorspace = None;
+    let mut is_mask = false;
+
+    for (key, value) in dict {
+        match key.as_str() {
+            "W" | "Width" => width = operand_to_i64(value),
+            "H" | "Height" => height = operand_to_i64(value),
+            "BPC" | "BitsPerComponent" => bits_per_component = operand_to_i64(value),
+            "CS" | "ColorSpace" => {
+                if let Operand::Name(name) = value {
+                    colorspace = Some(name.as_str());
+                }
+            }
+            "F" | "Filter" => return None,
+            "IM" | "ImageMask" => {
+                if let Operand::Boolean(b) = value {
+                    is_mask = *b;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let width = width?;
+    let height = height?;
+    if width <= 0 || height <= 0 {
+        return None;
+    }
+
+    let (components, bits_per_component): (i64, i64) = if is_mask {
+        (1, 1)
+    } else {
+        let components = match colorspace {
+            Some("G") | Some("DeviceGray") | Some("I") | Some("Indexed") => 1,
+            Some("RGB") | Some("DeviceRGB") => 3,
+            Some("CMYK") | Some("DeviceCMYK") => 4,
+            _ => return None,
+        };
+        (components, bits_per_component.unwrap_or(8))
+    };
+
+    let row_bits = width.checked_mul(components)?.checked_mul(bits_per_component)?;
+    let row_bytes = row_bits.div_ceil(8);
+    row_bytes.checked_mul(height).and_then(|n| n.try_into().ok())
+}
+
+/// Convert an operand to i64, supporting Integer and Real types.
+fn operand_to_i64(op: &Operand) -> Option<i64> {
+    match op {
+        Operand::Integer(i) => Some(*i),
+        Operand::Real(f) => Some(*f as i64),
+        _ => None,
+    }
+}
+
 /// Parse a single value in an inline image dictionary.
 fn parse_inline_image_value(input: &[u8], pos: &mut usize) -> Result<Operand, BackendError> {
     let b = input[*pos];
@@ -1033,6 +1121,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_inline_image_unfiltered_data_containing_incidental_ei_bytes() {
+        // 2x1 DeviceGray, 8 bpc = 2 bytes of data. The data itself spells
+        // " EI" once concatenated with the leading separator, which a naive
+        // "scan for whitespace + EI + whitespace/delimiter" heuristic would
+        // mistake for the terminator.
+        let stream = b"BI /W 2 /H 1 /CS /G /BPC 8 ID \x45\x49 EI";
+        let ops = tokenize(stream).unwrap();
+        if let Operand::LiteralString(ref data) = ops[0].operands[1] {
+            assert_eq!(data, &[0x45, 0x49], "computed length must win over incidental EI bytes");
+        } else {
+            panic!("expected literal string operand for BI data");
+        }
+    }
+
+    #[test]
+    fn parse_inline_image_filtered_data_falls_back_to_heuristic_scan() {
+        // With a /Filter present, the unfiltered length can't be computed,
+        // so the heuristic whitespace-bounded "EI" scan is still used.
+        let stream = b"BI /W 2 /H 2 /CS /RGB /F /Fl ID \x78\x9c\x01\x02 EI";
+        let ops = tokenize(stream).unwrap();
+        if let Operand::LiteralString(ref data) = ops[0].operands[1] {
+            assert_eq!(data, &[0x78, 0x9c, 0x01, 0x02]);
+        } else {
+            panic!("expected literal string operand for BI data");
+        }
+    }
+
     // ---- Edge cases ----
 
     #[test]
@@ -213,6 +213,13 @@ fn metadata_to_dict(py: Python<'_>, meta: &DocumentMetadata) -> PyResult<PyObjec
     dict.set_item("producer", meta.producer.as_deref())?;
     dict.set_item("creation_date", meta.creation_date.as_deref())?;
     dict.set_item("mod_date", meta.mod_date.as_deref())?;
+    dict.set_item("trapped", meta.trapped.as_deref())?;
+    let custom = PyDict::new(py);
+    for (key, value) in &meta.custom {
+        custom.set_item(key, value)?;
+    }
+    dict.set_item("custom", custom)?;
+    dict.set_item("xmp", meta.xmp.as_deref())?;
     Ok(dict.into_any().unbind())
 }
 
@@ -1215,12 +1222,7 @@ mod tests {
         let meta = DocumentMetadata {
             title: Some("Test Doc".to_string()),
             author: Some("Author".to_string()),
-            subject: None,
-            keywords: None,
-            creator: None,
-            producer: None,
-            creation_date: None,
-            mod_date: None,
+            ..Default::default()
         };
         Python::with_gil(|py| {
             let dict_obj = metadata_to_dict(py, &meta).expect("metadata_to_dict");
@@ -8,7 +8,7 @@
 
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
 use lopdf::{Object, Stream, dictionary};
-use pdfplumber::{Pdf, Strategy, TableSettings, TextOptions, WordOptions};
+use pdfplumber::{ExtractOptions, Pdf, Strategy, TableSettings, TextOptions, WordOptions};
 
 // ---------------------------------------------------------------------------
 // PDF fixture generators
@@ -259,6 +259,74 @@ fn stream_table_pdf_bytes() -> Vec<u8> {
     build_pdf(&[table_content_stream(20, 5)])
 }
 
+/// PDF with a single page drawing one embedded DCTDecode image XObject.
+/// The image payload is dummy bytes: extraction/benchmarking exercises the
+/// XObject/dictionary plumbing, not real JPEG decoding.
+fn image_pdf_bytes() -> Vec<u8> {
+    let mut doc = lopdf::Document::with_version("1.5");
+
+    let image_data = vec![0xAB_u8; 64 * 64 * 3];
+    let image_stream = Stream::new(
+        dictionary! {
+            "Type" => "XObject",
+            "Subtype" => "Image",
+            "Width" => Object::Integer(64),
+            "Height" => Object::Integer(64),
+            "ColorSpace" => "DeviceRGB",
+            "BitsPerComponent" => Object::Integer(8),
+            "Filter" => "DCTDecode",
+        },
+        image_data,
+    );
+    let image_id = doc.add_object(image_stream);
+
+    let content = b"q 200 0 0 200 50 50 cm /Im0 Do Q".to_vec();
+    let content_stream = Stream::new(dictionary! {}, content);
+    let content_id = doc.add_object(content_stream);
+
+    let resources = dictionary! {
+        "XObject" => dictionary! {
+            "Im0" => Object::Reference(image_id),
+        },
+    };
+
+    let page_dict = dictionary! {
+        "Type" => "Page",
+        "MediaBox" => vec![
+            Object::Integer(0),
+            Object::Integer(0),
+            Object::Integer(612),
+            Object::Integer(792),
+        ],
+        "Contents" => Object::Reference(content_id),
+        "Resources" => resources,
+    };
+    let page_id = doc.add_object(page_dict);
+
+    let pages_dict = dictionary! {
+        "Type" => "Pages",
+        "Kids" => vec![Object::Reference(page_id)],
+        "Count" => Object::Integer(1),
+    };
+    let pages_id = doc.add_object(pages_dict);
+
+    if let Ok(obj) = doc.get_object_mut(page_id) {
+        if let Ok(dict) = obj.as_dict_mut() {
+            dict.set("Parent", Object::Reference(pages_id));
+        }
+    }
+
+    let catalog_id = doc.add_object(dictionary! {
+        "Type" => "Catalog",
+        "Pages" => Object::Reference(pages_id),
+    });
+    doc.trailer.set("Root", Object::Reference(catalog_id));
+
+    let mut buf = Vec::new();
+    doc.save_to(&mut buf).unwrap();
+    buf
+}
+
 // ---------------------------------------------------------------------------
 // Benchmarks
 // ---------------------------------------------------------------------------
@@ -509,6 +577,65 @@ fn bench_edge_computation(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_render(c: &mut Criterion) {
+    let lattice = lattice_table_pdf_bytes();
+    let complex = complex_pdf_bytes();
+
+    let mut group = c.benchmark_group("render");
+
+    group.bench_function("lattice_20x5", |b| {
+        let pdf = Pdf::open(&lattice, None).unwrap();
+        b.iter(|| {
+            let page = pdf.page(0).unwrap();
+            black_box(page.render(2.0).pixels().len());
+        });
+    });
+
+    group.bench_function("complex_10page", |b| {
+        let pdf = Pdf::open(&complex, None).unwrap();
+        b.iter(|| {
+            for i in 0..pdf.page_count() {
+                let page = pdf.page(i).unwrap();
+                black_box(page.render(2.0).pixels().len());
+            }
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_image_extraction(c: &mut Criterion) {
+    let image_pdf = image_pdf_bytes();
+
+    let mut group = c.benchmark_group("image_extraction");
+    let opts = ExtractOptions {
+        extract_image_data: true,
+        ..ExtractOptions::default()
+    };
+
+    group.bench_function("64x64_dct_1page", |b| {
+        let pdf = Pdf::open(&image_pdf, Some(opts.clone())).unwrap();
+        b.iter(|| {
+            let page = pdf.page(0).unwrap();
+            black_box(page.images().len());
+        });
+    });
+
+    group.bench_function("save_to_tempfile", |b| {
+        let pdf = Pdf::open(&image_pdf, Some(opts.clone())).unwrap();
+        let page = pdf.page(0).unwrap();
+        let images = page.images();
+        let image = images.first().unwrap();
+        let path = std::env::temp_dir().join("pdfplumber_bench_image.jpg");
+        b.iter(|| {
+            image.save(&path).unwrap();
+        });
+        let _ = std::fs::remove_file(&path);
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_pdf_open,
@@ -519,5 +646,7 @@ criterion_group!(
     bench_table_detection_lattice,
     bench_table_detection_stream,
     bench_edge_computation,
+    bench_render,
+    bench_image_extraction,
 );
 criterion_main!(benches);
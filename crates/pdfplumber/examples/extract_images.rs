@@ -0,0 +1,55 @@
+//! Extract embedded raster images from a PDF and save each to disk.
+//!
+//! Usage: `cargo run --example extract_images -- <path-to-pdf> [output-dir]`
+
+use pdfplumber::{ExtractOptions, Pdf};
+
+fn main() {
+    let path = std::env::args().nth(1).unwrap_or_else(|| {
+        eprintln!("Usage: extract_images <path-to-pdf> [output-dir]");
+        std::process::exit(1);
+    });
+    let out_dir = std::env::args().nth(2).unwrap_or_else(|| ".".to_string());
+
+    let options = ExtractOptions {
+        extract_image_data: true,
+        ..ExtractOptions::default()
+    };
+    let pdf = Pdf::open_file(&path, Some(options)).unwrap_or_else(|e| {
+        eprintln!("Error opening PDF: {e}");
+        std::process::exit(1);
+    });
+
+    std::fs::create_dir_all(&out_dir).unwrap_or_else(|e| {
+        eprintln!("Error creating output directory {out_dir}: {e}");
+        std::process::exit(1);
+    });
+
+    let mut count = 0;
+    for page_result in pdf.pages_iter() {
+        let page = page_result.unwrap();
+        for image in page.images() {
+            if image.data.is_none() {
+                eprintln!(
+                    "  {} on page {} has no decoded data (open with extract_image_data enabled)",
+                    image.name,
+                    page.page_number()
+                );
+                continue;
+            }
+
+            let ext = image.filter.map_or("png", |f| f.extension());
+            let filename = format!("{out_dir}/page{}_{}.{ext}", page.page_number(), image.name);
+
+            match image.save(&filename) {
+                Ok(()) => {
+                    println!("Wrote {filename}");
+                    count += 1;
+                }
+                Err(e) => eprintln!("Error saving {filename}: {e}"),
+            }
+        }
+    }
+
+    println!("Extracted {count} image(s) to {out_dir}");
+}
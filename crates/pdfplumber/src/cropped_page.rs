@@ -5,12 +5,34 @@
 //! Coordinates are adjusted relative to the crop origin.
 
 use pdfplumber_core::{
-    BBox, Char, Curve, DedupeOptions, Edge, Image, Line, Rect, Table, TableFinder, TableSettings,
-    TextOptions, Word, WordExtractor, WordOptions, blocks_to_text, cluster_lines_into_blocks,
-    cluster_words_into_lines, dedupe_chars, derive_edges, extract_text_for_cells,
-    sort_blocks_reading_order, split_lines_at_columns, words_to_text,
+    BBox, Char, Ctm, Curve, DedupeOptions, Edge, Image, Line, OutputDevice, Rect, SearchMatch,
+    Table, TableFinder, TableSettings, TextOptions, Word, WordExtractor, WordOptions,
+    blocks_to_text, cluster_lines_into_blocks, cluster_words_into_lines, dedupe_chars,
+    derive_edges, extract_text_for_cells, fuzzy_search_chars, sort_blocks_reading_order,
+    split_lines_at_columns, words_to_text,
 };
 
+/// Options controlling how [`Page::crop`](crate::Page::crop)/
+/// [`CroppedPage::crop`] and their `_with_options` variants handle objects
+/// that only partially overlap the crop box.
+#[derive(Debug, Clone, Copy)]
+pub struct CropOptions {
+    /// When `true` (the default, matching pdfplumber), an object straddling
+    /// the crop boundary has its bbox intersected with the crop box, so only
+    /// the overlapping portion is kept — a rect poking out the right edge
+    /// keeps just its left portion, a curve's points outside the box are
+    /// dropped. The object's text/content is never altered, only its
+    /// geometry. When `false`, a partially-overlapping object is kept at its
+    /// full original size.
+    pub slice_partial: bool,
+}
+
+impl Default for CropOptions {
+    fn default() -> Self {
+        Self { slice_partial: true }
+    }
+}
+
 /// A spatially filtered view of a PDF page.
 ///
 /// Created by [`crate::Page::crop`], [`crate::Page::within_bbox`], or [`crate::Page::outside_bbox`].
@@ -123,19 +145,175 @@ impl CroppedPage {
         tables
     }
 
-    /// Apply a further crop to this cropped page.
+    /// Approximately search for `pattern` in this region's reading-order text,
+    /// tolerating up to `max_edits` insertions, deletions, or substitutions so
+    /// OCR noise and soft hyphens don't defeat exact matches.
+    ///
+    /// The char stream searched is `self.chars`, in array order (matching the
+    /// order the underlying spatial filter built them in, rather than a
+    /// word/line-clustered reading order). See
+    /// [`fuzzy_search_chars`](pdfplumber_core::fuzzy_search_chars) for the
+    /// matching algorithm.
+    pub fn search(&self, pattern: &str, max_edits: usize) -> Vec<SearchMatch> {
+        fuzzy_search_chars(&self.chars, pattern, max_edits, 0)
+    }
+
+    /// Stream this region's characters through an [`OutputDevice`], calling
+    /// `begin_page`, one `output_char` per character in array order, then
+    /// `end_page`. Useful for sinks (e.g. [`pdfplumber_core::HtmlOutput`])
+    /// that consume characters incrementally instead of materializing them.
+    pub fn stream_to(&self, device: &mut dyn OutputDevice) {
+        drive_output_device(device, self.width, self.height, &self.chars);
+    }
+
+    /// Apply a further crop to this cropped page, slicing partially
+    /// overlapping objects to fit (see [`CropOptions`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bbox` is inverted (`x0 > x1` or `top > bottom`) or extends
+    /// beyond this page's bounds.
     pub fn crop(&self, bbox: BBox) -> CroppedPage {
-        filter_and_build(self, bbox, FilterMode::Crop)
+        self.crop_with_options(bbox, CropOptions::default())
+    }
+
+    /// Apply a further crop to this cropped page with explicit [`CropOptions`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bbox` is inverted (`x0 > x1` or `top > bottom`) or extends
+    /// beyond this page's bounds.
+    pub fn crop_with_options(&self, bbox: BBox, options: CropOptions) -> CroppedPage {
+        validate_crop_bbox(bbox, self.bbox());
+        filter_and_build(self, bbox, FilterMode::Crop, options.slice_partial)
     }
 
     /// Return objects fully contained within the bbox.
+    ///
+    /// `CropOptions::slice_partial` has no effect here: an object only
+    /// passes this filter when it is already fully inside `bbox`, so there
+    /// is never a partial overlap to slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bbox` is inverted (`x0 > x1` or `top > bottom`) or extends
+    /// beyond this page's bounds.
     pub fn within_bbox(&self, bbox: BBox) -> CroppedPage {
-        filter_and_build(self, bbox, FilterMode::Within)
+        self.within_bbox_with_options(bbox, CropOptions::default())
+    }
+
+    /// Return objects fully contained within the bbox, with explicit
+    /// [`CropOptions`] (see [`Self::within_bbox`] for why `slice_partial` is
+    /// a no-op in this mode).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bbox` is inverted (`x0 > x1` or `top > bottom`) or extends
+    /// beyond this page's bounds.
+    pub fn within_bbox_with_options(&self, bbox: BBox, options: CropOptions) -> CroppedPage {
+        validate_crop_bbox(bbox, self.bbox());
+        filter_and_build(self, bbox, FilterMode::Within, options.slice_partial)
     }
 
     /// Return objects fully outside the bbox.
     pub fn outside_bbox(&self, bbox: BBox) -> CroppedPage {
-        filter_and_build(self, bbox, FilterMode::Outside)
+        filter_and_build(self, bbox, FilterMode::Outside, false)
+    }
+
+    /// Return objects that overlap the bbox at all, partially or fully.
+    ///
+    /// When `clip` is `true`, objects straddling the boundary are cut down to
+    /// the portion that falls inside `bbox` (mirroring how [`crop`](Self::crop)
+    /// re-bases coordinates but without discarding partially-overlapping
+    /// objects outright).
+    pub fn intersects_bbox(&self, bbox: BBox, clip: bool) -> CroppedPage {
+        filter_and_build(self, bbox, FilterMode::Intersects, clip)
+    }
+
+    /// Apply an affine transform `[a, b, c, d, e, f]` to every object in this
+    /// region, returning a new view with rotation, scaling, skew, or
+    /// translation applied.
+    ///
+    /// Bounding boxes are recomputed from the transformed corners of each
+    /// object, `Curve` points are transformed individually, and each
+    /// `Char.ctm` is composed with `matrix` so downstream font-size/upright
+    /// logic stays consistent. The resulting page's origin is re-based to
+    /// `(0, 0)`, matching the convention used by [`CroppedPage::crop`].
+    pub fn transform(&self, matrix: [f64; 6]) -> CroppedPage {
+        transform_page_data(self, self.width, self.height, matrix)
+    }
+
+    /// Estimate the dominant skew angle of the page's text, in degrees.
+    ///
+    /// Scanned PDFs often arrive rotated by a fraction of a degree. This
+    /// sweeps candidate angles from -5° to +5° in 0.1° steps, and for each
+    /// angle projects char-bbox centers onto the y-axis (after rotating by
+    /// the candidate angle) and scores the sharpness of the resulting
+    /// horizontal histogram as the sum of squared bin counts — well-aligned
+    /// text rows concentrate into few tall bins, so the angle that maximizes
+    /// this score is taken as the skew. Returns `0.0` if there are too few
+    /// chars (fewer than 4) to estimate a meaningful angle.
+    pub fn detect_skew_angle(&self) -> f64 {
+        const MIN_CHARS: usize = 4;
+        const BIN_HEIGHT: f64 = 1.0;
+
+        if self.chars.len() < MIN_CHARS {
+            return 0.0;
+        }
+
+        let centers: Vec<(f64, f64)> = self
+            .chars
+            .iter()
+            .map(|c| bbox_center(c.bbox.x0, c.bbox.top, c.bbox.x1, c.bbox.bottom))
+            .collect();
+
+        let mut best_angle = 0.0;
+        let mut best_score = f64::NEG_INFINITY;
+
+        let mut step = -50;
+        while step <= 50 {
+            let angle_deg = step as f64 * 0.1;
+            let angle_rad = angle_deg.to_radians();
+            let (sin_a, cos_a) = (angle_rad.sin(), angle_rad.cos());
+
+            let mut bins: std::collections::HashMap<i64, u32> = std::collections::HashMap::new();
+            for &(x, y) in &centers {
+                let ry = -x * sin_a + y * cos_a;
+                let bin = (ry / BIN_HEIGHT).floor() as i64;
+                *bins.entry(bin).or_insert(0) += 1;
+            }
+            let score: f64 = bins.values().map(|&count| (count as f64).powi(2)).sum();
+
+            // On a tie, prefer the candidate closest to zero rotation: with
+            // only a handful of chars, several angles can produce identical
+            // histograms, and "assume unrotated" is the safer default.
+            if score > best_score
+                || (score == best_score && angle_deg.abs() < best_angle.abs())
+            {
+                best_score = score;
+                best_angle = angle_deg;
+            }
+            step += 1;
+        }
+
+        best_angle
+    }
+
+    /// Correct page skew by rotating all objects by the negative of the
+    /// detected skew angle.
+    ///
+    /// Uses [`Self::detect_skew_angle`] to estimate the dominant text-baseline
+    /// angle, then applies the inverse rotation via the same transform path
+    /// as [`Self::transform`], so `Char.ctm`, bounding boxes, and curve points
+    /// all stay consistent. A page with too few chars to estimate skew is
+    /// returned unchanged (angle 0).
+    pub fn deskew(&self) -> CroppedPage {
+        let angle_deg = self.detect_skew_angle();
+        let angle_rad = (-angle_deg).to_radians();
+        let (sin_a, cos_a) = (angle_rad.sin(), angle_rad.cos());
+        // Rotation matrix [a, b, c, d, e, f] with x' = a*x + c*y + e, y' = b*x + d*y + f.
+        let matrix = [cos_a, sin_a, -sin_a, cos_a, 0.0, 0.0];
+        self.transform(matrix)
     }
 
     /// Remove duplicate overlapping characters, returning a new view.
@@ -178,6 +356,26 @@ pub(crate) fn from_page_data(
     }
 }
 
+/// Drive an [`OutputDevice`] over a page (or cropped page)'s characters:
+/// `begin_page`, one `output_char` per character in array order, then
+/// `end_page`. Shared by [`crate::Page::stream_to`] and
+/// [`CroppedPage::stream_to`].
+pub(crate) fn drive_output_device(
+    device: &mut dyn OutputDevice,
+    width: f64,
+    height: f64,
+    chars: &[Char],
+) {
+    device.begin_page(width, height);
+    for ch in chars {
+        let transform = Ctm::new(
+            ch.ctm[0], ch.ctm[1], ch.ctm[2], ch.ctm[3], ch.ctm[4], ch.ctm[5],
+        );
+        device.output_char(ch, transform);
+    }
+    device.end_page();
+}
+
 /// Filter mode for spatial operations.
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum FilterMode {
@@ -187,24 +385,39 @@ pub(crate) enum FilterMode {
     Within,
     /// Object fully outside bbox (no overlap).
     Outside,
+    /// Object overlaps bbox at all (partial or full).
+    Intersects,
 }
 
 /// Returns the center point of a bounding box.
+///
+/// Thin wrapper around [`BBox::center`].
 fn bbox_center(x0: f64, top: f64, x1: f64, bottom: f64) -> (f64, f64) {
-    ((x0 + x1) / 2.0, (top + bottom) / 2.0)
+    BBox::new(x0, top, x1, bottom).center()
 }
 
 /// Check if a point is within a bbox.
+///
+/// Thin wrapper around [`BBox::contains`].
 fn point_in_bbox(x: f64, y: f64, bbox: &BBox) -> bool {
-    x >= bbox.x0 && x <= bbox.x1 && y >= bbox.top && y <= bbox.bottom
+    bbox.contains(x, y)
 }
 
 /// Check if an object bbox is fully contained within a filter bbox.
+///
+/// Equivalent to both of the object's corners lying inside `bbox`.
 fn fully_within(obj_x0: f64, obj_top: f64, obj_x1: f64, obj_bottom: f64, bbox: &BBox) -> bool {
-    obj_x0 >= bbox.x0 && obj_x1 <= bbox.x1 && obj_top >= bbox.top && obj_bottom <= bbox.bottom
+    bbox.contains(obj_x0, obj_top) && bbox.contains(obj_x1, obj_bottom)
 }
 
 /// Check if an object bbox has no overlap with a filter bbox.
+///
+/// Not implemented via [`BBox::intersection`]: that treats any box with zero
+/// width/height as never intersecting anything (since it requires `x0 <
+/// x1`), which would wrongly call a zero-width object "outside" even when
+/// it sits strictly inside `bbox`. This keeps the original non-strict edge
+/// comparison, where only a genuinely disjoint (or edge-touching) object
+/// counts as outside.
 fn fully_outside(obj_x0: f64, obj_top: f64, obj_x1: f64, obj_bottom: f64, bbox: &BBox) -> bool {
     obj_x1 <= bbox.x0 || obj_x0 >= bbox.x1 || obj_bottom <= bbox.top || obj_top >= bbox.bottom
 }
@@ -225,14 +438,201 @@ fn passes_filter(
         }
         FilterMode::Within => fully_within(obj_x0, obj_top, obj_x1, obj_bottom, bbox),
         FilterMode::Outside => fully_outside(obj_x0, obj_top, obj_x1, obj_bottom, bbox),
+        FilterMode::Intersects => !fully_outside(obj_x0, obj_top, obj_x1, obj_bottom, bbox),
     }
 }
 
+/// Check that a crop rect is well-formed and falls within `page_bbox`,
+/// panicking with a descriptive message otherwise.
+///
+/// Used by [`crate::Page::crop`]/[`crate::Page::within_bbox`] and their
+/// [`CroppedPage`] counterparts, where an inverted or out-of-bounds rect
+/// would otherwise silently produce an empty or nonsensical result. Not
+/// applied to `outside_bbox`/`intersects_bbox`, where a rect beyond the
+/// page edges is a meaningful (if trivial) no-op.
+pub(crate) fn validate_crop_bbox(bbox: BBox, page_bbox: BBox) {
+    assert!(
+        bbox.x0 <= bbox.x1,
+        "invalid crop bbox: x0 ({}) must be <= x1 ({})",
+        bbox.x0,
+        bbox.x1
+    );
+    assert!(
+        bbox.top <= bbox.bottom,
+        "invalid crop bbox: top ({}) must be <= bottom ({})",
+        bbox.top,
+        bbox.bottom
+    );
+    assert!(
+        bbox.x0 >= page_bbox.x0
+            && bbox.x1 <= page_bbox.x1
+            && bbox.top >= page_bbox.top
+            && bbox.bottom <= page_bbox.bottom,
+        "crop bbox {bbox:?} is outside the page bounds {page_bbox:?}"
+    );
+}
+
+/// Intersect an object's bbox with the crop rect, clamping each edge inward.
+fn clip_bbox(obj_x0: f64, obj_top: f64, obj_x1: f64, obj_bottom: f64, bbox: &BBox) -> BBox {
+    BBox::new(
+        obj_x0.max(bbox.x0),
+        obj_top.max(bbox.top),
+        obj_x1.min(bbox.x1),
+        obj_bottom.min(bbox.bottom),
+    )
+}
+
 /// Adjust a coordinate by subtracting the crop origin offset.
 fn adjust_coord(val: f64, offset: f64) -> f64 {
     val - offset
 }
 
+/// Apply a PDF transformation matrix `[a, b, c, d, e, f]` to a point:
+/// `x' = a*x + c*y + e`, `y' = b*x + d*y + f`.
+fn transform_point(m: &[f64; 6], x: f64, y: f64) -> (f64, f64) {
+    (m[0] * x + m[2] * y + m[4], m[1] * x + m[3] * y + m[5])
+}
+
+/// Transform the four corners of a bbox and return the new axis-aligned
+/// bounding box as `(x0, top, x1, bottom)`.
+fn transform_bbox_corners(m: &[f64; 6], x0: f64, top: f64, x1: f64, bottom: f64) -> BBox {
+    let corners = [
+        transform_point(m, x0, top),
+        transform_point(m, x1, top),
+        transform_point(m, x0, bottom),
+        transform_point(m, x1, bottom),
+    ];
+    let nx0 = corners.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+    let nx1 = corners
+        .iter()
+        .map(|p| p.0)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let ntop = corners.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+    let nbottom = corners
+        .iter()
+        .map(|p| p.1)
+        .fold(f64::NEG_INFINITY, f64::max);
+    BBox::new(nx0, ntop, nx1, nbottom)
+}
+
+/// Build a `CroppedPage` by applying an affine transform to every object from
+/// `source`, then re-basing coordinates so the transformed page origin is
+/// `(0, 0)` (matching the convention used by [`filter_and_build`]).
+pub(crate) fn transform_page_data(
+    source: &dyn PageData,
+    width: f64,
+    height: f64,
+    matrix: [f64; 6],
+) -> CroppedPage {
+    let new_page_bbox = transform_bbox_corners(&matrix, 0.0, 0.0, width, height);
+    let dx = new_page_bbox.x0;
+    let dy = new_page_bbox.top;
+
+    let chars: Vec<Char> = source
+        .chars_data()
+        .iter()
+        .map(|c| {
+            let mut ch = c.clone();
+            let bbox = transform_bbox_corners(&matrix, c.bbox.x0, c.bbox.top, c.bbox.x1, c.bbox.bottom);
+            ch.bbox = BBox::new(
+                adjust_coord(bbox.x0, dx),
+                adjust_coord(bbox.top, dy),
+                adjust_coord(bbox.x1, dx),
+                adjust_coord(bbox.bottom, dy),
+            );
+            ch.doctop = ch.bbox.top;
+            let old_ctm = Ctm::new(
+                c.ctm[0], c.ctm[1], c.ctm[2], c.ctm[3], c.ctm[4], c.ctm[5],
+            );
+            let xform = Ctm::new(
+                matrix[0], matrix[1], matrix[2], matrix[3], matrix[4], matrix[5],
+            );
+            let new_ctm = old_ctm.concat(&xform);
+            ch.ctm = [
+                new_ctm.a, new_ctm.b, new_ctm.c, new_ctm.d,
+                new_ctm.e - dx, new_ctm.f - dy,
+            ];
+            ch
+        })
+        .collect();
+
+    let lines: Vec<Line> = source
+        .lines_data()
+        .iter()
+        .map(|l| {
+            let mut ln = l.clone();
+            let bbox = transform_bbox_corners(&matrix, l.x0, l.top, l.x1, l.bottom);
+            ln.x0 = adjust_coord(bbox.x0, dx);
+            ln.top = adjust_coord(bbox.top, dy);
+            ln.x1 = adjust_coord(bbox.x1, dx);
+            ln.bottom = adjust_coord(bbox.bottom, dy);
+            ln
+        })
+        .collect();
+
+    let rects: Vec<Rect> = source
+        .rects_data()
+        .iter()
+        .map(|r| {
+            let mut rc = r.clone();
+            let bbox = transform_bbox_corners(&matrix, r.x0, r.top, r.x1, r.bottom);
+            rc.x0 = adjust_coord(bbox.x0, dx);
+            rc.top = adjust_coord(bbox.top, dy);
+            rc.x1 = adjust_coord(bbox.x1, dx);
+            rc.bottom = adjust_coord(bbox.bottom, dy);
+            rc
+        })
+        .collect();
+
+    let curves: Vec<Curve> = source
+        .curves_data()
+        .iter()
+        .map(|c| {
+            let mut cv = c.clone();
+            cv.pts = c
+                .pts
+                .iter()
+                .map(|&(px, py)| {
+                    let (tx, ty) = transform_point(&matrix, px, py);
+                    (adjust_coord(tx, dx), adjust_coord(ty, dy))
+                })
+                .collect();
+            let bbox = transform_bbox_corners(&matrix, c.x0, c.top, c.x1, c.bottom);
+            cv.x0 = adjust_coord(bbox.x0, dx);
+            cv.top = adjust_coord(bbox.top, dy);
+            cv.x1 = adjust_coord(bbox.x1, dx);
+            cv.bottom = adjust_coord(bbox.bottom, dy);
+            cv
+        })
+        .collect();
+
+    let images: Vec<Image> = source
+        .images_data()
+        .iter()
+        .map(|i| {
+            let mut im = i.clone();
+            let bbox = transform_bbox_corners(&matrix, i.x0, i.top, i.x1, i.bottom);
+            im.x0 = adjust_coord(bbox.x0, dx);
+            im.top = adjust_coord(bbox.top, dy);
+            im.x1 = adjust_coord(bbox.x1, dx);
+            im.bottom = adjust_coord(bbox.bottom, dy);
+            im.width = im.x1 - im.x0;
+            im.height = im.bottom - im.top;
+            im
+        })
+        .collect();
+
+    CroppedPage {
+        width: new_page_bbox.width(),
+        height: new_page_bbox.height(),
+        chars,
+        lines,
+        rects,
+        curves,
+        images,
+    }
+}
+
 /// Trait for types that provide page-like data for filtering.
 pub(crate) trait PageData {
     fn chars_data(&self) -> &[Char];
@@ -261,7 +661,16 @@ impl PageData for CroppedPage {
 }
 
 /// Build a CroppedPage by filtering and coordinate-adjusting objects from source data.
-pub(crate) fn filter_and_build(source: &dyn PageData, bbox: BBox, mode: FilterMode) -> CroppedPage {
+///
+/// When `clip` is `true`, an object straddling the crop boundary has its bbox
+/// intersected with `bbox` before coordinate adjustment, and curve polylines
+/// are truncated to the points that fall inside the rect.
+pub(crate) fn filter_and_build(
+    source: &dyn PageData,
+    bbox: BBox,
+    mode: FilterMode,
+    clip: bool,
+) -> CroppedPage {
     let dx = bbox.x0;
     let dy = bbox.top;
 
@@ -271,13 +680,18 @@ pub(crate) fn filter_and_build(source: &dyn PageData, bbox: BBox, mode: FilterMo
         .filter(|c| passes_filter(c.bbox.x0, c.bbox.top, c.bbox.x1, c.bbox.bottom, &bbox, mode))
         .map(|c| {
             let mut ch = c.clone();
+            let b = if clip {
+                clip_bbox(ch.bbox.x0, ch.bbox.top, ch.bbox.x1, ch.bbox.bottom, &bbox)
+            } else {
+                ch.bbox
+            };
             ch.bbox = BBox::new(
-                adjust_coord(ch.bbox.x0, dx),
-                adjust_coord(ch.bbox.top, dy),
-                adjust_coord(ch.bbox.x1, dx),
-                adjust_coord(ch.bbox.bottom, dy),
+                adjust_coord(b.x0, dx),
+                adjust_coord(b.top, dy),
+                adjust_coord(b.x1, dx),
+                adjust_coord(b.bottom, dy),
             );
-            ch.doctop = adjust_coord(ch.doctop, dy);
+            ch.doctop = ch.bbox.top;
             ch
         })
         .collect();
@@ -288,10 +702,15 @@ pub(crate) fn filter_and_build(source: &dyn PageData, bbox: BBox, mode: FilterMo
         .filter(|l| passes_filter(l.x0, l.top, l.x1, l.bottom, &bbox, mode))
         .map(|l| {
             let mut ln = l.clone();
-            ln.x0 = adjust_coord(ln.x0, dx);
-            ln.top = adjust_coord(ln.top, dy);
-            ln.x1 = adjust_coord(ln.x1, dx);
-            ln.bottom = adjust_coord(ln.bottom, dy);
+            let b = if clip {
+                clip_bbox(ln.x0, ln.top, ln.x1, ln.bottom, &bbox)
+            } else {
+                BBox::new(ln.x0, ln.top, ln.x1, ln.bottom)
+            };
+            ln.x0 = adjust_coord(b.x0, dx);
+            ln.top = adjust_coord(b.top, dy);
+            ln.x1 = adjust_coord(b.x1, dx);
+            ln.bottom = adjust_coord(b.bottom, dy);
             ln
         })
         .collect();
@@ -302,10 +721,15 @@ pub(crate) fn filter_and_build(source: &dyn PageData, bbox: BBox, mode: FilterMo
         .filter(|r| passes_filter(r.x0, r.top, r.x1, r.bottom, &bbox, mode))
         .map(|r| {
             let mut rc = r.clone();
-            rc.x0 = adjust_coord(rc.x0, dx);
-            rc.top = adjust_coord(rc.top, dy);
-            rc.x1 = adjust_coord(rc.x1, dx);
-            rc.bottom = adjust_coord(rc.bottom, dy);
+            let b = if clip {
+                clip_bbox(rc.x0, rc.top, rc.x1, rc.bottom, &bbox)
+            } else {
+                BBox::new(rc.x0, rc.top, rc.x1, rc.bottom)
+            };
+            rc.x0 = adjust_coord(b.x0, dx);
+            rc.top = adjust_coord(b.top, dy);
+            rc.x1 = adjust_coord(b.x1, dx);
+            rc.bottom = adjust_coord(b.bottom, dy);
             rc
         })
         .collect();
@@ -316,10 +740,18 @@ pub(crate) fn filter_and_build(source: &dyn PageData, bbox: BBox, mode: FilterMo
         .filter(|c| passes_filter(c.x0, c.top, c.x1, c.bottom, &bbox, mode))
         .map(|c| {
             let mut cv = c.clone();
-            cv.x0 = adjust_coord(cv.x0, dx);
-            cv.top = adjust_coord(cv.top, dy);
-            cv.x1 = adjust_coord(cv.x1, dx);
-            cv.bottom = adjust_coord(cv.bottom, dy);
+            if clip {
+                cv.pts.retain(|&(px, py)| point_in_bbox(px, py, &bbox));
+            }
+            let b = if clip {
+                clip_bbox(cv.x0, cv.top, cv.x1, cv.bottom, &bbox)
+            } else {
+                BBox::new(cv.x0, cv.top, cv.x1, cv.bottom)
+            };
+            cv.x0 = adjust_coord(b.x0, dx);
+            cv.top = adjust_coord(b.top, dy);
+            cv.x1 = adjust_coord(b.x1, dx);
+            cv.bottom = adjust_coord(b.bottom, dy);
             cv.pts = cv.pts.iter().map(|(px, py)| (px - dx, py - dy)).collect();
             cv
         })
@@ -331,10 +763,17 @@ pub(crate) fn filter_and_build(source: &dyn PageData, bbox: BBox, mode: FilterMo
         .filter(|i| passes_filter(i.x0, i.top, i.x1, i.bottom, &bbox, mode))
         .map(|i| {
             let mut im = i.clone();
-            im.x0 = adjust_coord(im.x0, dx);
-            im.top = adjust_coord(im.top, dy);
-            im.x1 = adjust_coord(im.x1, dx);
-            im.bottom = adjust_coord(im.bottom, dy);
+            let b = if clip {
+                clip_bbox(im.x0, im.top, im.x1, im.bottom, &bbox)
+            } else {
+                BBox::new(im.x0, im.top, im.x1, im.bottom)
+            };
+            im.x0 = adjust_coord(b.x0, dx);
+            im.top = adjust_coord(b.top, dy);
+            im.x1 = adjust_coord(b.x1, dx);
+            im.bottom = adjust_coord(b.bottom, dy);
+            im.width = im.x1 - im.x0;
+            im.height = im.bottom - im.top;
             im
         })
         .collect();
@@ -559,6 +998,59 @@ mod tests {
         assert!((curve.pts[1].1 - 5.0).abs() < 1e-10);
     }
 
+    // ---- CropOptions::slice_partial tests ----
+
+    #[test]
+    fn test_crop_slices_char_straddling_each_side() {
+        // A 20x20 box with chars straddling the left, right, top, and bottom edges.
+        let chars = vec![
+            make_char("L", -5.0, 5.0, 5.0, 15.0),  // straddles left edge
+            make_char("R", 15.0, 5.0, 25.0, 15.0),  // straddles right edge
+            make_char("T", 5.0, -5.0, 15.0, 5.0),   // straddles top edge
+            make_char("B", 5.0, 15.0, 15.0, 25.0),  // straddles bottom edge
+        ];
+        let page = Page::new(0, 20.0, 20.0, chars);
+        let cropped = page.crop(BBox::new(0.0, 0.0, 20.0, 20.0));
+
+        let by_text = |text: &str| cropped.chars().iter().find(|c| c.text == text).unwrap();
+        assert_eq!(by_text("L").bbox, BBox::new(0.0, 5.0, 5.0, 15.0));
+        assert_eq!(by_text("R").bbox, BBox::new(15.0, 5.0, 20.0, 15.0));
+        assert_eq!(by_text("T").bbox, BBox::new(5.0, 0.0, 15.0, 5.0));
+        assert_eq!(by_text("B").bbox, BBox::new(5.0, 15.0, 15.0, 20.0));
+        // Text content is untouched by slicing.
+        assert_eq!(by_text("L").text, "L");
+    }
+
+    #[test]
+    fn test_crop_slices_rect_at_corner() {
+        let page = Page::with_geometry(
+            0,
+            20.0,
+            20.0,
+            vec![],
+            vec![],
+            vec![make_rect(-5.0, -5.0, 10.0, 10.0)],
+            vec![],
+        );
+        let cropped = page.crop(BBox::new(0.0, 0.0, 20.0, 20.0));
+        assert_eq!(cropped.rects().len(), 1);
+        assert_eq!(cropped.rects()[0].x0, 0.0);
+        assert_eq!(cropped.rects()[0].top, 0.0);
+        assert_eq!(cropped.rects()[0].x1, 10.0);
+        assert_eq!(cropped.rects()[0].bottom, 10.0);
+    }
+
+    #[test]
+    fn test_crop_with_options_slice_partial_false_keeps_full_bbox() {
+        let chars = vec![make_char("L", -5.0, 5.0, 5.0, 15.0)];
+        let page = Page::new(0, 20.0, 20.0, chars);
+        let cropped = page.crop_with_options(
+            BBox::new(0.0, 0.0, 20.0, 20.0),
+            CropOptions { slice_partial: false },
+        );
+        assert_eq!(cropped.chars()[0].bbox, BBox::new(-5.0, 5.0, 5.0, 15.0));
+    }
+
     // ---- within_bbox tests ----
 
     #[test]
@@ -665,6 +1157,164 @@ mod tests {
         assert!((b.bbox.top - 10.0).abs() < 1e-10);
     }
 
+    // ---- intersects_bbox tests ----
+
+    #[test]
+    fn test_intersects_bbox_includes_partial_overlap() {
+        let page = make_test_page();
+        // Rect spans (10,10)-(60,62); a bbox over just its left edge still
+        // overlaps it, even though the rect's center (35,36) falls outside.
+        let result = page.intersects_bbox(BBox::new(0.0, 0.0, 15.0, 70.0), false);
+        assert_eq!(result.rects().len(), 1);
+    }
+
+    #[test]
+    fn test_intersects_bbox_excludes_no_overlap() {
+        let page = make_test_page();
+        let result = page.intersects_bbox(BBox::new(0.0, 0.0, 5.0, 5.0), false);
+        assert_eq!(result.chars().len(), 0);
+        assert_eq!(result.rects().len(), 0);
+    }
+
+    #[test]
+    fn test_intersects_bbox_no_clip_keeps_full_bbox() {
+        let page = make_test_page();
+        // "A" at (10,10)-(20,22) overlaps a bbox of (0,0)-(15,70) but extends past it.
+        let result = page.intersects_bbox(BBox::new(0.0, 0.0, 15.0, 70.0), false);
+        let a = result.chars().iter().find(|c| c.text == "A").unwrap();
+        assert!((a.bbox.x1 - 20.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_intersects_bbox_clip_truncates_bbox() {
+        let page = make_test_page();
+        // With clip=true, "A"'s bbox is cut down to the overlapping region.
+        let result = page.intersects_bbox(BBox::new(0.0, 0.0, 15.0, 70.0), true);
+        let a = result.chars().iter().find(|c| c.text == "A").unwrap();
+        assert!((a.bbox.x1 - 15.0).abs() < 1e-10);
+        assert!((a.bbox.x0 - 10.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_intersects_bbox_clip_truncates_curve_points() {
+        let page = make_test_page();
+        // Curve points run from x=10 to x=60; clipping to x<=30 should drop
+        // the two rightmost points (50,75) and (60,80).
+        let result = page.intersects_bbox(BBox::new(0.0, 0.0, 30.0, 100.0), true);
+        let curve = &result.curves()[0];
+        assert_eq!(curve.pts.len(), 2);
+    }
+
+    // ---- fuzzy search tests ----
+
+    fn make_text_page(text: &str) -> Page {
+        let chars: Vec<Char> = text
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                make_char(
+                    &c.to_string(),
+                    i as f64 * 10.0,
+                    100.0,
+                    i as f64 * 10.0 + 10.0,
+                    112.0,
+                )
+            })
+            .collect();
+        Page::new(0, 200.0, 200.0, chars)
+    }
+
+    #[test]
+    fn test_search_fuzzy_tolerates_substitution() {
+        let page = make_text_page("Hello World");
+        let cropped = page.crop(page.bbox());
+        let matches = cropped.search("Xello", 1);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].text, "Hello");
+    }
+
+    #[test]
+    fn test_search_exact_match_zero_edits() {
+        let page = make_text_page("Hello World");
+        let cropped = page.crop(page.bbox());
+        let matches = cropped.search("World", 0);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].text, "World");
+    }
+
+    #[test]
+    fn test_search_no_match_beyond_max_edits() {
+        let page = make_text_page("Hello World");
+        let cropped = page.crop(page.bbox());
+        let matches = cropped.search("zzzzz", 1);
+
+        assert!(matches.is_empty());
+    }
+
+    // ---- skew detection / correction tests ----
+
+    #[test]
+    fn test_detect_skew_angle_aligned_page_returns_near_zero() {
+        let page = make_test_page();
+        let cropped = page.crop(page.bbox());
+        let angle = cropped.detect_skew_angle();
+        assert!(angle.abs() < 0.5, "expected near-zero skew, got {angle}");
+    }
+
+    #[test]
+    fn test_detect_skew_angle_too_few_chars_returns_zero() {
+        let chars = vec![make_char("A", 0.0, 0.0, 10.0, 12.0)];
+        let cropped = from_page_data(100.0, 100.0, chars, vec![], vec![], vec![], vec![]);
+        assert_eq!(cropped.detect_skew_angle(), 0.0);
+    }
+
+    fn make_rotated_row(angle_deg: f64) -> CroppedPage {
+        let theta = angle_deg.to_radians();
+        let chars: Vec<Char> = (0..10)
+            .map(|i| {
+                let x = i as f64 * 20.0;
+                let y = 100.0 + x * theta.tan();
+                make_char(&format!("{i}"), x, y, x + 10.0, y + 12.0)
+            })
+            .collect();
+        from_page_data(400.0, 400.0, chars, vec![], vec![], vec![], vec![])
+    }
+
+    fn vertical_spread(page: &CroppedPage) -> f64 {
+        let centers: Vec<f64> = page
+            .chars()
+            .iter()
+            .map(|c| bbox_center(c.bbox.x0, c.bbox.top, c.bbox.x1, c.bbox.bottom).1)
+            .collect();
+        let max = centers.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let min = centers.iter().cloned().fold(f64::INFINITY, f64::min);
+        max - min
+    }
+
+    #[test]
+    fn test_detect_skew_angle_finds_rotated_row() {
+        let cropped = make_rotated_row(3.0);
+        let angle = cropped.detect_skew_angle();
+        assert!(
+            (angle - 3.0).abs() < 0.5,
+            "expected ~3.0 degrees, got {angle}"
+        );
+    }
+
+    #[test]
+    fn test_deskew_reduces_vertical_spread() {
+        let cropped = make_rotated_row(4.0);
+        let before = vertical_spread(&cropped);
+        let deskewed = cropped.deskew();
+        let after = vertical_spread(&deskewed);
+        assert!(
+            after < before,
+            "expected deskew to reduce vertical spread: before={before}, after={after}"
+        );
+    }
+
     // ---- chained filtering tests ----
 
     #[test]
@@ -808,4 +1458,62 @@ mod tests {
         assert!((cropped.width() - 40.0).abs() < 1e-10);
         assert!((cropped.height() - 40.0).abs() < 1e-10);
     }
+
+    // ---- transform tests ----
+
+    #[test]
+    fn test_transform_translation() {
+        let page = make_test_page();
+        // Translate everything by (5, 7): identity scale/rotation, e=5, f=7
+        let transformed = page.transform([1.0, 0.0, 0.0, 1.0, 5.0, 7.0]);
+
+        let a = transformed.chars().iter().find(|c| c.text == "A").unwrap();
+        assert!((a.bbox.x0 - 10.0).abs() < 1e-10);
+        assert!((a.bbox.top - 10.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_transform_scale() {
+        let page = make_test_page();
+        // Scale everything by 2x
+        let transformed = page.transform([2.0, 0.0, 0.0, 2.0, 0.0, 0.0]);
+
+        assert!((transformed.width() - 200.0).abs() < 1e-9);
+        assert!((transformed.height() - 200.0).abs() < 1e-9);
+
+        let a = transformed.chars().iter().find(|c| c.text == "A").unwrap();
+        assert!((a.bbox.x0 - 20.0).abs() < 1e-9);
+        assert!((a.bbox.top - 20.0).abs() < 1e-9);
+        assert!((a.bbox.x1 - 40.0).abs() < 1e-9);
+        assert!((a.bbox.bottom - 44.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transform_rotation_90_rebases_origin() {
+        let page = Page::new(0, 100.0, 50.0, vec![]);
+        // 90 degree rotation: (x, y) -> (-y, x)
+        let transformed = page.transform([0.0, 1.0, -1.0, 0.0, 0.0, 0.0]);
+        // original bbox corners (0,0),(100,0),(0,50),(100,50) rotate to
+        // (0,0),(0,100),(-50,0),(-50,100) -> new bbox x0=-50,top=0,x1=0,bottom=100
+        assert!((transformed.width() - 50.0).abs() < 1e-9);
+        assert!((transformed.height() - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transform_composes_char_ctm() {
+        let page = make_test_page();
+        let transformed = page.transform([2.0, 0.0, 0.0, 2.0, 0.0, 0.0]);
+        let a = transformed.chars().iter().find(|c| c.text == "A").unwrap();
+        // original ctm is identity; composed with a 2x scale should scale a/d
+        assert!((a.ctm[0] - 2.0).abs() < 1e-9);
+        assert!((a.ctm[3] - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transform_curve_points() {
+        let page = make_test_page();
+        let transformed = page.transform([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+        let curve = &transformed.curves()[0];
+        assert_eq!(curve.pts.len(), 4);
+    }
 }
@@ -93,8 +93,8 @@ mod cropped_page;
 mod page;
 mod pdf;
 
-pub use cropped_page::CroppedPage;
-pub use page::Page;
+pub use cropped_page::{CropOptions, CroppedPage};
+pub use page::{Page, PageBox};
 pub use pdf::{PagesIter, Pdf};
 
 /// A page view produced by [`Page::filter`] or [`CroppedPage::filter`].
@@ -104,24 +104,34 @@ pub use pdf::{PagesIter, Pdf};
 /// and can be filtered again for composable filtering chains.
 pub type FilteredPage = CroppedPage;
 pub use pdfplumber_core::{
-    Annotation, AnnotationType, BBox, Bookmark, Cell, Char, Color, ColumnMode, Ctm, Curve,
-    DashPattern, DedupeOptions, DocumentMetadata, DrawStyle, Edge, EdgeSource, EncodingResolver,
+    AcroForm, Annotation, AnnotationType, BBox, Bitmap, Bookmark, Cell, Char, Color, ColumnMode,
+    Ctm, Curve,
+    CryptFilterMethod, DanglingRefPolicy, DashPattern, DedupeOptions, DocumentMetadata, DrawStyle,
+    Edge, EdgeSource,
+    EncodingResolver,
     ExplicitLines, ExportedImage, ExtGState, ExtractOptions, ExtractResult, ExtractWarning,
-    FieldType, FillRule, FontEncoding, FormField, GraphicsState, HtmlOptions, HtmlRenderer,
-    Hyperlink, Image, ImageContent, ImageExportOptions, ImageFilter, ImageFormat, ImageMetadata,
-    Intersection, Line, LineOrientation, MarkdownConversionOptions, MarkdownConversionResult,
-    MarkdownOptions, MarkdownRenderer, Orientation, PageObject, PageRegionOptions, PageRegions,
-    PaintedPath, Path, PathBuilder, PathSegment, PdfError, Point, Rect, RepairOptions,
+    ExtractWarningCode, FieldType, FillRule, FontEncoding, FormField, GraphicsState, HtmlOptions,
+    HtmlOutput, HtmlOutputOptions, HtmlRenderer, Hyperlink, Image, ImageContent, ImageExportOptions,
+    ImageFilter, ImageFormat, ImageMetadata, Intersection, Line, LineOrientation,
+    MarkdownConversionOptions, MarkdownConversionResult, MarkdownOptions, MarkdownRenderer,
+    Orientation, OrphanedObject, OutlineItem, OutputDevice, PageObject, PageRegionOptions,
+    PageRegions, PaintedPath, Path, PathBuilder, PathSegment, PdfDate, PdfError, Point, Rect,
+    RenderOptions,
+    RenderedPage, RepairOptions,
     RepairResult, SearchMatch, SearchOptions, Severity, SignatureInfo, StandardEncoding, Strategy,
-    StructElement, SvgDebugOptions, SvgOptions, SvgRenderer, Table, TableFinder, TableFinderDebug,
-    TableQuality, TableSettings, TextBlock, TextDirection, TextLine, TextOptions, UnicodeNorm,
-    ValidationIssue, Word, WordExtractor, WordOptions, blocks_to_text, cells_to_tables,
-    cluster_lines_into_blocks, cluster_words_into_lines, derive_edges, detect_columns,
-    edge_from_curve, edge_from_line, edges_from_rect, edges_to_cells, edges_to_intersections,
-    explicit_lines_to_edges, export_image_set, extract_shapes, extract_text_for_cells,
-    image_from_ctm, intersections_to_cells, is_cjk, is_cjk_text, join_edge_group,
-    normalize_table_columns, snap_edges, sort_blocks_column_order, sort_blocks_reading_order,
-    split_lines_at_columns, words_to_edges_stream, words_to_text,
+    Stripped, StripRegionOptions, StructElement, SvgDebugOptions, SvgOptions, SvgRenderer, Table,
+    TableFinder, TableFinderDebug, TableQuality, TableSettings, TextBlock, TextDirection,
+    TextLine, TextOptions, UnicodeNorm, ValidationIssue, Word, WordExtractor, WordOptions,
+    WordSearchMatch, blocks_to_text, calculate_area,
+    cells_to_tables, cluster_lines_into_blocks, cluster_words_into_lines, derive_edges,
+    detect_columns, edge_from_curve, edge_from_line, edges_from_rect, edges_to_cells,
+    edges_to_intersections, explicit_lines_to_edges, export_image_set, extract_shapes,
+    extract_text_for_cells, image_from_ctm, intersections_to_cells, is_cjk, is_cjk_text,
+    join_edge_group,
+    normalize_chars, normalize_table_columns, render_page, reverse_predictor, snap_edges,
+    sort_blocks_column_order, sort_blocks_reading_order,
+    split_lines_at_columns, strip_chars, strip_edges, strip_lines, strip_rects,
+    words_to_edges_stream, words_to_text,
 };
 pub use pdfplumber_parse::{
     self, CharEvent, ContentHandler, ImageEvent, LopdfBackend, LopdfDocument, LopdfPage,
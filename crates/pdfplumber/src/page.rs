@@ -1,15 +1,40 @@
 //! Page type for accessing extracted content from a PDF page.
 
 use pdfplumber_core::{
-    Annotation, BBox, Char, Curve, DedupeOptions, Edge, ExtractWarning, HtmlOptions, HtmlRenderer,
-    Hyperlink, Image, Line, MarkdownOptions, MarkdownRenderer, PageObject, Rect, SearchMatch,
-    SearchOptions, Table, TableFinder, TableSettings, TextOptions, Word, WordExtractor,
-    WordOptions, blocks_to_text, cluster_lines_into_blocks, cluster_words_into_lines, dedupe_chars,
-    derive_edges, extract_text_for_cells, search_chars, sort_blocks_reading_order,
-    split_lines_at_columns, words_to_text,
+    Annotation, BBox, Bitmap, Char, Curve, DedupeOptions, Edge, ExtractWarning, FormField,
+    HtmlOptions, HtmlRenderer, Hyperlink, Image, Line, MarkdownOptions, MarkdownRenderer,
+    PageObject, PageRegions, PdfError, Rect, SearchMatch, SearchOptions, StripRegionOptions,
+    StructElement, Table, TableFinder, TableSettings, TextOptions, Word, WordExtractor,
+    WordOptions, WordSearchMatch, blocks_to_text, check_raster_dimensions,
+    cluster_lines_into_blocks, cluster_words_into_lines, dedupe_chars, derive_edges,
+    extract_text_for_cells, search_chars, search_words, sort_blocks_reading_order,
+    split_lines_at_columns, strip_chars, strip_edges, words_to_text,
 };
 
-use crate::cropped_page::{CroppedPage, FilterMode, PageData, filter_and_build, from_page_data};
+use crate::cropped_page::{
+    CropOptions, CroppedPage, FilterMode, PageData, filter_and_build, from_page_data,
+    transform_page_data, validate_crop_bbox,
+};
+
+/// Which of a page's box rectangles to use as a coordinate origin.
+///
+/// PDFs can define several nested boxes (MediaBox, CropBox, TrimBox,
+/// BleedBox, ArtBox); the visible CropBox often differs from the physical
+/// MediaBox, so cropping "relative to the page" is ambiguous unless the
+/// caller says which box they mean. See [`Page::crop_relative`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageBox {
+    /// The physical page boundaries.
+    Media,
+    /// The visible region of the page.
+    Crop,
+    /// The intended finished dimensions after trimming.
+    Trim,
+    /// The clipping region for production output.
+    Bleed,
+    /// The extent of meaningful page content.
+    Art,
+}
 
 /// A single page from a PDF document.
 ///
@@ -48,6 +73,10 @@ pub struct Page {
     annotations: Vec<Annotation>,
     /// Hyperlinks extracted from Link annotations with resolved URIs.
     hyperlinks: Vec<Hyperlink>,
+    /// Form fields from the document AcroForm that belong to this page.
+    form_fields: Vec<FormField>,
+    /// Structure tree elements tagging this page's content, if present.
+    structure_tree: Option<Vec<StructElement>>,
     /// Non-fatal warnings collected during extraction.
     warnings: Vec<ExtractWarning>,
 }
@@ -73,6 +102,8 @@ impl Page {
             images: Vec::new(),
             annotations: Vec::new(),
             hyperlinks: Vec::new(),
+            form_fields: Vec::new(),
+            structure_tree: None,
             warnings: Vec::new(),
         }
     }
@@ -105,6 +136,8 @@ impl Page {
             images: Vec::new(),
             annotations: Vec::new(),
             hyperlinks: Vec::new(),
+            form_fields: Vec::new(),
+            structure_tree: None,
             warnings: Vec::new(),
         }
     }
@@ -139,6 +172,8 @@ impl Page {
             images,
             annotations: Vec::new(),
             hyperlinks: Vec::new(),
+            form_fields: Vec::new(),
+            structure_tree: None,
             warnings: Vec::new(),
         }
     }
@@ -159,9 +194,14 @@ impl Page {
         bleed_box: Option<BBox>,
         art_box: Option<BBox>,
         chars: Vec<Char>,
+        lines: Vec<Line>,
+        rects: Vec<Rect>,
+        curves: Vec<Curve>,
         images: Vec<Image>,
         annotations: Vec<Annotation>,
         hyperlinks: Vec<Hyperlink>,
+        form_fields: Vec<FormField>,
+        structure_tree: Option<Vec<StructElement>>,
         warnings: Vec<ExtractWarning>,
     ) -> Self {
         Self {
@@ -175,12 +215,14 @@ impl Page {
             bleed_box,
             art_box,
             chars,
-            lines: Vec::new(),
-            rects: Vec::new(),
-            curves: Vec::new(),
+            lines,
+            rects,
+            curves,
             images,
             annotations,
             hyperlinks,
+            form_fields,
+            structure_tree,
             warnings,
         }
     }
@@ -285,6 +327,21 @@ impl Page {
         &self.hyperlinks
     }
 
+    /// Returns the AcroForm fields whose widget annotation lives on this page.
+    ///
+    /// Values and bounding boxes are resolved from the document's `/AcroForm`
+    /// dictionary; see [`Pdf::form_fields`](crate::Pdf::form_fields) for the
+    /// whole-document equivalent.
+    pub fn form_fields(&self) -> &[FormField] {
+        &self.form_fields
+    }
+
+    /// Returns the structure tree elements tagging this page's content,
+    /// if the document has a `/StructTreeRoot`.
+    pub fn structure_tree(&self) -> Option<&[StructElement]> {
+        self.structure_tree.as_deref()
+    }
+
     /// Returns non-fatal warnings collected during page extraction.
     ///
     /// Warnings are purely informational and do not affect the correctness
@@ -382,19 +439,45 @@ impl Page {
     pub fn find_tables(&self, settings: &TableSettings) -> Vec<Table> {
         let edges = self.edges();
         let words = self.extract_words(&WordOptions::default());
+        Self::find_tables_from(edges, words, &self.chars, settings)
+    }
 
+    /// Detect tables as in [`Page::find_tables`], but first strip any chars
+    /// and edges that fall inside a detected header/footer `region` so
+    /// boilerplate content can't be mistaken for table rows or borders.
+    ///
+    /// See [`strip_chars`]/[`strip_edges`] for how `strip_options` controls
+    /// boundary-straddling objects.
+    pub fn find_tables_excluding_regions(
+        &self,
+        settings: &TableSettings,
+        regions: &PageRegions,
+        strip_options: &StripRegionOptions,
+    ) -> Vec<Table> {
+        let chars = strip_chars(&self.chars, regions, strip_options).kept;
+        let edges = strip_edges(&self.edges(), regions, strip_options).kept;
+        let words = WordExtractor::extract(&chars, &WordOptions::default());
+        Self::find_tables_from(edges, words, &chars, settings)
+    }
+
+    fn find_tables_from(
+        edges: Vec<Edge>,
+        words: Vec<Word>,
+        chars: &[Char],
+        settings: &TableSettings,
+    ) -> Vec<Table> {
         let finder = TableFinder::new_with_words(edges, words, settings.clone());
         let mut tables = finder.find_tables();
 
         // Populate cell text from page characters
         for table in &mut tables {
-            extract_text_for_cells(&mut table.cells, &self.chars);
+            extract_text_for_cells(&mut table.cells, chars);
             // Also populate text in rows and columns
             for row in &mut table.rows {
-                extract_text_for_cells(row, &self.chars);
+                extract_text_for_cells(row, chars);
             }
             for col in &mut table.columns {
-                extract_text_for_cells(col, &self.chars);
+                extract_text_for_cells(col, chars);
             }
         }
 
@@ -451,19 +534,80 @@ impl Page {
         search_chars(&self.chars, pattern, options, self.page_number)
     }
 
+    /// Search for a text pattern on this page at word granularity, returning
+    /// matches with a word-derived bounding box plus surrounding word
+    /// context (up to `context` words on either side of the match).
+    ///
+    /// Unlike [`Page::search`], which operates over characters, this builds
+    /// its text from [`Page::extract_words`] — useful when callers want the
+    /// match reported in terms of whole words (e.g. for snippet previews).
+    pub fn search_words(
+        &self,
+        pattern: &str,
+        options: &SearchOptions,
+        context: usize,
+    ) -> Vec<WordSearchMatch> {
+        let words = self.extract_words(&WordOptions::default());
+        search_words(&words, pattern, options, self.page_number, context)
+    }
+
+    /// Stream this page's characters through an [`OutputDevice`], calling
+    /// `begin_page`, one `output_char` per character in array order, then
+    /// `end_page`. Useful for sinks (e.g. [`pdfplumber_core::HtmlOutput`])
+    /// that consume characters incrementally instead of materializing them.
+    pub fn stream_to(&self, device: &mut dyn OutputDevice) {
+        drive_output_device(device, self.width, self.height, &self.chars);
+    }
+
     /// Return a [`CroppedPage`] with objects whose centers fall within `bbox`.
     ///
-    /// Coordinates in the returned page are adjusted relative to the crop origin.
+    /// Coordinates in the returned page are adjusted relative to the crop
+    /// origin, and objects that only partially overlap `bbox` are sliced to
+    /// fit (see [`CropOptions`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bbox` is inverted (`x0 > x1` or `top > bottom`) or extends
+    /// beyond the page's bounds.
     pub fn crop(&self, bbox: BBox) -> CroppedPage {
-        filter_and_build(self, bbox, FilterMode::Crop)
+        self.crop_with_options(bbox, CropOptions::default())
+    }
+
+    /// Like [`Self::crop`], with explicit [`CropOptions`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bbox` is inverted (`x0 > x1` or `top > bottom`) or extends
+    /// beyond the page's bounds.
+    pub fn crop_with_options(&self, bbox: BBox, options: CropOptions) -> CroppedPage {
+        validate_crop_bbox(bbox, self.bbox());
+        filter_and_build(self, bbox, FilterMode::Crop, options.slice_partial)
     }
 
     /// Return a [`CroppedPage`] with objects fully contained within `bbox`.
     ///
     /// Only objects whose entire bounding box is inside `bbox` are included.
     /// Coordinates are adjusted relative to the crop origin.
+    /// `CropOptions::slice_partial` has no effect here: a fully-contained
+    /// object is never partially overlapping, so there's nothing to slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bbox` is inverted (`x0 > x1` or `top > bottom`) or extends
+    /// beyond the page's bounds.
     pub fn within_bbox(&self, bbox: BBox) -> CroppedPage {
-        filter_and_build(self, bbox, FilterMode::Within)
+        self.within_bbox_with_options(bbox, CropOptions::default())
+    }
+
+    /// Like [`Self::within_bbox`], with explicit [`CropOptions`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bbox` is inverted (`x0 > x1` or `top > bottom`) or extends
+    /// beyond the page's bounds.
+    pub fn within_bbox_with_options(&self, bbox: BBox, options: CropOptions) -> CroppedPage {
+        validate_crop_bbox(bbox, self.bbox());
+        filter_and_build(self, bbox, FilterMode::Within, options.slice_partial)
     }
 
     /// Return a [`CroppedPage`] with objects fully outside `bbox`.
@@ -471,7 +615,131 @@ impl Page {
     /// Only objects whose bounding box has no overlap with `bbox` are included.
     /// Coordinates are adjusted relative to the bbox origin.
     pub fn outside_bbox(&self, bbox: BBox) -> CroppedPage {
-        filter_and_build(self, bbox, FilterMode::Outside)
+        filter_and_build(self, bbox, FilterMode::Outside, false)
+    }
+
+    /// Return a [`CroppedPage`] with objects that overlap `bbox` at all,
+    /// whether partially or fully.
+    ///
+    /// When `clip` is `true`, objects straddling the boundary are cut down to
+    /// the portion that falls inside `bbox`, so e.g. a rect half inside the
+    /// crop rect keeps only its overlapping half. When `false`, the full
+    /// original bounding box of each overlapping object is kept (re-based to
+    /// the crop origin, and possibly extending outside the returned page's
+    /// `width`/`height`).
+    pub fn intersects_bbox(&self, bbox: BBox, clip: bool) -> CroppedPage {
+        filter_and_build(self, bbox, FilterMode::Intersects, clip)
+    }
+
+    /// Crop relative to one of the page's box rectangles instead of the raw
+    /// MediaBox origin.
+    ///
+    /// `bbox` is interpreted as an offset within `relative_to`'s coordinate
+    /// space: it is translated by that box's `(x0, top)` before the existing
+    /// [`crop`](Self::crop) logic runs. This lets callers say "crop 1 inch in
+    /// from the CropBox edges" without manually computing page-coordinate
+    /// offsets. If the requested box (CropBox/TrimBox/BleedBox/ArtBox) was not
+    /// present in the PDF, the MediaBox is used instead.
+    pub fn crop_relative(&self, bbox: BBox, relative_to: PageBox) -> CroppedPage {
+        let origin = self.page_box(relative_to);
+        let translated = BBox::new(
+            bbox.x0 + origin.x0,
+            bbox.top + origin.top,
+            bbox.x1 + origin.x0,
+            bbox.bottom + origin.top,
+        );
+        self.crop_with_options(translated, CropOptions::default())
+    }
+
+    /// Resolve a [`PageBox`] to its rectangle, falling back per the PDF spec
+    /// when the requested box was not present in the page dictionary: CropBox
+    /// defaults to MediaBox, while TrimBox/BleedBox/ArtBox default to CropBox
+    /// (which may itself have fallen back to MediaBox).
+    fn page_box(&self, which: PageBox) -> BBox {
+        let effective_crop_box = self.crop_box.unwrap_or(self.media_box);
+        match which {
+            PageBox::Media => self.media_box,
+            PageBox::Crop => effective_crop_box,
+            PageBox::Trim => self.trim_box.unwrap_or(effective_crop_box),
+            PageBox::Bleed => self.bleed_box.unwrap_or(effective_crop_box),
+            PageBox::Art => self.art_box.unwrap_or(effective_crop_box),
+        }
+    }
+
+    /// Restrict extraction to one of the page's box rectangles.
+    ///
+    /// Unlike [`crop_relative`](Self::crop_relative), which interprets `bbox`
+    /// as an offset *within* the chosen box, this crops directly to the box's
+    /// own extent — the common case of "only look at the CropBox/TrimBox,
+    /// ignore bleed past it" when a document's MediaBox is larger than its
+    /// intended visible area.
+    pub fn crop_to_box(&self, which: PageBox) -> CroppedPage {
+        self.crop(self.page_box(which))
+    }
+
+    /// Split this page into a `cols` x `rows` grid of tiles, each returned as
+    /// an independent [`Page`] whose own origin is the tile's top-left
+    /// corner.
+    ///
+    /// The page's CropBox (falling back to the MediaBox, per
+    /// [`Self::page_box`]) is divided into `cols` equal-width columns and
+    /// `rows` equal-height rows. Each tile keeps only the objects that
+    /// overlap it, sliced down to the overlapping portion exactly like
+    /// [`Self::crop`]. Tiles are returned in row-major order (top row first,
+    /// left to right within each row).
+    ///
+    /// This mirrors the idea behind mupdf's `pdfposter` — x/y decimation
+    /// factors that cut one page into a grid — applied to extraction instead
+    /// of print layout, so very large sheets (maps, engineering drawings)
+    /// can be pulled apart region by region or turned into tiled previews.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cols` or `rows` is zero.
+    pub fn split_tiles(&self, cols: usize, rows: usize) -> Vec<Page> {
+        assert!(cols > 0, "split_tiles: cols must be > 0");
+        assert!(rows > 0, "split_tiles: rows must be > 0");
+
+        let region = self.page_box(PageBox::Crop);
+        let tile_width = region.width() / cols as f64;
+        let tile_height = region.height() / rows as f64;
+
+        let mut tiles = Vec::with_capacity(cols * rows);
+        for r in 0..rows {
+            let top = region.top + r as f64 * tile_height;
+            let bottom = if r + 1 == rows { region.bottom } else { top + tile_height };
+            for c in 0..cols {
+                let x0 = region.x0 + c as f64 * tile_width;
+                let x1 = if c + 1 == cols { region.x1 } else { x0 + tile_width };
+                let cropped =
+                    filter_and_build(self, BBox::new(x0, top, x1, bottom), FilterMode::Intersects, true);
+                tiles.push(Page::with_geometry_and_images(
+                    self.page_number,
+                    cropped.width(),
+                    cropped.height(),
+                    cropped.chars().to_vec(),
+                    cropped.lines().to_vec(),
+                    cropped.rects().to_vec(),
+                    cropped.curves().to_vec(),
+                    cropped.images().to_vec(),
+                ));
+            }
+        }
+        tiles
+    }
+
+    /// Apply an affine transform `[a, b, c, d, e, f]` to every object on this
+    /// page, returning a normalized (axis-aligned) view.
+    ///
+    /// Rotation, scaling, skew, and translation are all expressed by the same
+    /// six-value matrix, mirroring the `xform_page` concept from `paperjam`.
+    /// Bounding boxes are recomputed from the transformed corners of each
+    /// object, `Curve` points are transformed individually, and each
+    /// `Char.ctm` is composed with `matrix` so downstream font-size/upright
+    /// logic stays consistent. Use this to normalize a rotated page before
+    /// word or table extraction.
+    pub fn transform(&self, matrix: [f64; 6]) -> CroppedPage {
+        transform_page_data(self, self.width, self.height, matrix)
     }
 
     /// Return a filtered view retaining only objects that match the predicate.
@@ -569,6 +837,72 @@ impl Page {
         renderer.to_svg(options)
     }
 
+    /// Rasterize the page boundary (no overlays) to PNG-encoded bytes, at
+    /// `options.scale` resolution. See [`pdfplumber_core::SvgRenderer::to_png`]
+    /// for what is and isn't rasterized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pdfplumber::{Page, SvgOptions};
+    /// let page = Page::new(0, 612.0, 792.0, vec![]);
+    /// let png = page.to_png(&SvgOptions::default());
+    /// assert_eq!(&png[0..8], &[137, 80, 78, 71, 13, 10, 26, 10]);
+    /// ```
+    pub fn to_png(&self, options: &pdfplumber_core::SvgOptions) -> Vec<u8> {
+        let renderer = pdfplumber_core::SvgRenderer::new(self.width, self.height);
+        renderer.to_png(options)
+    }
+
+    /// Render this page to an RGBA [`Bitmap`], analogous to pdfplumber's
+    /// Python `page.to_image()`.
+    ///
+    /// This is a visual-debugging raster, not a faithful renderer: the page
+    /// is filled white, the same line/rect path operators used for edge and
+    /// table detection are stroked or filled, and each character is painted
+    /// as a filled box at its bbox since this crate has no font rasterizer.
+    ///
+    /// `scale` maps page points to pixels (e.g. `2.0` renders at 2x the
+    /// page's point dimensions).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PdfError::ResourceLimitExceeded`] if the page's dimensions
+    /// times `scale` would produce an unreasonably large raster (see
+    /// [`pdfplumber_core::check_raster_dimensions`]), rather than attempting
+    /// the allocation.
+    pub fn render(&self, scale: f64) -> Result<Bitmap, PdfError> {
+        let width = (self.width * scale).round() as u32;
+        let height = (self.height * scale).round() as u32;
+        check_raster_dimensions(width, height)?;
+
+        let mut bitmap = Bitmap::new(width, height);
+        bitmap.draw_rects(self.rects(), scale);
+        bitmap.draw_lines(self.lines(), scale);
+        bitmap.draw_chars(self.chars(), scale);
+        Ok(bitmap)
+    }
+
+    /// Rasterize this page into an RGBA [`RenderedPage`], painting fills and
+    /// strokes (color, line width) and blitting decoded image content in
+    /// addition to the line/rect/char painting [`Page::render`] does, so it
+    /// can produce page previews and thumbnails.
+    ///
+    /// See [`pdfplumber_core::render_page`] for what is and isn't rasterized,
+    /// and for the error returned if the requested raster is unreasonably large.
+    pub fn render_page(&self, options: &pdfplumber_core::RenderOptions) -> Result<pdfplumber_core::RenderedPage, PdfError> {
+        pdfplumber_core::render_page(
+            self.width,
+            self.height,
+            self.rects(),
+            self.lines(),
+            self.curves(),
+            self.images(),
+            self.chars(),
+            options,
+        )
+    }
+
     /// Generate a debug SVG showing the table detection pipeline.
     ///
     /// Runs the table detection pipeline and renders intermediate results:
@@ -1004,6 +1338,8 @@ mod tests {
             src_height: Some(480),
             bits_per_component: Some(8),
             color_space: Some("DeviceRGB".to_string()),
+            is_mask: false,
+            decode: None,
         };
         let img = image_from_ctm(&ctm, "Im0", 792.0, &meta);
 
@@ -1533,4 +1869,196 @@ mod tests {
             Page::with_geometry_and_images(0, 612.0, 792.0, vec![], vec![], vec![], vec![], vec![]);
         assert!(page.warnings().is_empty());
     }
+
+    // --- crop_relative tests ---
+
+    fn make_page_with_crop_box(crop_box: Option<BBox>) -> Page {
+        let chars = vec![
+            make_char("A", 10.0, 10.0, 20.0, 22.0),
+            make_char("B", 60.0, 60.0, 70.0, 72.0),
+        ];
+        Page::from_extraction(
+            0,
+            100.0,
+            100.0,
+            0,
+            BBox::new(0.0, 0.0, 100.0, 100.0),
+            crop_box,
+            None,
+            None,
+            None,
+            chars,
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            None,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_crop_relative_translates_by_crop_box_origin() {
+        let page = make_page_with_crop_box(Some(BBox::new(5.0, 5.0, 95.0, 95.0)));
+        // "A" at (10,10)-(20,22); relative to CropBox origin (5,5) that's
+        // offset (5,5)-(15,17) — request the region covering just that offset.
+        let cropped = page.crop_relative(BBox::new(0.0, 0.0, 20.0, 20.0), PageBox::Crop);
+        assert_eq!(cropped.chars().len(), 1);
+        assert_eq!(cropped.chars()[0].text, "A");
+    }
+
+    #[test]
+    fn test_crop_relative_falls_back_to_media_box() {
+        let page = make_page_with_crop_box(None);
+        // No CropBox set, so PageBox::Crop should behave like PageBox::Media.
+        let cropped = page.crop_relative(BBox::new(0.0, 0.0, 30.0, 30.0), PageBox::Crop);
+        assert_eq!(cropped.chars().len(), 1);
+        assert_eq!(cropped.chars()[0].text, "A");
+    }
+
+    #[test]
+    fn test_crop_relative_media_box_matches_plain_crop() {
+        let page = make_page_with_crop_box(Some(BBox::new(5.0, 5.0, 95.0, 95.0)));
+        let via_relative = page.crop_relative(BBox::new(0.0, 0.0, 30.0, 30.0), PageBox::Media);
+        let via_plain = page.crop(BBox::new(0.0, 0.0, 30.0, 30.0));
+        assert_eq!(via_relative.chars().len(), via_plain.chars().len());
+    }
+
+    // --- page box fallback chain / crop_to_box tests ---
+
+    #[test]
+    fn test_trim_box_falls_back_to_crop_box_not_media_box() {
+        let page = Page::from_extraction(
+            0,
+            100.0,
+            100.0,
+            0,
+            BBox::new(0.0, 0.0, 100.0, 100.0),
+            Some(BBox::new(5.0, 5.0, 95.0, 95.0)),
+            None,
+            None,
+            None,
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            None,
+            vec![],
+        );
+        let cropped = page.crop_to_box(PageBox::Trim);
+        assert_eq!(cropped.width(), 90.0);
+        assert_eq!(cropped.height(), 90.0);
+    }
+
+    #[test]
+    fn test_crop_to_box_media_matches_full_page() {
+        let page = make_page_with_crop_box(None);
+        let cropped = page.crop_to_box(PageBox::Media);
+        assert_eq!(cropped.chars().len(), 2);
+    }
+
+    #[test]
+    fn test_crop_to_box_trim_restricts_to_trim_box() {
+        let page = Page::from_extraction(
+            0,
+            100.0,
+            100.0,
+            0,
+            BBox::new(0.0, 0.0, 100.0, 100.0),
+            None,
+            Some(BBox::new(0.0, 0.0, 30.0, 30.0)),
+            None,
+            None,
+            vec![
+                make_char("A", 10.0, 10.0, 20.0, 22.0),
+                make_char("B", 60.0, 60.0, 70.0, 72.0),
+            ],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            None,
+            vec![],
+        );
+        let cropped = page.crop_to_box(PageBox::Trim);
+        assert_eq!(cropped.chars().len(), 1);
+        assert_eq!(cropped.chars()[0].text, "A");
+    }
+
+    // --- split_tiles tests ---
+
+    #[test]
+    fn test_split_tiles_count_and_dimensions() {
+        let page = Page::new(0, 100.0, 100.0, vec![]);
+        let tiles = page.split_tiles(2, 2);
+        assert_eq!(tiles.len(), 4);
+        for tile in &tiles {
+            assert_eq!(tile.width(), 50.0);
+            assert_eq!(tile.height(), 50.0);
+        }
+    }
+
+    #[test]
+    fn test_split_tiles_assigns_chars_to_correct_tile() {
+        let chars = vec![
+            make_char("A", 10.0, 10.0, 20.0, 22.0),
+            make_char("B", 60.0, 60.0, 70.0, 72.0),
+        ];
+        let page = Page::new(0, 100.0, 100.0, chars);
+        let tiles = page.split_tiles(2, 2);
+
+        // Row-major: [top-left, top-right, bottom-left, bottom-right].
+        assert_eq!(tiles[0].chars().len(), 1);
+        assert_eq!(tiles[0].chars()[0].text, "A");
+        assert_eq!(tiles[1].chars().len(), 0);
+        assert_eq!(tiles[2].chars().len(), 0);
+        assert_eq!(tiles[3].chars().len(), 1);
+        assert_eq!(tiles[3].chars()[0].text, "B");
+    }
+
+    #[test]
+    fn test_split_tiles_rebases_coordinates_to_tile_origin() {
+        let chars = vec![make_char("B", 60.0, 60.0, 70.0, 72.0)];
+        let page = Page::new(0, 100.0, 100.0, chars);
+        let tiles = page.split_tiles(2, 2);
+
+        let bottom_right = &tiles[3];
+        assert_eq!(bottom_right.chars()[0].bbox, BBox::new(10.0, 10.0, 20.0, 22.0));
+    }
+
+    #[test]
+    fn test_split_tiles_slices_objects_straddling_a_boundary() {
+        let rects = vec![make_rect(40.0, 10.0, 60.0, 20.0)];
+        let page = Page::with_geometry(0, 100.0, 100.0, vec![], vec![], rects, vec![]);
+        let tiles = page.split_tiles(2, 1);
+
+        assert_eq!(tiles[0].rects().len(), 1);
+        assert_eq!(tiles[0].rects()[0].x1, 50.0);
+        assert_eq!(tiles[1].rects().len(), 1);
+        assert_eq!(tiles[1].rects()[0].x0, 0.0);
+    }
+
+    #[test]
+    fn test_split_tiles_uneven_division_last_tile_absorbs_remainder() {
+        let page = Page::new(0, 100.0, 100.0, vec![]);
+        let tiles = page.split_tiles(3, 1);
+        let total_width: f64 = tiles.iter().map(|t| t.width()).sum();
+        assert_eq!(total_width, 100.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "cols must be > 0")]
+    fn test_split_tiles_zero_cols_panics() {
+        let page = Page::new(0, 100.0, 100.0, vec![]);
+        page.split_tiles(0, 1);
+    }
 }
@@ -3,11 +3,15 @@
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use pdfplumber_core::{
-    BBox, Bookmark, Char, Color, Ctm, Curve, DashPattern, DocumentMetadata, ExtractOptions,
-    ExtractWarning, FormField, Image, ImageContent, ImageFilter, ImageMetadata, Line,
-    PageRegionOptions, PageRegions, PaintedPath, Path, PdfError, Rect, RepairOptions, RepairResult,
-    SearchMatch, SearchOptions, SignatureInfo, StructElement, TextOptions, UnicodeNorm,
-    ValidationIssue, detect_page_regions, extract_shapes, image_from_ctm, normalize_chars,
+    AcroForm, Authentication, BBox, Bookmark, Char, Color, Ctm, Curve, DashPattern,
+    DocumentMetadata, ExtractOptions, ExtractWarning, ExtractWarningCode, FormField, Image,
+    ImageContent,
+    ImageFilter, ImageMetadata, Line, OrphanedObject, OutlineItem, PageRegionCandidate,
+    PageRegionOptions, PageRegions, PaintedPath, Path, PdfError, Permissions, Rect,
+    RenderOptions, RenderedPage, RepairOptions, RepairResult, SearchMatch, SearchOptions,
+    SignatureInfo, StructElement,
+    TextOptions, UnicodeNorm, ValidationIssue, detect_page_regions, detect_page_regions_with_bounds,
+    extract_shapes, image_from_ctm, normalize_chars,
 };
 use pdfplumber_parse::{
     CharEvent, ContentHandler, FontMetrics, ImageEvent, LopdfBackend, LopdfDocument, PageGeometry,
@@ -69,10 +73,24 @@ pub struct Pdf {
     metadata: DocumentMetadata,
     /// Cached document bookmarks (outline / table of contents).
     bookmarks: Vec<Bookmark>,
+    /// Cached AcroForm for the whole document (fields plus form-level
+    /// flags), resolved once and sliced by page index in [`Pdf::page`]
+    /// instead of being re-parsed per page.
+    acro_form: AcroForm,
+    /// Cached StructTreeRoot elements for the whole document, resolved
+    /// once and filtered by page index in [`Pdf::page`].
+    struct_elements: Vec<StructElement>,
+    /// Cached permission flags decoded from the /Encrypt dictionary's /P entry.
+    permissions: Permissions,
     /// Accumulated total objects extracted across all pages (for max_total_objects budget).
     total_objects: AtomicUsize,
     /// Accumulated total image bytes extracted across all pages (for max_total_image_bytes budget).
     total_image_bytes: AtomicUsize,
+    /// Objects orphaned by [`Pdf::open_with_repair`], kept alive for the
+    /// document's lifetime so a handle resolved before repair still has
+    /// somewhere to find the value it originally saw. Empty unless the
+    /// document was opened via `open_with_repair`.
+    orphaned_objects: Vec<OrphanedObject>,
 }
 
 /// Internal handler that collects content stream events during interpretation.
@@ -159,8 +177,14 @@ impl Pdf {
     ///
     /// # Errors
     ///
-    /// Returns [`PdfError::PasswordRequired`] if the PDF is encrypted.
+    /// Returns [`PdfError::PasswordRequired`] if the PDF is encrypted and
+    /// neither the empty password nor `options.password` authenticate it.
     /// Returns [`PdfError`] if the bytes are not a valid PDF document.
+    ///
+    /// If `options.repair` is set, a document that still fails to parse (a
+    /// misplaced `%PDF-` header, or an unparseable cross-reference table) is
+    /// retried through a best-effort recovery path before the error above is
+    /// returned — see `ExtractOptions::repair`.
     pub fn open(bytes: &[u8], options: Option<ExtractOptions>) -> Result<Self, PdfError> {
         // Check max_input_bytes before parsing
         if let Some(ref opts) = options {
@@ -174,14 +198,40 @@ impl Pdf {
                 }
             }
         }
-        let doc = LopdfBackend::open(bytes).map_err(PdfError::from)?;
+        let doc = match LopdfBackend::open(bytes) {
+            Ok(doc) => doc,
+            // Encrypted: try the empty user password first, then the
+            // caller-supplied password (if any) as a fallback.
+            Err(pdfplumber_parse::BackendError::Core(PdfError::PasswordRequired)) => {
+                match LopdfBackend::open_with_password(bytes, b"") {
+                    Ok(doc) => doc,
+                    Err(_) => match options.as_ref().and_then(|opts| opts.password.as_deref()) {
+                        Some(password) => {
+                            LopdfBackend::open_with_password(bytes, password.as_bytes())
+                                .map_err(PdfError::from)?
+                        }
+                        None => return Err(PdfError::PasswordRequired),
+                    },
+                }
+            }
+            Err(e) => {
+                if options.as_ref().is_some_and(|opts| opts.repair) {
+                    LopdfBackend::open_lenient(bytes).map_err(PdfError::from)?
+                } else {
+                    return Err(PdfError::from(e));
+                }
+            }
+        };
         Self::from_doc(doc, options)
     }
 
     /// Open an encrypted PDF document from bytes with a password.
     ///
-    /// Supports both user and owner passwords. If the PDF is not encrypted,
-    /// the password is ignored and the document opens normally.
+    /// Supports both user and owner passwords: if `password` doesn't match
+    /// the user password, it's retried as an owner password (classic R2-R4
+    /// handlers only). If the PDF is not encrypted, the password is ignored
+    /// and the document opens normally. Use [`Pdf::authentication`] to tell
+    /// which credential was actually used.
     ///
     /// # Arguments
     ///
@@ -202,6 +252,20 @@ impl Pdf {
         Self::from_doc(doc, options)
     }
 
+    // Pdf::open_with_identity (public-key / Adobe.PubSec certificate-based
+    // decryption via a PKCS#12 identity) was proposed here and deliberately
+    // not added. Unlocking it requires parsing the PKCS#12 container's ASN.1
+    // DER structure, performing RSA (PKCS#1 v1.5) decryption of the CMS/
+    // PKCS#7 enveloped recipient blob, and deriving the file key via SHA-1
+    // or SHA-256 — none of which this crate or its `lopdf` dependency
+    // implements, unlike Pdf::open_with_password's RC4/AES path, which
+    // delegates entirely to `lopdf`. Hand-rolling ASN.1/RSA/CMS parsing from
+    // scratch in a tree with no build or test feedback available would
+    // produce security-relevant code that looks plausible but is
+    // unverifiable; shipping a public method that can only ever return an
+    // error would be worse than not shipping it, since it invites callers to
+    // depend on an API that can never work. Left unimplemented.
+
     /// Open an encrypted PDF document from a file path with a password.
     ///
     /// Convenience wrapper around [`Pdf::open_with_password`] that reads the file
@@ -250,10 +314,22 @@ impl Pdf {
         let repair_opts = repair_opts.unwrap_or_default();
         let (repaired_bytes, result) =
             LopdfBackend::repair(bytes, &repair_opts).map_err(PdfError::from)?;
-        let pdf = Self::open(&repaired_bytes, options)?;
+        let mut pdf = Self::open(&repaired_bytes, options)?;
+        pdf.orphaned_objects = result.orphans.clone();
         Ok((pdf, result))
     }
 
+    /// Objects orphaned by the repair that produced this document, if any.
+    ///
+    /// Populated only for a document opened via [`Pdf::open_with_repair`]
+    /// with [`RepairOptions::preserve_orphans`] enabled (the default); empty
+    /// otherwise. Each entry's original content stays reachable for the
+    /// lifetime of this `Pdf`, so a caller holding a value resolved before
+    /// repair ran can still recover what it originally saw.
+    pub fn orphaned_objects(&self) -> &[OrphanedObject] {
+        &self.orphaned_objects
+    }
+
     /// Internal helper to construct a `Pdf` from a loaded `LopdfDocument`.
     fn from_doc(doc: LopdfDocument, options: Option<ExtractOptions>) -> Result<Self, PdfError> {
         let options = options.unwrap_or_default();
@@ -291,6 +367,14 @@ impl Pdf {
         // Extract document bookmarks (outline / table of contents)
         let bookmarks = LopdfBackend::document_bookmarks(&doc).map_err(PdfError::from)?;
 
+        // Extract the document-wide AcroForm and StructTreeRoot once; `page()`
+        // slices these cached vectors instead of re-parsing them per page.
+        let acro_form = LopdfBackend::document_acro_form(&doc).map_err(PdfError::from)?;
+        let struct_elements = LopdfBackend::document_structure_tree(&doc).map_err(PdfError::from)?;
+
+        // Decode document permission flags from /Encrypt's /P entry, if any.
+        let permissions = LopdfBackend::document_permissions(&doc);
+
         Ok(Self {
             doc,
             options,
@@ -298,8 +382,12 @@ impl Pdf {
             raw_page_heights,
             metadata,
             bookmarks,
+            acro_form,
+            struct_elements,
+            permissions,
             total_objects: AtomicUsize::new(0),
             total_image_bytes: AtomicUsize::new(0),
+            orphaned_objects: Vec::new(),
         })
     }
 
@@ -308,11 +396,37 @@ impl Pdf {
         LopdfBackend::page_count(&self.doc)
     }
 
-    /// Return the document metadata from the PDF /Info dictionary.
+    /// Which credential authenticated this document: [`Authentication::None`]
+    /// for an unencrypted document, [`Authentication::User`] or
+    /// [`Authentication::Owner`] for an encrypted one opened via
+    /// [`Pdf::open_with_password`] (or [`Pdf::open`]'s empty-password
+    /// fallback), depending on which password matched. Callers that need to
+    /// distinguish "opened with full permissions" from "opened via the
+    /// owner credential, permission restrictions notwithstanding" can check
+    /// this.
+    pub fn authentication(&self) -> Authentication {
+        self.doc.authentication()
+    }
+
+    /// Return the document's permission flags, decoded from the `/Encrypt`
+    /// dictionary's `/P` entry. For an unencrypted document, this is
+    /// [`Permissions::default`] (all capabilities granted). These flags are
+    /// advisory — nothing in this crate enforces them while extracting
+    /// content.
+    pub fn permissions(&self) -> Permissions {
+        self.permissions
+    }
+
+    /// Return the document metadata from the PDF /Info dictionary and, when
+    /// present, the catalog's XMP `/Metadata` stream.
     ///
     /// Returns a reference to the cached [`DocumentMetadata`] containing
-    /// title, author, subject, keywords, creator, producer, and dates.
-    /// Fields not present in the PDF are `None`.
+    /// title, author, subject, keywords, creator, producer, and dates, plus
+    /// any vendor-specific /Info keys in `custom` and the raw XMP bytes in
+    /// `xmp`. `title`/`creator` prefer the XMP `dc:title`/`dc:creator` values
+    /// over the /Info dictionary when both are present. Fields not present
+    /// in the PDF are `None`. Use [`DocumentMetadata::parse_pdf_date`] to
+    /// parse `creation_date`/`mod_date` into structured components.
     pub fn metadata(&self) -> &DocumentMetadata {
         &self.metadata
     }
@@ -326,16 +440,52 @@ impl Pdf {
         &self.bookmarks
     }
 
-    /// Extract all form fields from the document's AcroForm dictionary.
+    /// Extract the document outline as a hierarchical tree.
+    ///
+    /// Unlike [`Self::bookmarks`]'s flattened list, this preserves the
+    /// outline's nesting via [`OutlineItem::children`], and includes the
+    /// `/Count`, `/C`, and `/F` display hints. Recursion is bounded by
+    /// [`ExtractOptions::max_recursion_depth`], so a malformed or cyclic
+    /// `/Outlines` tree degrades to a partial result instead of looping.
     ///
-    /// Returns a list of [`FormField`]s from the `/AcroForm` dictionary.
-    /// Returns an empty Vec if the document has no AcroForm.
+    /// Returns an empty Vec if the document has no outlines.
     ///
     /// # Errors
     ///
-    /// Returns [`PdfError`] if the AcroForm exists but is malformed.
-    pub fn form_fields(&self) -> Result<Vec<FormField>, PdfError> {
-        LopdfBackend::document_form_fields(&self.doc).map_err(PdfError::from)
+    /// Returns [`PdfError`] if the /Outlines dictionary exists but is malformed.
+    pub fn outline(&self) -> Result<Vec<OutlineItem>, PdfError> {
+        LopdfBackend::document_outline(&self.doc, self.options.max_recursion_depth)
+            .map_err(PdfError::from)
+    }
+
+    /// Extract all form fields from the document's AcroForm dictionary.
+    ///
+    /// Returns the cached list of [`FormField`]s from the `/AcroForm`
+    /// dictionary, resolved once when the document was opened. Returns an
+    /// empty Vec if the document has no AcroForm. Shorthand for
+    /// `pdf.acro_form().fields`; see [`Pdf::acro_form`] for the form-level
+    /// flags (`/NeedAppearances`, `/SigFlags`).
+    pub fn form_fields(&self) -> &[FormField] {
+        &self.acro_form.fields
+    }
+
+    /// Return the document's AcroForm: its fields plus form-level flags.
+    ///
+    /// Returns the cached [`AcroForm`], resolved once when the document was
+    /// opened. Returns [`AcroForm::default`] if the document has no AcroForm.
+    pub fn acro_form(&self) -> &AcroForm {
+        &self.acro_form
+    }
+
+    /// Return the document's structure tree (StructTreeRoot) elements.
+    ///
+    /// Returns the cached, unfiltered list of top-level [`StructElement`]s,
+    /// resolved once when the document was opened. Mirrors [`Pdf::bookmarks`].
+    /// [`Pdf::page`] exposes the subset of this tree that belongs to a given
+    /// page via `Page::structure_tree()`. Returns an empty slice if the
+    /// document has no structure tree.
+    pub fn structure_tree(&self) -> &[StructElement] {
+        &self.struct_elements
     }
 
     /// Search all pages for a text pattern and return matches with bounding boxes.
@@ -467,10 +617,16 @@ impl Pdf {
     /// Returns a [`Page`] with characters, images, and metadata extracted
     /// from the PDF content stream.
     ///
+    /// When [`ExtractOptions::lenient`] is set, a content interpretation
+    /// failure or a type mismatch while reading annotations/hyperlinks is
+    /// recorded as a warning on the returned [`Page`] instead of aborting
+    /// extraction, so callers still get back whatever was successfully
+    /// collected.
+    ///
     /// # Errors
     ///
-    /// Returns [`PdfError`] if the index is out of range or content
-    /// interpretation fails.
+    /// Returns [`PdfError`] if the index is out of range, or (unless
+    /// `lenient` is set) content interpretation fails.
     pub fn page(&self, index: usize) -> Result<Page, PdfError> {
         let lopdf_page = LopdfBackend::get_page(&self.doc, index).map_err(PdfError::from)?;
 
@@ -489,8 +645,28 @@ impl Pdf {
 
         // Interpret page content
         let mut handler = CollectingHandler::new(index, self.options.collect_warnings);
-        LopdfBackend::interpret_page(&self.doc, &lopdf_page, &mut handler, &self.options)
-            .map_err(PdfError::from)?;
+        if self.doc.recovered() {
+            handler.on_warning(ExtractWarning::with_code(
+                ExtractWarningCode::MalformedObject,
+                "document opened via best-effort recovery (rebuilt cross-reference table by \
+                 scanning for object headers); some content may be missing or incomplete"
+                    .to_string(),
+            ));
+        }
+        let interpret_result =
+            LopdfBackend::interpret_page(&self.doc, &lopdf_page, &mut handler, &self.options);
+        if let Err(e) = interpret_result {
+            if self.options.lenient {
+                // Keep whatever chars/paths/images the handler collected
+                // before interpretation broke down.
+                handler.on_warning(ExtractWarning::with_code(
+                    ExtractWarningCode::MalformedObject,
+                    format!("page interpretation failed: {}", PdfError::from(e)),
+                ));
+            } else {
+                return Err(PdfError::from(e));
+            }
+        }
 
         // Convert CharEvents to Chars
         let page_height = self.raw_page_heights[index];
@@ -543,6 +719,8 @@ impl Pdf {
                     src_height: Some(event.height),
                     bits_per_component: event.bits_per_component,
                     color_space: event.colorspace.clone(),
+                    is_mask: event.is_mask,
+                    decode: event.decode.clone(),
                 };
                 let mut img = image_from_ctm(&ctm, &event.name, page_height, &meta);
 
@@ -553,9 +731,13 @@ impl Pdf {
                     img.filter = Some(filter);
                 }
 
-                // Optionally extract image data
+                // Optionally extract image data. Inline (BI/ID/EI) images
+                // have no XObject name to look up in page resources, so the
+                // interpreter decodes them eagerly into `event.data` instead.
                 if self.options.extract_image_data {
-                    if let Ok(content) =
+                    if let Some(ref data) = event.data {
+                        img.data = Some(data.clone());
+                    } else if let Ok(content) =
                         LopdfBackend::extract_image_content(&self.doc, &lopdf_page, &event.name)
                     {
                         img.data = Some(content.data);
@@ -567,29 +749,46 @@ impl Pdf {
             .collect();
 
         // Extract annotations from the page
-        let annotations =
-            LopdfBackend::page_annotations(&self.doc, &lopdf_page).map_err(PdfError::from)?;
+        let annotations = match LopdfBackend::page_annotations(&self.doc, &lopdf_page) {
+            Ok(annotations) => annotations,
+            Err(e) if self.options.lenient => {
+                handler.on_warning(ExtractWarning::with_code(
+                    ExtractWarningCode::MalformedObject,
+                    format!("failed to extract annotations: {}", PdfError::from(e)),
+                ));
+                Vec::new()
+            }
+            Err(e) => return Err(PdfError::from(e)),
+        };
 
         // Extract hyperlinks from the page
-        let hyperlinks =
-            LopdfBackend::page_hyperlinks(&self.doc, &lopdf_page).map_err(PdfError::from)?;
-
-        // Extract form fields for this page (filtered from document AcroForm)
-        let all_form_fields =
-            LopdfBackend::document_form_fields(&self.doc).map_err(PdfError::from)?;
-        let form_fields: Vec<FormField> = all_form_fields
-            .into_iter()
+        let hyperlinks = match LopdfBackend::page_hyperlinks(&self.doc, &lopdf_page) {
+            Ok(hyperlinks) => hyperlinks,
+            Err(e) if self.options.lenient => {
+                handler.on_warning(ExtractWarning::with_code(
+                    ExtractWarningCode::MalformedObject,
+                    format!("failed to extract hyperlinks: {}", PdfError::from(e)),
+                ));
+                Vec::new()
+            }
+            Err(e) => return Err(PdfError::from(e)),
+        };
+
+        // Form fields for this page, filtered from the cached document AcroForm
+        let form_fields: Vec<FormField> = self
+            .acro_form
+            .fields
+            .iter()
             .filter(|f| f.page_index == Some(index))
+            .cloned()
             .collect();
 
-        // Extract structure tree for this page (filtered from document StructTreeRoot)
-        let all_struct_elements =
-            LopdfBackend::document_structure_tree(&self.doc).map_err(PdfError::from)?;
-        let structure_tree = if all_struct_elements.is_empty() {
+        // Structure tree for this page, filtered from the cached document StructTreeRoot
+        let structure_tree = if self.struct_elements.is_empty() {
             None
         } else {
             let page_elements: Vec<StructElement> =
-                filter_struct_elements_for_page(&all_struct_elements, index);
+                filter_struct_elements_for_page(&self.struct_elements, index);
             if page_elements.is_empty() {
                 None
             } else {
@@ -655,6 +854,77 @@ impl Pdf {
         ))
     }
 
+    /// Rasterize a page by 0-based index into an RGBA [`RenderedPage`].
+    ///
+    /// See [`Page::render_page`] for what is and isn't rasterized.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PdfError`] if the index is out of range or content
+    /// interpretation fails, or [`PdfError::ResourceLimitExceeded`] if the
+    /// requested raster is unreasonably large.
+    pub fn render_page(&self, index: usize, options: &RenderOptions) -> Result<RenderedPage, PdfError> {
+        self.page(index)?.render_page(options)
+    }
+
+    /// Split a page by 0-based index into a `cols` x `rows` grid of tiles.
+    ///
+    /// See [`Page::split_tiles`] for how objects are assigned to tiles.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PdfError`] if the index is out of range, content
+    /// interpretation fails, or `cols`/`rows` is zero.
+    pub fn page_tiles(&self, index: usize, cols: usize, rows: usize) -> Result<Vec<Page>, PdfError> {
+        Ok(self.page(index)?.split_tiles(cols, rows))
+    }
+
+    /// Write a new single-file PDF containing only `indices` (0-based page
+    /// numbers). The retained pages keep their original document order,
+    /// whatever the order of `indices`, and an index given twice yields one
+    /// page.
+    ///
+    /// Builds a page tree referencing just the requested pages, copies
+    /// their content streams and transitively-referenced resources (fonts,
+    /// XObjects, colorspaces), drops every object no longer reachable, and
+    /// renumbers the xref, so the result is a normal, self-contained PDF.
+    /// The source `/Info` metadata is preserved, and outline bookmarks
+    /// whose destination isn't one of the retained pages are dropped.
+    ///
+    /// This covers the common "extract pages 3-7 into their own file" use
+    /// case without shelling out to another tool.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PdfError`] if any index is out of range, or the document
+    /// cannot be rewritten.
+    pub fn save_subset(
+        &self,
+        indices: &[usize],
+        mut writer: impl std::io::Write,
+    ) -> Result<(), PdfError> {
+        let bytes = LopdfBackend::save_subset(&self.doc, indices).map_err(PdfError::from)?;
+        writer
+            .write_all(&bytes)
+            .map_err(|e| PdfError::IoError(e.to_string()))
+    }
+
+    /// Like [`Self::save_subset`], writing directly to a file path.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PdfError`] if any index is out of range, the document
+    /// cannot be rewritten, or the file cannot be written.
+    #[cfg(feature = "std")]
+    pub fn save_subset_to_path(
+        &self,
+        indices: &[usize],
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<(), PdfError> {
+        let bytes = LopdfBackend::save_subset(&self.doc, indices).map_err(PdfError::from)?;
+        std::fs::write(path.as_ref(), bytes).map_err(|e| PdfError::IoError(e.to_string()))
+    }
+
     /// Validate the PDF document and report specification violations.
     ///
     /// Checks for common PDF issues such as missing required keys,
@@ -725,6 +995,58 @@ impl Pdf {
 
         Ok(detect_page_regions(&page_data, options))
     }
+
+    /// Detect repeating headers and footers across all pages, cropped to the
+    /// actual tight bounds of the detected text rather than a flat margin
+    /// fraction.
+    ///
+    /// Like [`Self::detect_page_regions`], but the returned `header`/`footer`
+    /// bboxes are the union of the glyph bounding boxes that produced the
+    /// repeating text across the pages that share it, so `body` isn't
+    /// over- or under-cropped relative to where the text actually sits.
+    /// `header_margin`/`footer_margin` still control the scan window used to
+    /// *collect* candidate text.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PdfError`] if any page fails to extract.
+    pub fn detect_page_regions_with_bounds(&self, options: &PageRegionOptions) -> Result<Vec<PageRegions>, PdfError> {
+        let text_options = TextOptions::default();
+        let mut page_data: Vec<PageRegionCandidate> = Vec::new();
+
+        for page_result in self.pages_iter() {
+            let page = page_result?;
+            let width = page.width();
+            let height = page.height();
+
+            let header_height = height * options.header_margin;
+            let header_page = page.crop(BBox::new(0.0, 0.0, width, header_height));
+            let header_text = header_page.extract_text(&text_options);
+            let header_bbox = header_page.chars().iter().map(|c| c.bbox).reduce(|a, b| a.union(&b));
+
+            let footer_height = height * options.footer_margin;
+            let footer_top = height - footer_height;
+            let footer_page = page.crop(BBox::new(0.0, footer_top, width, height));
+            let footer_text = footer_page.extract_text(&text_options);
+            let footer_bbox = footer_page
+                .chars()
+                .iter()
+                .map(|c| c.bbox)
+                .reduce(|a, b| a.union(&b))
+                .map(|b| BBox::new(b.x0, b.top + footer_top, b.x1, b.bottom + footer_top));
+
+            page_data.push(PageRegionCandidate {
+                header_text,
+                header_bbox,
+                footer_text,
+                footer_bbox,
+                width,
+                height,
+            });
+        }
+
+        Ok(detect_page_regions_with_bounds(&page_data, options))
+    }
 }
 
 /// Filter structure tree elements to only include those belonging to a specific page.
@@ -1464,6 +1786,93 @@ mod tests {
         }
     }
 
+    // --- lenient mode tests ---
+
+    #[test]
+    fn page_lenient_mode_converts_interpretation_failure_to_warning() {
+        // References an XObject that isn't declared in /Resources, which
+        // aborts interpretation outright.
+        let bytes = create_pdf_with_content(b"/XObj1 Do");
+        let opts = ExtractOptions {
+            lenient: true,
+            ..ExtractOptions::default()
+        };
+        let pdf = Pdf::open(&bytes, Some(opts)).unwrap();
+
+        let page = pdf.page(0).unwrap();
+        assert!(
+            page.warnings()
+                .iter()
+                .any(|w| w.description.contains("page interpretation failed")),
+            "expected an interpretation-failure warning, got {:?}",
+            page.warnings()
+        );
+        assert_eq!(page.warnings()[0].page, Some(0));
+    }
+
+    #[test]
+    fn page_without_lenient_mode_interpretation_failure_is_an_error() {
+        let bytes = create_pdf_with_content(b"/XObj1 Do");
+        let pdf = Pdf::open(&bytes, None).unwrap();
+
+        assert!(pdf.page(0).is_err());
+    }
+
+    #[test]
+    fn page_lenient_mode_converts_annotation_type_mismatch_to_warning() {
+        use lopdf::{Object, dictionary};
+
+        let mut doc = lopdf::Document::with_version("1.5");
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+            // /Annots should be an array; a bare dictionary is malformed.
+            "Annots" => dictionary! { "NotAnArray" => Object::Boolean(true) },
+        });
+        let pages_id = doc.add_object(dictionary! {
+            "Type" => "Pages",
+            "Kids" => vec![Object::Reference(page_id)],
+            "Count" => 1i64,
+        });
+        if let Ok(obj) = doc.get_object_mut(page_id) {
+            if let Ok(dict) = obj.as_dict_mut() {
+                dict.set("Parent", Object::Reference(pages_id));
+            }
+        }
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => Object::Reference(pages_id),
+        });
+        doc.trailer.set("Root", Object::Reference(catalog_id));
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+
+        let opts = ExtractOptions {
+            lenient: true,
+            ..ExtractOptions::default()
+        };
+        let pdf = Pdf::open(&bytes, Some(opts)).unwrap();
+
+        let page = pdf.page(0).unwrap();
+        assert!(page.annots().is_empty());
+        assert!(page.hyperlinks().is_empty());
+        assert!(
+            page.warnings()
+                .iter()
+                .any(|w| w.description.contains("failed to extract annotations")),
+            "expected an annotation-extraction warning, got {:?}",
+            page.warnings()
+        );
+        assert!(
+            page.warnings()
+                .iter()
+                .any(|w| w.description.contains("failed to extract hyperlinks")),
+            "expected a hyperlink-extraction warning, got {:?}",
+            page.warnings()
+        );
+    }
+
     // --- US-046: Page-level memory management tests ---
 
     #[test]
@@ -2089,6 +2498,34 @@ mod tests {
         assert_eq!(images[0].color_space, Some("DeviceGray".to_string()));
     }
 
+    #[test]
+    fn inline_image_data_not_extracted_by_default() {
+        let bytes = create_pdf_with_inline_image();
+        let pdf = Pdf::open(&bytes, None).unwrap();
+        let page = pdf.page(0).unwrap();
+
+        let images = page.images();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].data, None);
+    }
+
+    #[test]
+    fn inline_image_data_extracted_when_opt_in() {
+        let bytes = create_pdf_with_inline_image();
+        let opts = ExtractOptions {
+            extract_image_data: true,
+            ..ExtractOptions::default()
+        };
+        let pdf = Pdf::open(&bytes, Some(opts)).unwrap();
+        let page = pdf.page(0).unwrap();
+
+        let images = page.images();
+        assert_eq!(images.len(), 1);
+        let data = images[0].data.as_ref().unwrap();
+        // 2x2 RGB image = 12 bytes
+        assert_eq!(data, &[255, 0, 0, 0, 255, 0, 0, 0, 255, 128, 128, 128]);
+    }
+
     // --- Encrypted PDF facade tests ---
 
     /// PDF standard padding bytes.
@@ -2229,6 +2666,126 @@ mod tests {
         buf
     }
 
+    /// Create an encrypted PDF (RC4, 40-bit, V=1, R=2) like
+    /// [`create_encrypted_pdf`], but with a distinct owner password, so `/O`
+    /// can't be recovered by testing the user password against it.
+    fn create_encrypted_pdf_with_owner_password(
+        user_password: &[u8],
+        owner_password: &[u8],
+    ) -> Vec<u8> {
+        use lopdf::{Object, Stream, StringFormat, dictionary};
+
+        let file_id = b"testfileid123456";
+        let permissions: i32 = -4;
+
+        let mut padded_user_pw = Vec::with_capacity(32);
+        let user_pw_len = user_password.len().min(32);
+        padded_user_pw.extend_from_slice(&user_password[..user_pw_len]);
+        padded_user_pw.extend_from_slice(&PAD_BYTES[..32 - user_pw_len]);
+
+        let mut padded_owner_pw = Vec::with_capacity(32);
+        let owner_pw_len = owner_password.len().min(32);
+        padded_owner_pw.extend_from_slice(&owner_password[..owner_pw_len]);
+        padded_owner_pw.extend_from_slice(&PAD_BYTES[..32 - owner_pw_len]);
+
+        let o_key_digest = md5::compute(&padded_owner_pw);
+        let o_key = &o_key_digest[..5];
+        let o_value = rc4_transform(o_key, &padded_user_pw);
+
+        let mut key_input = Vec::with_capacity(128);
+        key_input.extend_from_slice(&padded_user_pw);
+        key_input.extend_from_slice(&o_value);
+        key_input.extend_from_slice(&(permissions as u32).to_le_bytes());
+        key_input.extend_from_slice(file_id);
+        let key_digest = md5::compute(&key_input);
+        let enc_key = key_digest[..5].to_vec();
+
+        let u_value = rc4_transform(&enc_key, &PAD_BYTES);
+
+        let mut doc = lopdf::Document::with_version("1.5");
+        let pages_id: lopdf::ObjectId = doc.new_object_id();
+
+        let content_bytes = b"BT /F1 12 Tf 72 720 Td (Hello World) Tj ET";
+        let stream = Stream::new(dictionary! {}, content_bytes.to_vec());
+        let content_id = doc.add_object(Object::Stream(stream));
+
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+        });
+
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+            "Contents" => Object::Reference(content_id),
+            "Resources" => dictionary! {
+                "Font" => dictionary! {
+                    "F1" => Object::Reference(font_id),
+                },
+            },
+        });
+
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![Object::Reference(page_id)],
+                "Count" => 1_i64,
+            }),
+        );
+
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+
+        for (&obj_id, obj) in doc.objects.iter_mut() {
+            let mut obj_key_input = Vec::with_capacity(10);
+            obj_key_input.extend_from_slice(&enc_key);
+            obj_key_input.extend_from_slice(&obj_id.0.to_le_bytes()[..3]);
+            obj_key_input.extend_from_slice(&obj_id.1.to_le_bytes()[..2]);
+            let obj_key_digest = md5::compute(&obj_key_input);
+            let obj_key_len = (enc_key.len() + 5).min(16);
+            let obj_key = &obj_key_digest[..obj_key_len];
+
+            match obj {
+                Object::Stream(stream) => {
+                    let encrypted = rc4_transform(obj_key, &stream.content);
+                    stream.set_content(encrypted);
+                }
+                Object::String(content, _) => {
+                    *content = rc4_transform(obj_key, content);
+                }
+                _ => {}
+            }
+        }
+
+        let encrypt_id = doc.add_object(dictionary! {
+            "Filter" => "Standard",
+            "V" => 1_i64,
+            "R" => 2_i64,
+            "Length" => 40_i64,
+            "O" => Object::String(o_value, StringFormat::Literal),
+            "U" => Object::String(u_value, StringFormat::Literal),
+            "P" => permissions as i64,
+        });
+        doc.trailer.set("Encrypt", Object::Reference(encrypt_id));
+        doc.trailer.set(
+            "ID",
+            Object::Array(vec![
+                Object::String(file_id.to_vec(), StringFormat::Literal),
+                Object::String(file_id.to_vec(), StringFormat::Literal),
+            ]),
+        );
+
+        let mut buf = Vec::new();
+        doc.save_to(&mut buf).expect("failed to save encrypted PDF");
+        buf
+    }
+
     #[test]
     fn pdf_open_encrypted_without_password_returns_password_required() {
         let bytes = create_encrypted_pdf(b"testpass");
@@ -2240,6 +2797,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pdf_open_encrypted_with_empty_user_password_opens_transparently() {
+        // Encrypted with the empty user password — Pdf::open should succeed
+        // without any password being supplied via options.
+        let bytes = create_encrypted_pdf(b"");
+        let pdf = Pdf::open(&bytes, None).unwrap();
+        assert_eq!(pdf.page_count(), 1);
+    }
+
+    #[test]
+    fn pdf_open_with_options_password_succeeds() {
+        let bytes = create_encrypted_pdf(b"testpass");
+        let opts = ExtractOptions {
+            password: Some("testpass".to_string()),
+            ..ExtractOptions::default()
+        };
+        let pdf = Pdf::open(&bytes, Some(opts)).unwrap();
+        assert_eq!(pdf.page_count(), 1);
+    }
+
+    #[test]
+    fn pdf_open_with_wrong_options_password_returns_invalid_password() {
+        let bytes = create_encrypted_pdf(b"testpass");
+        let opts = ExtractOptions {
+            password: Some("wrongpass".to_string()),
+            ..ExtractOptions::default()
+        };
+        let result = Pdf::open(&bytes, Some(opts));
+        match result {
+            Err(PdfError::InvalidPassword) => {} // expected
+            Err(e) => panic!("expected InvalidPassword, got: {e}"),
+            Ok(_) => panic!("expected error, got Ok"),
+        }
+    }
+
     #[test]
     fn pdf_open_with_password_correct() {
         let password = b"testpass";
@@ -2265,4 +2857,140 @@ mod tests {
         let pdf = Pdf::open_with_password(&bytes, b"anypassword", None).unwrap();
         assert_eq!(pdf.page_count(), 1);
     }
+
+    #[test]
+    fn pdf_authentication_is_none_for_unencrypted_pdf() {
+        let bytes = create_pdf_with_content(b"BT /F1 12 Tf 72 720 Td (Hi) Tj ET");
+        let pdf = Pdf::open(&bytes, None).unwrap();
+        assert_eq!(pdf.authentication(), Authentication::None);
+    }
+
+    #[test]
+    fn pdf_authentication_is_user_for_correct_user_password() {
+        let password = b"testpass";
+        let bytes = create_encrypted_pdf(password);
+        let pdf = Pdf::open_with_password(&bytes, password, None).unwrap();
+        assert_eq!(pdf.authentication(), Authentication::User);
+    }
+
+    #[test]
+    fn pdf_authentication_is_owner_when_opened_with_owner_password() {
+        let bytes = create_encrypted_pdf_with_owner_password(b"userpw", b"ownerpw");
+        let pdf = Pdf::open_with_password(&bytes, b"ownerpw", None).unwrap();
+        assert_eq!(pdf.page_count(), 1);
+        assert_eq!(pdf.authentication(), Authentication::Owner);
+    }
+
+    #[test]
+    fn pdf_permissions_is_default_for_unencrypted_pdf() {
+        let bytes = create_pdf_with_content(b"BT /F1 12 Tf 72 720 Td (Hi) Tj ET");
+        let pdf = Pdf::open(&bytes, None).unwrap();
+        assert_eq!(pdf.permissions(), Permissions::default());
+        assert!(pdf.permissions().can_print());
+        assert!(pdf.permissions().can_copy());
+    }
+
+    #[test]
+    fn pdf_permissions_decodes_p_value_from_encrypted_pdf() {
+        // create_encrypted_pdf bakes in P = -4.
+        let password = b"testpass";
+        let bytes = create_encrypted_pdf(password);
+        let pdf = Pdf::open_with_password(&bytes, password, None).unwrap();
+        assert_eq!(pdf.permissions().raw(), -4);
+        assert!(pdf.permissions().can_print());
+        assert!(pdf.permissions().can_copy());
+        assert!(pdf.permissions().can_assemble());
+    }
+
+    #[test]
+    fn pdf_open_with_misplaced_header_fails_without_repair() {
+        let mut bytes = b"garbage before the real header\n".to_vec();
+        bytes.extend(create_pdf_with_content(b"BT /F1 12 Tf 72 720 Td (Hi) Tj ET"));
+        let result = Pdf::open(&bytes, None);
+        assert!(result.is_err(), "expected a malformed PDF to fail to open");
+    }
+
+    #[test]
+    fn pdf_open_with_misplaced_header_recovers_with_repair() {
+        let mut bytes = b"garbage before the real header\n".to_vec();
+        bytes.extend(create_pdf_with_content(b"BT /F1 12 Tf 72 720 Td (Hi) Tj ET"));
+        let opts = ExtractOptions {
+            repair: true,
+            ..ExtractOptions::default()
+        };
+        let pdf = Pdf::open(&bytes, Some(opts)).unwrap();
+        assert_eq!(pdf.page_count(), 1);
+    }
+
+    #[test]
+    fn pdf_open_with_broken_xref_fails_without_repair() {
+        let mut bytes = create_pdf_with_content(b"BT /F1 12 Tf 72 720 Td (Hi) Tj ET");
+        let xref_pos = bytes
+            .windows(9)
+            .rposition(|w| w == b"startxref")
+            .expect("generated PDF should have a startxref marker");
+        bytes.truncate(xref_pos);
+        bytes.extend_from_slice(b"startxref\n0\n%%EOF");
+        let result = Pdf::open(&bytes, None);
+        assert!(result.is_err(), "expected a PDF with a broken xref to fail to open");
+    }
+
+    #[test]
+    fn pdf_open_with_broken_xref_recovers_with_repair() {
+        let mut bytes = create_pdf_with_content(b"BT /F1 12 Tf 72 720 Td (Hi) Tj ET");
+        let xref_pos = bytes
+            .windows(9)
+            .rposition(|w| w == b"startxref")
+            .expect("generated PDF should have a startxref marker");
+        bytes.truncate(xref_pos);
+        bytes.extend_from_slice(b"startxref\n0\n%%EOF");
+        let opts = ExtractOptions {
+            repair: true,
+            ..ExtractOptions::default()
+        };
+        let pdf = Pdf::open(&bytes, Some(opts)).unwrap();
+        assert_eq!(pdf.page_count(), 1);
+    }
+
+    #[test]
+    fn pdf_open_repair_true_does_not_affect_already_valid_pdfs() {
+        let bytes = create_pdf_with_content(b"BT /F1 12 Tf 72 720 Td (Hi) Tj ET");
+        let opts = ExtractOptions {
+            repair: true,
+            ..ExtractOptions::default()
+        };
+        let pdf = Pdf::open(&bytes, Some(opts)).unwrap();
+        assert_eq!(pdf.page_count(), 1);
+    }
+
+    #[test]
+    fn pdf_opened_via_recovery_surfaces_malformed_object_warning() {
+        let mut bytes = b"garbage before the real header\n".to_vec();
+        bytes.extend(create_pdf_with_content(b"BT /F1 12 Tf 72 720 Td (Hi) Tj ET"));
+        let opts = ExtractOptions {
+            repair: true,
+            ..ExtractOptions::default()
+        };
+        let pdf = Pdf::open(&bytes, Some(opts)).unwrap();
+        let page = pdf.page(0).unwrap();
+        let recovery_warning = page.warnings().iter().find(|w| {
+            w.code == ExtractWarningCode::MalformedObject && w.description.contains("recovery")
+        });
+        assert!(
+            recovery_warning.is_some(),
+            "expected a recovery warning on a page from a document opened via best-effort recovery"
+        );
+    }
+
+    #[test]
+    fn pdf_opened_cleanly_has_no_recovery_warning() {
+        let bytes = create_pdf_with_content(b"BT /F1 12 Tf 72 720 Td (Hi) Tj ET");
+        let opts = ExtractOptions {
+            repair: true,
+            ..ExtractOptions::default()
+        };
+        let pdf = Pdf::open(&bytes, Some(opts)).unwrap();
+        let page = pdf.page(0).unwrap();
+        assert!(!page.warnings().iter().any(|w| w.description.contains("recovery")));
+    }
 }
@@ -154,6 +154,8 @@ fn test_page_with_all_object_types() {
         src_height: Some(1080),
         bits_per_component: Some(8),
         color_space: Some("DeviceRGB".to_string()),
+        is_mask: false,
+        decode: None,
     };
     let img = image_from_ctm(&ctm, "Im0", page_height, &meta);
 
@@ -224,6 +226,8 @@ fn test_image_extraction_from_ctm_typical_pdf() {
         src_height: Some(2000),
         bits_per_component: Some(8),
         color_space: Some("DeviceRGB".to_string()),
+        is_mask: false,
+        decode: None,
     };
 
     let img = image_from_ctm(&ctm, "photo", page_height, &meta);
@@ -254,6 +258,8 @@ fn test_page_with_multiple_images() {
                 src_height: Some(600),
                 bits_per_component: Some(8),
                 color_space: Some("DeviceRGB".to_string()),
+                is_mask: false,
+                decode: None,
             };
             image_from_ctm(&ctm, &format!("Im{i}"), page_height, &meta)
         })
@@ -1288,6 +1288,197 @@ fn bookmarks_named_destination() {
     assert_eq!(bookmarks[0].dest_top, Some(500.0));
 }
 
+// --- Outline tests (chunk113-1) ---
+
+#[test]
+fn outline_multi_level_tree() {
+    let bytes = pdf_with_bookmarks();
+    let pdf = Pdf::open(&bytes, None).unwrap();
+    let outline = pdf.outline().unwrap();
+
+    // Two top-level entries: Chapter 1 (with a nested child) and Chapter 2.
+    assert_eq!(outline.len(), 2);
+
+    assert_eq!(outline[0].title, "Chapter 1");
+    assert_eq!(outline[0].page_number, Some(0));
+    assert_eq!(outline[0].count, 1);
+    assert_eq!(outline[0].children.len(), 1);
+    assert_eq!(outline[0].children[0].title, "Section 1.1");
+    assert_eq!(outline[0].children[0].page_number, Some(1));
+    assert_eq!(outline[0].children[0].dest_top, Some(700.0));
+    assert!(outline[0].children[0].children.is_empty());
+
+    assert_eq!(outline[1].title, "Chapter 2");
+    assert_eq!(outline[1].page_number, Some(2));
+    assert_eq!(outline[1].dest_top, Some(792.0));
+    assert!(outline[1].children.is_empty());
+}
+
+#[test]
+fn outline_no_outlines() {
+    let bytes = pdf_with_content(b"BT /F1 12 Tf 72 720 Td (Hello) Tj ET");
+    let pdf = Pdf::open(&bytes, None).unwrap();
+
+    assert!(pdf.outline().unwrap().is_empty());
+}
+
+#[test]
+fn outline_color_and_style_flags() {
+    use lopdf::{Object, Stream, dictionary};
+
+    let mut doc = lopdf::Document::with_version("1.5");
+
+    let font_id = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Helvetica",
+    });
+
+    let stream = Stream::new(dictionary! {}, b"BT /F1 12 Tf 72 720 Td (Test) Tj ET".to_vec());
+    let content_id = doc.add_object(stream);
+
+    let resources = dictionary! {
+        "Font" => dictionary! { "F1" => Object::Reference(font_id) },
+    };
+
+    let page_dict = dictionary! {
+        "Type" => "Page",
+        "MediaBox" => vec![Object::Integer(0), Object::Integer(0), Object::Integer(612), Object::Integer(792)],
+        "Contents" => Object::Reference(content_id),
+        "Resources" => resources,
+    };
+    let page_id = doc.add_object(page_dict);
+
+    let pages_dict = dictionary! {
+        "Type" => "Pages",
+        "Kids" => vec![Object::Reference(page_id)],
+        "Count" => Object::Integer(1),
+    };
+    let pages_id = doc.add_object(pages_dict);
+
+    if let Ok(page_obj) = doc.get_object_mut(page_id) {
+        if let Ok(dict) = page_obj.as_dict_mut() {
+            dict.set("Parent", Object::Reference(pages_id));
+        }
+    }
+
+    // Outline entry with a red color and italic+bold flags (bits 1 and 2 set).
+    let outline_item_id = doc.add_object(dictionary! {
+        "Title" => Object::string_literal("Styled"),
+        "C" => vec![Object::Real(1.0), Object::Real(0.0), Object::Real(0.0)],
+        "F" => Object::Integer(3),
+        "Dest" => vec![Object::Reference(page_id), Object::Name(b"Fit".to_vec())],
+    });
+
+    let outlines_id = doc.add_object(dictionary! {
+        "Type" => "Outlines",
+        "First" => Object::Reference(outline_item_id),
+        "Last" => Object::Reference(outline_item_id),
+        "Count" => Object::Integer(1),
+    });
+
+    let catalog_id = doc.add_object(dictionary! {
+        "Type" => "Catalog",
+        "Pages" => Object::Reference(pages_id),
+        "Outlines" => Object::Reference(outlines_id),
+    });
+    doc.trailer.set("Root", Object::Reference(catalog_id));
+
+    let mut buf = Vec::new();
+    doc.save_to(&mut buf).unwrap();
+
+    let pdf = Pdf::open(&buf, None).unwrap();
+    let outline = pdf.outline().unwrap();
+
+    assert_eq!(outline.len(), 1);
+    assert_eq!(outline[0].title, "Styled");
+    assert!(outline[0].italic);
+    assert!(outline[0].bold);
+    let color = outline[0].color.expect("color should be set");
+    assert_eq!(color.r, 1.0);
+    assert_eq!(color.g, 0.0);
+    assert_eq!(color.b, 0.0);
+}
+
+#[test]
+fn outline_cyclic_next_link_does_not_loop() {
+    use lopdf::{Object, Stream, dictionary};
+
+    let mut doc = lopdf::Document::with_version("1.5");
+
+    let font_id = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Helvetica",
+    });
+
+    let stream = Stream::new(dictionary! {}, b"BT /F1 12 Tf 72 720 Td (Test) Tj ET".to_vec());
+    let content_id = doc.add_object(stream);
+
+    let resources = dictionary! {
+        "Font" => dictionary! { "F1" => Object::Reference(font_id) },
+    };
+
+    let page_dict = dictionary! {
+        "Type" => "Page",
+        "MediaBox" => vec![Object::Integer(0), Object::Integer(0), Object::Integer(612), Object::Integer(792)],
+        "Contents" => Object::Reference(content_id),
+        "Resources" => resources,
+    };
+    let page_id = doc.add_object(page_dict);
+
+    let pages_dict = dictionary! {
+        "Type" => "Pages",
+        "Kids" => vec![Object::Reference(page_id)],
+        "Count" => Object::Integer(1),
+    };
+    let pages_id = doc.add_object(pages_dict);
+
+    if let Ok(page_obj) = doc.get_object_mut(page_id) {
+        if let Ok(dict) = page_obj.as_dict_mut() {
+            dict.set("Parent", Object::Reference(pages_id));
+        }
+    }
+
+    let item_a_id = doc.add_object(dictionary! {
+        "Title" => Object::string_literal("A"),
+    });
+    let item_b_id = doc.add_object(dictionary! {
+        "Title" => Object::string_literal("B"),
+        "Next" => Object::Reference(item_a_id),
+    });
+    // Make the chain cyclic: A -> B -> A -> ...
+    if let Ok(obj) = doc.get_object_mut(item_a_id) {
+        if let Ok(dict) = obj.as_dict_mut() {
+            dict.set("Next", Object::Reference(item_b_id));
+        }
+    }
+
+    let outlines_id = doc.add_object(dictionary! {
+        "Type" => "Outlines",
+        "First" => Object::Reference(item_a_id),
+        "Last" => Object::Reference(item_b_id),
+        "Count" => Object::Integer(2),
+    });
+
+    let catalog_id = doc.add_object(dictionary! {
+        "Type" => "Catalog",
+        "Pages" => Object::Reference(pages_id),
+        "Outlines" => Object::Reference(outlines_id),
+    });
+    doc.trailer.set("Root", Object::Reference(catalog_id));
+
+    let mut buf = Vec::new();
+    doc.save_to(&mut buf).unwrap();
+
+    let pdf = Pdf::open(&buf, None).unwrap();
+    // Must terminate and return exactly the two distinct entries, not loop forever.
+    let outline = pdf.outline().unwrap();
+    assert_eq!(outline.len(), 2);
+    assert_eq!(outline[0].title, "A");
+    assert_eq!(outline[1].title, "B");
+}
+
 // --- US-063: Text search with position ---
 
 #[test]
@@ -3,7 +3,7 @@
 //! Tests the full Pdf::open_with_repair() pipeline with programmatically
 //! created PDFs containing various issues that repair should fix.
 
-use pdfplumber::{Pdf, RepairOptions};
+use pdfplumber::{DanglingRefPolicy, Pdf, RepairOptions};
 
 // --- Test PDF creation helpers ---
 
@@ -221,6 +221,8 @@ fn open_with_repair_fixes_stream_length() {
         fix_stream_lengths: true,
         rebuild_xref: false,
         remove_broken_objects: false,
+        dangling_ref_policy: DanglingRefPolicy::default(),
+        preserve_orphans: true,
     };
     let (pdf, result) = Pdf::open_with_repair(&bytes, None, Some(opts)).unwrap();
     assert_eq!(pdf.page_count(), 1);
@@ -240,6 +242,41 @@ fn open_with_repair_fixes_stream_length() {
     );
 }
 
+#[test]
+fn open_with_repair_preserves_orphaned_stream_content() {
+    let bytes = pdf_with_missing_stream_length();
+    let opts = RepairOptions {
+        fix_stream_lengths: true,
+        rebuild_xref: false,
+        remove_broken_objects: false,
+        dangling_ref_policy: DanglingRefPolicy::default(),
+        preserve_orphans: true,
+    };
+    let (pdf, result) = Pdf::open_with_repair(&bytes, None, Some(opts)).unwrap();
+    assert_eq!(result.orphans.len(), 1);
+    assert_eq!(pdf.orphaned_objects(), result.orphans.as_slice());
+    assert_eq!(
+        result.orphans[0].original_content,
+        b"BT /F1 12 Tf 72 720 Td (Hello) Tj ET"
+    );
+}
+
+#[test]
+fn open_with_repair_preserve_orphans_disabled_leaves_orphans_empty() {
+    let bytes = pdf_with_missing_stream_length();
+    let opts = RepairOptions {
+        fix_stream_lengths: true,
+        rebuild_xref: false,
+        remove_broken_objects: false,
+        dangling_ref_policy: DanglingRefPolicy::default(),
+        preserve_orphans: false,
+    };
+    let (pdf, result) = Pdf::open_with_repair(&bytes, None, Some(opts)).unwrap();
+    assert!(result.has_repairs());
+    assert!(result.orphans.is_empty());
+    assert!(pdf.orphaned_objects().is_empty());
+}
+
 #[test]
 fn open_with_repair_removes_broken_references() {
     let bytes = pdf_with_broken_reference();
@@ -247,6 +284,8 @@ fn open_with_repair_removes_broken_references() {
         fix_stream_lengths: false,
         rebuild_xref: false,
         remove_broken_objects: true,
+        dangling_ref_policy: DanglingRefPolicy::default(),
+        preserve_orphans: true,
     };
     let (pdf, result) = Pdf::open_with_repair(&bytes, None, Some(opts)).unwrap();
     assert_eq!(pdf.page_count(), 1);
@@ -266,6 +305,29 @@ fn open_with_repair_removes_broken_references() {
     );
 }
 
+#[test]
+fn open_with_repair_remove_policy_drops_dangling_reference() {
+    let bytes = pdf_with_broken_reference();
+    let opts = RepairOptions {
+        fix_stream_lengths: false,
+        rebuild_xref: false,
+        remove_broken_objects: true,
+        dangling_ref_policy: DanglingRefPolicy::Remove,
+        preserve_orphans: true,
+    };
+    let (pdf, result) = Pdf::open_with_repair(&bytes, None, Some(opts)).unwrap();
+    assert_eq!(pdf.page_count(), 1);
+    let has_removal_fix = result
+        .log
+        .iter()
+        .any(|l| l.contains("999") && l.contains("removed"));
+    assert!(
+        has_removal_fix,
+        "expected repair log to mention removing the dangling reference, got: {:?}",
+        result.log
+    );
+}
+
 #[test]
 fn open_with_repair_default_options_repairs_all() {
     let bytes = pdf_with_missing_stream_length();
@@ -297,6 +359,8 @@ fn open_with_repair_all_options_disabled() {
         rebuild_xref: false,
         fix_stream_lengths: false,
         remove_broken_objects: false,
+        dangling_ref_policy: DanglingRefPolicy::default(),
+        preserve_orphans: true,
     };
     let (pdf, result) = Pdf::open_with_repair(&bytes, None, Some(opts)).unwrap();
     assert_eq!(pdf.page_count(), 1);